@@ -0,0 +1,241 @@
+use {
+    base64::{engine::general_purpose::STANDARD, Engine},
+    pinocchio_counter_program::{CounterEventV1, EventDiscriminator, EventDiscriminatorError},
+    solana_pubkey::Pubkey,
+};
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+#[derive(Debug, thiserror::Error)]
+pub enum CounterEventDecodeError {
+    #[error("Event data is not valid base64: {0}")]
+    InvalidBase64(#[from] base64::DecodeError),
+
+    #[error("Event data is empty")]
+    MissingDiscriminator,
+
+    #[error("Unknown event discriminator: {0}")]
+    InvalidDiscriminator(u8),
+
+    #[error("Event payload could not be deserialized: {0:?}")]
+    DeserializeError(wincode::ReadError),
+
+    #[error("Event payload size mismatch: expected {expected}, observed {observed}")]
+    PayloadSizeMismatch { expected: usize, observed: usize },
+}
+
+impl From<EventDiscriminatorError> for CounterEventDecodeError {
+    fn from(err: EventDiscriminatorError) -> Self {
+        match err {
+            EventDiscriminatorError::Missing => Self::MissingDiscriminator,
+            EventDiscriminatorError::Invalid(byte) => Self::InvalidDiscriminator(byte),
+        }
+    }
+}
+
+/// A counter event emitted by the program, tagged with the kind of state change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterEvent {
+    pub discriminator: EventDiscriminator,
+    pub event: CounterEventV1,
+}
+
+impl CounterEvent {
+    /// Decodes an event from the raw bytes passed to `sol_log_data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CounterEventDecodeError`] if the discriminator is unknown or the payload does
+    /// not have the expected layout.
+    pub fn decode(data: &[u8]) -> Result<Self, CounterEventDecodeError> {
+        let (discriminator, payload) = EventDiscriminator::parse(data)?;
+
+        if payload.len() != CounterEventV1::size() {
+            return Err(CounterEventDecodeError::PayloadSizeMismatch {
+                expected: CounterEventV1::size(),
+                observed: payload.len(),
+            });
+        }
+
+        let event = CounterEventV1::deserialize(payload)
+            .map_err(CounterEventDecodeError::DeserializeError)?;
+
+        Ok(Self {
+            discriminator,
+            event,
+        })
+    }
+}
+
+/// Parses the counter events emitted by `program_id` out of transaction logs, in emission order.
+///
+/// Program invocations are tracked through the runtime's `invoke`, `success` and `failed` log
+/// lines, so `Program data:` lines written by other programs, including ones invoked through
+/// CPI, are skipped.
+///
+/// # Errors
+///
+/// Returns [`CounterEventDecodeError`] if program data logged by `program_id` is not a valid
+/// counter event.
+pub fn parse_counter_events(
+    program_id: &Pubkey,
+    logs: &[String],
+) -> Result<Vec<CounterEvent>, CounterEventDecodeError> {
+    let program_id = program_id.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if invocations.last() == Some(&program_id.as_str()) {
+                for field in data.split_whitespace() {
+                    events.push(CounterEvent::decode(&STANDARD.decode(field)?)?);
+                }
+            }
+            continue;
+        }
+
+        let Some(rest) = log.strip_prefix("Program ") else {
+            continue;
+        };
+        let mut words = rest.split_whitespace();
+        let (Some(invoked_program), Some(status)) = (words.next(), words.next()) else {
+            continue;
+        };
+
+        match status {
+            "invoke" => invocations.push(invoked_program),
+            "success" | "failed:" => {
+                invocations.pop();
+            }
+            _ => {}
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_data(discriminator: EventDiscriminator, new_count: u64) -> Vec<u8> {
+        CounterEventV1 {
+            counter: [1; 32],
+            owner: [2; 32],
+            old_count: 0,
+            new_count,
+            slot: 7,
+        }
+        .serialize(discriminator)
+        .unwrap()
+    }
+
+    fn data_log(data: &[u8]) -> String {
+        format!("{PROGRAM_DATA_PREFIX}{}", STANDARD.encode(data))
+    }
+
+    #[test]
+    fn test_decode_roundtrip() {
+        let data = event_data(EventDiscriminator::CountIncrementedV1, 1);
+
+        let decoded = CounterEvent::decode(&data).unwrap();
+
+        assert_eq!(
+            decoded.discriminator,
+            EventDiscriminator::CountIncrementedV1
+        );
+        assert_eq!(decoded.event.counter, [1; 32]);
+        assert_eq!(decoded.event.owner, [2; 32]);
+        assert_eq!(decoded.event.old_count, 0);
+        assert_eq!(decoded.event.new_count, 1);
+        assert_eq!(decoded.event.slot, 7);
+    }
+
+    #[test]
+    fn test_decode_fails_for_invalid_discriminator() {
+        let mut data = event_data(EventDiscriminator::CountSetV1, 1);
+        data[0] = 0;
+
+        let err = CounterEvent::decode(&data).unwrap_err();
+        assert!(matches!(
+            err,
+            CounterEventDecodeError::InvalidDiscriminator(0)
+        ));
+    }
+
+    #[test]
+    fn test_decode_fails_for_empty_data() {
+        let err = CounterEvent::decode(&[]).unwrap_err();
+        assert!(matches!(err, CounterEventDecodeError::MissingDiscriminator));
+    }
+
+    #[test]
+    fn test_decode_fails_for_truncated_payload() {
+        let data = event_data(EventDiscriminator::CountSetV1, 1);
+
+        let err = CounterEvent::decode(&data[..data.len() - 1]).unwrap_err();
+        assert!(matches!(
+            err,
+            CounterEventDecodeError::PayloadSizeMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_counter_events_only_returns_events_of_program() {
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            data_log(&event_data(EventDiscriminator::CounterInitializedV1, 0)),
+            format!("Program {other_program_id} invoke [2]"),
+            data_log(b"not a counter event"),
+            format!("Program {other_program_id} success"),
+            data_log(&event_data(EventDiscriminator::CountIncrementedV1, 1)),
+            format!("Program {program_id} consumed 1000 of 200000 compute units"),
+            format!("Program {program_id} success"),
+            format!("Program {other_program_id} invoke [1]"),
+            data_log(b"also not a counter event"),
+            format!("Program {other_program_id} failed: custom program error: 0x1"),
+        ];
+
+        let events = parse_counter_events(&program_id, &logs).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0].discriminator,
+            EventDiscriminator::CounterInitializedV1
+        );
+        assert_eq!(
+            events[1].discriminator,
+            EventDiscriminator::CountIncrementedV1
+        );
+        assert_eq!(events[1].event.new_count, 1);
+    }
+
+    #[test]
+    fn test_parse_counter_events_returns_empty_without_program_data() {
+        let program_id = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            "Program log: hello".to_string(),
+            format!("Program {program_id} success"),
+        ];
+
+        let events = parse_counter_events(&program_id, &logs).unwrap();
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_parse_counter_events_fails_for_invalid_base64() {
+        let program_id = Pubkey::new_unique();
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            format!("{PROGRAM_DATA_PREFIX}!!!"),
+            format!("Program {program_id} success"),
+        ];
+
+        let err = parse_counter_events(&program_id, &logs).unwrap_err();
+        assert!(matches!(err, CounterEventDecodeError::InvalidBase64(_)));
+    }
+}
@@ -0,0 +1,241 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AcceptAuthorityV1IxError {
+    #[error("New authority must be a signer")]
+    NewAuthorityMustBeSigner,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+}
+
+/// Instruction builder for `AcceptAuthorityV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for accepting a pending
+/// authority transfer. It must be signed by the proposed authority.
+#[derive(Debug, Clone)]
+pub struct AcceptAuthorityV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub new_authority: AccountMeta,
+    pub counter: AccountMeta,
+}
+
+impl AcceptAuthorityV1Ix {
+    /// Creates a new instruction builder for `AcceptAuthorityV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `new_authority` - The public key of the pending authority accepting the transfer.
+    ///
+    /// # Returns
+    ///
+    /// A new `AcceptAuthorityV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, creator: Pubkey, new_authority: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &creator);
+
+        Self {
+            program_id,
+            creator,
+            new_authority: AccountMeta {
+                pubkey: new_authority,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+        }
+    }
+
+    /// Sets the new authority account metadata.
+    #[must_use]
+    pub fn with_new_authority(mut self, new_authority: AccountMeta) -> Self {
+        self.new_authority = new_authority;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AcceptAuthorityV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), AcceptAuthorityV1IxError> {
+        if !self.new_authority.is_signer {
+            return Err(AcceptAuthorityV1IxError::NewAuthorityMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(AcceptAuthorityV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(AcceptAuthorityV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AcceptAuthorityV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, AcceptAuthorityV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.new_authority,
+                self.counter,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: vec![InstructionDiscriminator::AcceptAuthorityV1.into()],
+        })
+    }
+}
+
+impl TryFrom<AcceptAuthorityV1Ix> for Instruction {
+    type Error = AcceptAuthorityV1IxError;
+
+    fn try_from(value: AcceptAuthorityV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+
+        let accept_ix = AcceptAuthorityV1Ix::new(program_id, creator, new_authority);
+
+        assert_eq!(accept_ix.program_id, program_id);
+        assert_eq!(accept_ix.creator, creator);
+        assert_eq!(accept_ix.new_authority.pubkey, new_authority);
+        assert!(accept_ix.new_authority.is_signer);
+        assert!(!accept_ix.new_authority.is_writable);
+        assert_eq!(
+            accept_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &creator)
+        );
+        assert!(accept_ix.counter.is_writable);
+        assert!(accept_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_new_authority_not_signer() {
+        let mut accept_ix = AcceptAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        accept_ix.new_authority.is_signer = false;
+
+        let err = accept_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "New authority must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let mut accept_ix = AcceptAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        accept_ix.counter.is_writable = false;
+
+        let err = accept_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let mut accept_ix = AcceptAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        accept_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = accept_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+
+        let instruction = AcceptAuthorityV1Ix::new(program_id, creator, new_authority)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, new_authority);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_counter_v1_address(&program_id, &creator)
+        );
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::AcceptAuthorityV1)]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut accept_ix = AcceptAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        accept_ix.new_authority.is_signer = false;
+
+        let err = Instruction::try_from(accept_ix).unwrap_err();
+        match err {
+            AcceptAuthorityV1IxError::NewAuthorityMustBeSigner => {}
+            _ => panic!("Expected NewAuthorityMustBeSigner, got {err:?}"),
+        }
+    }
+}
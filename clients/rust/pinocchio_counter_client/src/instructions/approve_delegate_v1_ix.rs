@@ -0,0 +1,542 @@
+use {
+    crate::{
+        config_v1_account_meta, find_counter_v1_address, find_delegate_v1_address,
+        find_multisig_v1_address,
+    },
+    pinocchio_counter_program::{ApproveDelegateV1Args, InstructionDiscriminator},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApproveDelegateV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Owner must be writable")]
+    OwnerMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Delegate account must be writable")]
+    DelegateMustBeWriteable,
+
+    #[error("Delegate address mismatch. Expected: {expected}, Observed: {observed}")]
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("First multisig signer must be writable")]
+    MultisigSignerMustBeWriteable,
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `ApproveDelegateV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for approving a
+/// delegate that may increment or decrement a counter on the owner's behalf. The owner
+/// pays the rent of the delegate record the first time a delegate is approved.
+#[derive(Debug, Clone)]
+pub struct ApproveDelegateV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub delegate: AccountMeta,
+    pub system_program: AccountMeta,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`ApproveDelegateV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+    pub delegate_authority: Pubkey,
+    pub allowance: u64,
+    pub expiry_slot: u64,
+}
+
+impl ApproveDelegateV1Ix {
+    /// Creates a new instruction builder for `ApproveDelegateV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `delegate_authority` - The public key allowed to sign as the delegate.
+    /// * `allowance` - The number of increments or decrements the delegate may perform.
+    /// * `expiry_slot` - The last slot at which the delegate may act.
+    ///
+    /// # Returns
+    ///
+    /// A new `ApproveDelegateV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(
+        program_id: Pubkey,
+        owner: Pubkey,
+        delegate_authority: Pubkey,
+        allowance: u64,
+        expiry_slot: u64,
+    ) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+        let delegate = find_delegate_v1_address(&program_id, &counter);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: false,
+            },
+            delegate: AccountMeta {
+                pubkey: delegate,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            delegate_authority,
+            allowance,
+            expiry_slot,
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the delegate record account metadata.
+    #[must_use]
+    pub fn with_delegate(mut self, delegate: AccountMeta) -> Self {
+        self.delegate = delegate;
+        self
+    }
+
+    /// Sets the public key allowed to sign as the delegate.
+    #[must_use]
+    pub fn with_delegate_authority(mut self, delegate_authority: Pubkey) -> Self {
+        self.delegate_authority = delegate_authority;
+        self
+    }
+
+    /// Sets the allowance value.
+    #[must_use]
+    pub fn with_allowance(mut self, allowance: u64) -> Self {
+        self.allowance = allowance;
+        self
+    }
+
+    /// Sets the expiry slot value.
+    #[must_use]
+    pub fn with_expiry_slot(mut self, expiry_slot: u64) -> Self {
+        self.expiry_slot = expiry_slot;
+        self
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    /// The first signer pays for the delegate account, so it is writable.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| AccountMeta {
+                pubkey: *signer,
+                is_signer: true,
+                is_writable: index == 0,
+            })
+            .collect();
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApproveDelegateV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), ApproveDelegateV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(ApproveDelegateV1IxError::OwnerMustBeSigner);
+        }
+
+        if self.multisig_signers.is_empty() && !self.owner.is_writable {
+            return Err(ApproveDelegateV1IxError::OwnerMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(ApproveDelegateV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(ApproveDelegateV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(ApproveDelegateV1IxError::MultisigSignerMustBeSigner);
+            }
+
+            if !self.multisig_signers[0].is_writable {
+                return Err(ApproveDelegateV1IxError::MultisigSignerMustBeWriteable);
+            }
+        }
+
+        if !self.delegate.is_writable {
+            return Err(ApproveDelegateV1IxError::DelegateMustBeWriteable);
+        }
+
+        let expected_delegate = find_delegate_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_delegate = self.delegate.pubkey;
+        if observed_delegate != expected_delegate {
+            return Err(ApproveDelegateV1IxError::DelegateAddressMismatch {
+                expected: expected_delegate,
+                observed: observed_delegate,
+            });
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(ApproveDelegateV1IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApproveDelegateV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, ApproveDelegateV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = ApproveDelegateV1Args {
+            delegate: self.delegate_authority.to_bytes(),
+            allowance: self.allowance,
+            expiry_slot: self.expiry_slot,
+        };
+        let args_data =
+            serialize(&args).map_err(|_| ApproveDelegateV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::ApproveDelegateV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![self.owner, self.counter, self.delegate, self.system_program];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<ApproveDelegateV1Ix> for Instruction {
+    type Error = ApproveDelegateV1IxError;
+
+    fn try_from(value: ApproveDelegateV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_delegate_v1_address},
+    };
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate_authority = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_delegate = find_delegate_v1_address(&program_id, &expected_counter);
+
+        let approve_ix = ApproveDelegateV1Ix::new(program_id, owner, delegate_authority, 5, 100);
+
+        assert_eq!(approve_ix.program_id, program_id);
+        assert_eq!(approve_ix.owner.pubkey, owner);
+        assert_eq!(approve_ix.counter.pubkey, expected_counter);
+        assert_eq!(approve_ix.delegate.pubkey, expected_delegate);
+        assert_eq!(
+            approve_ix.system_program.pubkey,
+            solana_system_program::id()
+        );
+        assert_eq!(approve_ix.delegate_authority, delegate_authority);
+        assert_eq!(approve_ix.allowance, 5);
+        assert_eq!(approve_ix.expiry_slot, 100);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, u64::MAX);
+
+        assert!(approve_ix.owner.is_signer);
+        assert!(approve_ix.owner.is_writable);
+        assert!(!approve_ix.counter.is_signer);
+        assert!(!approve_ix.counter.is_writable);
+        assert!(!approve_ix.delegate.is_signer);
+        assert!(approve_ix.delegate.is_writable);
+
+        assert!(approve_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.owner.is_signer = false;
+
+        let err = approve_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.owner.is_writable = false;
+
+        let err = approve_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = approve_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_delegate_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.delegate.is_writable = false;
+
+        let err = approve_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Delegate account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_delegate_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.delegate.pubkey = Pubkey::new_unique();
+
+        let err = approve_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Delegate address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = approve_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate_authority = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_delegate = find_delegate_v1_address(&program_id, &expected_counter);
+
+        let approve_ix = ApproveDelegateV1Ix::new(program_id, owner, delegate_authority, 3, 42);
+        let instruction = approve_ix.to_instruction(true).unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(instruction.accounts[2].pubkey, expected_delegate);
+        assert_eq!(instruction.accounts[3].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::ApproveDelegateV1)
+        );
+
+        let args = ApproveDelegateV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.delegate, delegate_authority.to_bytes());
+        assert_eq!(args.allowance, 3);
+        assert_eq!(args.expiry_slot, 42);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 1, 1);
+        approve_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(approve_ix).unwrap_err();
+        match err {
+            ApproveDelegateV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 10, u64::MAX)
+                .with_authority(authority);
+
+        assert_eq!(approve_ix.owner.pubkey, authority);
+        assert_eq!(approve_ix.creator, owner);
+        assert_eq!(
+            approve_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(approve_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let approve_ix = ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 3, 42)
+            .with_multisig_signers(&signers);
+        assert!(approve_ix.validate().is_ok());
+
+        let instruction = approve_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 7);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (index, (meta, signer)) in instruction.accounts[4..].iter().zip(&signers).enumerate() {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert_eq!(meta.is_writable, index == 0);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_first_multisig_signer_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut approve_ix =
+            ApproveDelegateV1Ix::new(program_id, owner, Pubkey::new_unique(), 3, 42)
+                .with_multisig_signers(&[Pubkey::new_unique()]);
+        approve_ix.multisig_signers[0].is_writable = false;
+
+        let err = approve_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "First multisig signer must be writable");
+    }
+}
@@ -0,0 +1,512 @@
+use {
+    crate::{
+        config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address,
+        find_tombstone_v1_address,
+    },
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CloseCounterV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Owner must be writable")]
+    OwnerMustBeWriteable,
+
+    #[error("Counter must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch: expected {expected:?}, observed {observed:?}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Destination must be writable")]
+    DestinationMustBeWriteable,
+
+    #[error("Destination must not be the counter")]
+    DestinationMustNotBeCounter,
+
+    #[error("Tombstone must be writable")]
+    TombstoneMustBeWriteable,
+
+    #[error("Tombstone address mismatch: expected {expected:?}, observed {observed:?}")]
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("First multisig signer must be writable")]
+    MultisigSignerMustBeWriteable,
+}
+
+/// Instruction builder for `CloseCounterV1`.
+///
+/// Closes the counter entirely, sending all of its lamports to the destination and creating its
+/// tombstone so that it cannot be initialized again.
+pub struct CloseCounterV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub destination: AccountMeta,
+    pub tombstone: AccountMeta,
+    pub system_program: AccountMeta,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`CloseCounterV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl CloseCounterV1Ix {
+    /// Creates a new instruction builder for `CloseCounterV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner, from which the counter address is
+    ///   derived.
+    /// * `destination` - The public key of the account receiving the counter's lamports. For a
+    ///   sponsored counter this must be the sponsor that paid its rent.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey, destination: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            destination: AccountMeta {
+                pubkey: destination,
+                is_signer: false,
+                is_writable: true,
+            },
+            tombstone: AccountMeta {
+                pubkey: find_tombstone_v1_address(&program_id, &counter),
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    /// The first signer pays for the tombstone account, so it is writable.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| AccountMeta {
+                pubkey: *signer,
+                is_signer: true,
+                is_writable: index == 0,
+            })
+            .collect();
+        self
+    }
+
+    /// Validates that the account metadata and addresses are correct.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CloseCounterV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), CloseCounterV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(CloseCounterV1IxError::OwnerMustBeSigner);
+        }
+
+        if self.multisig_signers.is_empty() && !self.owner.is_writable {
+            return Err(CloseCounterV1IxError::OwnerMustBeWriteable);
+        }
+
+        if !self.counter.is_writable {
+            return Err(CloseCounterV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(CloseCounterV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(CloseCounterV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(CloseCounterV1IxError::MultisigSignerMustBeSigner);
+            }
+
+            if !self.multisig_signers[0].is_writable {
+                return Err(CloseCounterV1IxError::MultisigSignerMustBeWriteable);
+            }
+        }
+
+        if !self.destination.is_writable {
+            return Err(CloseCounterV1IxError::DestinationMustBeWriteable);
+        }
+
+        if self.destination.pubkey == self.counter.pubkey {
+            return Err(CloseCounterV1IxError::DestinationMustNotBeCounter);
+        }
+
+        if !self.tombstone.is_writable {
+            return Err(CloseCounterV1IxError::TombstoneMustBeWriteable);
+        }
+
+        let expected_tombstone = find_tombstone_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_tombstone = self.tombstone.pubkey;
+        if observed_tombstone != expected_tombstone {
+            return Err(CloseCounterV1IxError::TombstoneAddressMismatch {
+                expected: expected_tombstone,
+                observed: observed_tombstone,
+            });
+        }
+
+        let expected_system_program = solana_system_program::id();
+        let observed_system_program = self.system_program.pubkey;
+        if observed_system_program != expected_system_program {
+            return Err(CloseCounterV1IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CloseCounterV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, CloseCounterV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let mut accounts = vec![
+            self.owner,
+            self.counter,
+            self.destination,
+            self.tombstone,
+            self.system_program,
+        ];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![InstructionDiscriminator::CloseCounterV1.into()],
+        })
+    }
+}
+
+impl TryFrom<CloseCounterV1Ix> for Instruction {
+    type Error = CloseCounterV1IxError;
+
+    fn try_from(value: CloseCounterV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_derives_counter_and_tombstone_addresses() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let close_ix = CloseCounterV1Ix::new(program_id, owner, destination);
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        assert_eq!(close_ix.program_id, program_id);
+        assert_eq!(close_ix.owner.pubkey, owner);
+        assert_eq!(close_ix.counter.pubkey, expected_counter);
+        assert_eq!(close_ix.destination.pubkey, destination);
+        assert_eq!(
+            close_ix.tombstone.pubkey,
+            find_tombstone_v1_address(&program_id, &expected_counter)
+        );
+        assert_eq!(close_ix.system_program.pubkey, solana_system_program::id());
+    }
+
+    #[test]
+    fn test_new_sets_account_metadata_correctly() {
+        let close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        assert!(close_ix.owner.is_signer);
+        assert!(close_ix.owner.is_writable);
+        assert!(!close_ix.counter.is_signer);
+        assert!(close_ix.counter.is_writable);
+        assert!(!close_ix.destination.is_signer);
+        assert!(close_ix.destination.is_writable);
+        assert!(!close_ix.tombstone.is_signer);
+        assert!(close_ix.tombstone.is_writable);
+        assert!(!close_ix.system_program.is_signer);
+        assert!(!close_ix.system_program.is_writable);
+
+        assert!(close_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.owner.is_signer = false;
+
+        let err = close_ix.validate().unwrap_err();
+        match err {
+            CloseCounterV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = close_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_destination_not_writable() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.destination.is_writable = false;
+
+        let err = close_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Destination must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_destination_is_counter() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        let close_ix = CloseCounterV1Ix::new(program_id, owner, counter);
+
+        let err = close_ix.validate().unwrap_err();
+        match err {
+            CloseCounterV1IxError::DestinationMustNotBeCounter => {}
+            _ => panic!("Expected DestinationMustNotBeCounter, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_fails_when_tombstone_not_writable() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.tombstone.is_writable = false;
+
+        let err = close_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Tombstone must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_tombstone_address_mismatch() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.tombstone.pubkey = Pubkey::new_unique();
+
+        let err = close_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Tombstone address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = close_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let instruction = CloseCounterV1Ix::new(program_id, owner, destination)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(instruction.accounts[2].pubkey, destination);
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            find_tombstone_v1_address(&program_id, &expected_counter)
+        );
+        assert_eq!(instruction.accounts[4].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::CloseCounterV1)]
+        );
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let close_ix =
+            CloseCounterV1Ix::new(program_id, owner, authority).with_authority(authority);
+
+        assert_eq!(close_ix.owner.pubkey, authority);
+        assert_eq!(close_ix.creator, owner);
+        assert_eq!(
+            close_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(close_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut close_ix = CloseCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        close_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(close_ix).unwrap_err();
+        match err {
+            CloseCounterV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let close_ix = CloseCounterV1Ix::new(program_id, owner, Pubkey::new_unique())
+            .with_multisig_signers(&signers);
+        assert!(close_ix.validate().is_ok());
+
+        let instruction = close_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 8);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (index, (meta, signer)) in instruction.accounts[5..].iter().zip(&signers).enumerate() {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert_eq!(meta.is_writable, index == 0);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_first_multisig_signer_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut close_ix = CloseCounterV1Ix::new(program_id, owner, Pubkey::new_unique())
+            .with_multisig_signers(&[Pubkey::new_unique()]);
+        close_ix.multisig_signers[0].is_writable = false;
+
+        let err = close_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "First multisig signer must be writable");
+    }
+}
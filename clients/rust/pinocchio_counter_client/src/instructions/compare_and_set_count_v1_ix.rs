@@ -0,0 +1,365 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
+    pinocchio_counter_program::{CompareAndSetCountV1Args, InstructionDiscriminator},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompareAndSetCountV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `CompareAndSetCountV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for setting a counter's count
+/// only if it still holds the value the client last observed, so concurrent read-modify-write
+/// updates fail instead of overwriting each other.
+#[derive(Debug, Clone)]
+pub struct CompareAndSetCountV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    /// The count the client last observed.
+    pub expected_count: u64,
+    pub new_count: u64,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`CompareAndSetCountV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl CompareAndSetCountV1Ix {
+    /// Creates a new instruction builder for `CompareAndSetCountV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `expected_count` - The count the client last observed.
+    /// * `new_count` - The count value to set.
+    ///
+    /// # Returns
+    ///
+    /// A new `CompareAndSetCountV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey, expected_count: u64, new_count: u64) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            expected_count,
+            new_count,
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the expected count value.
+    #[must_use]
+    pub fn with_expected_count(mut self, expected_count: u64) -> Self {
+        self.expected_count = expected_count;
+        self
+    }
+
+    /// Sets the new count value.
+    #[must_use]
+    pub fn with_new_count(mut self, new_count: u64) -> Self {
+        self.new_count = new_count;
+        self
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompareAndSetCountV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), CompareAndSetCountV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(CompareAndSetCountV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(CompareAndSetCountV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(CompareAndSetCountV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(CompareAndSetCountV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(CompareAndSetCountV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompareAndSetCountV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, CompareAndSetCountV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = CompareAndSetCountV1Args {
+            expected_count: self.expected_count,
+            new_count: self.new_count,
+        };
+        let args_data =
+            serialize(&args).map_err(|_| CompareAndSetCountV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::CompareAndSetCountV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![self.owner, self.counter];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<CompareAndSetCountV1Ix> for Instruction {
+    type Error = CompareAndSetCountV1IxError;
+
+    fn try_from(value: CompareAndSetCountV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let cas_ix = CompareAndSetCountV1Ix::new(program_id, owner, 1, 2);
+
+        assert_eq!(
+            cas_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_eq!(cas_ix.owner.pubkey, owner);
+        assert!(cas_ix.owner.is_signer);
+        assert!(cas_ix.counter.is_writable);
+        assert_eq!(cas_ix.expected_count, 1);
+        assert_eq!(cas_ix.new_count, 2);
+        assert!(cas_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let mut cas_ix =
+            CompareAndSetCountV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 2);
+        cas_ix.owner.is_signer = false;
+
+        let err = cas_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let mut cas_ix =
+            CompareAndSetCountV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 2);
+        cas_ix.counter.is_writable = false;
+
+        let err = cas_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let mut cas_ix =
+            CompareAndSetCountV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 2);
+        cas_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = cas_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let instruction = CompareAndSetCountV1Ix::new(program_id, owner, 7, 8)
+            .with_expected_count(41)
+            .with_new_count(42)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::CompareAndSetCountV1)
+        );
+
+        let args: CompareAndSetCountV1Args = wincode::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.expected_count, 41);
+        assert_eq!(args.new_count, 42);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut cas_ix =
+            CompareAndSetCountV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, 2);
+        cas_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(cas_ix).unwrap_err();
+        match err {
+            CompareAndSetCountV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let cas_ix = CompareAndSetCountV1Ix::new(program_id, owner, 1, 2).with_authority(authority);
+
+        assert_eq!(cas_ix.owner.pubkey, authority);
+        assert_eq!(
+            cas_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(cas_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let cas_ix =
+            CompareAndSetCountV1Ix::new(program_id, owner, 1, 2).with_multisig_signers(&signers);
+        assert!(cas_ix.validate().is_ok());
+
+        let instruction = cas_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[2..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+    }
+}
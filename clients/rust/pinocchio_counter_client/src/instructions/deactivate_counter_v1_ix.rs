@@ -1,5 +1,5 @@
 use {
-    crate::find_counter_v1_address,
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
     pinocchio_counter_program::InstructionDiscriminator,
     solana_instruction::{AccountMeta, Instruction},
     solana_pubkey::Pubkey,
@@ -18,12 +18,31 @@ pub enum DeactivateCounterV1IxError {
 
     #[error("Counter address mismatch: expected {expected:?}, observed {observed:?}")]
     CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Rent payer must be writable")]
+    RentPayerMustBeWriteable,
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("First multisig signer must be writable")]
+    MultisigSignerMustBeWriteable,
 }
 
 pub struct DeactivateCounterV1Ix {
     pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
     pub owner: AccountMeta,
     pub counter: AccountMeta,
+    /// The sponsor that paid the counter's rent, refunded instead of the owner.
+    pub rent_payer: Option<AccountMeta>,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`DeactivateCounterV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
 }
 
 impl DeactivateCounterV1Ix {
@@ -33,6 +52,7 @@ impl DeactivateCounterV1Ix {
 
         Self {
             program_id,
+            creator: owner,
             owner: AccountMeta {
                 pubkey: owner,
                 is_signer: true,
@@ -43,20 +63,68 @@ impl DeactivateCounterV1Ix {
                 is_signer: false,
                 is_writable: true,
             },
+            rent_payer: None,
+            multisig_signers: Vec::new(),
         }
     }
 
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    /// The first signer receives the reclaimed lamports unless a rent payer is set, so it is
+    /// writable.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| AccountMeta {
+                pubkey: *signer,
+                is_signer: true,
+                is_writable: index == 0,
+            })
+            .collect();
+        self
+    }
+
+    /// Refunds the reclaimed lamports to `rent_payer`, for counters created or reactivated with
+    /// `InitializeSponsoredCounterV1` or `ReactivateSponsoredCounterV1`.
+    #[must_use]
+    pub fn with_rent_payer(mut self, rent_payer: Pubkey) -> Self {
+        self.rent_payer = Some(AccountMeta {
+            pubkey: rent_payer,
+            is_signer: false,
+            is_writable: true,
+        });
+        self
+    }
+
     /// Validates that the account metadata and addresses are correct.
     ///
     /// # Errors
     ///
     /// Returns [`DeactivateCounterV1IxError`] if validation fails.
     pub fn validate(&self) -> Result<(), DeactivateCounterV1IxError> {
-        if !self.owner.is_signer {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
             return Err(DeactivateCounterV1IxError::OwnerMustBeSigner);
         }
 
-        if !self.owner.is_writable {
+        if self.multisig_signers.is_empty() && !self.owner.is_writable {
             return Err(DeactivateCounterV1IxError::OwnerMustBeWriteable);
         }
 
@@ -64,7 +132,7 @@ impl DeactivateCounterV1Ix {
             return Err(DeactivateCounterV1IxError::CounterMustBeWriteable);
         }
 
-        let expected_counter = find_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
         let observed_counter = self.counter.pubkey;
         if observed_counter != expected_counter {
             return Err(DeactivateCounterV1IxError::CounterAddressMismatch {
@@ -73,6 +141,34 @@ impl DeactivateCounterV1Ix {
             });
         }
 
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(DeactivateCounterV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(DeactivateCounterV1IxError::MultisigSignerMustBeSigner);
+            }
+
+            if !self.multisig_signers[0].is_writable {
+                return Err(DeactivateCounterV1IxError::MultisigSignerMustBeWriteable);
+            }
+        }
+
+        if self
+            .rent_payer
+            .as_ref()
+            .is_some_and(|rent_payer| !rent_payer.is_writable)
+        {
+            return Err(DeactivateCounterV1IxError::RentPayerMustBeWriteable);
+        }
+
         Ok(())
     }
 
@@ -86,9 +182,15 @@ impl DeactivateCounterV1Ix {
             self.validate()?;
         }
 
+        let mut accounts = vec![self.owner, self.counter];
+        accounts.extend(self.rent_payer);
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
         Ok(Instruction {
             program_id: self.program_id,
-            accounts: vec![self.owner, self.counter],
+            accounts,
             data: vec![InstructionDiscriminator::DeactivateCounterV1.into()],
         })
     }
@@ -227,7 +329,11 @@ mod tests {
         let instruction = deactivate_ix.to_instruction(true).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
         assert_eq!(instruction.accounts[0].pubkey, owner);
         assert_eq!(instruction.accounts[1].pubkey, expected_counter);
         assert_eq!(
@@ -270,7 +376,7 @@ mod tests {
         let instruction = Instruction::try_from(deactivate_ix).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
         assert_eq!(
             instruction.data,
             vec![u8::from(InstructionDiscriminator::DeactivateCounterV1)]
@@ -291,4 +397,94 @@ mod tests {
             _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
         }
     }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let deactivate_ix = DeactivateCounterV1Ix::new(program_id, owner).with_authority(authority);
+
+        assert_eq!(deactivate_ix.owner.pubkey, authority);
+        assert_eq!(deactivate_ix.creator, owner);
+        assert_eq!(
+            deactivate_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(deactivate_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_rent_payer_appends_writable_rent_payer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let rent_payer = Pubkey::new_unique();
+
+        let instruction = DeactivateCounterV1Ix::new(program_id, owner)
+            .with_rent_payer(rent_payer)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[2].pubkey, rent_payer);
+        assert!(!instruction.accounts[2].is_signer);
+        assert!(instruction.accounts[2].is_writable);
+    }
+
+    #[test]
+    fn test_validate_fails_when_rent_payer_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut deactivate_ix =
+            DeactivateCounterV1Ix::new(program_id, owner).with_rent_payer(Pubkey::new_unique());
+        if let Some(rent_payer) = deactivate_ix.rent_payer.as_mut() {
+            rent_payer.is_writable = false;
+        }
+
+        let err = deactivate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Rent payer must be writable");
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let deactivate_ix =
+            DeactivateCounterV1Ix::new(program_id, owner).with_multisig_signers(&signers);
+        assert!(deactivate_ix.validate().is_ok());
+
+        let instruction = deactivate_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (index, (meta, signer)) in instruction.accounts[2..].iter().zip(&signers).enumerate() {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert_eq!(meta.is_writable, index == 0);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_first_multisig_signer_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut deactivate_ix = DeactivateCounterV1Ix::new(program_id, owner)
+            .with_multisig_signers(&[Pubkey::new_unique()]);
+        deactivate_ix.multisig_signers[0].is_writable = false;
+
+        let err = deactivate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "First multisig signer must be writable");
+    }
 }
@@ -0,0 +1,536 @@
+use {
+    crate::{
+        config_v1_account_meta, find_counter_v1_address, find_delegate_v1_address,
+        find_multisig_v1_address,
+    },
+    pinocchio_counter_program::{DecrementByV1Args, InstructionDiscriminator},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecrementByV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Delegate account must be writable")]
+    DelegateMustBeWriteable,
+
+    #[error("Delegate address mismatch. Expected: {expected}, Observed: {observed}")]
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `DecrementByV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for decrementing
+/// a counter's count by an arbitrary amount. It includes methods for setting account
+/// metadata and validating the instruction's integrity.
+///
+/// When built with [`DecrementByV1Ix::new_delegated`], `owner` holds the signing delegate and
+/// `delegate` holds the counter's delegate record.
+#[derive(Debug, Clone)]
+pub struct DecrementByV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub delegate: Option<AccountMeta>,
+    pub amount: u64,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`DecrementByV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl DecrementByV1Ix {
+    /// Creates a new instruction builder for `DecrementByV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `amount` - The amount to decrement by.
+    ///
+    /// # Returns
+    ///
+    /// A new `DecrementByV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey, amount: u64) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            delegate: None,
+            amount,
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Creates a new instruction builder for `DecrementByV1` signed by an approved delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `delegate` - The public key of the approved delegate signing the instruction.
+    /// * `amount` - The amount to decrement by.
+    ///
+    /// # Returns
+    ///
+    /// A new `DecrementByV1Ix` instance that includes the counter's delegate record.
+    #[must_use]
+    pub fn new_delegated(program_id: Pubkey, owner: Pubkey, delegate: Pubkey, amount: u64) -> Self {
+        let mut ix = Self::new(program_id, owner, amount);
+        let delegate_record = find_delegate_v1_address(&program_id, &ix.counter.pubkey);
+
+        ix.owner.pubkey = delegate;
+        ix.delegate = Some(AccountMeta {
+            pubkey: delegate_record,
+            is_signer: false,
+            is_writable: true,
+        });
+        ix
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the amount value.
+    #[must_use]
+    pub fn with_amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Sets the delegate record account metadata.
+    #[must_use]
+    pub fn with_delegate(mut self, delegate: Option<AccountMeta>) -> Self {
+        self.delegate = delegate;
+        self
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.delegate = None;
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecrementByV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), DecrementByV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(DecrementByV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(DecrementByV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(DecrementByV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(DecrementByV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(DecrementByV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        let Some(delegate) = &self.delegate else {
+            return Ok(());
+        };
+
+        if !delegate.is_writable {
+            return Err(DecrementByV1IxError::DelegateMustBeWriteable);
+        }
+
+        let expected_delegate = find_delegate_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_delegate = delegate.pubkey;
+        if observed_delegate != expected_delegate {
+            return Err(DecrementByV1IxError::DelegateAddressMismatch {
+                expected: expected_delegate,
+                observed: observed_delegate,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecrementByV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, DecrementByV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = DecrementByV1Args {
+            amount: self.amount,
+        };
+        let args_data = serialize(&args).map_err(|_| DecrementByV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::DecrementByV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![self.owner, self.counter];
+        accounts.extend(self.delegate);
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<DecrementByV1Ix> for Instruction {
+    type Error = DecrementByV1IxError;
+
+    fn try_from(value: DecrementByV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_delegate_v1_address},
+    };
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let amount = 42u64;
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let decrement_ix = DecrementByV1Ix::new(program_id, owner, amount);
+
+        assert_eq!(decrement_ix.counter.pubkey, expected_counter);
+        assert_eq!(decrement_ix.program_id, program_id);
+        assert_eq!(decrement_ix.owner.pubkey, owner);
+        assert_eq!(decrement_ix.amount, amount);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let decrement_ix = DecrementByV1Ix::new(program_id, owner, 100);
+
+        assert!(decrement_ix.owner.is_signer);
+        assert!(!decrement_ix.owner.is_writable);
+        assert!(!decrement_ix.counter.is_signer);
+        assert!(decrement_ix.counter.is_writable);
+
+        assert!(decrement_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut decrement_ix = DecrementByV1Ix::new(program_id, owner, 50);
+        decrement_ix.owner.is_signer = false;
+
+        let err = decrement_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_succeeds_when_owner_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut decrement_ix = DecrementByV1Ix::new(program_id, owner, 50);
+        decrement_ix.owner.is_writable = false;
+
+        // This should succeed because the program doesn't require owner to be writable
+        assert!(decrement_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let wrong_counter = Pubkey::new_unique();
+
+        let mut decrement_ix = DecrementByV1Ix::new(program_id, owner, 50);
+        decrement_ix.counter.pubkey = wrong_counter;
+
+        let err = decrement_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut decrement_ix = DecrementByV1Ix::new(program_id, owner, 50);
+        decrement_ix.counter.is_writable = false;
+
+        let err = decrement_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let amount = 123u64;
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let decrement_ix = DecrementByV1Ix::new(program_id, owner, amount);
+        let instruction = decrement_ix.to_instruction(true).unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::DecrementByV1)
+        );
+
+        // Verify the amount is serialized correctly
+        let args: DecrementByV1Args = wincode::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.amount, amount);
+    }
+
+    #[test]
+    fn test_to_instruction_respects_validate_flag() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let decrement_ix1 = DecrementByV1Ix::new(program_id, owner, 10);
+        assert!(decrement_ix1.to_instruction(true).is_ok());
+
+        let decrement_ix2 = DecrementByV1Ix::new(program_id, owner, 20);
+        assert!(decrement_ix2.to_instruction(false).is_ok());
+
+        let mut decrement_ix3 = DecrementByV1Ix::new(program_id, owner, 30);
+        decrement_ix3.owner.is_signer = false;
+        assert!(decrement_ix3.to_instruction(true).is_err());
+
+        let mut decrement_ix4 = DecrementByV1Ix::new(program_id, owner, 40);
+        decrement_ix4.owner.is_signer = false;
+        let instruction = decrement_ix4.to_instruction(false).unwrap();
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert!(!instruction.accounts[0].is_signer);
+    }
+
+    #[test]
+    fn test_try_from_succeeds_for_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let decrement_ix = DecrementByV1Ix::new(program_id, owner, 99);
+        let instruction = Instruction::try_from(decrement_ix).unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::DecrementByV1)
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut decrement_ix = DecrementByV1Ix::new(program_id, owner, 50);
+        decrement_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(decrement_ix).unwrap_err();
+        match err {
+            DecrementByV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_new_delegated_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_delegate = find_delegate_v1_address(&program_id, &expected_counter);
+
+        let ix = DecrementByV1Ix::new_delegated(program_id, owner, delegate, 7);
+
+        assert_eq!(ix.owner.pubkey, delegate);
+        assert!(ix.owner.is_signer);
+        assert_eq!(ix.counter.pubkey, expected_counter);
+        let delegate_meta = ix.delegate.as_ref().unwrap();
+        assert_eq!(delegate_meta.pubkey, expected_delegate);
+        assert!(delegate_meta.is_writable);
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_delegate_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let mut ix = DecrementByV1Ix::new_delegated(program_id, owner, delegate, 7);
+        ix.delegate.as_mut().unwrap().pubkey = Pubkey::new_unique();
+
+        let err = ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            DecrementByV1IxError::DelegateAddressMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_to_instruction_appends_delegate_record() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let ix = DecrementByV1Ix::new_delegated(program_id, owner, delegate, 7);
+        let expected_delegate = ix.delegate.clone().unwrap();
+        let instruction = ix.to_instruction(true).unwrap();
+
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[0].pubkey, delegate);
+        assert_eq!(instruction.accounts[2], expected_delegate);
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let decrement_ix = DecrementByV1Ix::new(program_id, owner, 5).with_authority(authority);
+
+        assert_eq!(decrement_ix.owner.pubkey, authority);
+        assert_eq!(decrement_ix.creator, owner);
+        assert_eq!(
+            decrement_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(decrement_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let decrement_ix =
+            DecrementByV1Ix::new(program_id, owner, 5).with_multisig_signers(&signers);
+        assert!(decrement_ix.validate().is_ok());
+
+        let instruction = decrement_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[2..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+    }
+}
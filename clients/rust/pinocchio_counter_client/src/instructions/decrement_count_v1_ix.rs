@@ -1,5 +1,8 @@
 use {
-    crate::find_counter_v1_address,
+    crate::{
+        config_v1_account_meta, find_counter_v1_address, find_delegate_v1_address,
+        find_multisig_v1_address,
+    },
     pinocchio_counter_program::InstructionDiscriminator,
     solana_instruction::{AccountMeta, Instruction},
     solana_pubkey::Pubkey,
@@ -15,6 +18,18 @@ pub enum DecrementCountV1IxError {
 
     #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
     CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Delegate account must be writable")]
+    DelegateMustBeWriteable,
+
+    #[error("Delegate address mismatch. Expected: {expected}, Observed: {observed}")]
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
 }
 
 /// Instruction builder for `DecrementCountV1`.
@@ -22,11 +37,20 @@ pub enum DecrementCountV1IxError {
 /// This struct facilitates the creation of a Solana `Instruction` for decrementing
 /// a counter's count. It includes methods for setting account metadata and
 /// validating the instruction's integrity.
+///
+/// When built with [`DecrementCountV1Ix::new_delegated`], `owner` holds the signing delegate and
+/// `delegate` holds the counter's delegate record.
 #[derive(Debug, Clone)]
 pub struct DecrementCountV1Ix {
     pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
     pub owner: AccountMeta,
     pub counter: AccountMeta,
+    pub delegate: Option<AccountMeta>,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`DecrementCountV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
 }
 
 impl DecrementCountV1Ix {
@@ -46,6 +70,7 @@ impl DecrementCountV1Ix {
 
         Self {
             program_id,
+            creator: owner,
             owner: AccountMeta {
                 pubkey: owner,
                 is_signer: true,
@@ -56,9 +81,55 @@ impl DecrementCountV1Ix {
                 is_signer: false,
                 is_writable: true,
             },
+            delegate: None,
+            multisig_signers: Vec::new(),
         }
     }
 
+    /// Creates a new instruction builder for `DecrementCountV1` signed by an approved delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `delegate` - The public key of the approved delegate signing the instruction.
+    ///
+    /// # Returns
+    ///
+    /// A new `DecrementCountV1Ix` instance that includes the counter's delegate record.
+    #[must_use]
+    pub fn new_delegated(program_id: Pubkey, owner: Pubkey, delegate: Pubkey) -> Self {
+        let mut ix = Self::new(program_id, owner);
+        let delegate_record = find_delegate_v1_address(&program_id, &ix.counter.pubkey);
+
+        ix.owner.pubkey = delegate;
+        ix.delegate = Some(AccountMeta {
+            pubkey: delegate_record,
+            is_signer: false,
+            is_writable: true,
+        });
+        ix
+    }
+
+    /// Creates a new instruction builder for `DecrementCountV1` signed by any key, for counters made
+    /// public with `SetAccessModeV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `signer` - The public key signing the instruction.
+    ///
+    /// # Returns
+    ///
+    /// A new `DecrementCountV1Ix` instance signed by `signer`.
+    #[must_use]
+    pub fn new_public(program_id: Pubkey, owner: Pubkey, signer: Pubkey) -> Self {
+        let mut ix = Self::new(program_id, owner);
+        ix.owner.pubkey = signer;
+        ix
+    }
+
     /// Sets the owner account metadata.
     #[must_use]
     pub fn with_owner(mut self, owner: AccountMeta) -> Self {
@@ -73,13 +144,48 @@ impl DecrementCountV1Ix {
         self
     }
 
+    /// Sets the delegate record account metadata.
+    #[must_use]
+    pub fn with_delegate(mut self, delegate: Option<AccountMeta>) -> Self {
+        self.delegate = delegate;
+        self
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.delegate = None;
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
     /// Validates the instruction's account metadata.
     ///
     /// # Errors
     ///
     /// Returns [`DecrementCountV1IxError`] if validation fails.
     pub fn validate(&self) -> Result<(), DecrementCountV1IxError> {
-        if !self.owner.is_signer {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
             return Err(DecrementCountV1IxError::OwnerMustBeSigner);
         }
 
@@ -87,7 +193,7 @@ impl DecrementCountV1Ix {
             return Err(DecrementCountV1IxError::CounterMustBeWriteable);
         }
 
-        let expected_counter = find_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
         let observed_counter = self.counter.pubkey;
         if observed_counter != expected_counter {
             return Err(DecrementCountV1IxError::CounterAddressMismatch {
@@ -96,6 +202,39 @@ impl DecrementCountV1Ix {
             });
         }
 
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(DecrementCountV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(DecrementCountV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        let Some(delegate) = &self.delegate else {
+            return Ok(());
+        };
+
+        if !delegate.is_writable {
+            return Err(DecrementCountV1IxError::DelegateMustBeWriteable);
+        }
+
+        let expected_delegate = find_delegate_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_delegate = delegate.pubkey;
+        if observed_delegate != expected_delegate {
+            return Err(DecrementCountV1IxError::DelegateAddressMismatch {
+                expected: expected_delegate,
+                observed: observed_delegate,
+            });
+        }
+
         Ok(())
     }
 
@@ -109,9 +248,15 @@ impl DecrementCountV1Ix {
             self.validate()?;
         }
 
+        let mut accounts = vec![self.owner, self.counter];
+        accounts.extend(self.delegate);
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
         Ok(Instruction {
             program_id: self.program_id,
-            accounts: vec![self.owner, self.counter],
+            accounts,
             data: vec![InstructionDiscriminator::DecrementCountV1.into()],
         })
     }
@@ -127,7 +272,10 @@ impl TryFrom<DecrementCountV1Ix> for Instruction {
 
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::find_counter_v1_address};
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_delegate_v1_address},
+    };
 
     #[test]
     fn test_new_creates_valid_struct() {
@@ -216,7 +364,11 @@ mod tests {
         let instruction = decrement_ix.to_instruction(true).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
         assert_eq!(instruction.accounts[0].pubkey, owner);
         assert_eq!(instruction.accounts[1].pubkey, expected_counter);
         assert_eq!(
@@ -244,7 +396,7 @@ mod tests {
         decrement_ix4.owner.is_signer = false;
         let instruction = decrement_ix4.to_instruction(false).unwrap();
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
         assert!(!instruction.accounts[0].is_signer);
     }
 
@@ -257,7 +409,7 @@ mod tests {
         let instruction = Instruction::try_from(decrement_ix).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
         assert_eq!(
             instruction.data,
             vec![u8::from(InstructionDiscriminator::DecrementCountV1)]
@@ -278,4 +430,114 @@ mod tests {
             _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
         }
     }
+
+    #[test]
+    fn test_new_delegated_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_delegate = find_delegate_v1_address(&program_id, &expected_counter);
+
+        let ix = DecrementCountV1Ix::new_delegated(program_id, owner, delegate);
+
+        assert_eq!(ix.owner.pubkey, delegate);
+        assert!(ix.owner.is_signer);
+        assert_eq!(ix.counter.pubkey, expected_counter);
+        let delegate_meta = ix.delegate.as_ref().unwrap();
+        assert_eq!(delegate_meta.pubkey, expected_delegate);
+        assert!(delegate_meta.is_writable);
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_new_public_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+
+        let ix = DecrementCountV1Ix::new_public(program_id, owner, signer);
+
+        assert_eq!(ix.owner.pubkey, signer);
+        assert!(ix.owner.is_signer);
+        assert_eq!(ix.creator, owner);
+        assert_eq!(
+            ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(ix.delegate.is_none());
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_delegate_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let mut ix = DecrementCountV1Ix::new_delegated(program_id, owner, delegate);
+        ix.delegate.as_mut().unwrap().pubkey = Pubkey::new_unique();
+
+        let err = ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            DecrementCountV1IxError::DelegateAddressMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_to_instruction_appends_delegate_record() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let delegate = Pubkey::new_unique();
+
+        let ix = DecrementCountV1Ix::new_delegated(program_id, owner, delegate);
+        let expected_delegate = ix.delegate.clone().unwrap();
+        let instruction = ix.to_instruction(true).unwrap();
+
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[0].pubkey, delegate);
+        assert_eq!(instruction.accounts[2], expected_delegate);
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let decrement_ix = DecrementCountV1Ix::new(program_id, owner).with_authority(authority);
+
+        assert_eq!(decrement_ix.owner.pubkey, authority);
+        assert_eq!(decrement_ix.creator, owner);
+        assert_eq!(
+            decrement_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(decrement_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let decrement_ix =
+            DecrementCountV1Ix::new(program_id, owner).with_multisig_signers(&signers);
+        assert!(decrement_ix.validate().is_ok());
+
+        let instruction = decrement_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[2..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+    }
 }
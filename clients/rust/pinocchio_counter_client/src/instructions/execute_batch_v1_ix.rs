@@ -0,0 +1,477 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
+    pinocchio_counter_program::{BatchOperationV1, InstructionDiscriminator, MAX_BATCH_OPERATIONS},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExecuteBatchV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Batch must contain at least one operation")]
+    EmptyBatch,
+
+    #[error("Too many operations. Maximum: {maximum}, Observed: {observed}")]
+    TooManyOperations { maximum: usize, observed: usize },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `ExecuteBatchV1`.
+///
+/// Operations are appended in the order they are applied, so a batch setting the count to 10 and
+/// then incrementing it by 3 is built with `ExecuteBatchV1Ix::new(program_id, owner).set(10)`
+/// followed by `.increment_by(3)`.
+#[derive(Debug, Clone)]
+pub struct ExecuteBatchV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub operations: Vec<BatchOperationV1>,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`ExecuteBatchV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl ExecuteBatchV1Ix {
+    /// Creates a new instruction builder for `ExecuteBatchV1` with no operations.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    ///
+    /// # Returns
+    ///
+    /// A new `ExecuteBatchV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            operations: Vec::new(),
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Replaces the operations of the batch.
+    #[must_use]
+    pub fn with_operations(mut self, operations: Vec<BatchOperationV1>) -> Self {
+        self.operations = operations;
+        self
+    }
+
+    /// Appends an operation incrementing the count by 1.
+    #[must_use]
+    pub fn increment(self) -> Self {
+        self.push(BatchOperationV1::increment())
+    }
+
+    /// Appends an operation decrementing the count by 1.
+    #[must_use]
+    pub fn decrement(self) -> Self {
+        self.push(BatchOperationV1::decrement())
+    }
+
+    /// Appends an operation incrementing the count by `amount`.
+    #[must_use]
+    pub fn increment_by(self, amount: u64) -> Self {
+        self.push(BatchOperationV1::increment_by(amount))
+    }
+
+    /// Appends an operation decrementing the count by `amount`.
+    #[must_use]
+    pub fn decrement_by(self, amount: u64) -> Self {
+        self.push(BatchOperationV1::decrement_by(amount))
+    }
+
+    /// Appends an operation setting the count to `count`.
+    #[must_use]
+    pub fn set(self, count: u64) -> Self {
+        self.push(BatchOperationV1::set(count))
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
+    fn push(mut self, operation: BatchOperationV1) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Validates the instruction's account metadata and operations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteBatchV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), ExecuteBatchV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(ExecuteBatchV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(ExecuteBatchV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(ExecuteBatchV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if self.operations.is_empty() {
+            return Err(ExecuteBatchV1IxError::EmptyBatch);
+        }
+
+        if self.operations.len() > usize::from(MAX_BATCH_OPERATIONS) {
+            return Err(ExecuteBatchV1IxError::TooManyOperations {
+                maximum: usize::from(MAX_BATCH_OPERATIONS),
+                observed: self.operations.len(),
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(ExecuteBatchV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(ExecuteBatchV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// The operations are encoded as a wincode `Vec<BatchOperationV1>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteBatchV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, ExecuteBatchV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args_data =
+            serialize(&self.operations).map_err(|_| ExecuteBatchV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::ExecuteBatchV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![self.owner, self.counter];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<ExecuteBatchV1Ix> for Instruction {
+    type Error = ExecuteBatchV1IxError;
+
+    fn try_from(value: ExecuteBatchV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, pinocchio_counter_program::ExecuteBatchV1Args};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner);
+
+        assert_eq!(batch_ix.counter.pubkey, expected_counter);
+        assert_eq!(batch_ix.program_id, program_id);
+        assert_eq!(batch_ix.owner.pubkey, owner);
+        assert!(batch_ix.operations.is_empty());
+    }
+
+    #[test]
+    fn test_builder_appends_operations_in_order() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner)
+            .set(10)
+            .increment()
+            .increment_by(3)
+            .decrement()
+            .decrement_by(2);
+
+        assert_eq!(
+            batch_ix.operations,
+            vec![
+                BatchOperationV1::set(10),
+                BatchOperationV1::increment(),
+                BatchOperationV1::increment_by(3),
+                BatchOperationV1::decrement(),
+                BatchOperationV1::decrement_by(2),
+            ]
+        );
+        assert!(batch_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut batch_ix = ExecuteBatchV1Ix::new(program_id, owner).increment();
+        batch_ix.owner.is_signer = false;
+
+        let err = batch_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut batch_ix = ExecuteBatchV1Ix::new(program_id, owner).increment();
+        batch_ix.counter.is_writable = false;
+
+        let err = batch_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut batch_ix = ExecuteBatchV1Ix::new(program_id, owner).increment();
+        batch_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = batch_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_for_empty_batch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner);
+
+        let err = batch_ix.validate().unwrap_err();
+        assert!(matches!(err, ExecuteBatchV1IxError::EmptyBatch));
+    }
+
+    #[test]
+    fn test_validate_fails_for_too_many_operations() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner).with_operations(vec![
+                BatchOperationV1::increment();
+                usize::from(MAX_BATCH_OPERATIONS) + 1
+            ]);
+
+        let err = batch_ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ExecuteBatchV1IxError::TooManyOperations { .. }
+        ));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner)
+            .set(10)
+            .increment()
+            .decrement();
+        let operations = batch_ix.operations.clone();
+        let instruction = batch_ix.to_instruction(true).unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::ExecuteBatchV1)
+        );
+
+        // Verify the program reads the operations back in order
+        let args = ExecuteBatchV1Args::deserialize(&instruction.data[1..]).unwrap();
+        let decoded: Vec<_> = args.operations().map(Result::unwrap).collect();
+        assert_eq!(decoded, operations);
+    }
+
+    #[test]
+    fn test_to_instruction_respects_validate_flag() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let batch_ix1 = ExecuteBatchV1Ix::new(program_id, owner).increment();
+        assert!(batch_ix1.to_instruction(true).is_ok());
+
+        let batch_ix2 = ExecuteBatchV1Ix::new(program_id, owner);
+        assert!(batch_ix2.to_instruction(true).is_err());
+
+        let batch_ix3 = ExecuteBatchV1Ix::new(program_id, owner);
+        let instruction = batch_ix3.to_instruction(false).unwrap();
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut batch_ix = ExecuteBatchV1Ix::new(program_id, owner).increment();
+        batch_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(batch_ix).unwrap_err();
+        match err {
+            ExecuteBatchV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner)
+            .with_authority(authority)
+            .increment();
+
+        assert_eq!(batch_ix.owner.pubkey, authority);
+        assert_eq!(batch_ix.creator, owner);
+        assert_eq!(
+            batch_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(batch_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let batch_ix = ExecuteBatchV1Ix::new(program_id, owner)
+            .with_multisig_signers(&signers)
+            .increment();
+        assert!(batch_ix.validate().is_ok());
+
+        let instruction = batch_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[2..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+    }
+}
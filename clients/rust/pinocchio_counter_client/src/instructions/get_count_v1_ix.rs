@@ -0,0 +1,163 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetCountV1IxError {
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+}
+
+/// Instruction builder for `GetCountV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` that returns a counter's
+/// count as return data. The counter is passed read-only and no signer is required, so the
+/// instruction can be simulated or invoked through CPI by anyone.
+#[derive(Debug, Clone)]
+pub struct GetCountV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub counter: AccountMeta,
+}
+
+impl GetCountV1Ix {
+    /// Creates a new instruction builder for `GetCountV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's creator.
+    ///
+    /// # Returns
+    ///
+    /// A new `GetCountV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            creator: owner,
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: false,
+            },
+        }
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetCountV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), GetCountV1IxError> {
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(GetCountV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetCountV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, GetCountV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![self.counter, config_v1_account_meta(&self.program_id)],
+            data: vec![InstructionDiscriminator::GetCountV1.into()],
+        })
+    }
+}
+
+impl TryFrom<GetCountV1Ix> for Instruction {
+    type Error = GetCountV1IxError;
+
+    fn try_from(value: GetCountV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_derives_counter_pda_from_owner() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let get_count_ix = GetCountV1Ix::new(program_id, owner);
+
+        assert_eq!(get_count_ix.program_id, program_id);
+        assert_eq!(get_count_ix.creator, owner);
+        assert_eq!(
+            get_count_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(!get_count_ix.counter.is_signer);
+        assert!(!get_count_ix.counter.is_writable);
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let mut get_count_ix = GetCountV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique());
+        get_count_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = get_count_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let instruction = GetCountV1Ix::new(program_id, owner)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(
+            instruction.accounts[0].pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(!instruction.accounts[0].is_writable);
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::GetCountV1)]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut get_count_ix = GetCountV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique());
+        get_count_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = Instruction::try_from(get_count_ix).unwrap_err();
+        match err {
+            GetCountV1IxError::CounterAddressMismatch { .. } => {}
+        }
+    }
+}
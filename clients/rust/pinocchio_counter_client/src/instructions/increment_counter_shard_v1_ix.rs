@@ -0,0 +1,257 @@
+use {
+    crate::{
+        config_v1_account_meta, find_counter_shard_v1_address, find_sharded_counter_v1_address,
+        sharded_counter::shard_index_for_contributor,
+    },
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IncrementCounterShardV1IxError {
+    #[error("Contributor must be a signer")]
+    ContributorMustBeSigner,
+
+    #[error("Shard account must be writable")]
+    ShardMustBeWriteable,
+}
+
+/// Instruction builder for `IncrementCounterShardV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for incrementing one shard of a
+/// sharded counter. Any signer may contribute; [`IncrementCounterShardV1Ix::new`] picks the
+/// contributor's shard with [`shard_index_for_contributor`], so contributors spread their writes
+/// across the shards.
+#[derive(Debug, Clone)]
+pub struct IncrementCounterShardV1Ix {
+    pub program_id: Pubkey,
+    pub contributor: AccountMeta,
+    pub shard: AccountMeta,
+}
+
+impl IncrementCounterShardV1Ix {
+    /// Creates a new instruction builder for `IncrementCounterShardV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the sharded counter's owner.
+    /// * `contributor` - The public key of the signer contributing the increment.
+    /// * `shard_count` - The number of shards of the sharded counter.
+    ///
+    /// # Returns
+    ///
+    /// A new `IncrementCounterShardV1Ix` instance writing the contributor's shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shard_count` is `0`.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey, contributor: Pubkey, shard_count: u8) -> Self {
+        let index = shard_index_for_contributor(&contributor, shard_count);
+        Self::new_for_shard(program_id, owner, contributor, index)
+    }
+
+    /// Creates a new instruction builder for `IncrementCounterShardV1` writing a given shard.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the sharded counter's owner.
+    /// * `contributor` - The public key of the signer contributing the increment.
+    /// * `index` - The index of the shard to increment.
+    ///
+    /// # Returns
+    ///
+    /// A new `IncrementCounterShardV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new_for_shard(
+        program_id: Pubkey,
+        owner: Pubkey,
+        contributor: Pubkey,
+        index: u8,
+    ) -> Self {
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &owner);
+        let shard = find_counter_shard_v1_address(&program_id, &sharded_counter, index);
+
+        Self {
+            program_id,
+            contributor: AccountMeta {
+                pubkey: contributor,
+                is_signer: true,
+                is_writable: false,
+            },
+            shard: AccountMeta {
+                pubkey: shard,
+                is_signer: false,
+                is_writable: true,
+            },
+        }
+    }
+
+    /// Sets the contributor account metadata.
+    #[must_use]
+    pub fn with_contributor(mut self, contributor: AccountMeta) -> Self {
+        self.contributor = contributor;
+        self
+    }
+
+    /// Sets the shard account metadata.
+    #[must_use]
+    pub fn with_shard(mut self, shard: AccountMeta) -> Self {
+        self.shard = shard;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// The shard address is not checked here, because the program validates it against the
+    /// shard's stored seeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementCounterShardV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), IncrementCounterShardV1IxError> {
+        if !self.contributor.is_signer {
+            return Err(IncrementCounterShardV1IxError::ContributorMustBeSigner);
+        }
+
+        if !self.shard.is_writable {
+            return Err(IncrementCounterShardV1IxError::ShardMustBeWriteable);
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementCounterShardV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, IncrementCounterShardV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.contributor,
+                self.shard,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: vec![InstructionDiscriminator::IncrementCounterShardV1.into()],
+        })
+    }
+}
+
+impl TryFrom<IncrementCounterShardV1Ix> for Instruction {
+    type Error = IncrementCounterShardV1IxError;
+
+    fn try_from(value: IncrementCounterShardV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_picks_contributor_shard() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &owner);
+        let index = shard_index_for_contributor(&contributor, 4);
+
+        let ix = IncrementCounterShardV1Ix::new(program_id, owner, contributor, 4);
+
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.contributor.pubkey, contributor);
+        assert_eq!(
+            ix.shard.pubkey,
+            find_counter_shard_v1_address(&program_id, &sharded_counter, index)
+        );
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_contributor_not_signer() {
+        let mut ix = IncrementCounterShardV1Ix::new_for_shard(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+        );
+        ix.contributor.is_signer = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Contributor must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_shard_not_writable() {
+        let mut ix = IncrementCounterShardV1Ix::new_for_shard(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+        );
+        ix.shard.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Shard account must be writable");
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let contributor = Pubkey::new_unique();
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &owner);
+
+        let instruction =
+            IncrementCounterShardV1Ix::new_for_shard(program_id, owner, contributor, 2)
+                .to_instruction(true)
+                .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, contributor);
+        assert!(!instruction.accounts[0].is_writable);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_counter_shard_v1_address(&program_id, &sharded_counter, 2)
+        );
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::IncrementCounterShardV1)]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut ix = IncrementCounterShardV1Ix::new_for_shard(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+        );
+        ix.contributor.is_signer = false;
+
+        let err = Instruction::try_from(ix).unwrap_err();
+        match err {
+            IncrementCounterShardV1IxError::ContributorMustBeSigner => {}
+            _ => panic!("Expected ContributorMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use {
+    crate::{config_v1_account_meta, find_indexed_counter_v1_address},
+    pinocchio_counter_program::{IncrementIndexedCounterV1Args, InstructionDiscriminator},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IncrementIndexedCounterV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `IncrementIndexedCounterV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for incrementing an indexed
+/// counter by 1. The counter address is derived from the owner and `index`.
+#[derive(Debug, Clone)]
+pub struct IncrementIndexedCounterV1Ix {
+    pub program_id: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    /// The index the counter address is derived from, together with the owner.
+    pub index: u32,
+}
+
+impl IncrementIndexedCounterV1Ix {
+    /// Creates a new instruction builder for `IncrementIndexedCounterV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    ///
+    /// # Returns
+    ///
+    /// A new `IncrementIndexedCounterV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey, index: u32) -> Self {
+        let counter = find_indexed_counter_v1_address(&program_id, &owner, index);
+
+        Self {
+            program_id,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            index,
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementIndexedCounterV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), IncrementIndexedCounterV1IxError> {
+        if !self.owner.is_signer {
+            return Err(IncrementIndexedCounterV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(IncrementIndexedCounterV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter =
+            find_indexed_counter_v1_address(&self.program_id, &self.owner.pubkey, self.index);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(IncrementIndexedCounterV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementIndexedCounterV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, IncrementIndexedCounterV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = IncrementIndexedCounterV1Args { index: self.index };
+        let args_data =
+            serialize(&args).map_err(|_| IncrementIndexedCounterV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::IncrementIndexedCounterV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.owner,
+                self.counter,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<IncrementIndexedCounterV1Ix> for Instruction {
+    type Error = IncrementIndexedCounterV1IxError;
+
+    fn try_from(value: IncrementIndexedCounterV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_indexed_counter_v1_address},
+    };
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_indexed_counter_v1_address(&program_id, &owner, 3);
+
+        let ix = IncrementIndexedCounterV1Ix::new(program_id, owner, 3);
+
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.owner.pubkey, owner);
+        assert_eq!(ix.counter.pubkey, expected_counter);
+        assert_eq!(ix.index, 3);
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_new_derives_distinct_counters_per_index() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let first = IncrementIndexedCounterV1Ix::new(program_id, owner, 0);
+        let second = IncrementIndexedCounterV1Ix::new(program_id, owner, 1);
+
+        assert_ne!(first.counter.pubkey, second.counter.pubkey);
+        assert_ne!(
+            first.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut ix = IncrementIndexedCounterV1Ix::new(program_id, owner, 3);
+        ix.owner.is_signer = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut ix = IncrementIndexedCounterV1Ix::new(program_id, owner, 3);
+        ix.counter.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_index_does_not_match_counter() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut ix = IncrementIndexedCounterV1Ix::new(program_id, owner, 3);
+        ix.index = 4;
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_indexed_counter_v1_address(&program_id, &owner, 3);
+
+        let instruction = IncrementIndexedCounterV1Ix::new(program_id, owner, 3)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::IncrementIndexedCounterV1)
+        );
+
+        let args = IncrementIndexedCounterV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.index, 3);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut ix = IncrementIndexedCounterV1Ix::new(program_id, owner, 3);
+        ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(ix).unwrap_err();
+        match err {
+            IncrementIndexedCounterV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use {
+    crate::{find_config_v1_address, find_program_data_address},
+    pinocchio_counter_program::{InitializeConfigV1Args, InstructionDiscriminator},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeConfigV1IxError {
+    #[error("Authority must be a signer")]
+    AuthorityMustBeSigner,
+
+    #[error("Authority must be writable")]
+    AuthorityMustBeWriteable,
+
+    #[error("Config account must be writable")]
+    ConfigMustBeWriteable,
+
+    #[error("Config address mismatch. Expected: {expected}, Observed: {observed}")]
+    ConfigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Program data address mismatch. Expected: {expected}, Observed: {observed}")]
+    ProgramDataAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `InitializeConfigV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for creating the program's
+/// config account. Only the program's upgrade authority may send it, and it pays for the account.
+#[derive(Debug, Clone)]
+pub struct InitializeConfigV1Ix {
+    pub program_id: Pubkey,
+    pub authority: AccountMeta,
+    pub config: AccountMeta,
+    pub program_data: AccountMeta,
+    pub system_program: AccountMeta,
+    pub admin: Pubkey,
+}
+
+impl InitializeConfigV1Ix {
+    /// Creates a new instruction builder for `InitializeConfigV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `authority` - The public key of the program's upgrade authority.
+    /// * `admin` - The key that will be allowed to pause the program.
+    ///
+    /// # Returns
+    ///
+    /// A new `InitializeConfigV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, authority: Pubkey, admin: Pubkey) -> Self {
+        Self {
+            program_id,
+            authority: AccountMeta {
+                pubkey: authority,
+                is_signer: true,
+                is_writable: true,
+            },
+            config: AccountMeta {
+                pubkey: find_config_v1_address(&program_id),
+                is_signer: false,
+                is_writable: true,
+            },
+            program_data: AccountMeta {
+                pubkey: find_program_data_address(&program_id),
+                is_signer: false,
+                is_writable: false,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            admin,
+        }
+    }
+
+    /// Sets the authority account metadata.
+    #[must_use]
+    pub fn with_authority(mut self, authority: AccountMeta) -> Self {
+        self.authority = authority;
+        self
+    }
+
+    /// Sets the config account metadata.
+    #[must_use]
+    pub fn with_config(mut self, config: AccountMeta) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the program data account metadata.
+    #[must_use]
+    pub fn with_program_data(mut self, program_data: AccountMeta) -> Self {
+        self.program_data = program_data;
+        self
+    }
+
+    /// Sets the admin.
+    #[must_use]
+    pub fn with_admin(mut self, admin: Pubkey) -> Self {
+        self.admin = admin;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeConfigV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), InitializeConfigV1IxError> {
+        if !self.authority.is_signer {
+            return Err(InitializeConfigV1IxError::AuthorityMustBeSigner);
+        }
+
+        if !self.authority.is_writable {
+            return Err(InitializeConfigV1IxError::AuthorityMustBeWriteable);
+        }
+
+        if !self.config.is_writable {
+            return Err(InitializeConfigV1IxError::ConfigMustBeWriteable);
+        }
+
+        let expected_config = find_config_v1_address(&self.program_id);
+        let observed_config = self.config.pubkey;
+        if observed_config != expected_config {
+            return Err(InitializeConfigV1IxError::ConfigAddressMismatch {
+                expected: expected_config,
+                observed: observed_config,
+            });
+        }
+
+        let expected_program_data = find_program_data_address(&self.program_id);
+        let observed_program_data = self.program_data.pubkey;
+        if observed_program_data != expected_program_data {
+            return Err(InitializeConfigV1IxError::ProgramDataAddressMismatch {
+                expected: expected_program_data,
+                observed: observed_program_data,
+            });
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(InitializeConfigV1IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeConfigV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, InitializeConfigV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = InitializeConfigV1Args {
+            admin: self.admin.to_bytes(),
+        };
+        let args_data =
+            serialize(&args).map_err(|_| InitializeConfigV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::InitializeConfigV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.authority,
+                self.config,
+                self.program_data,
+                self.system_program,
+            ],
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<InitializeConfigV1Ix> for Instruction {
+    type Error = InitializeConfigV1IxError;
+
+    fn try_from(value: InitializeConfigV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let init_ix = InitializeConfigV1Ix::new(program_id, authority, admin);
+
+        assert_eq!(init_ix.program_id, program_id);
+        assert_eq!(init_ix.authority.pubkey, authority);
+        assert_eq!(init_ix.config.pubkey, find_config_v1_address(&program_id));
+        assert_eq!(
+            init_ix.program_data.pubkey,
+            find_program_data_address(&program_id)
+        );
+        assert_eq!(init_ix.system_program.pubkey, solana_system_program::id());
+        assert_eq!(init_ix.admin, admin);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        assert!(init_ix.authority.is_signer);
+        assert!(init_ix.authority.is_writable);
+        assert!(!init_ix.config.is_signer);
+        assert!(init_ix.config.is_writable);
+        assert!(!init_ix.program_data.is_signer);
+        assert!(!init_ix.program_data.is_writable);
+
+        assert!(init_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_signer() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.authority.is_signer = false;
+
+        let err = init_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Authority must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_writable() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.authority.is_writable = false;
+
+        let err = init_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Authority must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_config_not_writable() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.config.is_writable = false;
+
+        let err = init_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Config account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_config_address_mismatch() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.config.pubkey = Pubkey::new_unique();
+
+        let err = init_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Config address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_program_data_address_mismatch() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.program_data.pubkey = Pubkey::new_unique();
+
+        let err = init_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Program data address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = init_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let instruction = InitializeConfigV1Ix::new(program_id, authority, admin)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[0].pubkey, authority);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_config_v1_address(&program_id)
+        );
+        assert_eq!(
+            instruction.accounts[2].pubkey,
+            find_program_data_address(&program_id)
+        );
+        assert_eq!(instruction.accounts[3].pubkey, solana_system_program::id());
+
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::InitializeConfigV1)
+        );
+        let args = InitializeConfigV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.admin, admin.to_bytes());
+    }
+
+    #[test]
+    fn test_to_instruction_respects_validate_flag() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.authority.is_signer = false;
+        assert!(init_ix.clone().to_instruction(true).is_err());
+        assert!(init_ix.to_instruction(false).is_ok());
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut init_ix = InitializeConfigV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.config.is_writable = false;
+
+        let err = Instruction::try_from(init_ix).unwrap_err();
+        match err {
+            InitializeConfigV1IxError::ConfigMustBeWriteable => {}
+            _ => panic!("Expected ConfigMustBeWriteable, got {err:?}"),
+        }
+    }
+}
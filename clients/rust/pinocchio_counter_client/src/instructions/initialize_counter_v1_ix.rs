@@ -1,5 +1,5 @@
 use {
-    crate::find_counter_v1_address,
+    crate::{config_v1_account_meta, find_counter_v1_address, find_tombstone_v1_address},
     pinocchio_counter_program::InstructionDiscriminator,
     solana_instruction::{AccountMeta, Instruction},
     solana_pubkey::Pubkey,
@@ -21,6 +21,9 @@ pub enum InitializeCounterV1IxError {
 
     #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
     SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Tombstone address mismatch: expected {expected:?}, observed {observed:?}")]
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
 }
 
 pub struct InitializeCounterV1Ix {
@@ -28,6 +31,7 @@ pub struct InitializeCounterV1Ix {
     pub payer: AccountMeta,
     pub counter: AccountMeta,
     pub system_program: AccountMeta,
+    pub tombstone: AccountMeta,
 }
 
 impl InitializeCounterV1Ix {
@@ -52,6 +56,11 @@ impl InitializeCounterV1Ix {
                 is_signer: false,
                 is_writable: false,
             },
+            tombstone: AccountMeta {
+                pubkey: find_tombstone_v1_address(&program_id, &counter),
+                is_signer: false,
+                is_writable: false,
+            },
         }
     }
 
@@ -91,6 +100,15 @@ impl InitializeCounterV1Ix {
             });
         }
 
+        let expected_tombstone = find_tombstone_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_tombstone = self.tombstone.pubkey;
+        if observed_tombstone != expected_tombstone {
+            return Err(InitializeCounterV1IxError::TombstoneAddressMismatch {
+                expected: expected_tombstone,
+                observed: observed_tombstone,
+            });
+        }
+
         Ok(())
     }
 
@@ -106,7 +124,13 @@ impl InitializeCounterV1Ix {
 
         Ok(Instruction {
             program_id: self.program_id,
-            accounts: vec![self.payer, self.counter, self.system_program],
+            accounts: vec![
+                self.payer,
+                self.counter,
+                self.system_program,
+                self.tombstone,
+                config_v1_account_meta(&self.program_id),
+            ],
             data: vec![InstructionDiscriminator::InitializeCounterV1.into()],
         })
     }
@@ -156,6 +180,14 @@ mod tests {
         assert!(!init_ix.system_program.is_signer);
         assert!(!init_ix.system_program.is_writable);
         assert_eq!(init_ix.system_program.pubkey, solana_system_program::id());
+
+        // Tombstone is only read
+        assert!(!init_ix.tombstone.is_signer);
+        assert!(!init_ix.tombstone.is_writable);
+        assert_eq!(
+            init_ix.tombstone.pubkey,
+            find_tombstone_v1_address(&program_id, &init_ix.counter.pubkey)
+        );
     }
 
     #[test]
@@ -288,6 +320,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_fails_when_tombstone_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut init_ix = InitializeCounterV1Ix::new(program_id, payer);
+        init_ix.tombstone.pubkey = Pubkey::new_unique();
+
+        let err = init_ix.validate().unwrap_err();
+        match err {
+            InitializeCounterV1IxError::TombstoneAddressMismatch { .. } => {}
+            _ => panic!("Expected TombstoneAddressMismatch, got {err:?}"),
+        }
+        assert!(err.to_string().contains("Tombstone address mismatch"));
+    }
+
     #[test]
     fn test_to_instruction_creates_correct_structure() {
         let program_id = Pubkey::new_unique();
@@ -298,10 +346,18 @@ mod tests {
         let instruction = init_ix.to_instruction(true).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
         assert_eq!(instruction.accounts[0].pubkey, payer);
         assert_eq!(instruction.accounts[1].pubkey, expected_counter);
         assert_eq!(instruction.accounts[2].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            find_tombstone_v1_address(&program_id, &expected_counter)
+        );
         assert_eq!(
             instruction.data,
             vec![u8::from(InstructionDiscriminator::InitializeCounterV1)]
@@ -331,7 +387,7 @@ mod tests {
         // This should succeed even though it's invalid
         let instruction = init_ix4.to_instruction(false).unwrap();
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(instruction.accounts.len(), 5);
         assert_eq!(instruction.accounts[0].pubkey, payer);
         assert_eq!(
             instruction.accounts[1].pubkey,
@@ -353,7 +409,7 @@ mod tests {
         let instruction = Instruction::try_from(init_ix).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(instruction.accounts.len(), 5);
         assert_eq!(
             instruction.data,
             vec![u8::from(InstructionDiscriminator::InitializeCounterV1)]
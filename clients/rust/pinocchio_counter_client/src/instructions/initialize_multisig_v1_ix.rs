@@ -0,0 +1,464 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
+    pinocchio_counter_program::{
+        InitializeMultisigV1Args, InstructionDiscriminator, MAX_MULTISIG_MEMBERS,
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeMultisigV1IxError {
+    #[error("Authority must be a signer")]
+    AuthorityMustBeSigner,
+
+    #[error("Authority must be writable")]
+    AuthorityMustBeWriteable,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig account must be writable")]
+    MultisigMustBeWriteable,
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Invalid member count: expected between 1 and {maximum}, observed {observed}")]
+    InvalidMemberCount { maximum: usize, observed: usize },
+
+    #[error("Duplicate member: {member}")]
+    DuplicateMember { member: Pubkey },
+
+    #[error("Invalid threshold: {threshold} of {member_count} members")]
+    InvalidThreshold { threshold: u8, member_count: usize },
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `InitializeMultisigV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for handing a counter's
+/// authority to a multisig of `members`, of which `threshold` must sign later updates. The
+/// authority pays for the multisig account and for the additional rent when a `CounterV1`
+/// account is upgraded to `CounterV2`.
+#[derive(Debug, Clone)]
+pub struct InitializeMultisigV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub authority: AccountMeta,
+    pub counter: AccountMeta,
+    pub multisig: AccountMeta,
+    pub system_program: AccountMeta,
+    pub members: Vec<AccountMeta>,
+    pub threshold: u8,
+}
+
+impl InitializeMultisigV1Ix {
+    /// Creates a new instruction builder for `InitializeMultisigV1`, signed by the creator.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `members` - The public keys of the multisig members, which do not need to sign.
+    /// * `threshold` - The number of distinct members that must sign later updates.
+    ///
+    /// # Returns
+    ///
+    /// A new `InitializeMultisigV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, creator: Pubkey, members: &[Pubkey], threshold: u8) -> Self {
+        let counter = find_counter_v1_address(&program_id, &creator);
+        let multisig = find_multisig_v1_address(&program_id, &counter);
+
+        Self {
+            program_id,
+            creator,
+            authority: AccountMeta {
+                pubkey: creator,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            multisig: AccountMeta {
+                pubkey: multisig,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            members: members
+                .iter()
+                .map(|member| AccountMeta::new_readonly(*member, false))
+                .collect(),
+            threshold,
+        }
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.authority.pubkey = authority;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the multisig account metadata.
+    #[must_use]
+    pub fn with_multisig(mut self, multisig: AccountMeta) -> Self {
+        self.multisig = multisig;
+        self
+    }
+
+    /// Sets the member account metadata.
+    #[must_use]
+    pub fn with_members(mut self, members: Vec<AccountMeta>) -> Self {
+        self.members = members;
+        self
+    }
+
+    /// Sets the number of distinct members that must sign later updates.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: u8) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Validates the instruction's account metadata, members and threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeMultisigV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), InitializeMultisigV1IxError> {
+        if !self.authority.is_signer {
+            return Err(InitializeMultisigV1IxError::AuthorityMustBeSigner);
+        }
+
+        if !self.authority.is_writable {
+            return Err(InitializeMultisigV1IxError::AuthorityMustBeWriteable);
+        }
+
+        if !self.counter.is_writable {
+            return Err(InitializeMultisigV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(InitializeMultisigV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig.is_writable {
+            return Err(InitializeMultisigV1IxError::MultisigMustBeWriteable);
+        }
+
+        let expected_multisig = find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_multisig = self.multisig.pubkey;
+        if observed_multisig != expected_multisig {
+            return Err(InitializeMultisigV1IxError::MultisigAddressMismatch {
+                expected: expected_multisig,
+                observed: observed_multisig,
+            });
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(InitializeMultisigV1IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        let maximum = usize::from(MAX_MULTISIG_MEMBERS);
+        if self.members.is_empty() || self.members.len() > maximum {
+            return Err(InitializeMultisigV1IxError::InvalidMemberCount {
+                maximum,
+                observed: self.members.len(),
+            });
+        }
+
+        for (index, member) in self.members.iter().enumerate() {
+            if self.members[..index]
+                .iter()
+                .any(|previous| previous.pubkey == member.pubkey)
+            {
+                return Err(InitializeMultisigV1IxError::DuplicateMember {
+                    member: member.pubkey,
+                });
+            }
+        }
+
+        if self.threshold == 0 || usize::from(self.threshold) > self.members.len() {
+            return Err(InitializeMultisigV1IxError::InvalidThreshold {
+                threshold: self.threshold,
+                member_count: self.members.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeMultisigV1IxError`] if `validate` is `true` and validation fails, or
+    /// if serialization of instruction arguments fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, InitializeMultisigV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = InitializeMultisigV1Args {
+            threshold: self.threshold,
+        };
+        let args_data =
+            serialize(&args).map_err(|_| InitializeMultisigV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::InitializeMultisigV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![
+            self.authority,
+            self.counter,
+            self.multisig,
+            self.system_program,
+        ];
+        accounts.extend(self.members);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<InitializeMultisigV1Ix> for Instruction {
+    type Error = InitializeMultisigV1IxError;
+
+    fn try_from(value: InitializeMultisigV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_multisig_v1_address},
+    };
+
+    fn unique_members(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let members = unique_members(3);
+        let expected_counter = find_counter_v1_address(&program_id, &creator);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let multisig_ix = InitializeMultisigV1Ix::new(program_id, creator, &members, 2);
+
+        assert_eq!(multisig_ix.program_id, program_id);
+        assert_eq!(multisig_ix.authority.pubkey, creator);
+        assert!(multisig_ix.authority.is_signer);
+        assert!(multisig_ix.authority.is_writable);
+        assert_eq!(multisig_ix.counter.pubkey, expected_counter);
+        assert_eq!(multisig_ix.multisig.pubkey, expected_multisig);
+        assert!(multisig_ix.multisig.is_writable);
+        assert_eq!(
+            multisig_ix.system_program.pubkey,
+            solana_system_program::id()
+        );
+        assert_eq!(multisig_ix.members.len(), 3);
+        assert!(multisig_ix
+            .members
+            .iter()
+            .all(|member| !member.is_signer && !member.is_writable));
+        assert_eq!(multisig_ix.threshold, 2);
+        assert!(multisig_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let mut multisig_ix =
+            InitializeMultisigV1Ix::new(program_id, creator, &unique_members(2), 1);
+        multisig_ix.authority.is_signer = false;
+
+        let err = multisig_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Authority must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_multisig_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let mut multisig_ix =
+            InitializeMultisigV1Ix::new(program_id, creator, &unique_members(2), 1);
+        multisig_ix.multisig.pubkey = Pubkey::new_unique();
+
+        let err = multisig_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Multisig address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_without_members() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let multisig_ix = InitializeMultisigV1Ix::new(program_id, creator, &[], 1);
+
+        let err = multisig_ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            InitializeMultisigV1IxError::InvalidMemberCount { observed: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_fails_with_too_many_members() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let members = unique_members(usize::from(MAX_MULTISIG_MEMBERS) + 1);
+
+        let multisig_ix = InitializeMultisigV1Ix::new(program_id, creator, &members, 1);
+
+        let err = multisig_ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            InitializeMultisigV1IxError::InvalidMemberCount { .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_fails_with_duplicate_member() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let member = Pubkey::new_unique();
+
+        let multisig_ix = InitializeMultisigV1Ix::new(
+            program_id,
+            creator,
+            &[member, Pubkey::new_unique(), member],
+            2,
+        );
+
+        let err = multisig_ix.validate().unwrap_err();
+        match err {
+            InitializeMultisigV1IxError::DuplicateMember { member: duplicate } => {
+                assert_eq!(duplicate, member);
+            }
+            _ => panic!("Expected DuplicateMember, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_fails_with_invalid_threshold() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let members = unique_members(2);
+
+        for threshold in [0, 3] {
+            let multisig_ix = InitializeMultisigV1Ix::new(program_id, creator, &members, threshold);
+
+            let err = multisig_ix.validate().unwrap_err();
+            assert!(matches!(
+                err,
+                InitializeMultisigV1IxError::InvalidThreshold {
+                    member_count: 2,
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let members = unique_members(3);
+        let expected_counter = find_counter_v1_address(&program_id, &creator);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let multisig_ix = InitializeMultisigV1Ix::new(program_id, creator, &members, 2);
+        let instruction = multisig_ix.to_instruction(true).unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 8);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, creator);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(instruction.accounts[2].pubkey, expected_multisig);
+        assert_eq!(instruction.accounts[3].pubkey, solana_system_program::id());
+        for (meta, member) in instruction.accounts[4..].iter().zip(&members) {
+            assert_eq!(meta.pubkey, *member);
+        }
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::InitializeMultisigV1)
+        );
+
+        let args = InitializeMultisigV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.threshold, 2);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let mut multisig_ix =
+            InitializeMultisigV1Ix::new(program_id, creator, &unique_members(2), 1);
+        multisig_ix.authority.is_signer = false;
+
+        let err = Instruction::try_from(multisig_ix).unwrap_err();
+        match err {
+            InitializeMultisigV1IxError::AuthorityMustBeSigner => {}
+            _ => panic!("Expected AuthorityMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use {
+    crate::{
+        config_v1_account_meta, find_counter_shard_v1_address, find_sharded_counter_v1_address,
+    },
+    pinocchio_counter_program::{InstructionDiscriminator, MAX_COUNTER_SHARDS},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeShardedCounterV1IxError {
+    #[error("Payer must be a signer")]
+    PayerMustBeSigner,
+
+    #[error("Payer must be writable")]
+    PayerMustBeWriteable,
+
+    #[error("Sharded counter account must be writable")]
+    ShardedCounterMustBeWriteable,
+
+    #[error("Sharded counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    ShardedCounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch. Expected: {expected}, Observed: {observed}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Invalid shard count. Expected 1 to {maximum} shards, Observed: {observed}")]
+    InvalidShardCount { maximum: usize, observed: usize },
+
+    #[error("Shard account must be writable")]
+    ShardMustBeWriteable,
+
+    #[error("Shard address mismatch. Expected: {expected}, Observed: {observed}")]
+    ShardAddressMismatch { expected: Pubkey, observed: Pubkey },
+}
+
+/// Instruction builder for `InitializeShardedCounterV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for initializing a sharded
+/// counter owned by the payer, together with all of its shards. The sharded counter address is
+/// derived from the payer, and each shard address from the sharded counter and the shard index.
+#[derive(Debug, Clone)]
+pub struct InitializeShardedCounterV1Ix {
+    pub program_id: Pubkey,
+    pub payer: AccountMeta,
+    pub sharded_counter: AccountMeta,
+    pub system_program: AccountMeta,
+    /// The shards to create, in index order.
+    pub shards: Vec<AccountMeta>,
+}
+
+impl InitializeShardedCounterV1Ix {
+    /// Creates a new instruction builder for `InitializeShardedCounterV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer` - The public key of the sharded counter's owner, who pays for all accounts.
+    /// * `shard_count` - The number of shards to create, from 1 to `MAX_COUNTER_SHARDS`.
+    ///
+    /// # Returns
+    ///
+    /// A new `InitializeShardedCounterV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, payer: Pubkey, shard_count: u8) -> Self {
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &payer);
+        let shards = (0..shard_count)
+            .map(|index| AccountMeta {
+                pubkey: find_counter_shard_v1_address(&program_id, &sharded_counter, index),
+                is_signer: false,
+                is_writable: true,
+            })
+            .collect();
+
+        Self {
+            program_id,
+            payer: AccountMeta {
+                pubkey: payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            sharded_counter: AccountMeta {
+                pubkey: sharded_counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            shards,
+        }
+    }
+
+    /// Sets the payer account metadata.
+    #[must_use]
+    pub fn with_payer(mut self, payer: AccountMeta) -> Self {
+        self.payer = payer;
+        self
+    }
+
+    /// Sets the sharded counter account metadata.
+    #[must_use]
+    pub fn with_sharded_counter(mut self, sharded_counter: AccountMeta) -> Self {
+        self.sharded_counter = sharded_counter;
+        self
+    }
+
+    /// Sets the shard account metadata.
+    #[must_use]
+    pub fn with_shards(mut self, shards: Vec<AccountMeta>) -> Self {
+        self.shards = shards;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeShardedCounterV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), InitializeShardedCounterV1IxError> {
+        if !self.payer.is_signer {
+            return Err(InitializeShardedCounterV1IxError::PayerMustBeSigner);
+        }
+
+        if !self.payer.is_writable {
+            return Err(InitializeShardedCounterV1IxError::PayerMustBeWriteable);
+        }
+
+        if !self.sharded_counter.is_writable {
+            return Err(InitializeShardedCounterV1IxError::ShardedCounterMustBeWriteable);
+        }
+
+        let expected_sharded_counter =
+            find_sharded_counter_v1_address(&self.program_id, &self.payer.pubkey);
+        let observed_sharded_counter = self.sharded_counter.pubkey;
+        if observed_sharded_counter != expected_sharded_counter {
+            return Err(
+                InitializeShardedCounterV1IxError::ShardedCounterAddressMismatch {
+                    expected: expected_sharded_counter,
+                    observed: observed_sharded_counter,
+                },
+            );
+        }
+
+        let expected_system_program = solana_system_program::id();
+        let observed_system_program = self.system_program.pubkey;
+        if observed_system_program != expected_system_program {
+            return Err(
+                InitializeShardedCounterV1IxError::SystemProgramAddressMismatch {
+                    expected: expected_system_program,
+                    observed: observed_system_program,
+                },
+            );
+        }
+
+        if self.shards.is_empty() || self.shards.len() > usize::from(MAX_COUNTER_SHARDS) {
+            return Err(InitializeShardedCounterV1IxError::InvalidShardCount {
+                maximum: usize::from(MAX_COUNTER_SHARDS),
+                observed: self.shards.len(),
+            });
+        }
+
+        for (index, shard) in (0u8..).zip(&self.shards) {
+            if !shard.is_writable {
+                return Err(InitializeShardedCounterV1IxError::ShardMustBeWriteable);
+            }
+
+            let expected_shard =
+                find_counter_shard_v1_address(&self.program_id, &expected_sharded_counter, index);
+            if shard.pubkey != expected_shard {
+                return Err(InitializeShardedCounterV1IxError::ShardAddressMismatch {
+                    expected: expected_shard,
+                    observed: shard.pubkey,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeShardedCounterV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, InitializeShardedCounterV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let mut accounts = vec![self.payer, self.sharded_counter, self.system_program];
+        accounts.extend(self.shards);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![InstructionDiscriminator::InitializeShardedCounterV1.into()],
+        })
+    }
+}
+
+impl TryFrom<InitializeShardedCounterV1Ix> for Instruction {
+    type Error = InitializeShardedCounterV1IxError;
+
+    fn try_from(value: InitializeShardedCounterV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let expected_sharded_counter = find_sharded_counter_v1_address(&program_id, &payer);
+
+        let ix = InitializeShardedCounterV1Ix::new(program_id, payer, 4);
+
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.payer.pubkey, payer);
+        assert_eq!(ix.sharded_counter.pubkey, expected_sharded_counter);
+        assert_ne!(
+            ix.sharded_counter.pubkey,
+            find_counter_v1_address(&program_id, &payer)
+        );
+        assert_eq!(ix.shards.len(), 4);
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_signer() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.payer.is_signer = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_writable() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.payer.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_sharded_counter_not_writable() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.sharded_counter.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Sharded counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_sharded_counter_address_mismatch() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.sharded_counter.pubkey = Pubkey::new_unique();
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Sharded counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_without_shards() {
+        let ix = InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+
+        let err = ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            InitializeShardedCounterV1IxError::InvalidShardCount { observed: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_fails_with_too_many_shards() {
+        let ix = InitializeShardedCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            MAX_COUNTER_SHARDS + 1,
+        );
+
+        let err = ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            InitializeShardedCounterV1IxError::InvalidShardCount { .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_fails_when_shard_not_writable() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.shards[1].is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Shard account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_shards_out_of_order() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.shards.swap(0, 1);
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Shard address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &payer);
+
+        let instruction = InitializeShardedCounterV1Ix::new(program_id, payer, 3)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 7);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, payer);
+        assert_eq!(instruction.accounts[1].pubkey, sharded_counter);
+        assert_eq!(instruction.accounts[2].pubkey, solana_system_program::id());
+        for (index, account) in (0u8..).zip(&instruction.accounts[3..6]) {
+            assert_eq!(
+                account.pubkey,
+                find_counter_shard_v1_address(&program_id, &sharded_counter, index)
+            );
+        }
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(
+                InstructionDiscriminator::InitializeShardedCounterV1
+            )]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut ix =
+            InitializeShardedCounterV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.payer.is_signer = false;
+
+        let err = Instruction::try_from(ix).unwrap_err();
+        match err {
+            InitializeShardedCounterV1IxError::PayerMustBeSigner => {}
+            _ => panic!("Expected PayerMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_tombstone_v1_address},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeSponsoredCounterV1IxError {
+    #[error("Payer must be a signer")]
+    PayerMustBeSigner,
+
+    #[error("Payer must be writable")]
+    PayerMustBeWriteable,
+
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch: expected {expected:?}, observed {observed:?}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Tombstone address mismatch: expected {expected:?}, observed {observed:?}")]
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
+}
+
+/// Instruction builder for `InitializeSponsoredCounterV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for initializing the owner's
+/// counter with the rent paid by a separate payer, such as a relayer. The payer is refunded when
+/// the counter is deactivated.
+pub struct InitializeSponsoredCounterV1Ix {
+    pub program_id: Pubkey,
+    pub payer: AccountMeta,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub system_program: AccountMeta,
+    pub tombstone: AccountMeta,
+}
+
+impl InitializeSponsoredCounterV1Ix {
+    /// Creates a new instruction builder for `InitializeSponsoredCounterV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer` - The public key of the account paying the counter's rent.
+    /// * `owner` - The public key of the counter's owner, from which the counter address is
+    ///   derived.
+    ///
+    /// # Returns
+    ///
+    /// A new `InitializeSponsoredCounterV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, payer: Pubkey, owner: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            payer: AccountMeta {
+                pubkey: payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            tombstone: AccountMeta {
+                pubkey: find_tombstone_v1_address(&program_id, &counter),
+                is_signer: false,
+                is_writable: false,
+            },
+        }
+    }
+
+    /// Validates that the account metadata and addresses are correct.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeSponsoredCounterV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), InitializeSponsoredCounterV1IxError> {
+        if !self.payer.is_signer {
+            return Err(InitializeSponsoredCounterV1IxError::PayerMustBeSigner);
+        }
+
+        if !self.payer.is_writable {
+            return Err(InitializeSponsoredCounterV1IxError::PayerMustBeWriteable);
+        }
+
+        if !self.owner.is_signer {
+            return Err(InitializeSponsoredCounterV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(InitializeSponsoredCounterV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(
+                InitializeSponsoredCounterV1IxError::CounterAddressMismatch {
+                    expected: expected_counter,
+                    observed: observed_counter,
+                },
+            );
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(
+                InitializeSponsoredCounterV1IxError::SystemProgramAddressMismatch {
+                    expected: expected_system_program,
+                    observed: observed_system_program,
+                },
+            );
+        }
+
+        let expected_tombstone = find_tombstone_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_tombstone = self.tombstone.pubkey;
+        if observed_tombstone != expected_tombstone {
+            return Err(
+                InitializeSponsoredCounterV1IxError::TombstoneAddressMismatch {
+                    expected: expected_tombstone,
+                    observed: observed_tombstone,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeSponsoredCounterV1IxError`] if `validate` is `true` and validation
+    /// fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, InitializeSponsoredCounterV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.payer,
+                self.owner,
+                self.counter,
+                self.system_program,
+                self.tombstone,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: vec![InstructionDiscriminator::InitializeSponsoredCounterV1.into()],
+        })
+    }
+}
+
+impl TryFrom<InitializeSponsoredCounterV1Ix> for Instruction {
+    type Error = InitializeSponsoredCounterV1IxError;
+
+    fn try_from(value: InitializeSponsoredCounterV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_derives_counter_pda_from_owner() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let init_ix = InitializeSponsoredCounterV1Ix::new(program_id, payer, owner);
+
+        assert_eq!(init_ix.program_id, program_id);
+        assert_eq!(init_ix.payer.pubkey, payer);
+        assert_eq!(init_ix.owner.pubkey, owner);
+        assert_eq!(
+            init_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_ne!(
+            init_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &payer)
+        );
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        assert!(init_ix.payer.is_signer);
+        assert!(init_ix.payer.is_writable);
+        assert!(init_ix.owner.is_signer);
+        assert!(!init_ix.owner.is_writable);
+        assert!(!init_ix.counter.is_signer);
+        assert!(init_ix.counter.is_writable);
+        assert!(!init_ix.tombstone.is_signer);
+        assert!(!init_ix.tombstone.is_writable);
+
+        assert!(init_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_signer() {
+        let mut init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.payer.is_signer = false;
+
+        let err = init_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_writable() {
+        let mut init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.payer.is_writable = false;
+
+        let err = init_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let mut init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.owner.is_signer = false;
+
+        let err = init_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        // A counter derived from the payer instead of the owner
+        let mut init_ix =
+            InitializeSponsoredCounterV1Ix::new(program_id, payer, Pubkey::new_unique());
+        init_ix.counter.pubkey = find_counter_v1_address(&program_id, &payer);
+
+        let err = init_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = init_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_tombstone_address_mismatch() {
+        let mut init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.tombstone.pubkey = Pubkey::new_unique();
+
+        let err = init_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Tombstone address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let instruction = InitializeSponsoredCounterV1Ix::new(program_id, payer, owner)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, payer);
+        assert_eq!(instruction.accounts[1].pubkey, owner);
+        assert_eq!(
+            instruction.accounts[2].pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_eq!(instruction.accounts[3].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.accounts[4].pubkey,
+            find_tombstone_v1_address(&program_id, &instruction.accounts[2].pubkey)
+        );
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(
+                InstructionDiscriminator::InitializeSponsoredCounterV1
+            )]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut init_ix = InitializeSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        init_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(init_ix).unwrap_err();
+        match err {
+            InitializeSponsoredCounterV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+}
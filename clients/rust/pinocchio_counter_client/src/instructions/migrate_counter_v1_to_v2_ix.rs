@@ -0,0 +1,330 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrateCounterV1ToV2IxError {
+    #[error("Payer must be a signer")]
+    PayerMustBeSigner,
+
+    #[error("Payer must be writable")]
+    PayerMustBeWriteable,
+
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch: expected {expected:?}, observed {observed:?}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+}
+
+/// Instruction builder for `MigrateCounterV1ToV2`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for migrating the owner's
+/// `CounterV1` account to the `CounterV2` layout, with the increased rent paid by the payer.
+pub struct MigrateCounterV1ToV2Ix {
+    pub program_id: Pubkey,
+    pub payer: AccountMeta,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub system_program: AccountMeta,
+}
+
+impl MigrateCounterV1ToV2Ix {
+    /// Creates a new instruction builder for `MigrateCounterV1ToV2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer` - The public key of the account topping up the counter's rent.
+    /// * `owner` - The public key of the counter's owner, from which the counter address is
+    ///   derived.
+    ///
+    /// # Returns
+    ///
+    /// A new `MigrateCounterV1ToV2Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, payer: Pubkey, owner: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            payer: AccountMeta {
+                pubkey: payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+        }
+    }
+
+    /// Validates that the account metadata and addresses are correct.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigrateCounterV1ToV2IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), MigrateCounterV1ToV2IxError> {
+        if !self.payer.is_signer {
+            return Err(MigrateCounterV1ToV2IxError::PayerMustBeSigner);
+        }
+
+        if !self.payer.is_writable {
+            return Err(MigrateCounterV1ToV2IxError::PayerMustBeWriteable);
+        }
+
+        if !self.owner.is_signer {
+            return Err(MigrateCounterV1ToV2IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(MigrateCounterV1ToV2IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(MigrateCounterV1ToV2IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        let expected_system_program = solana_system_program::id();
+        let observed_system_program = self.system_program.pubkey;
+        if observed_system_program != expected_system_program {
+            return Err(MigrateCounterV1ToV2IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigrateCounterV1ToV2IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, MigrateCounterV1ToV2IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.payer,
+                self.owner,
+                self.counter,
+                self.system_program,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: vec![InstructionDiscriminator::MigrateCounterV1ToV2.into()],
+        })
+    }
+}
+
+impl TryFrom<MigrateCounterV1ToV2Ix> for Instruction {
+    type Error = MigrateCounterV1ToV2IxError;
+
+    fn try_from(value: MigrateCounterV1ToV2Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_derives_counter_pda_from_owner() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let migrate_ix = MigrateCounterV1ToV2Ix::new(program_id, payer, owner);
+
+        assert_eq!(migrate_ix.program_id, program_id);
+        assert_eq!(migrate_ix.payer.pubkey, payer);
+        assert_eq!(migrate_ix.owner.pubkey, owner);
+        assert_eq!(
+            migrate_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_eq!(
+            migrate_ix.system_program.pubkey,
+            solana_system_program::id()
+        );
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        assert!(migrate_ix.payer.is_signer);
+        assert!(migrate_ix.payer.is_writable);
+        assert!(migrate_ix.owner.is_signer);
+        assert!(!migrate_ix.owner.is_writable);
+        assert!(!migrate_ix.counter.is_signer);
+        assert!(migrate_ix.counter.is_writable);
+
+        assert!(migrate_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_signer() {
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        migrate_ix.payer.is_signer = false;
+
+        let err = migrate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_writable() {
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        migrate_ix.payer.is_writable = false;
+
+        let err = migrate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        migrate_ix.owner.is_signer = false;
+
+        let err = migrate_ix.validate().unwrap_err();
+        match err {
+            MigrateCounterV1ToV2IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        migrate_ix.counter.is_writable = false;
+
+        let err = migrate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        // A counter derived from the payer instead of the owner
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(program_id, payer, Pubkey::new_unique());
+        migrate_ix.counter.pubkey = find_counter_v1_address(&program_id, &payer);
+
+        let err = migrate_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        migrate_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = migrate_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let instruction = MigrateCounterV1ToV2Ix::new(program_id, payer, owner)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, payer);
+        assert_eq!(instruction.accounts[1].pubkey, owner);
+        assert_eq!(
+            instruction.accounts[2].pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_eq!(instruction.accounts[3].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::MigrateCounterV1ToV2)]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut migrate_ix = MigrateCounterV1ToV2Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        migrate_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(migrate_ix).unwrap_err();
+        match err {
+            MigrateCounterV1ToV2IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -1,15 +1,97 @@
+mod accept_authority_v1_ix;
+mod approve_delegate_v1_ix;
+mod close_counter_v1_ix;
+mod compare_and_increment_count_v1_ix;
+mod compare_and_set_count_v1_ix;
 mod deactivate_counter_v1_ix;
+mod deactivate_indexed_counter_v1_ix;
+mod decrement_by_v1_ix;
 mod decrement_count_v1_ix;
+mod decrement_indexed_counter_v1_ix;
+mod execute_batch_v1_ix;
+mod freeze_counter_v1_ix;
+mod get_count_v1_ix;
+mod increment_by_v1_ix;
 mod increment_count_v1_ix;
+mod increment_counter_shard_v1_ix;
+mod increment_indexed_counter_v1_ix;
+mod initialize_config_v1_ix;
 mod initialize_counter_v1_ix;
+mod initialize_indexed_counter_v1_ix;
+mod initialize_multisig_v1_ix;
+mod initialize_sharded_counter_v1_ix;
+mod initialize_sponsored_counter_v1_ix;
+mod migrate_counter_v1_to_v2_ix;
+mod propose_authority_v1_ix;
 mod reactivate_counter_v1_ix;
+mod reactivate_indexed_counter_v1_ix;
+mod reactivate_sponsored_counter_v1_ix;
+mod reconcile_shards_v1_ix;
+mod revoke_delegate_v1_ix;
+mod set_access_mode_v1_ix;
+mod set_cooldown_v1_ix;
+mod set_count_policy_v1_ix;
 mod set_count_v1_ix;
+mod set_indexed_count_v1_ix;
+mod set_pause_exemption_v1_ix;
+mod set_paused_v1_ix;
+mod thaw_counter_v1_ix;
 
 pub use {
+    accept_authority_v1_ix::{AcceptAuthorityV1Ix, AcceptAuthorityV1IxError},
+    approve_delegate_v1_ix::{ApproveDelegateV1Ix, ApproveDelegateV1IxError},
+    close_counter_v1_ix::{CloseCounterV1Ix, CloseCounterV1IxError},
+    compare_and_increment_count_v1_ix::{
+        CompareAndIncrementCountV1Ix, CompareAndIncrementCountV1IxError,
+    },
+    compare_and_set_count_v1_ix::{CompareAndSetCountV1Ix, CompareAndSetCountV1IxError},
     deactivate_counter_v1_ix::{DeactivateCounterV1Ix, DeactivateCounterV1IxError},
+    deactivate_indexed_counter_v1_ix::{
+        DeactivateIndexedCounterV1Ix, DeactivateIndexedCounterV1IxError,
+    },
+    decrement_by_v1_ix::{DecrementByV1Ix, DecrementByV1IxError},
     decrement_count_v1_ix::{DecrementCountV1Ix, DecrementCountV1IxError},
+    decrement_indexed_counter_v1_ix::{
+        DecrementIndexedCounterV1Ix, DecrementIndexedCounterV1IxError,
+    },
+    execute_batch_v1_ix::{ExecuteBatchV1Ix, ExecuteBatchV1IxError},
+    freeze_counter_v1_ix::{FreezeCounterV1Ix, FreezeCounterV1IxError},
+    get_count_v1_ix::{GetCountV1Ix, GetCountV1IxError},
+    increment_by_v1_ix::{IncrementByV1Ix, IncrementByV1IxError},
     increment_count_v1_ix::{IncrementCountV1Ix, IncrementCountV1IxError},
+    increment_counter_shard_v1_ix::{IncrementCounterShardV1Ix, IncrementCounterShardV1IxError},
+    increment_indexed_counter_v1_ix::{
+        IncrementIndexedCounterV1Ix, IncrementIndexedCounterV1IxError,
+    },
+    initialize_config_v1_ix::{InitializeConfigV1Ix, InitializeConfigV1IxError},
     initialize_counter_v1_ix::{InitializeCounterV1Ix, InitializeCounterV1IxError},
+    initialize_indexed_counter_v1_ix::{
+        InitializeIndexedCounterV1Ix, InitializeIndexedCounterV1IxError,
+    },
+    initialize_multisig_v1_ix::{InitializeMultisigV1Ix, InitializeMultisigV1IxError},
+    initialize_sharded_counter_v1_ix::{
+        InitializeShardedCounterV1Ix, InitializeShardedCounterV1IxError,
+    },
+    initialize_sponsored_counter_v1_ix::{
+        InitializeSponsoredCounterV1Ix, InitializeSponsoredCounterV1IxError,
+    },
+    migrate_counter_v1_to_v2_ix::{MigrateCounterV1ToV2Ix, MigrateCounterV1ToV2IxError},
+    propose_authority_v1_ix::{ProposeAuthorityV1Ix, ProposeAuthorityV1IxError},
     reactivate_counter_v1_ix::{ReactivateCounterV1Ix, ReactivateCounterV1IxError},
+    reactivate_indexed_counter_v1_ix::{
+        ReactivateIndexedCounterV1Ix, ReactivateIndexedCounterV1IxError,
+    },
+    reactivate_sponsored_counter_v1_ix::{
+        ReactivateSponsoredCounterV1Ix, ReactivateSponsoredCounterV1IxError,
+    },
+    reconcile_shards_v1_ix::{ReconcileShardsV1Ix, ReconcileShardsV1IxError},
+    revoke_delegate_v1_ix::{RevokeDelegateV1Ix, RevokeDelegateV1IxError},
+    set_access_mode_v1_ix::{SetAccessModeV1Ix, SetAccessModeV1IxError},
+    set_cooldown_v1_ix::{SetCooldownV1Ix, SetCooldownV1IxError},
+    set_count_policy_v1_ix::{SetCountPolicyV1Ix, SetCountPolicyV1IxError},
     set_count_v1_ix::{SetCountV1Ix, SetCountV1IxError},
+    set_indexed_count_v1_ix::{SetIndexedCountV1Ix, SetIndexedCountV1IxError},
+    set_pause_exemption_v1_ix::{SetPauseExemptionV1Ix, SetPauseExemptionV1IxError},
+    set_paused_v1_ix::{SetPausedV1Ix, SetPausedV1IxError},
+    thaw_counter_v1_ix::{ThawCounterV1Ix, ThawCounterV1IxError},
 };
@@ -0,0 +1,411 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
+    pinocchio_counter_program::{InstructionDiscriminator, ProposeAuthorityV1Args},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProposeAuthorityV1IxError {
+    #[error("Authority must be a signer")]
+    AuthorityMustBeSigner,
+
+    #[error("Authority must be writable")]
+    AuthorityMustBeWriteable,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `ProposeAuthorityV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for proposing a new
+/// authority for a counter. The authority pays for the additional rent when a `CounterV1`
+/// account is upgraded to `CounterV2`.
+#[derive(Debug, Clone)]
+pub struct ProposeAuthorityV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub authority: AccountMeta,
+    pub counter: AccountMeta,
+    pub system_program: AccountMeta,
+    pub new_authority: Pubkey,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`ProposeAuthorityV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl ProposeAuthorityV1Ix {
+    /// Creates a new instruction builder for `ProposeAuthorityV1`, signed by the creator.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `new_authority` - The public key proposed as the next authority.
+    ///
+    /// # Returns
+    ///
+    /// A new `ProposeAuthorityV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, creator: Pubkey, new_authority: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &creator);
+
+        Self {
+            program_id,
+            creator,
+            authority: AccountMeta {
+                pubkey: creator,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            new_authority,
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has already
+    /// been transferred.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.authority.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the authority and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.authority = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the proposed authority.
+    #[must_use]
+    pub fn with_new_authority(mut self, new_authority: Pubkey) -> Self {
+        self.new_authority = new_authority;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProposeAuthorityV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), ProposeAuthorityV1IxError> {
+        if self.multisig_signers.is_empty() && !self.authority.is_signer {
+            return Err(ProposeAuthorityV1IxError::AuthorityMustBeSigner);
+        }
+
+        if self.multisig_signers.is_empty() && !self.authority.is_writable {
+            return Err(ProposeAuthorityV1IxError::AuthorityMustBeWriteable);
+        }
+
+        if !self.counter.is_writable {
+            return Err(ProposeAuthorityV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(ProposeAuthorityV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.authority.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(ProposeAuthorityV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(ProposeAuthorityV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(ProposeAuthorityV1IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProposeAuthorityV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, ProposeAuthorityV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = ProposeAuthorityV1Args {
+            new_authority: self.new_authority.to_bytes(),
+        };
+        let args_data =
+            serialize(&args).map_err(|_| ProposeAuthorityV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::ProposeAuthorityV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![self.authority, self.counter, self.system_program];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<ProposeAuthorityV1Ix> for Instruction {
+    type Error = ProposeAuthorityV1IxError;
+
+    fn try_from(value: ProposeAuthorityV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &creator);
+
+        let propose_ix = ProposeAuthorityV1Ix::new(program_id, creator, new_authority);
+
+        assert_eq!(propose_ix.program_id, program_id);
+        assert_eq!(propose_ix.creator, creator);
+        assert_eq!(propose_ix.authority.pubkey, creator);
+        assert!(propose_ix.authority.is_signer);
+        assert!(propose_ix.authority.is_writable);
+        assert_eq!(propose_ix.counter.pubkey, expected_counter);
+        assert!(propose_ix.counter.is_writable);
+        assert_eq!(
+            propose_ix.system_program.pubkey,
+            solana_system_program::id()
+        );
+        assert_eq!(propose_ix.new_authority, new_authority);
+        assert!(propose_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let propose_ix = ProposeAuthorityV1Ix::new(program_id, creator, Pubkey::new_unique())
+            .with_authority(authority);
+
+        assert_eq!(propose_ix.authority.pubkey, authority);
+        assert_eq!(
+            propose_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &creator)
+        );
+        assert!(propose_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_signer() {
+        let mut propose_ix = ProposeAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        propose_ix.authority.is_signer = false;
+
+        let err = propose_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Authority must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_writable() {
+        let mut propose_ix = ProposeAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        propose_ix.authority.is_writable = false;
+
+        let err = propose_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Authority must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let mut propose_ix = ProposeAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        propose_ix.counter.is_writable = false;
+
+        let err = propose_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let mut propose_ix = ProposeAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        propose_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = propose_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut propose_ix = ProposeAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        propose_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = propose_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+
+        let instruction = ProposeAuthorityV1Ix::new(program_id, creator, new_authority)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, creator);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_counter_v1_address(&program_id, &creator)
+        );
+        assert_eq!(instruction.accounts[2].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::ProposeAuthorityV1)
+        );
+
+        let args = ProposeAuthorityV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.new_authority, new_authority.to_bytes());
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut propose_ix = ProposeAuthorityV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        propose_ix.authority.is_signer = false;
+
+        let err = Instruction::try_from(propose_ix).unwrap_err();
+        match err {
+            ProposeAuthorityV1IxError::AuthorityMustBeSigner => {}
+            _ => panic!("Expected AuthorityMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &creator);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let propose_ix = ProposeAuthorityV1Ix::new(program_id, creator, Pubkey::new_unique())
+            .with_multisig_signers(&signers);
+        assert!(propose_ix.validate().is_ok());
+
+        let instruction = propose_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[3..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::find_counter_v1_address,
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
     pinocchio_counter_program::InstructionDiscriminator,
     solana_instruction::{AccountMeta, Instruction},
     solana_pubkey::Pubkey,
@@ -21,6 +21,15 @@ pub enum ReactivateCounterV1IxError {
 
     #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
     SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Authority must be a signer")]
+    AuthorityMustBeSigner,
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
 }
 
 pub struct ReactivateCounterV1Ix {
@@ -28,6 +37,11 @@ pub struct ReactivateCounterV1Ix {
     pub payer: AccountMeta,
     pub counter: AccountMeta,
     pub system_program: AccountMeta,
+    /// The counter's authority, when it is no longer the creator.
+    pub authority: Option<AccountMeta>,
+    /// Members of the counter's multisig approving the reactivation, set with
+    /// [`ReactivateCounterV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
 }
 
 impl ReactivateCounterV1Ix {
@@ -52,9 +66,49 @@ impl ReactivateCounterV1Ix {
                 is_signer: false,
                 is_writable: false,
             },
+            authority: None,
+            multisig_signers: Vec::new(),
         }
     }
 
+    /// Approves the reactivation with `authority`, for counters whose authority had been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1` before they were
+    /// deactivated. The counter is restored with `authority` still in control.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(AccountMeta {
+            pubkey: authority,
+            is_signer: true,
+            is_writable: false,
+        });
+        self
+    }
+
+    /// Approves the reactivation with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1` before they were
+    /// deactivated.
+    ///
+    /// The multisig record takes the place of the authority and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    /// The counter is restored with the multisig still in control.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.authority = Some(AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        });
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta {
+                pubkey: *signer,
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+        self
+    }
+
     /// Validates that the account metadata and addresses are correct.
     ///
     /// # Errors
@@ -91,6 +145,31 @@ impl ReactivateCounterV1Ix {
             });
         }
 
+        if let Some(authority) = &self.authority {
+            if self.multisig_signers.is_empty() && !authority.is_signer {
+                return Err(ReactivateCounterV1IxError::AuthorityMustBeSigner);
+            }
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self
+                .authority
+                .as_ref()
+                .map_or_else(Pubkey::default, |authority| authority.pubkey);
+            if observed_multisig != expected_multisig {
+                return Err(ReactivateCounterV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(ReactivateCounterV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
         Ok(())
     }
 
@@ -104,9 +183,14 @@ impl ReactivateCounterV1Ix {
             self.validate()?;
         }
 
+        let mut accounts = vec![self.payer, self.counter, self.system_program];
+        accounts.extend(self.authority);
+        accounts.extend(self.multisig_signers);
+        accounts.push(config_v1_account_meta(&self.program_id));
+
         Ok(Instruction {
             program_id: self.program_id,
-            accounts: vec![self.payer, self.counter, self.system_program],
+            accounts,
             data: vec![InstructionDiscriminator::ReactivateCounterV1.into()],
         })
     }
@@ -278,7 +362,11 @@ mod tests {
         let instruction = reactivate_ix.to_instruction(true).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
         assert_eq!(instruction.accounts[0].pubkey, payer);
         assert_eq!(instruction.accounts[1].pubkey, expected_counter);
         assert_eq!(instruction.accounts[2].pubkey, solana_system_program::id());
@@ -322,7 +410,7 @@ mod tests {
         let instruction = Instruction::try_from(reactivate_ix).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(instruction.accounts.len(), 4);
         assert_eq!(
             instruction.data,
             vec![u8::from(InstructionDiscriminator::ReactivateCounterV1)]
@@ -343,4 +431,89 @@ mod tests {
             _ => panic!("Expected PayerMustBeSigner, got {err:?}"),
         }
     }
+
+    #[test]
+    fn test_with_authority_appends_authority_signer() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let instruction = ReactivateCounterV1Ix::new(program_id, payer)
+            .with_authority(authority)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[3].pubkey, authority);
+        assert!(instruction.accounts[3].is_signer);
+        assert!(!instruction.accounts[3].is_writable);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut reactivate_ix =
+            ReactivateCounterV1Ix::new(program_id, payer).with_authority(Pubkey::new_unique());
+        if let Some(authority) = &mut reactivate_ix.authority {
+            authority.is_signer = false;
+        }
+
+        let err = reactivate_ix.validate().unwrap_err();
+        match err {
+            ReactivateCounterV1IxError::AuthorityMustBeSigner => {}
+            _ => panic!("Expected AuthorityMustBeSigner, got {err:?}"),
+        }
+        assert_eq!(err.to_string(), "Authority must be a signer");
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_multisig_and_signers() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &payer);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let instruction = ReactivateCounterV1Ix::new(program_id, payer)
+            .with_multisig_signers(&signers)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.accounts.len(), 7);
+        assert_eq!(instruction.accounts[3].pubkey, expected_multisig);
+        assert!(!instruction.accounts[3].is_signer);
+        for (meta, signer) in instruction.accounts[4..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_multisig_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut reactivate_ix = ReactivateCounterV1Ix::new(program_id, payer)
+            .with_multisig_signers(&[Pubkey::new_unique()]);
+        if let Some(authority) = reactivate_ix.authority.as_mut() {
+            authority.pubkey = Pubkey::new_unique();
+        }
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ReactivateCounterV1IxError::MultisigAddressMismatch { .. }
+        ));
+    }
 }
@@ -0,0 +1,318 @@
+use {
+    crate::{config_v1_account_meta, find_indexed_counter_v1_address},
+    pinocchio_counter_program::{InstructionDiscriminator, ReactivateIndexedCounterV1Args},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReactivateIndexedCounterV1IxError {
+    #[error("Payer must be a signer")]
+    PayerMustBeSigner,
+
+    #[error("Payer must be writable")]
+    PayerMustBeWriteable,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch. Expected: {expected}, Observed: {observed}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `ReactivateIndexedCounterV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for reactivating a deactivated
+/// indexed counter. The counter address is derived from the payer and `index`.
+#[derive(Debug, Clone)]
+pub struct ReactivateIndexedCounterV1Ix {
+    pub program_id: Pubkey,
+    pub payer: AccountMeta,
+    pub counter: AccountMeta,
+    pub system_program: AccountMeta,
+    /// The index the counter address is derived from, together with the owner.
+    pub index: u32,
+}
+
+impl ReactivateIndexedCounterV1Ix {
+    /// Creates a new instruction builder for `ReactivateIndexedCounterV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer` - The public key of the counter's owner, who pays for the counter.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    ///
+    /// # Returns
+    ///
+    /// A new `ReactivateIndexedCounterV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, payer: Pubkey, index: u32) -> Self {
+        let counter = find_indexed_counter_v1_address(&program_id, &payer, index);
+
+        Self {
+            program_id,
+            payer: AccountMeta {
+                pubkey: payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            index,
+        }
+    }
+
+    /// Sets the payer account metadata.
+    #[must_use]
+    pub fn with_payer(mut self, payer: AccountMeta) -> Self {
+        self.payer = payer;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateIndexedCounterV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), ReactivateIndexedCounterV1IxError> {
+        if !self.payer.is_signer {
+            return Err(ReactivateIndexedCounterV1IxError::PayerMustBeSigner);
+        }
+
+        if !self.payer.is_writable {
+            return Err(ReactivateIndexedCounterV1IxError::PayerMustBeWriteable);
+        }
+
+        if !self.counter.is_writable {
+            return Err(ReactivateIndexedCounterV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter =
+            find_indexed_counter_v1_address(&self.program_id, &self.payer.pubkey, self.index);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(ReactivateIndexedCounterV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        let expected_system_program = solana_system_program::id();
+        let observed_system_program = self.system_program.pubkey;
+        if observed_system_program != expected_system_program {
+            return Err(
+                ReactivateIndexedCounterV1IxError::SystemProgramAddressMismatch {
+                    expected: expected_system_program,
+                    observed: observed_system_program,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateIndexedCounterV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, ReactivateIndexedCounterV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = ReactivateIndexedCounterV1Args { index: self.index };
+        let args_data =
+            serialize(&args).map_err(|_| ReactivateIndexedCounterV1IxError::SerializationError)?;
+
+        let mut instruction_data =
+            vec![InstructionDiscriminator::ReactivateIndexedCounterV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.payer,
+                self.counter,
+                self.system_program,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<ReactivateIndexedCounterV1Ix> for Instruction {
+    type Error = ReactivateIndexedCounterV1IxError;
+
+    fn try_from(value: ReactivateIndexedCounterV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_indexed_counter_v1_address},
+    };
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let expected_counter = find_indexed_counter_v1_address(&program_id, &payer, 3);
+
+        let ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.payer.pubkey, payer);
+        assert_eq!(ix.counter.pubkey, expected_counter);
+        assert_eq!(ix.index, 3);
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_new_derives_distinct_counters_per_index() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let first = ReactivateIndexedCounterV1Ix::new(program_id, payer, 0);
+        let second = ReactivateIndexedCounterV1Ix::new(program_id, payer, 1);
+
+        assert_ne!(first.counter.pubkey, second.counter.pubkey);
+        assert_ne!(
+            first.counter.pubkey,
+            find_counter_v1_address(&program_id, &payer)
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+        ix.payer.is_signer = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+        ix.payer.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+        ix.counter.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_index_does_not_match_counter() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+        ix.index = 4;
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+        ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let expected_counter = find_indexed_counter_v1_address(&program_id, &payer, 3);
+
+        let instruction = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, payer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::ReactivateIndexedCounterV1)
+        );
+
+        let args = ReactivateIndexedCounterV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.index, 3);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let mut ix = ReactivateIndexedCounterV1Ix::new(program_id, payer, 3);
+        ix.payer.is_signer = false;
+
+        let err = Instruction::try_from(ix).unwrap_err();
+        match err {
+            ReactivateIndexedCounterV1IxError::PayerMustBeSigner => {}
+            _ => panic!("Expected PayerMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -0,0 +1,491 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReactivateSponsoredCounterV1IxError {
+    #[error("Payer must be a signer")]
+    PayerMustBeSigner,
+
+    #[error("Payer must be writable")]
+    PayerMustBeWriteable,
+
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Counter must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch: expected {expected:?}, observed {observed:?}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Authority must be a signer")]
+    AuthorityMustBeSigner,
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+}
+
+/// Instruction builder for `ReactivateSponsoredCounterV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for reactivating the owner's
+/// deactivated counter with the rent paid by a separate payer, such as a relayer. The payer is
+/// refunded when the counter is deactivated again.
+pub struct ReactivateSponsoredCounterV1Ix {
+    pub program_id: Pubkey,
+    pub payer: AccountMeta,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub system_program: AccountMeta,
+    /// The counter's authority, when it is no longer the creator.
+    pub authority: Option<AccountMeta>,
+    /// Members of the counter's multisig approving the reactivation, set with
+    /// [`ReactivateSponsoredCounterV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl ReactivateSponsoredCounterV1Ix {
+    /// Creates a new instruction builder for `ReactivateSponsoredCounterV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer` - The public key of the account paying the counter's rent.
+    /// * `owner` - The public key of the counter's owner, from which the counter address is
+    ///   derived.
+    ///
+    /// # Returns
+    ///
+    /// A new `ReactivateSponsoredCounterV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, payer: Pubkey, owner: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            payer: AccountMeta {
+                pubkey: payer,
+                is_signer: true,
+                is_writable: true,
+            },
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            authority: None,
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Approves the reactivation with `authority`, for counters whose authority had been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1` before they were
+    /// deactivated. The counter is restored with `authority` still in control.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.authority = Some(AccountMeta {
+            pubkey: authority,
+            is_signer: true,
+            is_writable: false,
+        });
+        self
+    }
+
+    /// Approves the reactivation with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1` before they were
+    /// deactivated.
+    ///
+    /// The multisig record takes the place of the authority and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    /// The counter is restored with the multisig still in control.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.authority = Some(AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        });
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta {
+                pubkey: *signer,
+                is_signer: true,
+                is_writable: false,
+            })
+            .collect();
+        self
+    }
+
+    /// Validates that the account metadata and addresses are correct.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateSponsoredCounterV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), ReactivateSponsoredCounterV1IxError> {
+        if !self.payer.is_signer {
+            return Err(ReactivateSponsoredCounterV1IxError::PayerMustBeSigner);
+        }
+
+        if !self.payer.is_writable {
+            return Err(ReactivateSponsoredCounterV1IxError::PayerMustBeWriteable);
+        }
+
+        if !self.owner.is_signer {
+            return Err(ReactivateSponsoredCounterV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.counter.is_writable {
+            return Err(ReactivateSponsoredCounterV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(
+                ReactivateSponsoredCounterV1IxError::CounterAddressMismatch {
+                    expected: expected_counter,
+                    observed: observed_counter,
+                },
+            );
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(
+                ReactivateSponsoredCounterV1IxError::SystemProgramAddressMismatch {
+                    expected: expected_system_program,
+                    observed: observed_system_program,
+                },
+            );
+        }
+
+        if let Some(authority) = &self.authority {
+            if self.multisig_signers.is_empty() && !authority.is_signer {
+                return Err(ReactivateSponsoredCounterV1IxError::AuthorityMustBeSigner);
+            }
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self
+                .authority
+                .as_ref()
+                .map_or_else(Pubkey::default, |authority| authority.pubkey);
+            if observed_multisig != expected_multisig {
+                return Err(
+                    ReactivateSponsoredCounterV1IxError::MultisigAddressMismatch {
+                        expected: expected_multisig,
+                        observed: observed_multisig,
+                    },
+                );
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(ReactivateSponsoredCounterV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateSponsoredCounterV1IxError`] if `validate` is `true` and validation
+    /// fails.
+    pub fn to_instruction(
+        self,
+        validate: bool,
+    ) -> Result<Instruction, ReactivateSponsoredCounterV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let mut accounts = vec![self.payer, self.owner, self.counter, self.system_program];
+        accounts.extend(self.authority);
+        accounts.extend(self.multisig_signers);
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![InstructionDiscriminator::ReactivateSponsoredCounterV1.into()],
+        })
+    }
+}
+
+impl TryFrom<ReactivateSponsoredCounterV1Ix> for Instruction {
+    type Error = ReactivateSponsoredCounterV1IxError;
+
+    fn try_from(value: ReactivateSponsoredCounterV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_derives_counter_pda_from_owner() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let reactivate_ix = ReactivateSponsoredCounterV1Ix::new(program_id, payer, owner);
+
+        assert_eq!(reactivate_ix.program_id, program_id);
+        assert_eq!(reactivate_ix.payer.pubkey, payer);
+        assert_eq!(reactivate_ix.owner.pubkey, owner);
+        assert_eq!(
+            reactivate_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_ne!(
+            reactivate_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &payer)
+        );
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let reactivate_ix = ReactivateSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        assert!(reactivate_ix.payer.is_signer);
+        assert!(reactivate_ix.payer.is_writable);
+        assert!(reactivate_ix.owner.is_signer);
+        assert!(!reactivate_ix.owner.is_writable);
+        assert!(!reactivate_ix.counter.is_signer);
+        assert!(reactivate_ix.counter.is_writable);
+
+        assert!(reactivate_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_signer() {
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        reactivate_ix.payer.is_signer = false;
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_payer_not_writable() {
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        reactivate_ix.payer.is_writable = false;
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Payer must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        reactivate_ix.owner.is_signer = false;
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        // A counter derived from the payer instead of the owner
+        let mut reactivate_ix =
+            ReactivateSponsoredCounterV1Ix::new(program_id, payer, Pubkey::new_unique());
+        reactivate_ix.counter.pubkey = find_counter_v1_address(&program_id, &payer);
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        reactivate_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let instruction = ReactivateSponsoredCounterV1Ix::new(program_id, payer, owner)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, payer);
+        assert_eq!(instruction.accounts[1].pubkey, owner);
+        assert_eq!(
+            instruction.accounts[2].pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert_eq!(instruction.accounts[3].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(
+                InstructionDiscriminator::ReactivateSponsoredCounterV1
+            )]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        reactivate_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(reactivate_ix).unwrap_err();
+        match err {
+            ReactivateSponsoredCounterV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_authority_appends_authority_signer() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let instruction = ReactivateSponsoredCounterV1Ix::new(program_id, payer, owner)
+            .with_authority(authority)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(instruction.accounts[4].pubkey, authority);
+        assert!(instruction.accounts[4].is_signer);
+        assert!(!instruction.accounts[4].is_writable);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_authority_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(program_id, payer, owner)
+            .with_authority(Pubkey::new_unique());
+        if let Some(authority) = &mut reactivate_ix.authority {
+            authority.is_signer = false;
+        }
+
+        let err = reactivate_ix.validate().unwrap_err();
+        match err {
+            ReactivateSponsoredCounterV1IxError::AuthorityMustBeSigner => {}
+            _ => panic!("Expected AuthorityMustBeSigner, got {err:?}"),
+        }
+        assert_eq!(err.to_string(), "Authority must be a signer");
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_multisig_and_signers() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let instruction = ReactivateSponsoredCounterV1Ix::new(program_id, payer, owner)
+            .with_multisig_signers(&signers)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.accounts.len(), 8);
+        assert_eq!(instruction.accounts[4].pubkey, expected_multisig);
+        assert!(!instruction.accounts[4].is_signer);
+        for (meta, signer) in instruction.accounts[5..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_multisig_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut reactivate_ix = ReactivateSponsoredCounterV1Ix::new(program_id, payer, owner)
+            .with_multisig_signers(&[Pubkey::new_unique()]);
+        if let Some(authority) = reactivate_ix.authority.as_mut() {
+            authority.pubkey = Pubkey::new_unique();
+        }
+
+        let err = reactivate_ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ReactivateSponsoredCounterV1IxError::MultisigAddressMismatch { .. }
+        ));
+    }
+}
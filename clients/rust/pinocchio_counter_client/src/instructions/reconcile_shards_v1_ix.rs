@@ -0,0 +1,277 @@
+use {
+    crate::{
+        config_v1_account_meta, find_sharded_counter_v1_address,
+        sharded_counter::find_counter_shard_v1_addresses,
+    },
+    pinocchio_counter_program::{InstructionDiscriminator, MAX_COUNTER_SHARDS},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReconcileShardsV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Sharded counter account must be writable")]
+    ShardedCounterMustBeWriteable,
+
+    #[error("Sharded counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    ShardedCounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Invalid shard count. Expected 1 to {maximum} shards, Observed: {observed}")]
+    InvalidShardCount { maximum: usize, observed: usize },
+
+    #[error("Shard account must be writable")]
+    ShardMustBeWriteable,
+}
+
+/// Instruction builder for `ReconcileShardsV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for moving the counts of a
+/// sharded counter's shards into the sharded counter. Only the owner may reconcile.
+#[derive(Debug, Clone)]
+pub struct ReconcileShardsV1Ix {
+    pub program_id: Pubkey,
+    pub owner: AccountMeta,
+    pub sharded_counter: AccountMeta,
+    /// The shards to reconcile, in any order.
+    pub shards: Vec<AccountMeta>,
+}
+
+impl ReconcileShardsV1Ix {
+    /// Creates a new instruction builder for `ReconcileShardsV1` reconciling every shard.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the sharded counter's owner.
+    /// * `shard_count` - The number of shards of the sharded counter.
+    ///
+    /// # Returns
+    ///
+    /// A new `ReconcileShardsV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey, shard_count: u8) -> Self {
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &owner);
+        let shards = find_counter_shard_v1_addresses(&program_id, &sharded_counter, shard_count)
+            .into_iter()
+            .map(|pubkey| AccountMeta {
+                pubkey,
+                is_signer: false,
+                is_writable: true,
+            })
+            .collect();
+
+        Self {
+            program_id,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: false,
+            },
+            sharded_counter: AccountMeta {
+                pubkey: sharded_counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            shards,
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the sharded counter account metadata.
+    #[must_use]
+    pub fn with_sharded_counter(mut self, sharded_counter: AccountMeta) -> Self {
+        self.sharded_counter = sharded_counter;
+        self
+    }
+
+    /// Sets the shard account metadata.
+    #[must_use]
+    pub fn with_shards(mut self, shards: Vec<AccountMeta>) -> Self {
+        self.shards = shards;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReconcileShardsV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), ReconcileShardsV1IxError> {
+        if !self.owner.is_signer {
+            return Err(ReconcileShardsV1IxError::OwnerMustBeSigner);
+        }
+
+        if !self.sharded_counter.is_writable {
+            return Err(ReconcileShardsV1IxError::ShardedCounterMustBeWriteable);
+        }
+
+        let expected_sharded_counter =
+            find_sharded_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let observed_sharded_counter = self.sharded_counter.pubkey;
+        if observed_sharded_counter != expected_sharded_counter {
+            return Err(ReconcileShardsV1IxError::ShardedCounterAddressMismatch {
+                expected: expected_sharded_counter,
+                observed: observed_sharded_counter,
+            });
+        }
+
+        if self.shards.is_empty() || self.shards.len() > usize::from(MAX_COUNTER_SHARDS) {
+            return Err(ReconcileShardsV1IxError::InvalidShardCount {
+                maximum: usize::from(MAX_COUNTER_SHARDS),
+                observed: self.shards.len(),
+            });
+        }
+
+        if self.shards.iter().any(|shard| !shard.is_writable) {
+            return Err(ReconcileShardsV1IxError::ShardMustBeWriteable);
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReconcileShardsV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, ReconcileShardsV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let mut accounts = vec![self.owner, self.sharded_counter];
+        accounts.extend(self.shards);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![InstructionDiscriminator::ReconcileShardsV1.into()],
+        })
+    }
+}
+
+impl TryFrom<ReconcileShardsV1Ix> for Instruction {
+    type Error = ReconcileShardsV1IxError;
+
+    fn try_from(value: ReconcileShardsV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_shard_v1_address};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &owner);
+
+        let ix = ReconcileShardsV1Ix::new(program_id, owner, 3);
+
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.owner.pubkey, owner);
+        assert_eq!(ix.sharded_counter.pubkey, sharded_counter);
+        assert_eq!(ix.shards.len(), 3);
+        assert!(ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let mut ix = ReconcileShardsV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.owner.is_signer = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_sharded_counter_not_writable() {
+        let mut ix = ReconcileShardsV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.sharded_counter.is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Sharded counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_sharded_counter_address_mismatch() {
+        let mut ix = ReconcileShardsV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.sharded_counter.pubkey = Pubkey::new_unique();
+
+        let err = ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Sharded counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_without_shards() {
+        let ix = ReconcileShardsV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 0);
+
+        let err = ix.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            ReconcileShardsV1IxError::InvalidShardCount { observed: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_validate_fails_when_shard_not_writable() {
+        let mut ix = ReconcileShardsV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.shards[0].is_writable = false;
+
+        let err = ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Shard account must be writable");
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let sharded_counter = find_sharded_counter_v1_address(&program_id, &owner);
+
+        let instruction = ReconcileShardsV1Ix::new(program_id, owner, 2)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, sharded_counter);
+        assert_eq!(
+            instruction.accounts[3].pubkey,
+            find_counter_shard_v1_address(&program_id, &sharded_counter, 1)
+        );
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::ReconcileShardsV1)]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut ix = ReconcileShardsV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), 2);
+        ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(ix).unwrap_err();
+        match err {
+            ReconcileShardsV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+}
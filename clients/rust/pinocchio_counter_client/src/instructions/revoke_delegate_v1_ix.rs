@@ -0,0 +1,431 @@
+use {
+    crate::{
+        config_v1_account_meta, find_counter_v1_address, find_delegate_v1_address,
+        find_multisig_v1_address,
+    },
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum RevokeDelegateV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Owner must be writable")]
+    OwnerMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Delegate account must be writable")]
+    DelegateMustBeWriteable,
+
+    #[error("Delegate address mismatch. Expected: {expected}, Observed: {observed}")]
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("First multisig signer must be writable")]
+    MultisigSignerMustBeWriteable,
+}
+
+/// Instruction builder for `RevokeDelegateV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for revoking a
+/// counter's delegate. The delegate record is closed and its rent refunded to the owner.
+#[derive(Debug, Clone)]
+pub struct RevokeDelegateV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub delegate: AccountMeta,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`RevokeDelegateV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+}
+
+impl RevokeDelegateV1Ix {
+    /// Creates a new instruction builder for `RevokeDelegateV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    ///
+    /// # Returns
+    ///
+    /// A new `RevokeDelegateV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, owner: Pubkey) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+        let delegate = find_delegate_v1_address(&program_id, &counter);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: false,
+            },
+            delegate: AccountMeta {
+                pubkey: delegate,
+                is_signer: false,
+                is_writable: true,
+            },
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the delegate record account metadata.
+    #[must_use]
+    pub fn with_delegate(mut self, delegate: AccountMeta) -> Self {
+        self.delegate = delegate;
+        self
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    /// The first signer receives the delegate account's lamports, so it is writable.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| AccountMeta {
+                pubkey: *signer,
+                is_signer: true,
+                is_writable: index == 0,
+            })
+            .collect();
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RevokeDelegateV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), RevokeDelegateV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(RevokeDelegateV1IxError::OwnerMustBeSigner);
+        }
+
+        if self.multisig_signers.is_empty() && !self.owner.is_writable {
+            return Err(RevokeDelegateV1IxError::OwnerMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(RevokeDelegateV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(RevokeDelegateV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(RevokeDelegateV1IxError::MultisigSignerMustBeSigner);
+            }
+
+            if !self.multisig_signers[0].is_writable {
+                return Err(RevokeDelegateV1IxError::MultisigSignerMustBeWriteable);
+            }
+        }
+
+        if !self.delegate.is_writable {
+            return Err(RevokeDelegateV1IxError::DelegateMustBeWriteable);
+        }
+
+        let expected_delegate = find_delegate_v1_address(&self.program_id, &self.counter.pubkey);
+        let observed_delegate = self.delegate.pubkey;
+        if observed_delegate != expected_delegate {
+            return Err(RevokeDelegateV1IxError::DelegateAddressMismatch {
+                expected: expected_delegate,
+                observed: observed_delegate,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RevokeDelegateV1IxError`] if `validate` is `true` and validation fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, RevokeDelegateV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let mut accounts = vec![self.owner, self.counter, self.delegate];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![InstructionDiscriminator::RevokeDelegateV1.into()],
+        })
+    }
+}
+
+impl TryFrom<RevokeDelegateV1Ix> for Instruction {
+    type Error = RevokeDelegateV1IxError;
+
+    fn try_from(value: RevokeDelegateV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{find_counter_v1_address, find_delegate_v1_address},
+    };
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_delegate = find_delegate_v1_address(&program_id, &expected_counter);
+
+        let revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+
+        assert_eq!(revoke_ix.program_id, program_id);
+        assert_eq!(revoke_ix.owner.pubkey, owner);
+        assert_eq!(revoke_ix.counter.pubkey, expected_counter);
+        assert_eq!(revoke_ix.delegate.pubkey, expected_delegate);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+
+        assert!(revoke_ix.owner.is_signer);
+        assert!(revoke_ix.owner.is_writable);
+        assert!(!revoke_ix.counter.is_writable);
+        assert!(revoke_ix.delegate.is_writable);
+
+        assert!(revoke_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+        revoke_ix.owner.is_signer = false;
+
+        let err = revoke_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+        revoke_ix.owner.is_writable = false;
+
+        let err = revoke_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+        revoke_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = revoke_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_delegate_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+        revoke_ix.delegate.is_writable = false;
+
+        let err = revoke_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Delegate account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_delegate_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+        revoke_ix.delegate.pubkey = Pubkey::new_unique();
+
+        let err = revoke_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Delegate address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_delegate = find_delegate_v1_address(&program_id, &expected_counter);
+
+        let instruction = RevokeDelegateV1Ix::new(program_id, owner)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(instruction.accounts[2].pubkey, expected_delegate);
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::RevokeDelegateV1)]
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner);
+        revoke_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(revoke_ix).unwrap_err();
+        match err {
+            RevokeDelegateV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let revoke_ix = RevokeDelegateV1Ix::new(program_id, owner).with_authority(authority);
+
+        assert_eq!(revoke_ix.owner.pubkey, authority);
+        assert_eq!(revoke_ix.creator, owner);
+        assert_eq!(
+            revoke_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(revoke_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let revoke_ix = RevokeDelegateV1Ix::new(program_id, owner).with_multisig_signers(&signers);
+        assert!(revoke_ix.validate().is_ok());
+
+        let instruction = revoke_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (index, (meta, signer)) in instruction.accounts[3..].iter().zip(&signers).enumerate() {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert_eq!(meta.is_writable, index == 0);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+
+    #[test]
+    fn test_validate_fails_when_first_multisig_signer_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut revoke_ix = RevokeDelegateV1Ix::new(program_id, owner)
+            .with_multisig_signers(&[Pubkey::new_unique()]);
+        revoke_ix.multisig_signers[0].is_writable = false;
+
+        let err = revoke_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "First multisig signer must be writable");
+    }
+}
@@ -0,0 +1,450 @@
+use {
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
+    pinocchio_counter_program::{InstructionDiscriminator, SetCooldownV1Args},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetCooldownV1IxError {
+    #[error("Owner must be a signer")]
+    OwnerMustBeSigner,
+
+    #[error("Owner must be writable")]
+    OwnerMustBeWriteable,
+
+    #[error("Counter account must be writable")]
+    CounterMustBeWriteable,
+
+    #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("System program address mismatch: expected {expected:?}, observed {observed:?}")]
+    SystemProgramAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `SetCooldownV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for setting the cooldown
+/// between changes to a counter's count. The owner pays for the additional rent when a
+/// `CounterV1` account is upgraded to `CounterV2`.
+#[derive(Debug, Clone)]
+pub struct SetCooldownV1Ix {
+    pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
+    pub owner: AccountMeta,
+    pub counter: AccountMeta,
+    pub system_program: AccountMeta,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`SetCooldownV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
+    pub cooldown_slots: u64,
+    pub cooldown_seconds: u64,
+}
+
+impl SetCooldownV1Ix {
+    /// Creates a new instruction builder for `SetCooldownV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `cooldown_slots` - Slots that must pass after the last modification before the count
+    ///   may be incremented or decremented, or `0` for no slot cooldown.
+    /// * `cooldown_seconds` - Seconds that must pass after the last modification before the count
+    ///   may be incremented or decremented, or `0` for no time cooldown.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetCooldownV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(
+        program_id: Pubkey,
+        owner: Pubkey,
+        cooldown_slots: u64,
+        cooldown_seconds: u64,
+    ) -> Self {
+        let counter = find_counter_v1_address(&program_id, &owner);
+
+        Self {
+            program_id,
+            creator: owner,
+            owner: AccountMeta {
+                pubkey: owner,
+                is_signer: true,
+                is_writable: true,
+            },
+            counter: AccountMeta {
+                pubkey: counter,
+                is_signer: false,
+                is_writable: true,
+            },
+            system_program: AccountMeta {
+                pubkey: solana_system_program::id(),
+                is_signer: false,
+                is_writable: false,
+            },
+            cooldown_slots,
+            cooldown_seconds,
+            multisig_signers: Vec::new(),
+        }
+    }
+
+    /// Sets the owner account metadata.
+    #[must_use]
+    pub fn with_owner(mut self, owner: AccountMeta) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Sets the counter account metadata.
+    #[must_use]
+    pub fn with_counter(mut self, counter: AccountMeta) -> Self {
+        self.counter = counter;
+        self
+    }
+
+    /// Sets the slot and time cooldowns.
+    #[must_use]
+    pub fn with_cooldown(mut self, cooldown_slots: u64, cooldown_seconds: u64) -> Self {
+        self.cooldown_slots = cooldown_slots;
+        self.cooldown_seconds = cooldown_seconds;
+        self
+    }
+
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetCooldownV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), SetCooldownV1IxError> {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
+            return Err(SetCooldownV1IxError::OwnerMustBeSigner);
+        }
+
+        if self.multisig_signers.is_empty() && !self.owner.is_writable {
+            return Err(SetCooldownV1IxError::OwnerMustBeWriteable);
+        }
+
+        if !self.counter.is_writable {
+            return Err(SetCooldownV1IxError::CounterMustBeWriteable);
+        }
+
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
+        let observed_counter = self.counter.pubkey;
+        if observed_counter != expected_counter {
+            return Err(SetCooldownV1IxError::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: observed_counter,
+            });
+        }
+
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(SetCooldownV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(SetCooldownV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
+        let observed_system_program = self.system_program.pubkey;
+        let expected_system_program = solana_system_program::id();
+        if observed_system_program != expected_system_program {
+            return Err(SetCooldownV1IxError::SystemProgramAddressMismatch {
+                expected: expected_system_program,
+                observed: observed_system_program,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetCooldownV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, SetCooldownV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = SetCooldownV1Args {
+            cooldown_slots: self.cooldown_slots,
+            cooldown_seconds: self.cooldown_seconds,
+        };
+        let args_data = serialize(&args).map_err(|_| SetCooldownV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::SetCooldownV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        let mut accounts = vec![self.owner, self.counter, self.system_program];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<SetCooldownV1Ix> for Instruction {
+    type Error = SetCooldownV1IxError;
+
+    fn try_from(value: SetCooldownV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::find_counter_v1_address};
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+
+        assert_eq!(cooldown_ix.counter.pubkey, expected_counter);
+        assert_eq!(cooldown_ix.program_id, program_id);
+        assert_eq!(cooldown_ix.owner.pubkey, owner);
+        assert_eq!(
+            cooldown_ix.system_program.pubkey,
+            solana_system_program::id()
+        );
+        assert_eq!(cooldown_ix.cooldown_slots, 10);
+        assert_eq!(cooldown_ix.cooldown_seconds, 5);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+
+        assert!(cooldown_ix.owner.is_signer);
+        assert!(cooldown_ix.owner.is_writable);
+        assert!(!cooldown_ix.counter.is_signer);
+        assert!(cooldown_ix.counter.is_writable);
+        assert!(!cooldown_ix.system_program.is_signer);
+        assert!(!cooldown_ix.system_program.is_writable);
+
+        assert!(cooldown_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_signer() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+        cooldown_ix.owner.is_signer = false;
+
+        let err = cooldown_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_owner_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+        cooldown_ix.owner.is_writable = false;
+
+        let err = cooldown_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Owner must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+        cooldown_ix.counter.pubkey = Pubkey::new_unique();
+
+        let err = cooldown_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Counter address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_when_counter_not_writable() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+        cooldown_ix.counter.is_writable = false;
+
+        let err = cooldown_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Counter account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_system_program_address_mismatch() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+        cooldown_ix.system_program.pubkey = Pubkey::new_unique();
+
+        let err = cooldown_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("System program address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+
+        let instruction = SetCooldownV1Ix::new(program_id, owner, 10, 5)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+        assert_eq!(instruction.accounts[0].pubkey, owner);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        assert_eq!(instruction.accounts[2].pubkey, solana_system_program::id());
+        assert_eq!(
+            instruction.data[0],
+            u8::from(InstructionDiscriminator::SetCooldownV1)
+        );
+
+        let args = SetCooldownV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert_eq!(args.cooldown_slots, 10);
+        assert_eq!(args.cooldown_seconds, 5);
+    }
+
+    #[test]
+    fn test_to_instruction_respects_validate_flag() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 0, 0);
+        cooldown_ix.owner.is_signer = false;
+        assert!(cooldown_ix.clone().to_instruction(true).is_err());
+
+        let instruction = cooldown_ix.to_instruction(false).unwrap();
+        assert_eq!(instruction.program_id, program_id);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5);
+        cooldown_ix.owner.is_signer = false;
+
+        let err = Instruction::try_from(cooldown_ix).unwrap_err();
+        match err {
+            SetCooldownV1IxError::OwnerMustBeSigner => {}
+            _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let cooldown_ix = SetCooldownV1Ix::new(program_id, owner, 10, 5).with_authority(authority);
+
+        assert_eq!(cooldown_ix.owner.pubkey, authority);
+        assert_eq!(cooldown_ix.creator, owner);
+        assert_eq!(
+            cooldown_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(cooldown_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let cooldown_ix =
+            SetCooldownV1Ix::new(program_id, owner, 10, 5).with_multisig_signers(&signers);
+        assert!(cooldown_ix.validate().is_ok());
+
+        let instruction = cooldown_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 6);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[3..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    crate::find_counter_v1_address,
+    crate::{config_v1_account_meta, find_counter_v1_address, find_multisig_v1_address},
     pinocchio_counter_program::{InstructionDiscriminator, SetCountV1Args},
     solana_instruction::{AccountMeta, Instruction},
     solana_pubkey::Pubkey,
@@ -17,6 +17,12 @@ pub enum SetCountV1IxError {
     #[error("Counter address mismatch. Expected: {expected}, Observed: {observed}")]
     CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
 
+    #[error("Multisig address mismatch. Expected: {expected}, Observed: {observed}")]
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Multisig signer must be a signer")]
+    MultisigSignerMustBeSigner,
+
     #[error("Failed to serialize instruction arguments")]
     SerializationError,
 }
@@ -29,9 +35,14 @@ pub enum SetCountV1IxError {
 #[derive(Debug, Clone)]
 pub struct SetCountV1Ix {
     pub program_id: Pubkey,
+    /// The counter's creator, from which the counter address is derived.
+    pub creator: Pubkey,
     pub owner: AccountMeta,
     pub counter: AccountMeta,
     pub count: u64,
+    /// Members of the counter's multisig approving the instruction, set with
+    /// [`SetCountV1Ix::with_multisig_signers`].
+    pub multisig_signers: Vec<AccountMeta>,
 }
 
 impl SetCountV1Ix {
@@ -52,6 +63,7 @@ impl SetCountV1Ix {
 
         Self {
             program_id,
+            creator: owner,
             owner: AccountMeta {
                 pubkey: owner,
                 is_signer: true,
@@ -63,6 +75,7 @@ impl SetCountV1Ix {
                 is_writable: true,
             },
             count,
+            multisig_signers: Vec::new(),
         }
     }
 
@@ -87,13 +100,40 @@ impl SetCountV1Ix {
         self
     }
 
+    /// Signs with `authority` instead of the creator, for counters whose authority has been
+    /// transferred with `ProposeAuthorityV1` and `AcceptAuthorityV1`.
+    #[must_use]
+    pub fn with_authority(mut self, authority: Pubkey) -> Self {
+        self.owner.pubkey = authority;
+        self
+    }
+
+    /// Approves the instruction with members of the counter's multisig, for counters whose
+    /// authority was handed to a multisig with `InitializeMultisigV1`.
+    ///
+    /// The multisig record takes the place of the owner and does not sign. Each of `signers`
+    /// signs instead, and at least the multisig's threshold of distinct members must be given.
+    #[must_use]
+    pub fn with_multisig_signers(mut self, signers: &[Pubkey]) -> Self {
+        self.owner = AccountMeta {
+            pubkey: find_multisig_v1_address(&self.program_id, &self.counter.pubkey),
+            is_signer: false,
+            is_writable: false,
+        };
+        self.multisig_signers = signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true))
+            .collect();
+        self
+    }
+
     /// Validates the instruction's account metadata.
     ///
     /// # Errors
     ///
     /// Returns [`SetCountV1IxError`] if validation fails.
     pub fn validate(&self) -> Result<(), SetCountV1IxError> {
-        if !self.owner.is_signer {
+        if self.multisig_signers.is_empty() && !self.owner.is_signer {
             return Err(SetCountV1IxError::OwnerMustBeSigner);
         }
 
@@ -101,7 +141,7 @@ impl SetCountV1Ix {
             return Err(SetCountV1IxError::CounterMustBeWriteable);
         }
 
-        let expected_counter = find_counter_v1_address(&self.program_id, &self.owner.pubkey);
+        let expected_counter = find_counter_v1_address(&self.program_id, &self.creator);
         let observed_counter = self.counter.pubkey;
         if observed_counter != expected_counter {
             return Err(SetCountV1IxError::CounterAddressMismatch {
@@ -110,6 +150,22 @@ impl SetCountV1Ix {
             });
         }
 
+        if !self.multisig_signers.is_empty() {
+            let expected_multisig =
+                find_multisig_v1_address(&self.program_id, &self.counter.pubkey);
+            let observed_multisig = self.owner.pubkey;
+            if observed_multisig != expected_multisig {
+                return Err(SetCountV1IxError::MultisigAddressMismatch {
+                    expected: expected_multisig,
+                    observed: observed_multisig,
+                });
+            }
+
+            if self.multisig_signers.iter().any(|signer| !signer.is_signer) {
+                return Err(SetCountV1IxError::MultisigSignerMustBeSigner);
+            }
+        }
+
         Ok(())
     }
 
@@ -130,9 +186,14 @@ impl SetCountV1Ix {
         let mut instruction_data = vec![InstructionDiscriminator::SetCountV1.into()];
         instruction_data.extend_from_slice(&args_data);
 
+        let mut accounts = vec![self.owner, self.counter];
+        accounts.extend(self.multisig_signers);
+
+        accounts.push(config_v1_account_meta(&self.program_id));
+
         Ok(Instruction {
             program_id: self.program_id,
-            accounts: vec![self.owner, self.counter],
+            accounts,
             data: instruction_data,
         })
     }
@@ -240,7 +301,11 @@ mod tests {
         let instruction = set_ix.to_instruction(true).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(
+            instruction.accounts.last(),
+            Some(&config_v1_account_meta(&program_id))
+        );
         assert_eq!(instruction.accounts[0].pubkey, owner);
         assert_eq!(instruction.accounts[1].pubkey, expected_counter);
         assert_eq!(
@@ -272,7 +337,7 @@ mod tests {
         set_ix4.owner.is_signer = false;
         let instruction = set_ix4.to_instruction(false).unwrap();
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
         assert!(!instruction.accounts[0].is_signer);
     }
 
@@ -285,7 +350,7 @@ mod tests {
         let instruction = Instruction::try_from(set_ix).unwrap();
 
         assert_eq!(instruction.program_id, program_id);
-        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts.len(), 3);
         assert_eq!(
             instruction.data[0],
             u8::from(InstructionDiscriminator::SetCountV1)
@@ -306,4 +371,44 @@ mod tests {
             _ => panic!("Expected OwnerMustBeSigner, got {err:?}"),
         }
     }
+
+    #[test]
+    fn test_with_authority_keeps_creator_counter_address() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let set_count_ix = SetCountV1Ix::new(program_id, owner, 5).with_authority(authority);
+
+        assert_eq!(set_count_ix.owner.pubkey, authority);
+        assert_eq!(set_count_ix.creator, owner);
+        assert_eq!(
+            set_count_ix.counter.pubkey,
+            find_counter_v1_address(&program_id, &owner)
+        );
+        assert!(set_count_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_with_multisig_signers_appends_signers() {
+        let program_id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let expected_counter = find_counter_v1_address(&program_id, &owner);
+        let expected_multisig = find_multisig_v1_address(&program_id, &expected_counter);
+
+        let set_ix = SetCountV1Ix::new(program_id, owner, 42).with_multisig_signers(&signers);
+        assert!(set_ix.validate().is_ok());
+
+        let instruction = set_ix.to_instruction(true).unwrap();
+        assert_eq!(instruction.accounts.len(), 5);
+        assert_eq!(instruction.accounts[0].pubkey, expected_multisig);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.accounts[1].pubkey, expected_counter);
+        for (meta, signer) in instruction.accounts[2..].iter().zip(&signers) {
+            assert_eq!(meta.pubkey, *signer);
+            assert!(meta.is_signer);
+            assert!(!meta.is_writable);
+        }
+    }
 }
@@ -0,0 +1,301 @@
+use {
+    crate::find_config_v1_address,
+    pinocchio_counter_program::{InstructionDiscriminator, SetPauseExemptionV1Args},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetPauseExemptionV1IxError {
+    #[error("Admin must be a signer")]
+    AdminMustBeSigner,
+
+    #[error("Config account must be writable")]
+    ConfigMustBeWriteable,
+
+    #[error("Config address mismatch. Expected: {expected}, Observed: {observed}")]
+    ConfigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Instruction {0:?} is never paused")]
+    InstructionNotPauseChecked(InstructionDiscriminator),
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `SetPauseExemptionV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for letting an instruction keep
+/// running while the program is paused, or for subjecting it to the pause again. Only the admin
+/// recorded in the config may send it.
+#[derive(Debug, Clone)]
+pub struct SetPauseExemptionV1Ix {
+    pub program_id: Pubkey,
+    pub admin: AccountMeta,
+    pub config: AccountMeta,
+    pub instruction: InstructionDiscriminator,
+    pub exempt: bool,
+}
+
+impl SetPauseExemptionV1Ix {
+    /// Creates a new instruction builder for `SetPauseExemptionV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `admin` - The public key of the program's admin.
+    /// * `instruction` - The instruction whose exemption is set.
+    /// * `exempt` - Whether the instruction keeps running while the program is paused.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetPauseExemptionV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(
+        program_id: Pubkey,
+        admin: Pubkey,
+        instruction: InstructionDiscriminator,
+        exempt: bool,
+    ) -> Self {
+        Self {
+            program_id,
+            admin: AccountMeta {
+                pubkey: admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            config: AccountMeta {
+                pubkey: find_config_v1_address(&program_id),
+                is_signer: false,
+                is_writable: true,
+            },
+            instruction,
+            exempt,
+        }
+    }
+
+    /// Sets the admin account metadata.
+    #[must_use]
+    pub fn with_admin(mut self, admin: AccountMeta) -> Self {
+        self.admin = admin;
+        self
+    }
+
+    /// Sets the config account metadata.
+    #[must_use]
+    pub fn with_config(mut self, config: AccountMeta) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Validates the instruction's account metadata and arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetPauseExemptionV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), SetPauseExemptionV1IxError> {
+        if !self.admin.is_signer {
+            return Err(SetPauseExemptionV1IxError::AdminMustBeSigner);
+        }
+
+        if !self.config.is_writable {
+            return Err(SetPauseExemptionV1IxError::ConfigMustBeWriteable);
+        }
+
+        let expected_config = find_config_v1_address(&self.program_id);
+        let observed_config = self.config.pubkey;
+        if observed_config != expected_config {
+            return Err(SetPauseExemptionV1IxError::ConfigAddressMismatch {
+                expected: expected_config,
+                observed: observed_config,
+            });
+        }
+
+        if !self.instruction.is_pause_checked() {
+            return Err(SetPauseExemptionV1IxError::InstructionNotPauseChecked(
+                self.instruction,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetPauseExemptionV1IxError`] if `validate` is `true` and validation fails, or
+    /// if serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, SetPauseExemptionV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = SetPauseExemptionV1Args {
+            instruction: self.instruction,
+            exempt: self.exempt,
+        };
+        let args_data =
+            serialize(&args).map_err(|_| SetPauseExemptionV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::SetPauseExemptionV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![self.admin, self.config],
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<SetPauseExemptionV1Ix> for Instruction {
+    type Error = SetPauseExemptionV1IxError;
+
+    fn try_from(value: SetPauseExemptionV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exemption_ix() -> SetPauseExemptionV1Ix {
+        SetPauseExemptionV1Ix::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            InstructionDiscriminator::DeactivateCounterV1,
+            true,
+        )
+    }
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let exemption_ix = SetPauseExemptionV1Ix::new(
+            program_id,
+            admin,
+            InstructionDiscriminator::GetCountV1,
+            true,
+        );
+
+        assert_eq!(exemption_ix.program_id, program_id);
+        assert_eq!(exemption_ix.admin.pubkey, admin);
+        assert_eq!(
+            exemption_ix.config.pubkey,
+            find_config_v1_address(&program_id)
+        );
+        assert_eq!(
+            exemption_ix.instruction,
+            InstructionDiscriminator::GetCountV1
+        );
+        assert!(exemption_ix.exempt);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let exemption_ix = exemption_ix();
+
+        assert!(exemption_ix.admin.is_signer);
+        assert!(!exemption_ix.admin.is_writable);
+        assert!(!exemption_ix.config.is_signer);
+        assert!(exemption_ix.config.is_writable);
+
+        assert!(exemption_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_admin_not_signer() {
+        let mut exemption_ix = exemption_ix();
+        exemption_ix.admin.is_signer = false;
+
+        let err = exemption_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Admin must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_config_not_writable() {
+        let mut exemption_ix = exemption_ix();
+        exemption_ix.config.is_writable = false;
+
+        let err = exemption_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Config account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_config_address_mismatch() {
+        let mut exemption_ix = exemption_ix();
+        exemption_ix.config.pubkey = Pubkey::new_unique();
+
+        let err = exemption_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Config address mismatch"));
+    }
+
+    #[test]
+    fn test_validate_fails_for_config_instruction() {
+        let mut exemption_ix = exemption_ix();
+        exemption_ix.instruction = InstructionDiscriminator::SetPausedV1;
+
+        let err = exemption_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Instruction SetPausedV1 is never paused");
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let instruction = SetPauseExemptionV1Ix::new(
+            program_id,
+            admin,
+            InstructionDiscriminator::DeactivateCounterV1,
+            true,
+        )
+        .to_instruction(true)
+        .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts[0].pubkey, admin);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_config_v1_address(&program_id)
+        );
+        // wincode writes the exempted discriminator as a `u32` enum tag
+        let mut expected_data = vec![u8::from(InstructionDiscriminator::SetPauseExemptionV1)];
+        expected_data.extend_from_slice(
+            &u32::from(u8::from(InstructionDiscriminator::DeactivateCounterV1)).to_le_bytes(),
+        );
+        expected_data.push(1);
+        assert_eq!(instruction.data, expected_data);
+    }
+
+    #[test]
+    fn test_to_instruction_respects_validate_flag() {
+        let mut exemption_ix = exemption_ix();
+        exemption_ix.instruction = InstructionDiscriminator::InitializeConfigV1;
+        assert!(exemption_ix.clone().to_instruction(true).is_err());
+
+        let instruction = exemption_ix.to_instruction(false).unwrap();
+        assert_eq!(
+            instruction.data[1],
+            u8::from(InstructionDiscriminator::InitializeConfigV1)
+        );
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut exemption_ix = exemption_ix();
+        exemption_ix.admin.is_signer = false;
+
+        let err = Instruction::try_from(exemption_ix).unwrap_err();
+        match err {
+            SetPauseExemptionV1IxError::AdminMustBeSigner => {}
+            _ => panic!("Expected AdminMustBeSigner, got {err:?}"),
+        }
+    }
+}
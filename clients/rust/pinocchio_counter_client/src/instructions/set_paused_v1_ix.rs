@@ -0,0 +1,247 @@
+use {
+    crate::find_config_v1_address,
+    pinocchio_counter_program::{InstructionDiscriminator, SetPausedV1Args},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    wincode::serialize,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetPausedV1IxError {
+    #[error("Admin must be a signer")]
+    AdminMustBeSigner,
+
+    #[error("Config account must be writable")]
+    ConfigMustBeWriteable,
+
+    #[error("Config address mismatch. Expected: {expected}, Observed: {observed}")]
+    ConfigAddressMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Failed to serialize instruction arguments")]
+    SerializationError,
+}
+
+/// Instruction builder for `SetPausedV1`.
+///
+/// This struct facilitates the creation of a Solana `Instruction` for pausing or unpausing the
+/// program. Only the admin recorded in the config may send it.
+#[derive(Debug, Clone)]
+pub struct SetPausedV1Ix {
+    pub program_id: Pubkey,
+    pub admin: AccountMeta,
+    pub config: AccountMeta,
+    pub paused: bool,
+}
+
+impl SetPausedV1Ix {
+    /// Creates a new instruction builder for `SetPausedV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `admin` - The public key of the program's admin.
+    /// * `paused` - Whether the program should be paused.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetPausedV1Ix` instance with default account metadata.
+    #[must_use]
+    pub fn new(program_id: Pubkey, admin: Pubkey, paused: bool) -> Self {
+        Self {
+            program_id,
+            admin: AccountMeta {
+                pubkey: admin,
+                is_signer: true,
+                is_writable: false,
+            },
+            config: AccountMeta {
+                pubkey: find_config_v1_address(&program_id),
+                is_signer: false,
+                is_writable: true,
+            },
+            paused,
+        }
+    }
+
+    /// Sets the admin account metadata.
+    #[must_use]
+    pub fn with_admin(mut self, admin: AccountMeta) -> Self {
+        self.admin = admin;
+        self
+    }
+
+    /// Sets the config account metadata.
+    #[must_use]
+    pub fn with_config(mut self, config: AccountMeta) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Validates the instruction's account metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetPausedV1IxError`] if validation fails.
+    pub fn validate(&self) -> Result<(), SetPausedV1IxError> {
+        if !self.admin.is_signer {
+            return Err(SetPausedV1IxError::AdminMustBeSigner);
+        }
+
+        if !self.config.is_writable {
+            return Err(SetPausedV1IxError::ConfigMustBeWriteable);
+        }
+
+        let expected_config = find_config_v1_address(&self.program_id);
+        let observed_config = self.config.pubkey;
+        if observed_config != expected_config {
+            return Err(SetPausedV1IxError::ConfigAddressMismatch {
+                expected: expected_config,
+                observed: observed_config,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Converts the instruction builder into a Solana instruction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetPausedV1IxError`] if `validate` is `true` and validation fails, or if
+    /// serialization of instruction arguments fails.
+    pub fn to_instruction(self, validate: bool) -> Result<Instruction, SetPausedV1IxError> {
+        if validate {
+            self.validate()?;
+        }
+
+        let args = SetPausedV1Args {
+            paused: self.paused,
+        };
+        let args_data = serialize(&args).map_err(|_| SetPausedV1IxError::SerializationError)?;
+
+        let mut instruction_data = vec![InstructionDiscriminator::SetPausedV1.into()];
+        instruction_data.extend_from_slice(&args_data);
+
+        Ok(Instruction {
+            program_id: self.program_id,
+            accounts: vec![self.admin, self.config],
+            data: instruction_data,
+        })
+    }
+}
+
+impl TryFrom<SetPausedV1Ix> for Instruction {
+    type Error = SetPausedV1IxError;
+
+    fn try_from(value: SetPausedV1Ix) -> Result<Self, Self::Error> {
+        value.to_instruction(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_creates_valid_struct() {
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let set_paused_ix = SetPausedV1Ix::new(program_id, admin, true);
+
+        assert_eq!(set_paused_ix.program_id, program_id);
+        assert_eq!(set_paused_ix.admin.pubkey, admin);
+        assert_eq!(
+            set_paused_ix.config.pubkey,
+            find_config_v1_address(&program_id)
+        );
+        assert!(set_paused_ix.paused);
+    }
+
+    #[test]
+    fn test_validate_succeeds_for_valid_struct() {
+        let set_paused_ix = SetPausedV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), true);
+
+        assert!(set_paused_ix.admin.is_signer);
+        assert!(!set_paused_ix.admin.is_writable);
+        assert!(!set_paused_ix.config.is_signer);
+        assert!(set_paused_ix.config.is_writable);
+
+        assert!(set_paused_ix.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_fails_when_admin_not_signer() {
+        let mut set_paused_ix =
+            SetPausedV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), true);
+        set_paused_ix.admin.is_signer = false;
+
+        let err = set_paused_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Admin must be a signer");
+    }
+
+    #[test]
+    fn test_validate_fails_when_config_not_writable() {
+        let mut set_paused_ix =
+            SetPausedV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), true);
+        set_paused_ix.config.is_writable = false;
+
+        let err = set_paused_ix.validate().unwrap_err();
+        assert_eq!(err.to_string(), "Config account must be writable");
+    }
+
+    #[test]
+    fn test_validate_fails_when_config_address_mismatch() {
+        let mut set_paused_ix =
+            SetPausedV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), true);
+        set_paused_ix.config.pubkey = Pubkey::new_unique();
+
+        let err = set_paused_ix.validate().unwrap_err();
+        assert!(err.to_string().contains("Config address mismatch"));
+    }
+
+    #[test]
+    fn test_to_instruction_creates_correct_structure() {
+        let program_id = Pubkey::new_unique();
+        let admin = Pubkey::new_unique();
+
+        let instruction = SetPausedV1Ix::new(program_id, admin, true)
+            .to_instruction(true)
+            .unwrap();
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts[0].pubkey, admin);
+        assert_eq!(
+            instruction.accounts[1].pubkey,
+            find_config_v1_address(&program_id)
+        );
+        assert_eq!(
+            instruction.data,
+            vec![u8::from(InstructionDiscriminator::SetPausedV1), 1]
+        );
+    }
+
+    #[test]
+    fn test_to_instruction_encodes_unpause() {
+        let instruction = SetPausedV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), false)
+            .to_instruction(true)
+            .unwrap();
+
+        let args = SetPausedV1Args::deserialize(&instruction.data[1..]).unwrap();
+        assert!(!args.paused);
+    }
+
+    #[test]
+    fn test_try_from_fails_for_invalid_struct() {
+        let mut set_paused_ix =
+            SetPausedV1Ix::new(Pubkey::new_unique(), Pubkey::new_unique(), true);
+        set_paused_ix.admin.is_signer = false;
+
+        let err = Instruction::try_from(set_paused_ix).unwrap_err();
+        match err {
+            SetPausedV1IxError::AdminMustBeSigner => {}
+            _ => panic!("Expected AdminMustBeSigner, got {err:?}"),
+        }
+    }
+}
@@ -1,6 +1,17 @@
-use {pinocchio_counter_program::COUNTER_V1_SEED, solana_pubkey::Pubkey};
+use {
+    pinocchio_counter_program::{
+        BPF_LOADER_UPGRADEABLE_ID, CONFIG_V1_SEED, COUNTER_SHARD_V1_SEED, COUNTER_V1_SEED,
+        DELEGATE_V1_SEED, INDEXED_COUNTER_V1_SEED, MULTISIG_V1_SEED, SHARDED_COUNTER_V1_SEED,
+        TOMBSTONE_V1_SEED,
+    },
+    solana_instruction::AccountMeta,
+    solana_pubkey::Pubkey,
+};
 
+pub mod events;
 pub mod instructions;
+pub mod return_data;
+pub mod sharded_counter;
 pub mod transactions;
 
 #[must_use]
@@ -13,3 +24,110 @@ pub fn find_counter_v1(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
     let seeds = &[COUNTER_V1_SEED, owner.as_ref()];
     Pubkey::find_program_address(seeds, program_id)
 }
+
+#[must_use]
+pub fn find_delegate_v1_address(program_id: &Pubkey, counter: &Pubkey) -> Pubkey {
+    find_delegate_v1(program_id, counter).0
+}
+
+#[must_use]
+pub fn find_delegate_v1(program_id: &Pubkey, counter: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[DELEGATE_V1_SEED, counter.as_ref()];
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+#[must_use]
+pub fn find_multisig_v1_address(program_id: &Pubkey, counter: &Pubkey) -> Pubkey {
+    find_multisig_v1(program_id, counter).0
+}
+
+#[must_use]
+pub fn find_multisig_v1(program_id: &Pubkey, counter: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[MULTISIG_V1_SEED, counter.as_ref()];
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+#[must_use]
+pub fn find_indexed_counter_v1_address(program_id: &Pubkey, owner: &Pubkey, index: u32) -> Pubkey {
+    find_indexed_counter_v1(program_id, owner, index).0
+}
+
+#[must_use]
+pub fn find_indexed_counter_v1(program_id: &Pubkey, owner: &Pubkey, index: u32) -> (Pubkey, u8) {
+    let seeds = &[
+        INDEXED_COUNTER_V1_SEED,
+        owner.as_ref(),
+        &index.to_le_bytes(),
+    ];
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+#[must_use]
+pub fn find_sharded_counter_v1_address(program_id: &Pubkey, owner: &Pubkey) -> Pubkey {
+    find_sharded_counter_v1(program_id, owner).0
+}
+
+#[must_use]
+pub fn find_sharded_counter_v1(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[SHARDED_COUNTER_V1_SEED, owner.as_ref()];
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+#[must_use]
+pub fn find_counter_shard_v1_address(
+    program_id: &Pubkey,
+    sharded_counter: &Pubkey,
+    index: u8,
+) -> Pubkey {
+    find_counter_shard_v1(program_id, sharded_counter, index).0
+}
+
+#[must_use]
+pub fn find_counter_shard_v1(
+    program_id: &Pubkey,
+    sharded_counter: &Pubkey,
+    index: u8,
+) -> (Pubkey, u8) {
+    let seeds = &[COUNTER_SHARD_V1_SEED, sharded_counter.as_ref(), &[index]];
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+#[must_use]
+pub fn find_config_v1_address(program_id: &Pubkey) -> Pubkey {
+    find_config_v1(program_id).0
+}
+
+#[must_use]
+pub fn find_config_v1(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_V1_SEED], program_id)
+}
+
+#[must_use]
+pub fn find_tombstone_v1_address(program_id: &Pubkey, counter: &Pubkey) -> Pubkey {
+    find_tombstone_v1(program_id, counter).0
+}
+
+#[must_use]
+pub fn find_tombstone_v1(program_id: &Pubkey, counter: &Pubkey) -> (Pubkey, u8) {
+    let seeds = &[TOMBSTONE_V1_SEED, counter.as_ref()];
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+/// Returns the read-only config account that every instruction except the config instructions
+/// takes last, after its own accounts, so the program can check whether it is paused.
+#[must_use]
+pub fn config_v1_account_meta(program_id: &Pubkey) -> AccountMeta {
+    AccountMeta {
+        pubkey: find_config_v1_address(program_id),
+        is_signer: false,
+        is_writable: false,
+    }
+}
+
+/// Returns the address of the program data account that the BPF upgradeable loader keeps for
+/// `program_id`, which records the program's upgrade authority.
+#[must_use]
+pub fn find_program_data_address(program_id: &Pubkey) -> Pubkey {
+    let loader_id = Pubkey::new_from_array(BPF_LOADER_UPGRADEABLE_ID);
+    Pubkey::find_program_address(&[program_id.as_ref()], &loader_id).0
+}
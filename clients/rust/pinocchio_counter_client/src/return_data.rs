@@ -0,0 +1,106 @@
+use {
+    pinocchio_counter_program::CountReturnDataV1, solana_pubkey::Pubkey,
+    solana_transaction_context::TransactionReturnData,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CountReturnDataDecodeError {
+    #[error("Return data was set by another program. Expected: {expected}, Observed: {observed}")]
+    ProgramIdMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Return data is too long. Expected at most {expected} bytes, observed {observed}")]
+    DataTooLong { expected: usize, observed: usize },
+
+    #[error("Return data could not be deserialized: {0:?}")]
+    DeserializeError(wincode::ReadError),
+}
+
+/// Decodes the [`CountReturnDataV1`] set by the counter program from a transaction's return data.
+///
+/// RPC nodes strip trailing zero bytes from return data, so shorter data is zero-padded back to
+/// its full size before being deserialized.
+///
+/// # Errors
+///
+/// Returns [`CountReturnDataDecodeError`] if the return data was not set by `program_id` or is
+/// not a valid [`CountReturnDataV1`].
+pub fn decode_count_return_data(
+    program_id: &Pubkey,
+    return_data: &TransactionReturnData,
+) -> Result<CountReturnDataV1, CountReturnDataDecodeError> {
+    if return_data.program_id != *program_id {
+        return Err(CountReturnDataDecodeError::ProgramIdMismatch {
+            expected: *program_id,
+            observed: return_data.program_id,
+        });
+    }
+
+    let size = CountReturnDataV1::size();
+    if return_data.data.len() > size {
+        return Err(CountReturnDataDecodeError::DataTooLong {
+            expected: size,
+            observed: return_data.data.len(),
+        });
+    }
+
+    let mut data = return_data.data.clone();
+    data.resize(size, 0);
+
+    CountReturnDataV1::deserialize(&data).map_err(CountReturnDataDecodeError::DeserializeError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn return_data(program_id: Pubkey, data: Vec<u8>) -> TransactionReturnData {
+        TransactionReturnData { program_id, data }
+    }
+
+    #[test]
+    fn test_decode_count_return_data() {
+        let program_id = Pubkey::new_unique();
+        let data = return_data(program_id, 42u64.to_le_bytes().to_vec());
+
+        let decoded = decode_count_return_data(&program_id, &data).unwrap();
+        assert_eq!(decoded.count, 42);
+    }
+
+    #[test]
+    fn test_decode_count_return_data_pads_trimmed_zeros() {
+        let program_id = Pubkey::new_unique();
+
+        let decoded = decode_count_return_data(&program_id, &return_data(program_id, vec![1]));
+        assert_eq!(decoded.unwrap().count, 1);
+
+        let decoded = decode_count_return_data(&program_id, &return_data(program_id, vec![]));
+        assert_eq!(decoded.unwrap().count, 0);
+    }
+
+    #[test]
+    fn test_decode_count_return_data_fails_for_other_program() {
+        let program_id = Pubkey::new_unique();
+        let data = return_data(Pubkey::new_unique(), 42u64.to_le_bytes().to_vec());
+
+        let err = decode_count_return_data(&program_id, &data).unwrap_err();
+        assert!(matches!(
+            err,
+            CountReturnDataDecodeError::ProgramIdMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_decode_count_return_data_fails_when_too_long() {
+        let program_id = Pubkey::new_unique();
+        let data = return_data(program_id, vec![1; 9]);
+
+        let err = decode_count_return_data(&program_id, &data).unwrap_err();
+        assert!(matches!(
+            err,
+            CountReturnDataDecodeError::DataTooLong {
+                expected: 8,
+                observed: 9
+            }
+        ));
+    }
+}
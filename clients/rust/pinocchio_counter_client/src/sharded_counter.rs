@@ -0,0 +1,286 @@
+use {
+    crate::find_counter_shard_v1_address,
+    pinocchio_counter_program::{
+        AccountDiscriminator, AccountDiscriminatorError, CounterShardV1, ShardedCounterV1,
+    },
+    solana_pubkey::Pubkey,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ShardedCountError {
+    #[error("Account data is not a valid sharded counter account: {0:?}")]
+    InvalidShardedCounter(AccountDiscriminatorError),
+
+    #[error("Account data is not a valid counter shard account: {0:?}")]
+    InvalidShard(AccountDiscriminatorError),
+
+    #[error("Account data could not be deserialized: {0:?}")]
+    DeserializeError(wincode::ReadError),
+
+    #[error(
+        "Shard belongs to another sharded counter. Expected: {expected}, Observed: {observed}"
+    )]
+    ShardedCounterMismatch { expected: Pubkey, observed: Pubkey },
+
+    #[error("Shard index {index} is out of range for {shard_count} shards")]
+    ShardIndexOutOfRange { index: u8, shard_count: u8 },
+
+    #[error("Shard {0} was passed more than once")]
+    DuplicateShard(u8),
+
+    #[error("Wrong number of shards. Expected: {expected}, Observed: {observed}")]
+    ShardCountMismatch { expected: usize, observed: usize },
+}
+
+/// Returns the addresses of all shards of a sharded counter, in index order.
+#[must_use]
+pub fn find_counter_shard_v1_addresses(
+    program_id: &Pubkey,
+    sharded_counter: &Pubkey,
+    shard_count: u8,
+) -> Vec<Pubkey> {
+    (0..shard_count)
+        .map(|index| find_counter_shard_v1_address(program_id, sharded_counter, index))
+        .collect()
+}
+
+/// Picks the shard a contributor writes to.
+///
+/// Public keys are uniformly distributed, so taking the first eight bytes modulo the shard count
+/// spreads contributors evenly across shards, and a contributor always writes the same shard.
+///
+/// # Panics
+///
+/// Panics if `shard_count` is `0`.
+#[must_use]
+pub fn shard_index_for_contributor(contributor: &Pubkey, shard_count: u8) -> u8 {
+    assert!(shard_count > 0, "a sharded counter has at least one shard");
+
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&contributor.as_ref()[..8]);
+    let index = u64::from_le_bytes(prefix) % u64::from(shard_count);
+
+    u8::try_from(index).expect("index is less than shard_count")
+}
+
+/// Reads the total count of a sharded counter from its account data.
+///
+/// The total is the count already reconciled into the sharded counter plus the count of every
+/// shard, saturating at `u64::MAX` like the program does. `shards` must hold the data of every
+/// shard of the counter, in any order, so that no unreconciled increments are missed.
+///
+/// # Errors
+///
+/// Returns [`ShardedCountError`] if any account is not of the expected type, a shard belongs to
+/// another sharded counter, or the shards passed are not exactly the counter's shards.
+pub fn read_sharded_count(
+    sharded_counter: &Pubkey,
+    sharded_counter_data: &[u8],
+    shards: &[&[u8]],
+) -> Result<u64, ShardedCountError> {
+    AccountDiscriminator::check(
+        AccountDiscriminator::ShardedCounterV1Account,
+        sharded_counter_data,
+    )
+    .map_err(ShardedCountError::InvalidShardedCounter)?;
+    let sharded_counter_state = ShardedCounterV1::deserialize(sharded_counter_data)
+        .map_err(ShardedCountError::DeserializeError)?;
+
+    let shard_count = sharded_counter_state.shard_count;
+    if shards.len() != usize::from(shard_count) {
+        return Err(ShardedCountError::ShardCountMismatch {
+            expected: usize::from(shard_count),
+            observed: shards.len(),
+        });
+    }
+
+    let mut seen = vec![false; usize::from(shard_count)];
+    let mut total = sharded_counter_state.count;
+
+    for shard_data in shards {
+        AccountDiscriminator::check(AccountDiscriminator::CounterShardV1Account, shard_data)
+            .map_err(ShardedCountError::InvalidShard)?;
+        let shard_state =
+            CounterShardV1::deserialize(shard_data).map_err(ShardedCountError::DeserializeError)?;
+
+        if shard_state.sharded_counter != sharded_counter.to_bytes() {
+            return Err(ShardedCountError::ShardedCounterMismatch {
+                expected: *sharded_counter,
+                observed: Pubkey::from(shard_state.sharded_counter),
+            });
+        }
+
+        let Some(seen_shard) = seen.get_mut(usize::from(shard_state.index)) else {
+            return Err(ShardedCountError::ShardIndexOutOfRange {
+                index: shard_state.index,
+                shard_count,
+            });
+        };
+
+        if *seen_shard {
+            return Err(ShardedCountError::DuplicateShard(shard_state.index));
+        }
+        *seen_shard = true;
+
+        total = total.saturating_add(shard_state.count);
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sharded_counter_data(shard_count: u8, count: u64) -> Vec<u8> {
+        ShardedCounterV1 {
+            discriminator: AccountDiscriminator::ShardedCounterV1Account,
+            owner: Pubkey::new_unique().to_bytes(),
+            bump: 255,
+            shard_count,
+            count,
+        }
+        .serialize()
+        .unwrap()
+    }
+
+    fn shard_data(sharded_counter: &Pubkey, index: u8, count: u64) -> Vec<u8> {
+        CounterShardV1 {
+            discriminator: AccountDiscriminator::CounterShardV1Account,
+            sharded_counter: sharded_counter.to_bytes(),
+            index,
+            bump: 255,
+            count,
+        }
+        .serialize()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_counter_shard_v1_addresses_are_distinct_and_ordered() {
+        let program_id = Pubkey::new_unique();
+        let sharded_counter = Pubkey::new_unique();
+
+        let addresses = find_counter_shard_v1_addresses(&program_id, &sharded_counter, 4);
+
+        assert_eq!(addresses.len(), 4);
+        for (index, address) in (0u8..).zip(&addresses) {
+            assert_eq!(
+                *address,
+                find_counter_shard_v1_address(&program_id, &sharded_counter, index)
+            );
+        }
+        for (i, a) in addresses.iter().enumerate() {
+            for b in &addresses[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shard_index_for_contributor_is_stable_and_in_range() {
+        for _ in 0..64 {
+            let contributor = Pubkey::new_unique();
+            let index = shard_index_for_contributor(&contributor, 5);
+            assert!(index < 5);
+            assert_eq!(index, shard_index_for_contributor(&contributor, 5));
+        }
+    }
+
+    #[test]
+    fn test_read_sharded_count_sums_root_and_shards() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(3, 10);
+        let shard_0 = shard_data(&sharded_counter, 0, 1);
+        let shard_1 = shard_data(&sharded_counter, 1, 2);
+        let shard_2 = shard_data(&sharded_counter, 2, 3);
+
+        let total =
+            read_sharded_count(&sharded_counter, &root, &[&shard_2, &shard_0, &shard_1]).unwrap();
+
+        assert_eq!(total, 16);
+    }
+
+    #[test]
+    fn test_read_sharded_count_saturates() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(1, u64::MAX);
+        let shard_0 = shard_data(&sharded_counter, 0, 1);
+
+        let total = read_sharded_count(&sharded_counter, &root, &[&shard_0]).unwrap();
+
+        assert_eq!(total, u64::MAX);
+    }
+
+    #[test]
+    fn test_read_sharded_count_fails_when_shard_missing() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(2, 0);
+        let shard_0 = shard_data(&sharded_counter, 0, 1);
+
+        let err = read_sharded_count(&sharded_counter, &root, &[&shard_0]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ShardedCountError::ShardCountMismatch {
+                expected: 2,
+                observed: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn test_read_sharded_count_fails_when_shard_duplicated() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(2, 0);
+        let shard_0 = shard_data(&sharded_counter, 0, 1);
+
+        let err = read_sharded_count(&sharded_counter, &root, &[&shard_0, &shard_0]).unwrap_err();
+
+        assert!(matches!(err, ShardedCountError::DuplicateShard(0)));
+    }
+
+    #[test]
+    fn test_read_sharded_count_fails_when_shard_index_out_of_range() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(1, 0);
+        let shard_1 = shard_data(&sharded_counter, 1, 1);
+
+        let err = read_sharded_count(&sharded_counter, &root, &[&shard_1]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ShardedCountError::ShardIndexOutOfRange {
+                index: 1,
+                shard_count: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn test_read_sharded_count_fails_for_shard_of_another_counter() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(1, 0);
+        let shard_0 = shard_data(&Pubkey::new_unique(), 0, 1);
+
+        let err = read_sharded_count(&sharded_counter, &root, &[&shard_0]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ShardedCountError::ShardedCounterMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_read_sharded_count_fails_for_wrong_account_types() {
+        let sharded_counter = Pubkey::new_unique();
+        let root = sharded_counter_data(1, 0);
+        let shard_0 = shard_data(&sharded_counter, 0, 1);
+
+        let err = read_sharded_count(&sharded_counter, &shard_0, &[&shard_0]).unwrap_err();
+        assert!(matches!(err, ShardedCountError::InvalidShardedCounter(_)));
+
+        let err = read_sharded_count(&sharded_counter, &root, &[&root]).unwrap_err();
+        assert!(matches!(err, ShardedCountError::InvalidShard(_)));
+    }
+}
@@ -0,0 +1,71 @@
+use {
+    crate::instructions::{AcceptAuthorityV1Ix, AcceptAuthorityV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AcceptAuthorityV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    AcceptAuthorityV1IxError(#[from] AcceptAuthorityV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct AcceptAuthorityV1SimpleTx(VersionedTransaction);
+
+impl AcceptAuthorityV1SimpleTx {
+    /// Creates a new versioned transaction for accepting a pending counter authority transfer.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `new_authority_kp` - The keypair of the pending authority, also the fee payer.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AcceptAuthorityV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        creator: Pubkey,
+        new_authority_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, AcceptAuthorityV1SimpleTxError> {
+        let new_authority_pk = new_authority_kp.pubkey();
+
+        let ix =
+            AcceptAuthorityV1Ix::new(program_id, creator, new_authority_pk).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &new_authority_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[new_authority_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<AcceptAuthorityV1SimpleTx> for VersionedTransaction {
+    fn from(value: AcceptAuthorityV1SimpleTx) -> Self {
+        value.0
+    }
+}
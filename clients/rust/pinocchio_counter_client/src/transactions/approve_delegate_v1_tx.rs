@@ -0,0 +1,137 @@
+use {
+    crate::instructions::{ApproveDelegateV1Ix, ApproveDelegateV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApproveDelegateV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    ApproveDelegateV1IxError(#[from] ApproveDelegateV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct ApproveDelegateV1SimpleTx(VersionedTransaction);
+
+impl ApproveDelegateV1SimpleTx {
+    /// Creates a new versioned transaction for approving a counter's delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `delegate_authority` - The public key allowed to sign as the delegate.
+    /// * `allowance` - The number of increments or decrements the delegate may perform.
+    /// * `expiry_slot` - The last slot at which the delegate may act.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApproveDelegateV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        delegate_authority: Pubkey,
+        allowance: u64,
+        expiry_slot: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ApproveDelegateV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = ApproveDelegateV1Ix::new(
+            program_id,
+            owner_pk,
+            delegate_authority,
+            allowance,
+            expiry_slot,
+        )
+        .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for approving a delegate for a counter whose authority
+    /// is a multisig created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction and the
+    /// delegate account.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `signer_kps` - The keypairs of the multisig members approving the instruction.
+    /// * `delegate_authority` - The public key allowed to sign as the delegate.
+    /// * `allowance` - The number of increments or decrements the delegate may perform.
+    /// * `expiry_slot` - The last slot at which the delegate may act.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ApproveDelegateV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        creator: Pubkey,
+        signer_kps: &[Keypair],
+        delegate_authority: Pubkey,
+        allowance: u64,
+        expiry_slot: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ApproveDelegateV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = ApproveDelegateV1Ix::new(
+            program_id,
+            creator,
+            delegate_authority,
+            allowance,
+            expiry_slot,
+        )
+        .with_multisig_signers(&signer_pks)
+        .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<ApproveDelegateV1SimpleTx> for VersionedTransaction {
+    fn from(value: ApproveDelegateV1SimpleTx) -> Self {
+        value.0
+    }
+}
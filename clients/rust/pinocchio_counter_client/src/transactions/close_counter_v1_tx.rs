@@ -0,0 +1,116 @@
+use {
+    crate::instructions::{CloseCounterV1Ix, CloseCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CloseCounterV1SimpleTxError {
+    #[error(transparent)]
+    CloseCounterV1IxError(#[from] CloseCounterV1IxError),
+
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct CloseCounterV1SimpleTx(VersionedTransaction);
+
+impl CloseCounterV1SimpleTx {
+    /// Creates a new versioned transaction for closing a counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner, which also pays for the tombstone record.
+    /// * `destination` - The public key of the account receiving the counter's lamports.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CloseCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        destination: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, CloseCounterV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = CloseCounterV1Ix::new(program_id, owner_pk, destination).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for closing a counter whose authority is a multisig
+    /// created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction and the
+    /// tombstone.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `signer_kps` - The keypairs of the multisig members approving the instruction.
+    /// * `destination` - The public key of the account receiving the counter's lamports.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CloseCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        creator: Pubkey,
+        signer_kps: &[Keypair],
+        destination: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, CloseCounterV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = CloseCounterV1Ix::new(program_id, creator, destination)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<CloseCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: CloseCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
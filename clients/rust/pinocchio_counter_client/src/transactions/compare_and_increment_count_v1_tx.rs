@@ -0,0 +1,72 @@
+use {
+    crate::instructions::{CompareAndIncrementCountV1Ix, CompareAndIncrementCountV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompareAndIncrementCountV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    CompareAndIncrementCountV1IxError(#[from] CompareAndIncrementCountV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct CompareAndIncrementCountV1SimpleTx(VersionedTransaction);
+
+impl CompareAndIncrementCountV1SimpleTx {
+    /// Creates a new versioned transaction for incrementing a counter by 1 only if its count still equals
+    /// `expected_count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `expected_count` - The count the client last observed.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompareAndIncrementCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        expected_count: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, CompareAndIncrementCountV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = CompareAndIncrementCountV1Ix::new(program_id, owner_pk, expected_count)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<CompareAndIncrementCountV1SimpleTx> for VersionedTransaction {
+    fn from(value: CompareAndIncrementCountV1SimpleTx) -> Self {
+        value.0
+    }
+}
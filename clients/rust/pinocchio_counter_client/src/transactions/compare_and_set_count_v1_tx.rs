@@ -0,0 +1,73 @@
+use {
+    crate::instructions::{CompareAndSetCountV1Ix, CompareAndSetCountV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CompareAndSetCountV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    CompareAndSetCountV1IxError(#[from] CompareAndSetCountV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct CompareAndSetCountV1SimpleTx(VersionedTransaction);
+
+impl CompareAndSetCountV1SimpleTx {
+    /// Creates a new versioned transaction for setting a counter's count only if it still equals `expected_count`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `expected_count` - The count the client last observed.
+    /// * `new_count` - The count value to set.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompareAndSetCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        expected_count: u64,
+        new_count: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, CompareAndSetCountV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = CompareAndSetCountV1Ix::new(program_id, owner_pk, expected_count, new_count)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<CompareAndSetCountV1SimpleTx> for VersionedTransaction {
+    fn from(value: CompareAndSetCountV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -53,6 +53,91 @@ impl DeactivateCounterV1SimpleTx {
 
         Ok(Self(tx))
     }
+
+    /// Creates a new versioned transaction for deactivating a sponsored counter.
+    ///
+    /// The reclaimed lamports are refunded to `rent_payer`, which must be the sponsor recorded
+    /// when the counter was initialized or reactivated.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `rent_payer` - The public key of the sponsor that paid the counter's rent.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeactivateCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_with_rent_payer(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        rent_payer: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, DeactivateCounterV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = DeactivateCounterV1Ix::new(program_id, owner_pk)
+            .with_rent_payer(rent_payer)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for deactivating a counter whose authority is a multisig
+    /// created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction and receives
+    /// the reclaimed lamports.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `signer_kps` - The keypairs of the multisig members approving the instruction.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeactivateCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        creator: Pubkey,
+        signer_kps: &[Keypair],
+        recent_blockhash: Hash,
+    ) -> Result<Self, DeactivateCounterV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = DeactivateCounterV1Ix::new(program_id, creator)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
 }
 
 impl From<DeactivateCounterV1SimpleTx> for VersionedTransaction {
@@ -0,0 +1,72 @@
+use {
+    crate::instructions::{DeactivateIndexedCounterV1Ix, DeactivateIndexedCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeactivateIndexedCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    DeactivateIndexedCounterV1IxError(#[from] DeactivateIndexedCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct DeactivateIndexedCounterV1SimpleTx(VersionedTransaction);
+
+impl DeactivateIndexedCounterV1SimpleTx {
+    /// Creates a new versioned transaction for deactivating an indexed counter and refunding its
+    /// rent to the owner.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner, which also pays the transaction fee.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DeactivateIndexedCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        index: u32,
+        recent_blockhash: Hash,
+    ) -> Result<Self, DeactivateIndexedCounterV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix =
+            DeactivateIndexedCounterV1Ix::new(program_id, owner_pk, index).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<DeactivateIndexedCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: DeactivateIndexedCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
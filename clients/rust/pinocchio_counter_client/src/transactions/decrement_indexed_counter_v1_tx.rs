@@ -0,0 +1,71 @@
+use {
+    crate::instructions::{DecrementIndexedCounterV1Ix, DecrementIndexedCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DecrementIndexedCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    DecrementIndexedCounterV1IxError(#[from] DecrementIndexedCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct DecrementIndexedCounterV1SimpleTx(VersionedTransaction);
+
+impl DecrementIndexedCounterV1SimpleTx {
+    /// Creates a new versioned transaction for decrementing an indexed counter by 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner, which also pays the transaction fee.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecrementIndexedCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        index: u32,
+        recent_blockhash: Hash,
+    ) -> Result<Self, DecrementIndexedCounterV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix =
+            DecrementIndexedCounterV1Ix::new(program_id, owner_pk, index).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<DecrementIndexedCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: DecrementIndexedCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
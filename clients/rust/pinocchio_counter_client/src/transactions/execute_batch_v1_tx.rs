@@ -0,0 +1,73 @@
+use {
+    crate::instructions::{ExecuteBatchV1Ix, ExecuteBatchV1IxError},
+    pinocchio_counter_program::BatchOperationV1,
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExecuteBatchV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    ExecuteBatchV1IxError(#[from] ExecuteBatchV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct ExecuteBatchV1SimpleTx(VersionedTransaction);
+
+impl ExecuteBatchV1SimpleTx {
+    /// Creates a new versioned transaction applying a batch of operations to a counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `operations` - The operations to apply, in order.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteBatchV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        operations: Vec<BatchOperationV1>,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ExecuteBatchV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = ExecuteBatchV1Ix::new(program_id, owner_pk)
+            .with_operations(operations)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<ExecuteBatchV1SimpleTx> for VersionedTransaction {
+    fn from(value: ExecuteBatchV1SimpleTx) -> Self {
+        value.0
+    }
+}
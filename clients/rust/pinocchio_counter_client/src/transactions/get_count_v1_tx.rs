@@ -0,0 +1,71 @@
+use {
+    crate::instructions::{GetCountV1Ix, GetCountV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetCountV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    GetCountV1IxError(#[from] GetCountV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct GetCountV1SimpleTx(VersionedTransaction);
+
+impl GetCountV1SimpleTx {
+    /// Creates a new versioned transaction for reading a counter's count as return data.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair paying the transaction fee. It need not be related to the
+    ///   counter.
+    /// * `owner` - The public key of the counter's creator.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, GetCountV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+
+        let ix = GetCountV1Ix::new(program_id, owner).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<GetCountV1SimpleTx> for VersionedTransaction {
+    fn from(value: GetCountV1SimpleTx) -> Self {
+        value.0
+    }
+}
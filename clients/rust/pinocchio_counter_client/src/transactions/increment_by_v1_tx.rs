@@ -0,0 +1,110 @@
+use {
+    crate::instructions::{IncrementByV1Ix, IncrementByV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IncrementByV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    IncrementByV1IxError(#[from] IncrementByV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct IncrementByV1SimpleTx(VersionedTransaction);
+
+impl IncrementByV1SimpleTx {
+    /// Creates a new versioned transaction for incrementing a counter's count by an arbitrary amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `amount` - The amount to increment by.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementByV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, IncrementByV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = IncrementByV1Ix::new(program_id, owner_pk, amount).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+    /// Creates a new versioned transaction for incrementing a counter as its approved delegate.
+    ///
+    /// The delegate signs and pays for the transaction, and one unit of its allowance is consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `delegate_kp` - The keypair of the approved delegate.
+    /// * `amount` - The amount to increment by.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementByV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_delegated(
+        program_id: Pubkey,
+        owner: Pubkey,
+        delegate_kp: Keypair,
+        amount: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, IncrementByV1SimpleTxError> {
+        let delegate_pk = delegate_kp.pubkey();
+
+        let ix = IncrementByV1Ix::new_delegated(program_id, owner, delegate_pk, amount)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &delegate_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[delegate_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<IncrementByV1SimpleTx> for VersionedTransaction {
+    fn from(value: IncrementByV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -51,6 +51,125 @@ impl IncrementCountV1SimpleTx {
         let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
         tx.sanitize()?;
 
+        Ok(Self(tx))
+    }
+    /// Creates a new versioned transaction for incrementing a counter as its approved delegate.
+    ///
+    /// The delegate signs and pays for the transaction, and one unit of its allowance is consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `delegate_kp` - The keypair of the approved delegate.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_delegated(
+        program_id: Pubkey,
+        owner: Pubkey,
+        delegate_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, IncrementCountV1SimpleTxError> {
+        let delegate_pk = delegate_kp.pubkey();
+
+        let ix = IncrementCountV1Ix::new_delegated(program_id, owner, delegate_pk)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &delegate_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[delegate_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for incrementing a public counter as a key other than its
+    /// owner. The program rejects it unless the counter was made public with `SetAccessModeV1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `signer_kp` - The keypair signing and paying for the transaction.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_public(
+        program_id: Pubkey,
+        owner: Pubkey,
+        signer_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, IncrementCountV1SimpleTxError> {
+        let signer_pk = signer_kp.pubkey();
+
+        let ix =
+            IncrementCountV1Ix::new_public(program_id, owner, signer_pk).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for incrementing a counter whose authority is a
+    /// multisig created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the counter's owner.
+    /// * `signer_kps` - The keypairs of the multisig members approving the increment.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        owner: Pubkey,
+        signer_kps: &[Keypair],
+        recent_blockhash: Hash,
+    ) -> Result<Self, IncrementCountV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = IncrementCountV1Ix::new(program_id, owner)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
         Ok(Self(tx))
     }
 }
@@ -0,0 +1,74 @@
+use {
+    crate::instructions::{IncrementCounterShardV1Ix, IncrementCounterShardV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum IncrementCounterShardV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    IncrementCounterShardV1IxError(#[from] IncrementCounterShardV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct IncrementCounterShardV1SimpleTx(VersionedTransaction);
+
+impl IncrementCounterShardV1SimpleTx {
+    /// Creates a new versioned transaction for incrementing the contributor's shard of a sharded
+    /// counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner` - The public key of the sharded counter's owner.
+    /// * `contributor_kp` - The keypair of the contributor, which also pays the transaction fee.
+    /// * `shard_count` - The number of shards of the sharded counter.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IncrementCounterShardV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner: Pubkey,
+        contributor_kp: Keypair,
+        shard_count: u8,
+        recent_blockhash: Hash,
+    ) -> Result<Self, IncrementCounterShardV1SimpleTxError> {
+        let contributor_pk = contributor_kp.pubkey();
+
+        let ix = IncrementCounterShardV1Ix::new(program_id, owner, contributor_pk, shard_count)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &contributor_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[contributor_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<IncrementCounterShardV1SimpleTx> for VersionedTransaction {
+    fn from(value: IncrementCounterShardV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,70 @@
+use {
+    crate::instructions::{InitializeConfigV1Ix, InitializeConfigV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeConfigV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    InitializeConfigV1IxError(#[from] InitializeConfigV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct InitializeConfigV1SimpleTx(VersionedTransaction);
+
+impl InitializeConfigV1SimpleTx {
+    /// Creates a new versioned transaction for creating the program's config account.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `authority_kp` - The keypair of the program's upgrade authority, which also pays.
+    /// * `admin` - The key that will be allowed to pause the program.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeConfigV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        authority_kp: Keypair,
+        admin: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, InitializeConfigV1SimpleTxError> {
+        let authority_pk = authority_kp.pubkey();
+
+        let ix = InitializeConfigV1Ix::new(program_id, authority_pk, admin).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &authority_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[authority_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<InitializeConfigV1SimpleTx> for VersionedTransaction {
+    fn from(value: InitializeConfigV1SimpleTx) -> Self {
+        value.0
+    }
+}
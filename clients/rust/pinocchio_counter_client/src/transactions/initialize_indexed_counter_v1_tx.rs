@@ -0,0 +1,71 @@
+use {
+    crate::instructions::{InitializeIndexedCounterV1Ix, InitializeIndexedCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeIndexedCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    InitializeIndexedCounterV1IxError(#[from] InitializeIndexedCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct InitializeIndexedCounterV1SimpleTx(VersionedTransaction);
+
+impl InitializeIndexedCounterV1SimpleTx {
+    /// Creates a new versioned transaction for initializing an indexed counter owned by the payer.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair of the counter's owner, which also pays the transaction fee.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeIndexedCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        index: u32,
+        recent_blockhash: Hash,
+    ) -> Result<Self, InitializeIndexedCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+
+        let ix =
+            InitializeIndexedCounterV1Ix::new(program_id, payer_pk, index).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<InitializeIndexedCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: InitializeIndexedCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
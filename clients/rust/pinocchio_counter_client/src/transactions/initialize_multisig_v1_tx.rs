@@ -0,0 +1,76 @@
+use {
+    crate::instructions::{InitializeMultisigV1Ix, InitializeMultisigV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeMultisigV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    InitializeMultisigV1IxError(#[from] InitializeMultisigV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct InitializeMultisigV1SimpleTx(VersionedTransaction);
+
+impl InitializeMultisigV1SimpleTx {
+    /// Creates a new versioned transaction for handing a counter's authority to a multisig.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `authority_kp` - The keypair of the counter's current authority, also the fee payer.
+    /// * `members` - The public keys of the multisig members.
+    /// * `threshold` - The number of distinct members that must sign later updates.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeMultisigV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        creator: Pubkey,
+        authority_kp: Keypair,
+        members: &[Pubkey],
+        threshold: u8,
+        recent_blockhash: Hash,
+    ) -> Result<Self, InitializeMultisigV1SimpleTxError> {
+        let authority_pk = authority_kp.pubkey();
+
+        let ix = InitializeMultisigV1Ix::new(program_id, creator, members, threshold)
+            .with_authority(authority_pk)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &authority_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[authority_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<InitializeMultisigV1SimpleTx> for VersionedTransaction {
+    fn from(value: InitializeMultisigV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,72 @@
+use {
+    crate::instructions::{InitializeShardedCounterV1Ix, InitializeShardedCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeShardedCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    InitializeShardedCounterV1IxError(#[from] InitializeShardedCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct InitializeShardedCounterV1SimpleTx(VersionedTransaction);
+
+impl InitializeShardedCounterV1SimpleTx {
+    /// Creates a new versioned transaction for initializing a sharded counter owned by the payer,
+    /// together with its shards.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair of the sharded counter's owner, which also pays the transaction fee.
+    /// * `shard_count` - The number of shards to create.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeShardedCounterV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        shard_count: u8,
+        recent_blockhash: Hash,
+    ) -> Result<Self, InitializeShardedCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+
+        let ix = InitializeShardedCounterV1Ix::new(program_id, payer_pk, shard_count)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<InitializeShardedCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: InitializeShardedCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,75 @@
+use {
+    crate::instructions::{InitializeSponsoredCounterV1Ix, InitializeSponsoredCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum InitializeSponsoredCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    InitializeSponsoredCounterV1IxError(#[from] InitializeSponsoredCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct InitializeSponsoredCounterV1SimpleTx(VersionedTransaction);
+
+impl InitializeSponsoredCounterV1SimpleTx {
+    /// Creates a new versioned transaction for initializing a counter whose rent is sponsored.
+    ///
+    /// The payer is the transaction fee payer and pays the counter's rent, and the owner only
+    /// signs.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair paying the transaction fee and the counter's rent.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitializeSponsoredCounterV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, InitializeSponsoredCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = InitializeSponsoredCounterV1Ix::new(program_id, payer_pk, owner_pk)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp, owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<InitializeSponsoredCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: InitializeSponsoredCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,75 @@
+use {
+    crate::instructions::{MigrateCounterV1ToV2Ix, MigrateCounterV1ToV2IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrateCounterV1ToV2SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    MigrateCounterV1ToV2IxError(#[from] MigrateCounterV1ToV2IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct MigrateCounterV1ToV2SimpleTx(VersionedTransaction);
+
+impl MigrateCounterV1ToV2SimpleTx {
+    /// Creates a new versioned transaction for migrating a counter to the `CounterV2` layout.
+    ///
+    /// The payer is the transaction fee payer and tops up the counter's rent, and the owner only
+    /// signs.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair paying the transaction fee and the counter's additional rent.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigrateCounterV1ToV2SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, MigrateCounterV1ToV2SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+        let owner_pk = owner_kp.pubkey();
+
+        let ix =
+            MigrateCounterV1ToV2Ix::new(program_id, payer_pk, owner_pk).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp, owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<MigrateCounterV1ToV2SimpleTx> for VersionedTransaction {
+    fn from(value: MigrateCounterV1ToV2SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -1,15 +1,103 @@
+mod accept_authority_v1_tx;
+mod approve_delegate_v1_tx;
+mod close_counter_v1_tx;
+mod compare_and_increment_count_v1_tx;
+mod compare_and_set_count_v1_tx;
 mod deactivate_counter_v1_tx;
+mod deactivate_indexed_counter_v1_tx;
+mod decrement_by_v1_tx;
 mod decrement_count_v1_tx;
+mod decrement_indexed_counter_v1_tx;
+mod execute_batch_v1_tx;
+mod freeze_counter_v1_tx;
+mod get_count_v1_tx;
+mod increment_by_v1_tx;
 mod increment_count_v1_tx;
+mod increment_counter_shard_v1_tx;
+mod increment_indexed_counter_v1_tx;
+mod initialize_config_v1_tx;
 mod initialize_counter_v1_tx;
+mod initialize_indexed_counter_v1_tx;
+mod initialize_multisig_v1_tx;
+mod initialize_sharded_counter_v1_tx;
+mod initialize_sponsored_counter_v1_tx;
+mod migrate_counter_v1_to_v2_tx;
+mod propose_authority_v1_tx;
 mod reactivate_counter_v1_tx;
+mod reactivate_indexed_counter_v1_tx;
+mod reactivate_sponsored_counter_v1_tx;
+mod reconcile_shards_v1_tx;
+mod revoke_delegate_v1_tx;
+mod set_access_mode_v1_tx;
+mod set_cooldown_v1_tx;
+mod set_count_policy_v1_tx;
 mod set_count_v1_tx;
+mod set_indexed_count_v1_tx;
+mod set_pause_exemption_v1_tx;
+mod set_paused_v1_tx;
+mod thaw_counter_v1_tx;
 
 pub use {
+    accept_authority_v1_tx::{AcceptAuthorityV1SimpleTx, AcceptAuthorityV1SimpleTxError},
+    approve_delegate_v1_tx::{ApproveDelegateV1SimpleTx, ApproveDelegateV1SimpleTxError},
+    close_counter_v1_tx::{CloseCounterV1SimpleTx, CloseCounterV1SimpleTxError},
+    compare_and_increment_count_v1_tx::{
+        CompareAndIncrementCountV1SimpleTx, CompareAndIncrementCountV1SimpleTxError,
+    },
+    compare_and_set_count_v1_tx::{
+        CompareAndSetCountV1SimpleTx, CompareAndSetCountV1SimpleTxError,
+    },
     deactivate_counter_v1_tx::{DeactivateCounterV1SimpleTx, DeactivateCounterV1SimpleTxError},
+    deactivate_indexed_counter_v1_tx::{
+        DeactivateIndexedCounterV1SimpleTx, DeactivateIndexedCounterV1SimpleTxError,
+    },
+    decrement_by_v1_tx::{DecrementByV1SimpleTx, DecrementByV1SimpleTxError},
     decrement_count_v1_tx::{DecrementCountV1SimpleTx, DecrementCountV1SimpleTxError},
+    decrement_indexed_counter_v1_tx::{
+        DecrementIndexedCounterV1SimpleTx, DecrementIndexedCounterV1SimpleTxError,
+    },
+    execute_batch_v1_tx::{ExecuteBatchV1SimpleTx, ExecuteBatchV1SimpleTxError},
+    freeze_counter_v1_tx::{FreezeCounterV1SimpleTx, FreezeCounterV1SimpleTxError},
+    get_count_v1_tx::{GetCountV1SimpleTx, GetCountV1SimpleTxError},
+    increment_by_v1_tx::{IncrementByV1SimpleTx, IncrementByV1SimpleTxError},
     increment_count_v1_tx::{IncrementCountV1SimpleTx, IncrementCountV1SimpleTxError},
+    increment_counter_shard_v1_tx::{
+        IncrementCounterShardV1SimpleTx, IncrementCounterShardV1SimpleTxError,
+    },
+    increment_indexed_counter_v1_tx::{
+        IncrementIndexedCounterV1SimpleTx, IncrementIndexedCounterV1SimpleTxError,
+    },
+    initialize_config_v1_tx::{InitializeConfigV1SimpleTx, InitializeConfigV1SimpleTxError},
     initialize_counter_v1_tx::{InitializeCounterV1SimpleTx, InitializeCounterV1SimpleTxError},
+    initialize_indexed_counter_v1_tx::{
+        InitializeIndexedCounterV1SimpleTx, InitializeIndexedCounterV1SimpleTxError,
+    },
+    initialize_multisig_v1_tx::{InitializeMultisigV1SimpleTx, InitializeMultisigV1SimpleTxError},
+    initialize_sharded_counter_v1_tx::{
+        InitializeShardedCounterV1SimpleTx, InitializeShardedCounterV1SimpleTxError,
+    },
+    initialize_sponsored_counter_v1_tx::{
+        InitializeSponsoredCounterV1SimpleTx, InitializeSponsoredCounterV1SimpleTxError,
+    },
+    migrate_counter_v1_to_v2_tx::{
+        MigrateCounterV1ToV2SimpleTx, MigrateCounterV1ToV2SimpleTxError,
+    },
+    propose_authority_v1_tx::{ProposeAuthorityV1SimpleTx, ProposeAuthorityV1SimpleTxError},
     reactivate_counter_v1_tx::{ReactivateCounterV1SimpleTx, ReactivateCounterV1SimpleTxError},
+    reactivate_indexed_counter_v1_tx::{
+        ReactivateIndexedCounterV1SimpleTx, ReactivateIndexedCounterV1SimpleTxError,
+    },
+    reactivate_sponsored_counter_v1_tx::{
+        ReactivateSponsoredCounterV1SimpleTx, ReactivateSponsoredCounterV1SimpleTxError,
+    },
+    reconcile_shards_v1_tx::{ReconcileShardsV1SimpleTx, ReconcileShardsV1SimpleTxError},
+    revoke_delegate_v1_tx::{RevokeDelegateV1SimpleTx, RevokeDelegateV1SimpleTxError},
+    set_access_mode_v1_tx::{SetAccessModeV1SimpleTx, SetAccessModeV1SimpleTxError},
+    set_cooldown_v1_tx::{SetCooldownV1SimpleTx, SetCooldownV1SimpleTxError},
+    set_count_policy_v1_tx::{SetCountPolicyV1SimpleTx, SetCountPolicyV1SimpleTxError},
     set_count_v1_tx::{SetCountV1SimpleTx, SetCountV1SimpleTxError},
+    set_indexed_count_v1_tx::{SetIndexedCountV1SimpleTx, SetIndexedCountV1SimpleTxError},
+    set_pause_exemption_v1_tx::{SetPauseExemptionV1SimpleTx, SetPauseExemptionV1SimpleTxError},
+    set_paused_v1_tx::{SetPausedV1SimpleTx, SetPausedV1SimpleTxError},
+    thaw_counter_v1_tx::{ThawCounterV1SimpleTx, ThawCounterV1SimpleTxError},
 };
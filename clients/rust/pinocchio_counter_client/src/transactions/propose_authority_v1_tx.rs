@@ -0,0 +1,119 @@
+use {
+    crate::instructions::{ProposeAuthorityV1Ix, ProposeAuthorityV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProposeAuthorityV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    ProposeAuthorityV1IxError(#[from] ProposeAuthorityV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct ProposeAuthorityV1SimpleTx(VersionedTransaction);
+
+impl ProposeAuthorityV1SimpleTx {
+    /// Creates a new versioned transaction for proposing a new counter authority.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `authority_kp` - The keypair of the counter's current authority, also the fee payer.
+    /// * `new_authority` - The public key proposed as the next authority.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProposeAuthorityV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        creator: Pubkey,
+        authority_kp: Keypair,
+        new_authority: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ProposeAuthorityV1SimpleTxError> {
+        let authority_pk = authority_kp.pubkey();
+
+        let ix = ProposeAuthorityV1Ix::new(program_id, creator, new_authority)
+            .with_authority(authority_pk)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &authority_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[authority_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for proposing a new authority for a counter whose
+    /// authority is a multisig created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `signer_kps` - The keypairs of the multisig members approving the proposal.
+    /// * `new_authority` - The public key proposed as the next authority.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProposeAuthorityV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        creator: Pubkey,
+        signer_kps: &[Keypair],
+        new_authority: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ProposeAuthorityV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = ProposeAuthorityV1Ix::new(program_id, creator, new_authority)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<ProposeAuthorityV1SimpleTx> for VersionedTransaction {
+    fn from(value: ProposeAuthorityV1SimpleTx) -> Self {
+        value.0
+    }
+}
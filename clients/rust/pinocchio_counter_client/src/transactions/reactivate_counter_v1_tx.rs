@@ -53,6 +53,90 @@ impl ReactivateCounterV1SimpleTx {
 
         Ok(Self(tx))
     }
+
+    /// Creates a new versioned transaction for reactivating a counter whose authority had been
+    /// transferred away from its creator before it was deactivated.
+    ///
+    /// The creator pays, and the authority signs to approve the reactivation.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair of the counter's creator, paying the transaction fee and rent.
+    /// * `authority_kp` - The keypair of the authority the counter had when it was deactivated.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_with_authority(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        authority_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReactivateCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+
+        let ix = ReactivateCounterV1Ix::new(program_id, payer_pk)
+            .with_authority(authority_kp.pubkey())
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp, authority_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for reactivating a counter whose authority had been
+    /// handed to a multisig with `InitializeMultisigV1` before it was deactivated.
+    ///
+    /// The creator pays, and every keypair in `signer_kps` signs to approve the reactivation.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair of the counter's creator, paying the transaction fee and rent.
+    /// * `signer_kps` - The keypairs of the multisig members approving the reactivation.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        signer_kps: &[Keypair],
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReactivateCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        let ix = ReactivateCounterV1Ix::new(program_id, payer_pk)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = core::iter::once(&payer_kp).chain(signer_kps).collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
 }
 
 impl From<ReactivateCounterV1SimpleTx> for VersionedTransaction {
@@ -0,0 +1,71 @@
+use {
+    crate::instructions::{ReactivateIndexedCounterV1Ix, ReactivateIndexedCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReactivateIndexedCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    ReactivateIndexedCounterV1IxError(#[from] ReactivateIndexedCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct ReactivateIndexedCounterV1SimpleTx(VersionedTransaction);
+
+impl ReactivateIndexedCounterV1SimpleTx {
+    /// Creates a new versioned transaction for reactivating a deactivated indexed counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair of the counter's owner, which also pays the transaction fee.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateIndexedCounterV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        index: u32,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReactivateIndexedCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+
+        let ix =
+            ReactivateIndexedCounterV1Ix::new(program_id, payer_pk, index).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<ReactivateIndexedCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: ReactivateIndexedCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,170 @@
+use {
+    crate::instructions::{ReactivateSponsoredCounterV1Ix, ReactivateSponsoredCounterV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReactivateSponsoredCounterV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    ReactivateSponsoredCounterV1IxError(#[from] ReactivateSponsoredCounterV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct ReactivateSponsoredCounterV1SimpleTx(VersionedTransaction);
+
+impl ReactivateSponsoredCounterV1SimpleTx {
+    /// Creates a new versioned transaction for reactivating a counter whose rent is sponsored.
+    ///
+    /// The payer is the transaction fee payer and pays the counter's rent, and the owner only
+    /// signs.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair paying the transaction fee and the counter's rent.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateSponsoredCounterV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReactivateSponsoredCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = ReactivateSponsoredCounterV1Ix::new(program_id, payer_pk, owner_pk)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp, owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for reactivating a sponsored counter whose authority
+    /// had been transferred away from its owner before it was deactivated.
+    ///
+    /// The payer is the transaction fee payer and pays the counter's rent, and the owner and the
+    /// authority only sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair paying the transaction fee and the counter's rent.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `authority_kp` - The keypair of the authority the counter had when it was deactivated.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateSponsoredCounterV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new_with_authority(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner_kp: Keypair,
+        authority_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReactivateSponsoredCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = ReactivateSponsoredCounterV1Ix::new(program_id, payer_pk, owner_pk)
+            .with_authority(authority_kp.pubkey())
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[payer_kp, owner_kp, authority_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for reactivating a sponsored counter whose authority
+    /// had been handed to a multisig with `InitializeMultisigV1` before it was deactivated.
+    ///
+    /// The payer is the transaction fee payer and pays the counter's rent, and the owner and
+    /// every keypair in `signer_kps` only sign.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `payer_kp` - The keypair paying the transaction fee and the counter's rent.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `signer_kps` - The keypairs of the multisig members approving the reactivation.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReactivateSponsoredCounterV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner_kp: Keypair,
+        signer_kps: &[Keypair],
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReactivateSponsoredCounterV1SimpleTxError> {
+        let payer_pk = payer_kp.pubkey();
+        let owner_pk = owner_kp.pubkey();
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        let ix = ReactivateSponsoredCounterV1Ix::new(program_id, payer_pk, owner_pk)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &payer_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = [&payer_kp, &owner_kp]
+            .into_iter()
+            .chain(signer_kps)
+            .collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<ReactivateSponsoredCounterV1SimpleTx> for VersionedTransaction {
+    fn from(value: ReactivateSponsoredCounterV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,71 @@
+use {
+    crate::instructions::{ReconcileShardsV1Ix, ReconcileShardsV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReconcileShardsV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    ReconcileShardsV1IxError(#[from] ReconcileShardsV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct ReconcileShardsV1SimpleTx(VersionedTransaction);
+
+impl ReconcileShardsV1SimpleTx {
+    /// Creates a new versioned transaction for reconciling every shard of a sharded counter.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the sharded counter's owner, which also pays the transaction fee.
+    /// * `shard_count` - The number of shards of the sharded counter.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReconcileShardsV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        shard_count: u8,
+        recent_blockhash: Hash,
+    ) -> Result<Self, ReconcileShardsV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix =
+            ReconcileShardsV1Ix::new(program_id, owner_pk, shard_count).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<ReconcileShardsV1SimpleTx> for VersionedTransaction {
+    fn from(value: ReconcileShardsV1SimpleTx) -> Self {
+        value.0
+    }
+}
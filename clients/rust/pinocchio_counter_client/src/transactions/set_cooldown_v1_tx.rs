@@ -0,0 +1,125 @@
+use {
+    crate::instructions::{SetCooldownV1Ix, SetCooldownV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetCooldownV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SetCooldownV1IxError(#[from] SetCooldownV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct SetCooldownV1SimpleTx(VersionedTransaction);
+
+impl SetCooldownV1SimpleTx {
+    /// Creates a new versioned transaction for setting the cooldown between changes to a
+    /// counter's count.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `cooldown_slots` - Slots that must pass after the last modification before the count
+    ///   may change, or `0` for no slot cooldown.
+    /// * `cooldown_seconds` - Seconds that must pass after the last modification before the count
+    ///   may change, or `0` for no time cooldown.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetCooldownV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        cooldown_slots: u64,
+        cooldown_seconds: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetCooldownV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = SetCooldownV1Ix::new(program_id, owner_pk, cooldown_slots, cooldown_seconds)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for setting the cooldown of a counter whose authority is
+    /// a multisig created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `signer_kps` - The keypairs of the multisig members approving the instruction.
+    /// * `cooldown_slots` - Slots that must pass after the last modification before the count
+    ///   may change, or `0` for no slot cooldown.
+    /// * `cooldown_seconds` - Seconds that must pass after the last modification before the count
+    ///   may change, or `0` for no time cooldown.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetCooldownV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        creator: Pubkey,
+        signer_kps: &[Keypair],
+        cooldown_slots: u64,
+        cooldown_seconds: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetCooldownV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = SetCooldownV1Ix::new(program_id, creator, cooldown_slots, cooldown_seconds)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<SetCooldownV1SimpleTx> for VersionedTransaction {
+    fn from(value: SetCooldownV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,129 @@
+use {
+    crate::instructions::{SetCountPolicyV1Ix, SetCountPolicyV1IxError},
+    pinocchio_counter_program::OverflowPolicy,
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetCountPolicyV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SetCountPolicyV1IxError(#[from] SetCountPolicyV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct SetCountPolicyV1SimpleTx(VersionedTransaction);
+
+impl SetCountPolicyV1SimpleTx {
+    /// Creates a new versioned transaction for configuring a counter's overflow policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner.
+    /// * `overflow_policy` - The policy applied when an update leaves the bounds.
+    /// * `min_count` - The inclusive minimum count, or `None` for `0`.
+    /// * `max_count` - The inclusive maximum count, or `None` for `u64::MAX`.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetCountPolicyV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        overflow_policy: OverflowPolicy,
+        min_count: Option<u64>,
+        max_count: Option<u64>,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetCountPolicyV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix = SetCountPolicyV1Ix::new(program_id, owner_pk, overflow_policy)
+            .with_min_count(min_count)
+            .with_max_count(max_count)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+
+    /// Creates a new versioned transaction for setting the count policy of a counter whose
+    /// authority is a multisig created with `InitializeMultisigV1`.
+    ///
+    /// Every keypair in `signer_kps` signs, and the first one pays for the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `creator` - The public key of the counter's creator.
+    /// * `signer_kps` - The keypairs of the multisig members approving the instruction.
+    /// * `overflow_policy` - The policy applied when an update leaves the bounds.
+    /// * `min_count` - The inclusive minimum count, or `None` for `0`.
+    /// * `max_count` - The inclusive maximum count, or `None` for `u64::MAX`.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetCountPolicyV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new_multisig(
+        program_id: Pubkey,
+        creator: Pubkey,
+        signer_kps: &[Keypair],
+        overflow_policy: OverflowPolicy,
+        min_count: Option<u64>,
+        max_count: Option<u64>,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetCountPolicyV1SimpleTxError> {
+        let signer_pks: Vec<Pubkey> = signer_kps.iter().map(Signer::pubkey).collect();
+
+        // Validation rejects an empty signer set, so there is always a fee payer below
+        let ix = SetCountPolicyV1Ix::new(program_id, creator, overflow_policy)
+            .with_min_count(min_count)
+            .with_max_count(max_count)
+            .with_multisig_signers(&signer_pks)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &signer_pks[0],
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let signers: Vec<&Keypair> = signer_kps.iter().collect();
+        let tx = VersionedTransaction::try_new(message, &signers)?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<SetCountPolicyV1SimpleTx> for VersionedTransaction {
+    fn from(value: SetCountPolicyV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,74 @@
+use {
+    crate::instructions::{SetIndexedCountV1Ix, SetIndexedCountV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetIndexedCountV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SetIndexedCountV1IxError(#[from] SetIndexedCountV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct SetIndexedCountV1SimpleTx(VersionedTransaction);
+
+impl SetIndexedCountV1SimpleTx {
+    /// Creates a new versioned transaction for setting an indexed counter's count to a specific
+    /// value.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `owner_kp` - The keypair of the counter's owner, which also pays the transaction fee.
+    /// * `index` - The index of the counter among the owner's indexed counters.
+    /// * `count` - The count value to set.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetIndexedCountV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        index: u32,
+        count: u64,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetIndexedCountV1SimpleTxError> {
+        let owner_pk = owner_kp.pubkey();
+
+        let ix =
+            SetIndexedCountV1Ix::new(program_id, owner_pk, index, count).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &owner_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<SetIndexedCountV1SimpleTx> for VersionedTransaction {
+    fn from(value: SetIndexedCountV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,75 @@
+use {
+    crate::instructions::{SetPauseExemptionV1Ix, SetPauseExemptionV1IxError},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetPauseExemptionV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SetPauseExemptionV1IxError(#[from] SetPauseExemptionV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct SetPauseExemptionV1SimpleTx(VersionedTransaction);
+
+impl SetPauseExemptionV1SimpleTx {
+    /// Creates a new versioned transaction for exempting an instruction from the pause, or for
+    /// removing its exemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `admin_kp` - The keypair of the program's admin.
+    /// * `instruction` - The instruction whose exemption is set.
+    /// * `exempt` - Whether the instruction keeps running while the program is paused.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetPauseExemptionV1SimpleTxError`] if instruction validation, message
+    /// compilation, transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        admin_kp: Keypair,
+        instruction: InstructionDiscriminator,
+        exempt: bool,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetPauseExemptionV1SimpleTxError> {
+        let admin_pk = admin_kp.pubkey();
+
+        let ix = SetPauseExemptionV1Ix::new(program_id, admin_pk, instruction, exempt)
+            .to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &admin_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[admin_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<SetPauseExemptionV1SimpleTx> for VersionedTransaction {
+    fn from(value: SetPauseExemptionV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,70 @@
+use {
+    crate::instructions::{SetPausedV1Ix, SetPausedV1IxError},
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, CompileError},
+    solana_pubkey::Pubkey,
+    solana_sanitize::SanitizeError,
+    solana_transaction::{versioned::VersionedTransaction, SignerError, VersionedMessage},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SetPausedV1SimpleTxError {
+    #[error(transparent)]
+    CompileError(#[from] CompileError),
+
+    #[error(transparent)]
+    SetPausedV1IxError(#[from] SetPausedV1IxError),
+
+    #[error(transparent)]
+    SanitizeError(#[from] SanitizeError),
+
+    #[error(transparent)]
+    SignerError(#[from] SignerError),
+}
+
+pub struct SetPausedV1SimpleTx(VersionedTransaction);
+
+impl SetPausedV1SimpleTx {
+    /// Creates a new versioned transaction for pausing or unpausing the program.
+    ///
+    /// # Arguments
+    ///
+    /// * `program_id` - The ID of the Pinocchio counter program.
+    /// * `admin_kp` - The keypair of the program's admin.
+    /// * `paused` - Whether the program should be paused.
+    /// * `recent_blockhash` - The recent blockhash for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetPausedV1SimpleTxError`] if instruction validation, message compilation,
+    /// transaction signing, or transaction sanitization fails.
+    pub fn try_new(
+        program_id: Pubkey,
+        admin_kp: Keypair,
+        paused: bool,
+        recent_blockhash: Hash,
+    ) -> Result<Self, SetPausedV1SimpleTxError> {
+        let admin_pk = admin_kp.pubkey();
+
+        let ix = SetPausedV1Ix::new(program_id, admin_pk, paused).to_instruction(true)?;
+
+        let message = VersionedMessage::V0(v0::Message::try_compile(
+            &admin_pk,
+            &[ix],
+            &[],
+            recent_blockhash,
+        )?);
+
+        let tx = VersionedTransaction::try_new(message, &[admin_kp])?;
+        tx.sanitize()?;
+
+        Ok(Self(tx))
+    }
+}
+
+impl From<SetPausedV1SimpleTx> for VersionedTransaction {
+    fn from(value: SetPausedV1SimpleTx) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,26 @@
+use wincode::{SchemaRead, SchemaWrite};
+
+/// Who may increment and decrement a counter.
+///
+/// Only the counter authority may set the count, change the policy or access mode, rotate the
+/// authority, manage delegates and deactivate the counter, whatever the access mode.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, SchemaRead, SchemaWrite)]
+pub enum AccessMode {
+    /// Only the authority, or an approved delegate, may increment and decrement the count.
+    #[default]
+    #[wincode(tag = 0)]
+    Private = 0,
+
+    /// Any signer may increment and decrement the count.
+    #[wincode(tag = 1)]
+    Public = 1,
+}
+
+impl AccessMode {
+    /// Returns `true` if any signer may increment and decrement the count.
+    #[must_use]
+    pub const fn is_public(self) -> bool {
+        matches!(self, Self::Public)
+    }
+}
@@ -1,14 +1,41 @@
 use {
-    crate::CounterV1,
+    crate::{
+        ConfigV1, CounterShardV1, CounterV1, CounterV2, DeactivatedCounterV1, DelegateV1,
+        IndexedCounterV1, MultisigV1, ShardedCounterV1, TombstoneV1, DEACTIVATED_ACCOUNT_SIZE,
+    },
     wincode::{SchemaRead, SchemaWrite},
 };
 
 #[repr(u8)]
-#[derive(Debug, PartialEq, SchemaRead, SchemaWrite)]
+#[derive(Debug, Clone, Copy, PartialEq, SchemaRead, SchemaWrite)]
 pub enum AccountDiscriminator {
     #[wincode(tag = 1)]
     CounterV1Account = 1,
 
+    #[wincode(tag = 2)]
+    CounterV2Account = 2,
+
+    #[wincode(tag = 3)]
+    DelegateV1Account = 3,
+
+    #[wincode(tag = 4)]
+    IndexedCounterV1Account = 4,
+
+    #[wincode(tag = 5)]
+    ShardedCounterV1Account = 5,
+
+    #[wincode(tag = 6)]
+    CounterShardV1Account = 6,
+
+    #[wincode(tag = 7)]
+    ConfigV1Account = 7,
+
+    #[wincode(tag = 8)]
+    TombstoneV1Account = 8,
+
+    #[wincode(tag = 9)]
+    MultisigV1Account = 9,
+
     #[wincode(tag = 255)]
     DeactivatedAccount = 255,
 }
@@ -31,6 +58,14 @@ impl From<AccountDiscriminator> for u8 {
     fn from(discriminator: AccountDiscriminator) -> Self {
         match discriminator {
             AccountDiscriminator::CounterV1Account => 1,
+            AccountDiscriminator::CounterV2Account => 2,
+            AccountDiscriminator::DelegateV1Account => 3,
+            AccountDiscriminator::IndexedCounterV1Account => 4,
+            AccountDiscriminator::ShardedCounterV1Account => 5,
+            AccountDiscriminator::CounterShardV1Account => 6,
+            AccountDiscriminator::ConfigV1Account => 7,
+            AccountDiscriminator::TombstoneV1Account => 8,
+            AccountDiscriminator::MultisigV1Account => 9,
             AccountDiscriminator::DeactivatedAccount => 255,
         }
     }
@@ -42,6 +77,14 @@ impl TryFrom<u8> for AccountDiscriminator {
     fn try_from(byte: u8) -> Result<Self, Self::Error> {
         match byte {
             1 => Ok(AccountDiscriminator::CounterV1Account),
+            2 => Ok(AccountDiscriminator::CounterV2Account),
+            3 => Ok(AccountDiscriminator::DelegateV1Account),
+            4 => Ok(AccountDiscriminator::IndexedCounterV1Account),
+            5 => Ok(AccountDiscriminator::ShardedCounterV1Account),
+            6 => Ok(AccountDiscriminator::CounterShardV1Account),
+            7 => Ok(AccountDiscriminator::ConfigV1Account),
+            8 => Ok(AccountDiscriminator::TombstoneV1Account),
+            9 => Ok(AccountDiscriminator::MultisigV1Account),
             255 => Ok(AccountDiscriminator::DeactivatedAccount),
             _ => Err(AccountDiscriminatorError::Invalid(byte)),
         }
@@ -87,9 +130,67 @@ impl AccountDiscriminator {
         Ok(())
     }
 
+    /// Checks that account data holds an active counter in any supported layout.
+    ///
+    /// Accepts `CounterV1Account` and `CounterV2Account`, each with its own expected size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccountDiscriminatorError`] if the discriminator is missing, invalid, not an
+    /// active counter layout, or if the account size is incorrect.
+    pub fn check_counter(data: &[u8]) -> Result<(), AccountDiscriminatorError> {
+        match data
+            .first()
+            .map(|byte| AccountDiscriminator::try_from(*byte))
+        {
+            Some(Ok(AccountDiscriminator::CounterV2Account)) => {
+                Self::check(AccountDiscriminator::CounterV2Account, data)
+            }
+            _ => Self::check(AccountDiscriminator::CounterV1Account, data),
+        }
+    }
+
+    /// Checks that account data holds a deactivated counter.
+    ///
+    /// Accepts a [`DeactivatedCounterV1`] record, as well as the bare discriminator kept by
+    /// counters deactivated before the record existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccountDiscriminatorError`] if the discriminator is missing, invalid, not
+    /// `DeactivatedAccount`, or if the account size is incorrect.
+    pub fn check_deactivated_counter(data: &[u8]) -> Result<(), AccountDiscriminatorError> {
+        if data.len() == DEACTIVATED_ACCOUNT_SIZE {
+            return Self::check(AccountDiscriminator::DeactivatedAccount, data);
+        }
+
+        Self::check(
+            AccountDiscriminator::DeactivatedAccount,
+            data.get(..DEACTIVATED_ACCOUNT_SIZE).unwrap_or_default(),
+        )?;
+
+        let observed_size = data.len();
+        if observed_size != DeactivatedCounterV1::size() {
+            return Err(AccountDiscriminatorError::SerializedSizeMismatch {
+                expected: DeactivatedCounterV1::size(),
+                observed: observed_size,
+            });
+        }
+
+        Ok(())
+    }
+
     fn expected_account_size(self) -> usize {
         match self {
             AccountDiscriminator::CounterV1Account => CounterV1::size(),
+            AccountDiscriminator::CounterV2Account => CounterV2::size(),
+            AccountDiscriminator::DelegateV1Account => DelegateV1::size(),
+            AccountDiscriminator::IndexedCounterV1Account => IndexedCounterV1::size(),
+            AccountDiscriminator::ShardedCounterV1Account => ShardedCounterV1::size(),
+            AccountDiscriminator::CounterShardV1Account => CounterShardV1::size(),
+            AccountDiscriminator::ConfigV1Account => ConfigV1::size(),
+            AccountDiscriminator::TombstoneV1Account => TombstoneV1::size(),
+            AccountDiscriminator::MultisigV1Account => MultisigV1::size(),
             AccountDiscriminator::DeactivatedAccount => 1,
         }
     }
@@ -110,6 +211,78 @@ mod tests {
             serialized[0]
         );
 
+        // Verify CounterV2Account serializes to 2
+        let counter_disc = AccountDiscriminator::CounterV2Account;
+        let serialized = wincode::serialize(&counter_disc)?;
+        assert_eq!(
+            serialized[0], 2,
+            "CounterV2Account should serialize to byte 2, got {}",
+            serialized[0]
+        );
+
+        // Verify DelegateV1Account serializes to 3
+        let delegate_disc = AccountDiscriminator::DelegateV1Account;
+        let serialized = wincode::serialize(&delegate_disc)?;
+        assert_eq!(
+            serialized[0], 3,
+            "DelegateV1Account should serialize to byte 3, got {}",
+            serialized[0]
+        );
+
+        // Verify IndexedCounterV1Account serializes to 4
+        let indexed_counter_disc = AccountDiscriminator::IndexedCounterV1Account;
+        let serialized = wincode::serialize(&indexed_counter_disc)?;
+        assert_eq!(
+            serialized[0], 4,
+            "IndexedCounterV1Account should serialize to byte 4, got {}",
+            serialized[0]
+        );
+
+        // Verify ShardedCounterV1Account serializes to 5
+        let sharded_counter_disc = AccountDiscriminator::ShardedCounterV1Account;
+        let serialized = wincode::serialize(&sharded_counter_disc)?;
+        assert_eq!(
+            serialized[0], 5,
+            "ShardedCounterV1Account should serialize to byte 5, got {}",
+            serialized[0]
+        );
+
+        // Verify CounterShardV1Account serializes to 6
+        let counter_shard_disc = AccountDiscriminator::CounterShardV1Account;
+        let serialized = wincode::serialize(&counter_shard_disc)?;
+        assert_eq!(
+            serialized[0], 6,
+            "CounterShardV1Account should serialize to byte 6, got {}",
+            serialized[0]
+        );
+
+        // Verify ConfigV1Account serializes to 7
+        let config_disc = AccountDiscriminator::ConfigV1Account;
+        let serialized = wincode::serialize(&config_disc)?;
+        assert_eq!(
+            serialized[0], 7,
+            "ConfigV1Account should serialize to byte 7, got {}",
+            serialized[0]
+        );
+
+        // Verify TombstoneV1Account serializes to 8
+        let tombstone_disc = AccountDiscriminator::TombstoneV1Account;
+        let serialized = wincode::serialize(&tombstone_disc)?;
+        assert_eq!(
+            serialized[0], 8,
+            "TombstoneV1Account should serialize to byte 8, got {}",
+            serialized[0]
+        );
+
+        // Verify MultisigV1Account serializes to 9
+        let multisig_disc = AccountDiscriminator::MultisigV1Account;
+        let serialized = wincode::serialize(&multisig_disc)?;
+        assert_eq!(
+            serialized[0], 9,
+            "MultisigV1Account should serialize to byte 9, got {}",
+            serialized[0]
+        );
+
         // Verify DeactivatedAccount serializes to 255
         let deactivated_disc = AccountDiscriminator::DeactivatedAccount;
         let serialized = wincode::serialize(&deactivated_disc)?;
@@ -130,4 +303,61 @@ mod tests {
             "AccountDiscriminator should be 1 byte with repr(u8)"
         );
     }
+
+    #[test]
+    fn test_check_counter_accepts_both_layouts() {
+        let mut v1 = vec![0u8; CounterV1::size()];
+        v1[0] = 1;
+        assert_eq!(AccountDiscriminator::check_counter(&v1), Ok(()));
+
+        let mut v2 = vec![0u8; CounterV2::size()];
+        v2[0] = 2;
+        assert_eq!(AccountDiscriminator::check_counter(&v2), Ok(()));
+
+        v2[0] = 1;
+        assert!(matches!(
+            AccountDiscriminator::check_counter(&v2),
+            Err(AccountDiscriminatorError::SerializedSizeMismatch { .. })
+        ));
+
+        assert!(matches!(
+            AccountDiscriminator::check_counter(&[255]),
+            Err(AccountDiscriminatorError::DiscriminatorMismatch { .. })
+        ));
+        assert_eq!(
+            AccountDiscriminator::check_counter(&[]),
+            Err(AccountDiscriminatorError::Missing)
+        );
+    }
+    #[test]
+    fn test_check_deactivated_counter_accepts_record_and_bare_discriminator() {
+        assert_eq!(
+            AccountDiscriminator::check_deactivated_counter(&[255]),
+            Ok(())
+        );
+
+        let mut record = vec![0u8; DeactivatedCounterV1::size()];
+        record[0] = 255;
+        assert_eq!(
+            AccountDiscriminator::check_deactivated_counter(&record),
+            Ok(())
+        );
+
+        record.push(0);
+        assert!(matches!(
+            AccountDiscriminator::check_deactivated_counter(&record),
+            Err(AccountDiscriminatorError::SerializedSizeMismatch { .. })
+        ));
+
+        let mut v1 = vec![0u8; CounterV1::size()];
+        v1[0] = 1;
+        assert!(matches!(
+            AccountDiscriminator::check_deactivated_counter(&v1),
+            Err(AccountDiscriminatorError::DiscriminatorMismatch { .. })
+        ));
+        assert_eq!(
+            AccountDiscriminator::check_deactivated_counter(&[]),
+            Err(AccountDiscriminatorError::Missing)
+        );
+    }
 }
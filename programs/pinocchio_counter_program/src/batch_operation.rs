@@ -0,0 +1,213 @@
+use {
+    crate::{CountPolicy, CountPolicyError},
+    wincode::{ReadError, SchemaRead, SchemaWrite},
+};
+
+/// Update applied to the count by a [`BatchOperationV1`].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SchemaRead, SchemaWrite)]
+pub enum BatchOperationKindV1 {
+    /// Increment the count by 1, as `IncrementCountV1` does.
+    #[wincode(tag = 0)]
+    Increment = 0,
+
+    /// Decrement the count by 1, as `DecrementCountV1` does.
+    #[wincode(tag = 1)]
+    Decrement = 1,
+
+    /// Increment the count by `value`, as `IncrementByV1` does.
+    #[wincode(tag = 2)]
+    IncrementBy = 2,
+
+    /// Decrement the count by `value`, as `DecrementByV1` does.
+    #[wincode(tag = 3)]
+    DecrementBy = 3,
+
+    /// Set the count to `value`, as `SetCountV1` does.
+    #[wincode(tag = 4)]
+    Set = 4,
+}
+
+/// One operation of an `ExecuteBatchV1` instruction.
+///
+/// Every operation has the same size, so the batch can be decoded without allocating. `value` is
+/// ignored by `Increment` and `Decrement`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SchemaRead, SchemaWrite)]
+pub struct BatchOperationV1 {
+    pub kind: BatchOperationKindV1,
+    pub value: u64,
+}
+
+impl BatchOperationV1 {
+    /// Returns the size in bytes of an encoded [`BatchOperationV1`].
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // BatchOperationV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Deserializes an operation from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(data: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(data)
+    }
+
+    /// Returns an operation incrementing the count by 1.
+    #[must_use]
+    pub const fn increment() -> Self {
+        Self {
+            kind: BatchOperationKindV1::Increment,
+            value: 0,
+        }
+    }
+
+    /// Returns an operation decrementing the count by 1.
+    #[must_use]
+    pub const fn decrement() -> Self {
+        Self {
+            kind: BatchOperationKindV1::Decrement,
+            value: 0,
+        }
+    }
+
+    /// Returns an operation incrementing the count by `amount`.
+    #[must_use]
+    pub const fn increment_by(amount: u64) -> Self {
+        Self {
+            kind: BatchOperationKindV1::IncrementBy,
+            value: amount,
+        }
+    }
+
+    /// Returns an operation decrementing the count by `amount`.
+    #[must_use]
+    pub const fn decrement_by(amount: u64) -> Self {
+        Self {
+            kind: BatchOperationKindV1::DecrementBy,
+            value: amount,
+        }
+    }
+
+    /// Returns an operation setting the count to `count`.
+    #[must_use]
+    pub const fn set(count: u64) -> Self {
+        Self {
+            kind: BatchOperationKindV1::Set,
+            value: count,
+        }
+    }
+
+    /// Returns `true` for the operations subject to the counter's cooldown, which are all the
+    /// operations that change the count.
+    #[must_use]
+    pub const fn is_cooldown_limited(&self) -> bool {
+        match self.kind {
+            BatchOperationKindV1::Increment
+            | BatchOperationKindV1::Decrement
+            | BatchOperationKindV1::IncrementBy
+            | BatchOperationKindV1::DecrementBy
+            | BatchOperationKindV1::Set => true,
+        }
+    }
+
+    /// Applies the operation to `count` under `policy`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountPolicyError`] if `policy` rejects the resulting count.
+    pub fn apply(&self, policy: &CountPolicy, count: u64) -> Result<u64, CountPolicyError> {
+        match self.kind {
+            BatchOperationKindV1::Increment => policy.increment(count, 1),
+            BatchOperationKindV1::Decrement => policy.decrement(count, 1),
+            BatchOperationKindV1::IncrementBy => policy.increment(count, self.value),
+            BatchOperationKindV1::DecrementBy => policy.decrement(count, self.value),
+            BatchOperationKindV1::Set => policy.set(self.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::OverflowPolicy};
+
+    fn policy() -> CountPolicy {
+        CountPolicy {
+            overflow_policy: OverflowPolicy::Error,
+            min_count: 0,
+            max_count: 10,
+        }
+    }
+
+    #[test]
+    fn test_operations_have_fixed_size() -> wincode::Result<()> {
+        for operation in [
+            BatchOperationV1::increment(),
+            BatchOperationV1::decrement(),
+            BatchOperationV1::increment_by(u64::MAX),
+            BatchOperationV1::decrement_by(1),
+            BatchOperationV1::set(7),
+        ] {
+            let data = wincode::serialize(&operation)?;
+            assert_eq!(data.len(), BatchOperationV1::size());
+            assert_eq!(BatchOperationV1::deserialize(&data)?, operation);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_fails_for_invalid_kind() -> wincode::Result<()> {
+        let mut data = wincode::serialize(&BatchOperationV1::increment())?;
+        data[0] = 5;
+
+        assert!(BatchOperationV1::deserialize(&data).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_follows_count_policy() {
+        let policy = policy();
+
+        assert_eq!(BatchOperationV1::increment().apply(&policy, 4), Ok(5));
+        assert_eq!(BatchOperationV1::decrement().apply(&policy, 4), Ok(3));
+        assert_eq!(BatchOperationV1::increment_by(6).apply(&policy, 4), Ok(10));
+        assert_eq!(BatchOperationV1::decrement_by(4).apply(&policy, 4), Ok(0));
+        assert_eq!(BatchOperationV1::set(7).apply(&policy, 4), Ok(7));
+    }
+
+    #[test]
+    fn test_apply_fails_outside_bounds() {
+        let policy = policy();
+
+        assert_eq!(
+            BatchOperationV1::increment_by(7).apply(&policy, 4),
+            Err(CountPolicyError::AboveMaximum)
+        );
+        assert_eq!(
+            BatchOperationV1::decrement().apply(&policy, 0),
+            Err(CountPolicyError::BelowMinimum)
+        );
+        assert_eq!(
+            BatchOperationV1::set(11).apply(&policy, 4),
+            Err(CountPolicyError::AboveMaximum)
+        );
+    }
+
+    #[test]
+    fn test_count_changes_are_cooldown_limited() {
+        assert!(BatchOperationV1::increment().is_cooldown_limited());
+        assert!(BatchOperationV1::decrement().is_cooldown_limited());
+        assert!(BatchOperationV1::increment_by(1).is_cooldown_limited());
+        assert!(BatchOperationV1::decrement_by(1).is_cooldown_limited());
+        assert!(BatchOperationV1::set(1).is_cooldown_limited());
+    }
+}
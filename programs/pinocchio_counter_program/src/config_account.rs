@@ -0,0 +1,60 @@
+use {
+    crate::{AccountDiscriminator, AccountDiscriminatorError, ConfigV1},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::ReadError,
+};
+
+/// Errors raised while loading the program's config account.
+///
+/// Each config instruction maps these into its own error type so that every failure keeps a
+/// stable, instruction-specific error code.
+#[derive(Debug)]
+pub enum LoadConfigError {
+    ProgramError(ProgramError),
+    ConfigMustBeOwnedByProgram,
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+}
+
+/// Loads the program's config account.
+///
+/// The program only creates a `ConfigV1` account at the config address, and only the program can
+/// write to accounts it owns, so a program-owned account holding a `ConfigV1` is the config.
+///
+/// Validates:
+/// - The config is owned by the program, which means `InitializeConfigV1` has run
+/// - The config has the `ConfigV1Account` discriminator and size
+///
+/// # Errors
+///
+/// Returns [`LoadConfigError`] if any of the checks fail.
+pub(crate) fn load_config(
+    program_id: &Pubkey,
+    config: &AccountInfo,
+) -> Result<ConfigV1, LoadConfigError> {
+    if !config.is_owned_by(program_id) {
+        return Err(LoadConfigError::ConfigMustBeOwnedByProgram);
+    }
+
+    let config_data = config.try_borrow_data()?;
+    AccountDiscriminator::check(AccountDiscriminator::ConfigV1Account, &config_data)?;
+    Ok(ConfigV1::deserialize(&config_data)?)
+}
+
+impl From<AccountDiscriminatorError> for LoadConfigError {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for LoadConfigError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for LoadConfigError {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
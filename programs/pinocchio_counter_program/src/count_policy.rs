@@ -0,0 +1,205 @@
+use wincode::{SchemaRead, SchemaWrite};
+
+/// Behaviour applied when an update would move the count outside of its bounds.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, SchemaRead, SchemaWrite)]
+pub enum OverflowPolicy {
+    /// Clamp the count to the nearest bound.
+    #[default]
+    #[wincode(tag = 0)]
+    Saturate = 0,
+
+    /// Reject the update.
+    #[wincode(tag = 1)]
+    Error = 1,
+
+    /// Wrap around to the opposite bound, modulo the size of the range.
+    #[wincode(tag = 2)]
+    Wrap = 2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountPolicyError {
+    AboveMaximum,
+    BelowMinimum,
+}
+
+/// Overflow policy together with the inclusive bounds it is enforced against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CountPolicy {
+    pub overflow_policy: OverflowPolicy,
+    pub min_count: u64,
+    pub max_count: u64,
+}
+
+impl Default for CountPolicy {
+    /// Saturates over the full `u64` range, matching the behaviour of `CounterV1`.
+    fn default() -> Self {
+        Self {
+            overflow_policy: OverflowPolicy::Saturate,
+            min_count: 0,
+            max_count: u64::MAX,
+        }
+    }
+}
+
+impl CountPolicy {
+    /// Returns the count resulting from adding `amount` to `count`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountPolicyError`] if the result is out of bounds under [`OverflowPolicy::Error`].
+    pub fn increment(&self, count: u64, amount: u64) -> Result<u64, CountPolicyError> {
+        self.resolve(i128::from(count) + i128::from(amount))
+    }
+
+    /// Returns the count resulting from subtracting `amount` from `count`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountPolicyError`] if the result is out of bounds under [`OverflowPolicy::Error`].
+    pub fn decrement(&self, count: u64, amount: u64) -> Result<u64, CountPolicyError> {
+        self.resolve(i128::from(count) - i128::from(amount))
+    }
+
+    /// Returns the count resulting from setting the count to `count`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CountPolicyError`] if `count` is out of bounds under [`OverflowPolicy::Error`].
+    pub fn set(&self, count: u64) -> Result<u64, CountPolicyError> {
+        self.resolve(i128::from(count))
+    }
+
+    /// Returns `true` if `count` lies within `[min_count, max_count]`.
+    #[must_use]
+    pub fn contains(&self, count: u64) -> bool {
+        (self.min_count..=self.max_count).contains(&count)
+    }
+
+    fn resolve(&self, target: i128) -> Result<u64, CountPolicyError> {
+        let min = i128::from(self.min_count);
+        let max = i128::from(self.max_count);
+
+        let resolved = if (min..=max).contains(&target) {
+            target
+        } else {
+            match self.overflow_policy {
+                OverflowPolicy::Saturate => target.clamp(min, max),
+                OverflowPolicy::Error if target > max => {
+                    return Err(CountPolicyError::AboveMaximum)
+                }
+                OverflowPolicy::Error => return Err(CountPolicyError::BelowMinimum),
+                OverflowPolicy::Wrap => min + (target - min).rem_euclid(max - min + 1),
+            }
+        };
+
+        // `resolved` lies within `[min_count, max_count]`, so the conversion cannot fail.
+        u64::try_from(resolved).map_err(|_| CountPolicyError::AboveMaximum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(overflow_policy: OverflowPolicy, min_count: u64, max_count: u64) -> CountPolicy {
+        CountPolicy {
+            overflow_policy,
+            min_count,
+            max_count,
+        }
+    }
+
+    #[test]
+    fn test_default_policy_matches_counter_v1_saturation() {
+        let policy = CountPolicy::default();
+
+        assert_eq!(policy.increment(u64::MAX, 1), Ok(u64::MAX));
+        assert_eq!(policy.decrement(0, 1), Ok(0));
+        assert_eq!(policy.increment(5, 10), Ok(15));
+        assert_eq!(policy.decrement(15, 10), Ok(5));
+    }
+
+    #[test]
+    fn test_saturate_clamps_to_bounds() {
+        let policy = policy(OverflowPolicy::Saturate, 10, 10_000);
+
+        assert_eq!(policy.increment(9_999, 5), Ok(10_000));
+        assert_eq!(policy.decrement(12, 5), Ok(10));
+        assert_eq!(policy.set(0), Ok(10));
+        assert_eq!(policy.set(u64::MAX), Ok(10_000));
+    }
+
+    #[test]
+    fn test_error_rejects_out_of_bounds() {
+        let policy = policy(OverflowPolicy::Error, 0, 10_000);
+
+        assert_eq!(policy.increment(10_000, 0), Ok(10_000));
+        assert_eq!(
+            policy.increment(10_000, 1),
+            Err(CountPolicyError::AboveMaximum)
+        );
+        assert_eq!(policy.decrement(0, 1), Err(CountPolicyError::BelowMinimum));
+        assert_eq!(policy.set(10_001), Err(CountPolicyError::AboveMaximum));
+    }
+
+    #[test]
+    fn test_error_rejects_below_custom_minimum() {
+        let policy = policy(OverflowPolicy::Error, 100, 200);
+
+        assert_eq!(policy.set(99), Err(CountPolicyError::BelowMinimum));
+        assert_eq!(
+            policy.decrement(100, 1),
+            Err(CountPolicyError::BelowMinimum)
+        );
+    }
+
+    #[test]
+    fn test_wrap_wraps_around_range() {
+        let policy = policy(OverflowPolicy::Wrap, 0, 9);
+
+        assert_eq!(policy.increment(9, 1), Ok(0));
+        assert_eq!(policy.increment(7, 25), Ok(2));
+        assert_eq!(policy.decrement(0, 1), Ok(9));
+        assert_eq!(policy.decrement(3, 25), Ok(8));
+        assert_eq!(policy.set(12), Ok(2));
+    }
+
+    #[test]
+    fn test_wrap_over_full_range_matches_wrapping_arithmetic() {
+        let policy = CountPolicy {
+            overflow_policy: OverflowPolicy::Wrap,
+            ..CountPolicy::default()
+        };
+
+        assert_eq!(policy.increment(u64::MAX, 1), Ok(0));
+        assert_eq!(policy.increment(u64::MAX, u64::MAX), Ok(u64::MAX - 1));
+        assert_eq!(policy.decrement(0, 1), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_single_value_range() {
+        for overflow_policy in [OverflowPolicy::Saturate, OverflowPolicy::Wrap] {
+            let policy = policy(overflow_policy, 7, 7);
+            assert_eq!(policy.increment(7, 3), Ok(7));
+            assert_eq!(policy.decrement(7, 3), Ok(7));
+        }
+    }
+
+    #[test]
+    fn test_overflow_policy_serialization() -> wincode::Result<()> {
+        for (policy, tag) in [
+            (OverflowPolicy::Saturate, 0u8),
+            (OverflowPolicy::Error, 1u8),
+            (OverflowPolicy::Wrap, 2u8),
+        ] {
+            let serialized = wincode::serialize(&policy)?;
+            assert_eq!(serialized[0], tag);
+            let deserialized: OverflowPolicy = wincode::deserialize(&serialized)?;
+            assert_eq!(deserialized, policy);
+        }
+
+        Ok(())
+    }
+}
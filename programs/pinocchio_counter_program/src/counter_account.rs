@@ -0,0 +1,137 @@
+use {
+    crate::{
+        create_counter_v1_address, find_counter_v1, AccountDiscriminator,
+        AccountDiscriminatorError, Counter, CounterV1Ref, DeactivatedCounterV1,
+        DEACTIVATED_ACCOUNT_SIZE,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::ReadError,
+};
+
+/// Errors raised while loading an active counter account.
+///
+/// Each instruction that loads a counter maps these into its own error type so that every
+/// failure keeps a stable, instruction-specific error code.
+#[derive(Debug)]
+pub enum LoadCounterError {
+    ProgramError(ProgramError),
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+}
+
+/// Validates an active counter in place and returns a read-only view over its data.
+///
+/// The address is re-created from the creator and bump seed stored in the counter rather than from
+/// the signer, so the counter keeps validating after its authority has been rotated to a different
+/// key. Using the stored bump avoids searching for the bump seed on every call. Only the program
+/// can write counter data, and it only creates counters at their canonical address, so a stored
+/// bump that re-creates the observed address proves the address is canonical.
+///
+/// Only the fields the checks need are read, so count updates validate the counter without
+/// deserializing it.
+///
+/// Validates:
+/// - The counter has the `CounterV1Account` or `CounterV2Account` discriminator and size
+/// - The counter address is derived from its stored creator and bump seed
+///
+/// # Errors
+///
+/// Returns [`LoadCounterError`] if any of the checks fail.
+pub(crate) fn load_counter_view<'a>(
+    program_id: &Pubkey,
+    counter: &AccountInfo,
+    counter_data: &'a [u8],
+) -> Result<CounterV1Ref<'a>, LoadCounterError> {
+    let counter_state = CounterV1Ref::try_from_bytes(counter_data)?;
+
+    let expected_counter =
+        create_counter_v1_address(program_id, counter_state.owner(), counter_state.bump())?;
+    let observed_counter = counter.key();
+    if observed_counter != &expected_counter {
+        return Err(LoadCounterError::CounterAddressMismatch {
+            expected: expected_counter,
+            observed: *observed_counter,
+        });
+    }
+
+    Ok(counter_state)
+}
+
+/// Loads an active counter, validated as in [`load_counter_view`], and deserializes it for
+/// instructions that rewrite the whole account.
+///
+/// # Errors
+///
+/// Returns [`LoadCounterError`] if any of the checks fail or the counter cannot be deserialized.
+pub(crate) fn load_counter(
+    program_id: &Pubkey,
+    counter: &AccountInfo,
+) -> Result<Counter, LoadCounterError> {
+    let counter_data = counter.try_borrow_data()?;
+    load_counter_view(program_id, counter, &counter_data)?;
+    Ok(Counter::deserialize(&counter_data)?)
+}
+
+/// Loads a deactivated counter, checking its address as [`load_counter_view`] does for an active
+/// one.
+///
+/// A [`DeactivatedCounterV1`] record keeps the creator and bump seed, so its address is
+/// re-created from them. A counter deactivated before the record existed keeps only its
+/// discriminator, so its address is derived from `creator` instead, and `creator` is returned as
+/// its authority.
+///
+/// Validates:
+/// - The counter has the `DeactivatedAccount` discriminator and size
+/// - The counter address is derived from its stored creator and bump seed, or from `creator`
+///
+/// # Errors
+///
+/// Returns [`LoadCounterError`] if any of the checks fail.
+pub(crate) fn load_deactivated_counter(
+    program_id: &Pubkey,
+    counter: &AccountInfo,
+    creator: &Pubkey,
+) -> Result<DeactivatedCounterV1, LoadCounterError> {
+    let counter_data = counter.try_borrow_data()?;
+    AccountDiscriminator::check_deactivated_counter(&counter_data)?;
+
+    let (expected_counter, deactivated) = if counter_data.len() == DEACTIVATED_ACCOUNT_SIZE {
+        let (expected_counter, bump) = find_counter_v1(program_id, creator);
+        let deactivated = DeactivatedCounterV1::from_account_data(&counter_data, creator, bump)?;
+        (expected_counter, deactivated)
+    } else {
+        let deactivated = DeactivatedCounterV1::deserialize(&counter_data)?;
+        let expected_counter =
+            create_counter_v1_address(program_id, &deactivated.owner, deactivated.bump)?;
+        (expected_counter, deactivated)
+    };
+
+    let observed_counter = counter.key();
+    if observed_counter != &expected_counter {
+        return Err(LoadCounterError::CounterAddressMismatch {
+            expected: expected_counter,
+            observed: *observed_counter,
+        });
+    }
+
+    Ok(deactivated)
+}
+
+impl From<AccountDiscriminatorError> for LoadCounterError {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for LoadCounterError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for LoadCounterError {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
@@ -0,0 +1,526 @@
+use {
+    crate::{
+        AccessMode, AccountDiscriminator, AccountDiscriminatorError, CountPolicy, CounterV1,
+        CounterV2, OverflowPolicy, NO_PENDING_AUTHORITY, NO_RENT_PAYER,
+    },
+    pinocchio::{pubkey::Pubkey, sysvars::clock::Clock},
+    wincode::ReadError,
+};
+
+const PUBKEY_SIZE: usize = core::mem::size_of::<Pubkey>();
+const U64_SIZE: usize = core::mem::size_of::<u64>();
+const I64_SIZE: usize = core::mem::size_of::<i64>();
+const BOOL_SIZE: usize = core::mem::size_of::<bool>();
+const ACCESS_MODE_SIZE: usize = if let wincode::TypeMeta::Static { size, .. } =
+    <AccessMode as wincode::SchemaWrite>::TYPE_META
+{
+    size
+} else {
+    // AccessMode is a fieldless enum, so TYPE_META is always Static.
+    unreachable!()
+};
+
+// Fields shared by the `CounterV1` and `CounterV2` layouts, located from the end of `CounterV1`.
+const COUNT_OFFSET: usize = CounterV1::size() - U64_SIZE;
+const BUMP_OFFSET: usize = COUNT_OFFSET - 1;
+const OWNER_OFFSET: usize = BUMP_OFFSET - PUBKEY_SIZE;
+
+// Fields only present in `CounterV2`, located from the end of `CounterV2`.
+const COOLDOWN_SECONDS_OFFSET: usize = CounterV2::size() - U64_SIZE;
+const COOLDOWN_SLOTS_OFFSET: usize = COOLDOWN_SECONDS_OFFSET - U64_SIZE;
+const LAST_MODIFIED_UNIX_TIMESTAMP_OFFSET: usize = COOLDOWN_SLOTS_OFFSET - I64_SIZE;
+const LAST_MODIFIED_SLOT_OFFSET: usize = LAST_MODIFIED_UNIX_TIMESTAMP_OFFSET - U64_SIZE;
+const CREATED_SLOT_OFFSET: usize = LAST_MODIFIED_SLOT_OFFSET - U64_SIZE;
+const FROZEN_OFFSET: usize = CREATED_SLOT_OFFSET - BOOL_SIZE;
+const ACCESS_MODE_OFFSET: usize = FROZEN_OFFSET - ACCESS_MODE_SIZE;
+const RENT_PAYER_OFFSET: usize = ACCESS_MODE_OFFSET - PUBKEY_SIZE;
+const PENDING_AUTHORITY_OFFSET: usize = RENT_PAYER_OFFSET - PUBKEY_SIZE;
+const AUTHORITY_OFFSET: usize = PENDING_AUTHORITY_OFFSET - PUBKEY_SIZE;
+const MAX_COUNT_OFFSET: usize = AUTHORITY_OFFSET - U64_SIZE;
+const MIN_COUNT_OFFSET: usize = MAX_COUNT_OFFSET - U64_SIZE;
+const OVERFLOW_POLICY_OFFSET: usize = CounterV1::size();
+
+/// Borrowed, read-only view over the data of an active counter account.
+///
+/// Reads fields in place instead of deserializing the whole account. The view accepts both the
+/// [`CounterV1`] layout and the [`CounterV2`] layout, which extends it, and the discriminator and
+/// length are validated once when the view is created.
+#[derive(Clone, Copy)]
+pub struct CounterV1Ref<'a> {
+    data: &'a [u8],
+}
+
+/// Borrowed, mutable view over the data of an active counter account.
+///
+/// Like [`CounterV1Ref`], but also writes fields in place, so updating the count does not
+/// serialize the account into a new buffer.
+pub struct CounterV1Mut<'a> {
+    data: &'a mut [u8],
+}
+
+impl<'a> CounterV1Ref<'a> {
+    /// Creates a view over counter account data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccountDiscriminatorError`] if the data is not an active counter or does not
+    /// have the size of its layout.
+    pub fn try_from_bytes(data: &'a [u8]) -> Result<Self, AccountDiscriminatorError> {
+        AccountDiscriminator::check_counter(data)?;
+        Ok(Self { data })
+    }
+
+    /// Returns `true` if the counter uses the [`CounterV2`] layout.
+    #[must_use]
+    pub fn is_v2(&self) -> bool {
+        is_v2(self.data)
+    }
+
+    /// Returns the counter's creator, whose key the counter address is derived from.
+    #[must_use]
+    pub fn owner(&self) -> &'a Pubkey {
+        read_pubkey(self.data, OWNER_OFFSET)
+    }
+
+    #[must_use]
+    pub fn bump(&self) -> u8 {
+        self.data[BUMP_OFFSET]
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        read_u64(self.data, COUNT_OFFSET)
+    }
+
+    /// Returns the key allowed to operate the counter. For [`CounterV1`] this is the creator.
+    #[must_use]
+    pub fn authority(&self) -> &'a Pubkey {
+        if self.is_v2() {
+            read_pubkey(self.data, AUTHORITY_OFFSET)
+        } else {
+            self.owner()
+        }
+    }
+
+    /// Returns the proposed next authority, if an authority transfer is pending.
+    #[must_use]
+    pub fn pending_authority(&self) -> Option<&'a Pubkey> {
+        if !self.is_v2() {
+            return None;
+        }
+        let pending_authority = read_pubkey(self.data, PENDING_AUTHORITY_OFFSET);
+        (pending_authority != &NO_PENDING_AUTHORITY).then_some(pending_authority)
+    }
+
+    /// Returns the key refunded on deactivation, if the rent was paid by a sponsor.
+    #[must_use]
+    pub fn rent_payer(&self) -> Option<&'a Pubkey> {
+        if !self.is_v2() {
+            return None;
+        }
+        let rent_payer = read_pubkey(self.data, RENT_PAYER_OFFSET);
+        (rent_payer != &NO_RENT_PAYER).then_some(rent_payer)
+    }
+
+    /// Returns the count policy of the counter. [`CounterV1`] always uses the default policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadError`] if the stored overflow policy is not a known variant.
+    pub fn policy(&self) -> Result<CountPolicy, ReadError> {
+        read_policy(self.data)
+    }
+
+    /// Returns the access mode of the counter. [`CounterV1`] is always private.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadError`] if the stored access mode is not a known variant.
+    pub fn access_mode(&self) -> Result<AccessMode, ReadError> {
+        if !self.is_v2() {
+            return Ok(AccessMode::Private);
+        }
+        wincode::deserialize(&self.data[ACCESS_MODE_OFFSET..FROZEN_OFFSET])
+    }
+
+    /// Returns `true` if the count is frozen. [`CounterV1`] is never frozen.
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        self.is_v2() && self.data[FROZEN_OFFSET] != 0
+    }
+
+    /// Returns the slot in which the counter was created or upgraded to [`CounterV2`], or `None`
+    /// for [`CounterV1`], which does not record it.
+    #[must_use]
+    pub fn created_slot(&self) -> Option<u64> {
+        self.is_v2()
+            .then(|| read_u64(self.data, CREATED_SLOT_OFFSET))
+    }
+
+    /// Returns the slot and Unix timestamp of the counter's last modification, or `None` for
+    /// [`CounterV1`], which does not record it.
+    #[must_use]
+    pub fn last_modified(&self) -> Option<(u64, i64)> {
+        self.is_v2().then(|| {
+            (
+                read_u64(self.data, LAST_MODIFIED_SLOT_OFFSET),
+                read_i64(self.data, LAST_MODIFIED_UNIX_TIMESTAMP_OFFSET),
+            )
+        })
+    }
+
+    /// Returns `true` if `signer` may increment or decrement the count without a delegate: the
+    /// authority always may, and any signer may while the counter is public.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadError`] if the stored access mode is not a known variant.
+    pub fn allows_count_update_by(&self, signer: &Pubkey) -> Result<bool, ReadError> {
+        Ok(self.access_mode()?.is_public() || self.authority() == signer)
+    }
+
+    /// Returns `true` if count updates are rate limited. [`CounterV1`] has no cooldown.
+    #[must_use]
+    pub fn has_cooldown(&self) -> bool {
+        self.is_v2()
+            && (read_u64(self.data, COOLDOWN_SLOTS_OFFSET) != 0
+                || read_u64(self.data, COOLDOWN_SECONDS_OFFSET) != 0)
+    }
+
+    /// Returns `true` if the counter's cooldown has passed at `clock`. [`CounterV1`] has no
+    /// cooldown.
+    #[must_use]
+    pub fn cooldown_elapsed(&self, clock: &Clock) -> bool {
+        let Some((last_modified_slot, last_modified_unix_timestamp)) = self.last_modified() else {
+            return true;
+        };
+        let cooldown_slots = read_u64(self.data, COOLDOWN_SLOTS_OFFSET);
+        let cooldown_seconds = read_u64(self.data, COOLDOWN_SECONDS_OFFSET);
+
+        let slots_elapsed =
+            cooldown_slots == 0 || clock.slot >= last_modified_slot.saturating_add(cooldown_slots);
+        let seconds_elapsed = cooldown_seconds == 0
+            || clock.unix_timestamp
+                >= last_modified_unix_timestamp.saturating_add_unsigned(cooldown_seconds);
+        slots_elapsed && seconds_elapsed
+    }
+}
+
+impl<'a> CounterV1Mut<'a> {
+    /// Creates a mutable view over counter account data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccountDiscriminatorError`] if the data is not an active counter or does not
+    /// have the size of its layout.
+    pub fn try_from_bytes(data: &'a mut [u8]) -> Result<Self, AccountDiscriminatorError> {
+        AccountDiscriminator::check_counter(data)?;
+        Ok(Self { data })
+    }
+
+    /// Returns a read-only view over the same data.
+    #[must_use]
+    pub fn as_view(&self) -> CounterV1Ref<'_> {
+        CounterV1Ref { data: self.data }
+    }
+
+    /// Returns the counter's creator, whose key the counter address is derived from.
+    #[must_use]
+    pub fn owner(&self) -> &Pubkey {
+        read_pubkey(self.data, OWNER_OFFSET)
+    }
+
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        read_u64(self.data, COUNT_OFFSET)
+    }
+
+    /// Returns the count policy of the counter. [`CounterV1`] always uses the default policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadError`] if the stored overflow policy is not a known variant.
+    pub fn policy(&self) -> Result<CountPolicy, ReadError> {
+        read_policy(self.data)
+    }
+
+    /// Writes the count in place.
+    pub fn set_count(&mut self, count: u64) {
+        self.data[COUNT_OFFSET..COUNT_OFFSET + U64_SIZE].copy_from_slice(&count.to_le_bytes());
+    }
+
+    /// Records `clock` as the time of the counter's last modification, in place. Does nothing for
+    /// [`CounterV1`], which does not record it.
+    pub fn record_modification(&mut self, clock: &Clock) {
+        if !is_v2(self.data) {
+            return;
+        }
+        self.data[LAST_MODIFIED_SLOT_OFFSET..LAST_MODIFIED_SLOT_OFFSET + U64_SIZE]
+            .copy_from_slice(&clock.slot.to_le_bytes());
+        self.data
+            [LAST_MODIFIED_UNIX_TIMESTAMP_OFFSET..LAST_MODIFIED_UNIX_TIMESTAMP_OFFSET + I64_SIZE]
+            .copy_from_slice(&clock.unix_timestamp.to_le_bytes());
+    }
+}
+
+fn is_v2(data: &[u8]) -> bool {
+    data[0] == u8::from(AccountDiscriminator::CounterV2Account)
+}
+
+fn read_pubkey(data: &[u8], offset: usize) -> &Pubkey {
+    data[offset..]
+        .first_chunk::<PUBKEY_SIZE>()
+        .expect("counter length is validated when the view is created")
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; U64_SIZE];
+    bytes.copy_from_slice(&data[offset..offset + U64_SIZE]);
+    u64::from_le_bytes(bytes)
+}
+
+fn read_i64(data: &[u8], offset: usize) -> i64 {
+    let mut bytes = [0u8; I64_SIZE];
+    bytes.copy_from_slice(&data[offset..offset + I64_SIZE]);
+    i64::from_le_bytes(bytes)
+}
+
+fn read_policy(data: &[u8]) -> Result<CountPolicy, ReadError> {
+    if !is_v2(data) {
+        return Ok(CountPolicy::default());
+    }
+
+    let overflow_policy: OverflowPolicy =
+        wincode::deserialize(&data[OVERFLOW_POLICY_OFFSET..MIN_COUNT_OFFSET])?;
+    Ok(CountPolicy {
+        overflow_policy,
+        min_count: read_u64(data, MIN_COUNT_OFFSET),
+        max_count: read_u64(data, MAX_COUNT_OFFSET),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{state::test_clock, Counter},
+    };
+
+    fn counter_v1() -> CounterV1 {
+        CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: [1; 32],
+            bump: 254,
+            count: 42,
+        }
+    }
+
+    fn counter_v2() -> CounterV2 {
+        CounterV2 {
+            discriminator: AccountDiscriminator::CounterV2Account,
+            owner: [2; 32],
+            bump: 253,
+            count: 7,
+            overflow_policy: OverflowPolicy::Wrap,
+            min_count: 5,
+            max_count: 500,
+            authority: [3; 32],
+            pending_authority: [4; 32],
+            rent_payer: [5; 32],
+            access_mode: AccessMode::Public,
+            frozen: true,
+            created_slot: 100,
+            last_modified_slot: 200,
+            last_modified_unix_timestamp: 1_700_000_000,
+            cooldown_slots: 300,
+            cooldown_seconds: 60,
+        }
+    }
+
+    #[test]
+    fn test_ref_reads_counter_v1_fields() -> wincode::Result<()> {
+        let data = counter_v1().serialize()?;
+
+        let view = CounterV1Ref::try_from_bytes(&data).unwrap();
+
+        assert!(!view.is_v2());
+        assert_eq!(view.owner(), &[1; 32]);
+        assert_eq!(view.bump(), 254);
+        assert_eq!(view.count(), 42);
+        assert_eq!(view.authority(), &[1; 32]);
+        assert_eq!(view.pending_authority(), None);
+        assert_eq!(view.rent_payer(), None);
+        assert_eq!(view.policy().unwrap(), CountPolicy::default());
+        assert_eq!(view.access_mode().unwrap(), AccessMode::Private);
+        assert!(!view.is_frozen());
+        assert_eq!(view.created_slot(), None);
+        assert_eq!(view.last_modified(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ref_reads_counter_v2_fields() -> wincode::Result<()> {
+        let original = counter_v2();
+        let data = original.serialize()?;
+
+        let view = CounterV1Ref::try_from_bytes(&data).unwrap();
+
+        assert!(view.is_v2());
+        assert_eq!(view.owner(), &[2; 32]);
+        assert_eq!(view.bump(), 253);
+        assert_eq!(view.count(), 7);
+        assert_eq!(view.authority(), &[3; 32]);
+        assert_eq!(view.pending_authority(), Some(&[4; 32]));
+        assert_eq!(view.rent_payer(), Some(&[5; 32]));
+        assert_eq!(view.policy().unwrap(), original.policy());
+        assert_eq!(view.access_mode().unwrap(), AccessMode::Public);
+        assert!(view.is_frozen());
+        assert_eq!(view.created_slot(), Some(100));
+        assert_eq!(view.last_modified(), Some((200, 1_700_000_000)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ref_treats_empty_v2_keys_as_none() -> wincode::Result<()> {
+        let data = CounterV2::from_v1(&counter_v1(), &test_clock(0, 0)).serialize()?;
+
+        let view = CounterV1Ref::try_from_bytes(&data).unwrap();
+
+        assert_eq!(view.authority(), &[1; 32]);
+        assert_eq!(view.pending_authority(), None);
+        assert_eq!(view.rent_payer(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ref_checks_match_deserialized_counter() -> wincode::Result<()> {
+        let mut private_v2 = counter_v2();
+        private_v2.access_mode = AccessMode::Private;
+        let mut no_cooldown_v2 = counter_v2();
+        no_cooldown_v2.cooldown_slots = 0;
+        no_cooldown_v2.cooldown_seconds = 0;
+
+        for data in [
+            counter_v1().serialize()?,
+            counter_v2().serialize()?,
+            private_v2.serialize()?,
+            no_cooldown_v2.serialize()?,
+        ] {
+            let view = CounterV1Ref::try_from_bytes(&data).unwrap();
+            let counter = Counter::deserialize(&data)?;
+
+            for signer in [[1; 32], [3; 32], [9; 32]] {
+                assert_eq!(
+                    view.allows_count_update_by(&signer).unwrap(),
+                    counter.allows_count_update_by(&signer)
+                );
+            }
+
+            assert_eq!(view.has_cooldown(), counter.has_cooldown());
+            for (slot, unix_timestamp) in [
+                (200, 1_700_000_000),
+                (499, 1_700_000_060),
+                (500, 1_700_000_059),
+                (500, 1_700_000_060),
+            ] {
+                let clock = test_clock(slot, unix_timestamp);
+                assert_eq!(
+                    view.cooldown_elapsed(&clock),
+                    counter.cooldown_elapsed(&clock)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mut_set_count_matches_serialized_layout() -> wincode::Result<()> {
+        for mut data in [counter_v1().serialize()?, counter_v2().serialize()?] {
+            let mut view = CounterV1Mut::try_from_bytes(&mut data).unwrap();
+            let owner = *view.owner();
+            view.set_count(u64::MAX - 1);
+            assert_eq!(view.count(), u64::MAX - 1);
+            assert_eq!(view.as_view().count(), u64::MAX - 1);
+
+            let counter = Counter::deserialize(&data)?;
+            assert_eq!(counter.count(), u64::MAX - 1);
+            assert_eq!(counter.owner(), &owner);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mut_record_modification_matches_serialized_layout() -> wincode::Result<()> {
+        let clock = test_clock(300, 1_700_000_060);
+
+        let mut data = counter_v2().serialize()?;
+        let mut view = CounterV1Mut::try_from_bytes(&mut data).unwrap();
+        view.record_modification(&clock);
+        assert_eq!(view.as_view().last_modified(), Some((300, 1_700_000_060)));
+
+        let counter = CounterV2::deserialize(&data)?;
+        assert_eq!(counter.created_slot, 100);
+        assert_eq!(counter.last_modified_slot, 300);
+        assert_eq!(counter.last_modified_unix_timestamp, 1_700_000_060);
+        assert_eq!(counter.cooldown_slots, 300);
+        assert_eq!(counter.cooldown_seconds, 60);
+        assert!(counter.frozen);
+
+        // CounterV1 has nowhere to record the modification, so its data is left unchanged.
+        let original = counter_v1().serialize()?;
+        let mut data = original.clone();
+        CounterV1Mut::try_from_bytes(&mut data)
+            .unwrap()
+            .record_modification(&clock);
+        assert_eq!(data, original);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_views_reject_invalid_data() -> wincode::Result<()> {
+        let data = counter_v1().serialize()?;
+
+        assert_eq!(
+            CounterV1Ref::try_from_bytes(&[]).err(),
+            Some(AccountDiscriminatorError::Missing)
+        );
+        assert!(CounterV1Ref::try_from_bytes(&data[..data.len() - 1]).is_err());
+
+        let mut deactivated = [u8::from(AccountDiscriminator::DeactivatedAccount)];
+        assert!(CounterV1Mut::try_from_bytes(&mut deactivated).is_err());
+
+        let mut v2_data = counter_v2().serialize()?;
+        v2_data.truncate(CounterV1::size());
+        assert!(CounterV1Mut::try_from_bytes(&mut v2_data).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ref_fails_for_invalid_overflow_policy() -> wincode::Result<()> {
+        let mut data = counter_v2().serialize()?;
+        data[OVERFLOW_POLICY_OFFSET] = 9;
+
+        let view = CounterV1Ref::try_from_bytes(&data).unwrap();
+        assert!(view.policy().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ref_fails_for_invalid_access_mode() -> wincode::Result<()> {
+        let mut data = counter_v2().serialize()?;
+        data[ACCESS_MODE_OFFSET] = 9;
+
+        let view = CounterV1Ref::try_from_bytes(&data).unwrap();
+        assert!(view.access_mode().is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use {
+    crate::{find_delegate_v1, AccountDiscriminator, AccountDiscriminatorError, DelegateV1},
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{ReadError, WriteError},
+};
+
+/// Errors raised while authorizing or charging a delegate on an increment or decrement.
+///
+/// Each instruction that accepts a delegate maps these into its own error type so that every
+/// failure keeps a stable, instruction-specific error code.
+#[derive(Debug)]
+pub enum DelegateAuthorizationError {
+    ProgramError(ProgramError),
+    DelegateMustBeWriteable,
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    DelegateMismatch,
+    DelegateAuthorityChanged,
+    DelegateExpired { expiry_slot: u64, current_slot: u64 },
+    DelegateAllowanceExhausted,
+}
+
+/// Checks that `delegate` is the delegate record of `counter` and authorizes `signer`.
+///
+/// Validates:
+/// - The delegate record is writable so its allowance can be consumed
+/// - The delegate record address is derived from the counter
+/// - The delegate record has the `DelegateV1Account` discriminator
+/// - `signer` is the approved delegate, the delegation has not expired and has allowance left
+/// - The delegation was approved by `counter_authority`, the counter's current authority, so a
+///   delegate approved before an authority transfer is no longer accepted
+///
+/// # Errors
+///
+/// Returns [`DelegateAuthorizationError`] if any of the checks fail.
+pub(crate) fn authorize_delegate(
+    program_id: &Pubkey,
+    counter: &Pubkey,
+    counter_authority: &Pubkey,
+    signer: &Pubkey,
+    delegate: &AccountInfo,
+) -> Result<(), DelegateAuthorizationError> {
+    if !delegate.is_writable() {
+        return Err(DelegateAuthorizationError::DelegateMustBeWriteable);
+    }
+
+    let (expected_delegate, _bump) = find_delegate_v1(program_id, counter);
+    let observed_delegate = delegate.key();
+    if observed_delegate != &expected_delegate {
+        return Err(DelegateAuthorizationError::DelegateAddressMismatch {
+            expected: expected_delegate,
+            observed: *observed_delegate,
+        });
+    }
+
+    let delegate_state = {
+        let delegate_data = delegate.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::DelegateV1Account, &delegate_data)?;
+        DelegateV1::deserialize(&delegate_data)?
+    };
+
+    if &delegate_state.delegate != signer {
+        return Err(DelegateAuthorizationError::DelegateMismatch);
+    }
+
+    if &delegate_state.authority != counter_authority {
+        return Err(DelegateAuthorizationError::DelegateAuthorityChanged);
+    }
+
+    let current_slot = Clock::get()?.slot;
+    if current_slot > delegate_state.expiry_slot {
+        return Err(DelegateAuthorizationError::DelegateExpired {
+            expiry_slot: delegate_state.expiry_slot,
+            current_slot,
+        });
+    }
+
+    if delegate_state.allowance == 0 {
+        return Err(DelegateAuthorizationError::DelegateAllowanceExhausted);
+    }
+
+    Ok(())
+}
+
+/// Consumes one unit of allowance from a delegate record validated by [`authorize_delegate`].
+///
+/// # Errors
+///
+/// Returns [`DelegateAuthorizationError`] if the record cannot be read or written.
+pub(crate) fn consume_delegate_allowance(
+    delegate: &AccountInfo,
+) -> Result<(), DelegateAuthorizationError> {
+    let mut delegate_state = {
+        let delegate_data = delegate.try_borrow_data()?;
+        DelegateV1::deserialize(&delegate_data)?
+    };
+
+    delegate_state.allowance = delegate_state
+        .allowance
+        .checked_sub(1)
+        .ok_or(DelegateAuthorizationError::DelegateAllowanceExhausted)?;
+
+    let written = delegate_state.serialize_into(&mut delegate.try_borrow_mut_data()?)?;
+
+    if written != DelegateV1::size() {
+        return Err(DelegateAuthorizationError::SerializedSizeMismatch {
+            expected: DelegateV1::size(),
+            observed: written,
+        });
+    }
+
+    Ok(())
+}
+
+impl From<AccountDiscriminatorError> for DelegateAuthorizationError {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for DelegateAuthorizationError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for DelegateAuthorizationError {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for DelegateAuthorizationError {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
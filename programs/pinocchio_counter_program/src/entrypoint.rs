@@ -1,16 +1,29 @@
 use {
     crate::{
-        DeactivateCounterV1, DecrementCountV1, IncrementCountV1, InitializeCounterV1,
-        InstructionDiscriminator, InstructionDiscriminatorError, InstructionError,
-        ReactivateCounterV1, SetCountV1,
+        log::{log_error_details, log_instruction_discriminator_error, log_instruction_error},
+        pause::check_not_paused,
+        AcceptAuthorityV1, ApproveDelegateV1, CloseCounterV1, CompareAndIncrementCountV1,
+        CompareAndSetCountV1, DeactivateCounterV1, DeactivateIndexedCounterV1, DecrementByV1,
+        DecrementCountV1, DecrementIndexedCounterV1, ExecuteBatchV1, FreezeCounterV1, GetCountV1,
+        IncrementByV1, IncrementCountV1, IncrementCounterShardV1, IncrementIndexedCounterV1,
+        InitializeConfigV1, InitializeCounterV1, InitializeIndexedCounterV1, InitializeMultisigV1,
+        InitializeShardedCounterV1, InitializeSponsoredCounterV1, InstructionDiscriminator,
+        InstructionDiscriminatorError, InstructionError, MigrateCounterV1ToV2, PauseCheckError,
+        ProposeAuthorityV1, ReactivateCounterV1, ReactivateIndexedCounterV1,
+        ReactivateSponsoredCounterV1, ReconcileShardsV1, RevokeDelegateV1, SetAccessModeV1,
+        SetCooldownV1, SetCountPolicyV1, SetCountV1, SetIndexedCountV1, SetPauseExemptionV1,
+        SetPausedV1, ThawCounterV1,
     },
     pinocchio::{
-        account_info::AccountInfo, entrypoint, msg, program_error::ProgramError, pubkey::Pubkey,
-        ProgramResult,
+        account_info::AccountInfo, no_allocator, nostd_panic_handler, program_error::ProgramError,
+        pubkey::Pubkey, ProgramResult,
     },
 };
 
-entrypoint!(process_instruction);
+#[cfg(not(feature = "lazy-entrypoint"))]
+pinocchio::program_entrypoint!(process_instruction);
+no_allocator!();
+nostd_panic_handler!();
 
 pub fn process_instruction(
     program_id: &Pubkey,
@@ -20,41 +33,241 @@ pub fn process_instruction(
     let (discriminator, args) = InstructionDiscriminator::parse(instruction_data)
         .map_err(handle_instruction_discriminator_error)?;
 
+    // Pause-checked instructions take the config account last, after their own accounts.
+    let accounts = if discriminator.is_pause_checked() {
+        check_not_paused(program_id, discriminator, accounts)
+            .map_err(|err| handle_pause_check_error(&discriminator, err))?
+    } else {
+        accounts
+    };
+
     match discriminator {
         InstructionDiscriminator::InitializeCounterV1 => {
             InitializeCounterV1::try_from((program_id, accounts, args))
                 .and_then(|ix| ix.execute())
-                .map_err(handle_instruction_error)?;
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
         }
 
         InstructionDiscriminator::DeactivateCounterV1 => {
             DeactivateCounterV1::try_from((program_id, accounts, args))
                 .and_then(|ix| ix.execute())
-                .map_err(handle_instruction_error)?;
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
         }
 
         InstructionDiscriminator::IncrementCountV1 => {
             IncrementCountV1::try_from((program_id, accounts, args))
                 .and_then(|ix| ix.execute())
-                .map_err(handle_instruction_error)?;
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
         }
 
         InstructionDiscriminator::DecrementCountV1 => {
             DecrementCountV1::try_from((program_id, accounts, args))
                 .and_then(|ix| ix.execute())
-                .map_err(handle_instruction_error)?;
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
         }
 
         InstructionDiscriminator::SetCountV1 => {
             SetCountV1::try_from((program_id, accounts, args))
                 .and_then(|ix| ix.execute())
-                .map_err(handle_instruction_error)?;
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
         }
 
         InstructionDiscriminator::ReactivateCounterV1 => {
             ReactivateCounterV1::try_from((program_id, accounts, args))
                 .and_then(|ix| ix.execute())
-                .map_err(handle_instruction_error)?;
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::IncrementByV1 => {
+            IncrementByV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::DecrementByV1 => {
+            DecrementByV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::SetCountPolicyV1 => {
+            SetCountPolicyV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ApproveDelegateV1 => {
+            ApproveDelegateV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::RevokeDelegateV1 => {
+            RevokeDelegateV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ProposeAuthorityV1 => {
+            ProposeAuthorityV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::AcceptAuthorityV1 => {
+            AcceptAuthorityV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::InitializeIndexedCounterV1 => {
+            InitializeIndexedCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::IncrementIndexedCounterV1 => {
+            IncrementIndexedCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::DecrementIndexedCounterV1 => {
+            DecrementIndexedCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::SetIndexedCountV1 => {
+            SetIndexedCountV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::DeactivateIndexedCounterV1 => {
+            DeactivateIndexedCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ReactivateIndexedCounterV1 => {
+            ReactivateIndexedCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::InitializeSponsoredCounterV1 => {
+            InitializeSponsoredCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ReactivateSponsoredCounterV1 => {
+            ReactivateSponsoredCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::GetCountV1 => {
+            GetCountV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::CompareAndSetCountV1 => {
+            CompareAndSetCountV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::CompareAndIncrementCountV1 => {
+            CompareAndIncrementCountV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::InitializeShardedCounterV1 => {
+            InitializeShardedCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::IncrementCounterShardV1 => {
+            IncrementCounterShardV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ReconcileShardsV1 => {
+            ReconcileShardsV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::SetAccessModeV1 => {
+            SetAccessModeV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::FreezeCounterV1 => {
+            FreezeCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ThawCounterV1 => {
+            ThawCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::InitializeConfigV1 => {
+            InitializeConfigV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::SetPausedV1 => {
+            SetPausedV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::SetPauseExemptionV1 => {
+            SetPauseExemptionV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::CloseCounterV1 => {
+            CloseCounterV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::MigrateCounterV1ToV2 => {
+            MigrateCounterV1ToV2::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::SetCooldownV1 => {
+            SetCooldownV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::InitializeMultisigV1 => {
+            InitializeMultisigV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
+        }
+
+        InstructionDiscriminator::ExecuteBatchV1 => {
+            ExecuteBatchV1::try_from((program_id, accounts, args))
+                .and_then(|ix| ix.execute())
+                .map_err(|err| handle_instruction_error(&discriminator, err))?;
         }
     }
 
@@ -62,14 +275,33 @@ pub fn process_instruction(
 }
 
 fn handle_instruction_discriminator_error(err: InstructionDiscriminatorError) -> ProgramError {
-    let error_msg = format!("Instruction Discriminator Error: {:?}", err);
-    msg!(&error_msg);
-    err.into()
+    log_error_details("Instruction Discriminator Error: ", &err);
+
+    let program_error: ProgramError = err.into();
+    log_instruction_discriminator_error(u64::from(program_error));
+    program_error
+}
+
+fn handle_pause_check_error(
+    discriminator: &InstructionDiscriminator,
+    err: PauseCheckError,
+) -> ProgramError {
+    log_error_details("Pause Check Error: ", &err);
+
+    let program_error: ProgramError = err.into();
+    log_instruction_error(discriminator.name(), u64::from(program_error));
+    program_error
 }
 
-fn handle_instruction_error<E: std::fmt::Debug + Into<InstructionError>>(err: E) -> ProgramError {
+fn handle_instruction_error<E: Into<InstructionError>>(
+    discriminator: &InstructionDiscriminator,
+    err: E,
+) -> ProgramError {
     let instruction_error: InstructionError = err.into();
-    let error_msg = format!("Instruction Error: {:?}", instruction_error);
-    msg!(&error_msg);
-    instruction_error.into()
+
+    log_error_details("Instruction Error: ", &instruction_error);
+
+    let program_error: ProgramError = instruction_error.into();
+    log_instruction_error(discriminator.name(), u64::from(program_error));
+    program_error
 }
@@ -1,22 +1,66 @@
 use {
     crate::{
         instructions::{
-            DeactivateCounterV1Error, DecrementCountV1Error, IncrementCountV1Error,
-            InitializeCounterV1Error, ReactivateCounterV1Error, SetCountV1Error,
+            AcceptAuthorityV1Error, ApproveDelegateV1Error, CloseCounterV1Error,
+            CompareAndIncrementCountV1Error, CompareAndSetCountV1Error, DeactivateCounterV1Error,
+            DeactivateIndexedCounterV1Error, DecrementByV1Error, DecrementCountV1Error,
+            DecrementIndexedCounterV1Error, ExecuteBatchV1Error, FreezeCounterV1Error,
+            GetCountV1Error, IncrementByV1Error, IncrementCountV1Error,
+            IncrementCounterShardV1Error, IncrementIndexedCounterV1Error, InitializeConfigV1Error,
+            InitializeCounterV1Error, InitializeIndexedCounterV1Error, InitializeMultisigV1Error,
+            InitializeShardedCounterV1Error, InitializeSponsoredCounterV1Error,
+            MigrateCounterV1ToV2Error, ProposeAuthorityV1Error, ReactivateCounterV1Error,
+            ReactivateIndexedCounterV1Error, ReactivateSponsoredCounterV1Error,
+            ReconcileShardsV1Error, RevokeDelegateV1Error, SetAccessModeV1Error,
+            SetCooldownV1Error, SetCountPolicyV1Error, SetCountV1Error, SetIndexedCountV1Error,
+            SetPauseExemptionV1Error, SetPausedV1Error, ThawCounterV1Error,
         },
-        InstructionDiscriminatorError,
+        InstructionDiscriminatorError, PauseCheckError,
     },
     pinocchio::program_error::ProgramError,
 };
 
 // Code offsets for each error type
 const INSTRUCTION_DISCRIMINATOR_ERROR_OFFSET: u32 = 0x000; // 0
+const PAUSE_CHECK_ERROR_OFFSET: u32 = 0x080; // 128
 const INITIALIZE_COUNTER_V1_OFFSET: u32 = 0x100; // 256
 const DEACTIVATE_COUNTER_V1_OFFSET: u32 = 0x200; // 512
 const INCREMENT_COUNT_V1_OFFSET: u32 = 0x300; // 768
 const DECREMENT_COUNT_V1_OFFSET: u32 = 0x400; // 1024
 const SET_COUNT_V1_OFFSET: u32 = 0x500; // 1280
 const REACTIVATE_COUNTER_V1_OFFSET: u32 = 0x600; // 1536
+const INCREMENT_BY_V1_OFFSET: u32 = 0x700; // 1792
+const DECREMENT_BY_V1_OFFSET: u32 = 0x800; // 2048
+const SET_COUNT_POLICY_V1_OFFSET: u32 = 0x900; // 2304
+const APPROVE_DELEGATE_V1_OFFSET: u32 = 0xA00; // 2560
+const REVOKE_DELEGATE_V1_OFFSET: u32 = 0xB00; // 2816
+const PROPOSE_AUTHORITY_V1_OFFSET: u32 = 0xC00; // 3072
+const ACCEPT_AUTHORITY_V1_OFFSET: u32 = 0xD00; // 3328
+const INITIALIZE_INDEXED_COUNTER_V1_OFFSET: u32 = 0xE00; // 3584
+const INCREMENT_INDEXED_COUNTER_V1_OFFSET: u32 = 0xF00; // 3840
+const DECREMENT_INDEXED_COUNTER_V1_OFFSET: u32 = 0x1000; // 4096
+const SET_INDEXED_COUNT_V1_OFFSET: u32 = 0x1100; // 4352
+const DEACTIVATE_INDEXED_COUNTER_V1_OFFSET: u32 = 0x1200; // 4608
+const REACTIVATE_INDEXED_COUNTER_V1_OFFSET: u32 = 0x1300; // 4864
+const INITIALIZE_SPONSORED_COUNTER_V1_OFFSET: u32 = 0x1400; // 5120
+const REACTIVATE_SPONSORED_COUNTER_V1_OFFSET: u32 = 0x1500; // 5376
+const GET_COUNT_V1_OFFSET: u32 = 0x1600; // 5632
+const COMPARE_AND_SET_COUNT_V1_OFFSET: u32 = 0x1700; // 5888
+const COMPARE_AND_INCREMENT_COUNT_V1_OFFSET: u32 = 0x1800; // 6144
+const INITIALIZE_SHARDED_COUNTER_V1_OFFSET: u32 = 0x1900; // 6400
+const INCREMENT_COUNTER_SHARD_V1_OFFSET: u32 = 0x1A00; // 6656
+const RECONCILE_SHARDS_V1_OFFSET: u32 = 0x1B00; // 6912
+const SET_ACCESS_MODE_V1_OFFSET: u32 = 0x1C00; // 7168
+const FREEZE_COUNTER_V1_OFFSET: u32 = 0x1D00; // 7424
+const THAW_COUNTER_V1_OFFSET: u32 = 0x1E00; // 7680
+const INITIALIZE_CONFIG_V1_OFFSET: u32 = 0x1F00; // 7936
+const SET_PAUSED_V1_OFFSET: u32 = 0x2000; // 8192
+const SET_PAUSE_EXEMPTION_V1_OFFSET: u32 = 0x2100; // 8448
+const CLOSE_COUNTER_V1_OFFSET: u32 = 0x2200; // 8704
+const MIGRATE_COUNTER_V1_TO_V2_OFFSET: u32 = 0x2300; // 8960
+const SET_COOLDOWN_V1_OFFSET: u32 = 0x2400; // 9216
+const INITIALIZE_MULTISIG_V1_OFFSET: u32 = 0x2500; // 9472
+const EXECUTE_BATCH_V1_OFFSET: u32 = 0x2600; // 9728
 
 #[derive(Debug)]
 pub enum InstructionError {
@@ -27,6 +71,38 @@ pub enum InstructionError {
     DecrementCountV1(DecrementCountV1Error),
     SetCountV1(SetCountV1Error),
     ReactivateCounterV1(ReactivateCounterV1Error),
+    IncrementByV1(IncrementByV1Error),
+    DecrementByV1(DecrementByV1Error),
+    SetCountPolicyV1(SetCountPolicyV1Error),
+    ApproveDelegateV1(ApproveDelegateV1Error),
+    RevokeDelegateV1(RevokeDelegateV1Error),
+    ProposeAuthorityV1(ProposeAuthorityV1Error),
+    AcceptAuthorityV1(AcceptAuthorityV1Error),
+    InitializeIndexedCounterV1(InitializeIndexedCounterV1Error),
+    IncrementIndexedCounterV1(IncrementIndexedCounterV1Error),
+    DecrementIndexedCounterV1(DecrementIndexedCounterV1Error),
+    SetIndexedCountV1(SetIndexedCountV1Error),
+    DeactivateIndexedCounterV1(DeactivateIndexedCounterV1Error),
+    ReactivateIndexedCounterV1(ReactivateIndexedCounterV1Error),
+    InitializeSponsoredCounterV1(InitializeSponsoredCounterV1Error),
+    ReactivateSponsoredCounterV1(ReactivateSponsoredCounterV1Error),
+    GetCountV1(GetCountV1Error),
+    CompareAndSetCountV1(CompareAndSetCountV1Error),
+    CompareAndIncrementCountV1(CompareAndIncrementCountV1Error),
+    InitializeShardedCounterV1(InitializeShardedCounterV1Error),
+    IncrementCounterShardV1(IncrementCounterShardV1Error),
+    ReconcileShardsV1(ReconcileShardsV1Error),
+    SetAccessModeV1(SetAccessModeV1Error),
+    FreezeCounterV1(FreezeCounterV1Error),
+    ThawCounterV1(ThawCounterV1Error),
+    InitializeConfigV1(InitializeConfigV1Error),
+    SetPausedV1(SetPausedV1Error),
+    SetPauseExemptionV1(SetPauseExemptionV1Error),
+    CloseCounterV1(CloseCounterV1Error),
+    MigrateCounterV1ToV2(MigrateCounterV1ToV2Error),
+    SetCooldownV1(SetCooldownV1Error),
+    InitializeMultisigV1(InitializeMultisigV1Error),
+    ExecuteBatchV1(ExecuteBatchV1Error),
 }
 
 pub type InstructionResult<T> = Result<T, InstructionError>;
@@ -44,12 +120,16 @@ impl From<InstructionError> for ProgramError {
                         InitializeCounterV1Error::CounterMustBeWriteable => 0x04,
                         InitializeCounterV1Error::CounterAddressMismatch { .. } => 0x05,
                         InitializeCounterV1Error::CounterMustBeEmpty => 0x06,
-                        InitializeCounterV1Error::CounterMustHaveZeroLamports => 0x07,
+                        // 0x07 reserved (retired: CounterMustHaveZeroLamports - pre-funded counters are topped up)
                         InitializeCounterV1Error::CounterMustBeOwnedBySystemProgram => 0x08,
                         InitializeCounterV1Error::SystemProgramAddressMismatch => 0x09,
                         InitializeCounterV1Error::DeserializeError(_) => 0x0a,
                         InitializeCounterV1Error::SerializeError(_) => 0x0b,
                         InitializeCounterV1Error::SerializedSizeMismatch { .. } => 0x0c,
+                        InitializeCounterV1Error::TombstoneAddressMismatch { .. } => 0x0d,
+                        InitializeCounterV1Error::AccountDiscriminatorError(_) => 0x0e,
+                        InitializeCounterV1Error::TombstoneCounterMismatch { .. } => 0x0f,
+                        InitializeCounterV1Error::CounterClosed => 0x10,
                         InitializeCounterV1Error::ProgramError(_) => {
                             unreachable!(
                                 "ProgramError variant should be extracted before this point"
@@ -71,6 +151,18 @@ impl From<InstructionError> for ProgramError {
                         // 0x09 reserved to maintain existing error code mappings
                         // 0x0a reserved (retired: OwnerMismatch - redundant with address validation)
                         DeactivateCounterV1Error::AccountDiscriminatorError(_) => 0x0b,
+                        DeactivateCounterV1Error::AuthorityMismatch => 0x0c,
+                        DeactivateCounterV1Error::RentPayerMustBeWriteable => 0x0d,
+                        DeactivateCounterV1Error::RentPayerMismatch { .. } => 0x0e,
+                        DeactivateCounterV1Error::SerializeError(_) => 0x0f,
+                        DeactivateCounterV1Error::MultisigAddressMismatch { .. } => 0x10,
+                        DeactivateCounterV1Error::MultisigAccountDiscriminatorError(_) => 0x11,
+                        DeactivateCounterV1Error::MultisigSignerMustBeSigner => 0x12,
+                        DeactivateCounterV1Error::MultisigSignerNotMember => 0x13,
+                        DeactivateCounterV1Error::MultisigDuplicateSigner => 0x14,
+                        DeactivateCounterV1Error::MultisigThresholdNotMet { .. } => 0x15,
+                        DeactivateCounterV1Error::MultisigSignerMustBeWriteable => 0x16,
+                        DeactivateCounterV1Error::SerializedSizeMismatch { .. } => 0x17,
                         DeactivateCounterV1Error::ProgramError(_) => {
                             unreachable!(
                                 "ProgramError variant should be extracted before this point"
@@ -92,6 +184,24 @@ impl From<InstructionError> for ProgramError {
                         // 0x09 reserved (retired: OwnerMismatch - redundant with address validation)
                         IncrementCountV1Error::SerializedSizeMismatch { .. } => 0x0a,
                         IncrementCountV1Error::AccountDiscriminatorError(_) => 0x0b,
+                        IncrementCountV1Error::CountAboveMaximum => 0x0c,
+                        IncrementCountV1Error::CountBelowMinimum => 0x0d,
+                        IncrementCountV1Error::DelegateMustBeWriteable => 0x0e,
+                        IncrementCountV1Error::DelegateAddressMismatch { .. } => 0x0f,
+                        IncrementCountV1Error::DelegateAccountDiscriminatorError(_) => 0x10,
+                        IncrementCountV1Error::DelegateMismatch => 0x11,
+                        IncrementCountV1Error::DelegateExpired { .. } => 0x12,
+                        IncrementCountV1Error::DelegateAllowanceExhausted => 0x13,
+                        IncrementCountV1Error::DelegateAuthorityChanged => 0x14,
+                        IncrementCountV1Error::AuthorityMismatch => 0x15,
+                        IncrementCountV1Error::CounterFrozen => 0x16,
+                        IncrementCountV1Error::CooldownNotElapsed => 0x17,
+                        IncrementCountV1Error::MultisigAddressMismatch { .. } => 0x18,
+                        IncrementCountV1Error::MultisigAccountDiscriminatorError(_) => 0x19,
+                        IncrementCountV1Error::MultisigSignerMustBeSigner => 0x1a,
+                        IncrementCountV1Error::MultisigSignerNotMember => 0x1b,
+                        IncrementCountV1Error::MultisigDuplicateSigner => 0x1c,
+                        IncrementCountV1Error::MultisigThresholdNotMet { .. } => 0x1d,
                         IncrementCountV1Error::ProgramError(_) => {
                             unreachable!(
                                 "ProgramError variant should be extracted before this point"
@@ -113,6 +223,24 @@ impl From<InstructionError> for ProgramError {
                         // 0x09 reserved (retired: OwnerMismatch - redundant with address validation)
                         DecrementCountV1Error::SerializedSizeMismatch { .. } => 0x0a,
                         DecrementCountV1Error::AccountDiscriminatorError(_) => 0x0b,
+                        DecrementCountV1Error::CountAboveMaximum => 0x0c,
+                        DecrementCountV1Error::CountBelowMinimum => 0x0d,
+                        DecrementCountV1Error::DelegateMustBeWriteable => 0x0e,
+                        DecrementCountV1Error::DelegateAddressMismatch { .. } => 0x0f,
+                        DecrementCountV1Error::DelegateAccountDiscriminatorError(_) => 0x10,
+                        DecrementCountV1Error::DelegateMismatch => 0x11,
+                        DecrementCountV1Error::DelegateExpired { .. } => 0x12,
+                        DecrementCountV1Error::DelegateAllowanceExhausted => 0x13,
+                        DecrementCountV1Error::DelegateAuthorityChanged => 0x14,
+                        DecrementCountV1Error::AuthorityMismatch => 0x15,
+                        DecrementCountV1Error::CounterFrozen => 0x16,
+                        DecrementCountV1Error::CooldownNotElapsed => 0x17,
+                        DecrementCountV1Error::MultisigAddressMismatch { .. } => 0x18,
+                        DecrementCountV1Error::MultisigAccountDiscriminatorError(_) => 0x19,
+                        DecrementCountV1Error::MultisigSignerMustBeSigner => 0x1a,
+                        DecrementCountV1Error::MultisigSignerNotMember => 0x1b,
+                        DecrementCountV1Error::MultisigDuplicateSigner => 0x1c,
+                        DecrementCountV1Error::MultisigThresholdNotMet { .. } => 0x1d,
                         DecrementCountV1Error::ProgramError(_) => {
                             unreachable!(
                                 "ProgramError variant should be extracted before this point"
@@ -132,8 +260,19 @@ impl From<InstructionError> for ProgramError {
                         SetCountV1Error::DeserializeError(_) => 0x07,
                         SetCountV1Error::SerializeError(_) => 0x08,
                         // 0x09 reserved (retired: OwnerMismatch - redundant with address validation)
-                        SetCountV1Error::SerializedSizeMismatch { .. } => 0x0a,
+                        // 0x0a reserved (retired: SerializedSizeMismatch - counter is updated in place)
                         SetCountV1Error::AccountDiscriminatorError(_) => 0x0b,
+                        SetCountV1Error::CountAboveMaximum => 0x0c,
+                        SetCountV1Error::CountBelowMinimum => 0x0d,
+                        SetCountV1Error::AuthorityMismatch => 0x0e,
+                        SetCountV1Error::CounterFrozen => 0x0f,
+                        SetCountV1Error::MultisigAddressMismatch { .. } => 0x10,
+                        SetCountV1Error::MultisigAccountDiscriminatorError(_) => 0x11,
+                        SetCountV1Error::MultisigSignerMustBeSigner => 0x12,
+                        SetCountV1Error::MultisigSignerNotMember => 0x13,
+                        SetCountV1Error::MultisigDuplicateSigner => 0x14,
+                        SetCountV1Error::MultisigThresholdNotMet { .. } => 0x15,
+                        SetCountV1Error::CooldownNotElapsed => 0x16,
                         SetCountV1Error::ProgramError(_) => {
                             unreachable!(
                                 "ProgramError variant should be extracted before this point"
@@ -156,6 +295,14 @@ impl From<InstructionError> for ProgramError {
                         // 0x0a reserved to maintain existing error code mappings
                         ReactivateCounterV1Error::SerializedSizeMismatch { .. } => 0x0b,
                         ReactivateCounterV1Error::AccountDiscriminatorError(_) => 0x0c,
+                        ReactivateCounterV1Error::AuthorityMustBeSigner => 0x0d,
+                        ReactivateCounterV1Error::AuthorityMismatch => 0x0e,
+                        ReactivateCounterV1Error::MultisigAddressMismatch { .. } => 0x0f,
+                        ReactivateCounterV1Error::MultisigAccountDiscriminatorError(_) => 0x10,
+                        ReactivateCounterV1Error::MultisigSignerMustBeSigner => 0x11,
+                        ReactivateCounterV1Error::MultisigSignerNotMember => 0x12,
+                        ReactivateCounterV1Error::MultisigDuplicateSigner => 0x13,
+                        ReactivateCounterV1Error::MultisigThresholdNotMet { .. } => 0x14,
                         ReactivateCounterV1Error::ProgramError(_) => {
                             unreachable!(
                                 "ProgramError variant should be extracted before this point"
@@ -163,6 +310,811 @@ impl From<InstructionError> for ProgramError {
                         }
                     },
             ),
+            InstructionError::IncrementByV1(e) => ProgramError::Custom(
+                INCREMENT_BY_V1_OFFSET
+                    + match e {
+                        IncrementByV1Error::NotEnoughAccounts { .. } => 0x01,
+                        IncrementByV1Error::OwnerMustBeSigner => 0x02,
+                        IncrementByV1Error::CounterMustBeWriteable => 0x03,
+                        IncrementByV1Error::CounterAddressMismatch { .. } => 0x04,
+                        IncrementByV1Error::DeserializeError(_) => 0x05,
+                        IncrementByV1Error::SerializeError(_) => 0x06,
+                        IncrementByV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        IncrementByV1Error::AccountDiscriminatorError(_) => 0x08,
+                        IncrementByV1Error::CountAboveMaximum => 0x09,
+                        IncrementByV1Error::CountBelowMinimum => 0x0a,
+                        IncrementByV1Error::DelegateMustBeWriteable => 0x0b,
+                        IncrementByV1Error::DelegateAddressMismatch { .. } => 0x0c,
+                        IncrementByV1Error::DelegateAccountDiscriminatorError(_) => 0x0d,
+                        IncrementByV1Error::DelegateMismatch => 0x0e,
+                        IncrementByV1Error::DelegateExpired { .. } => 0x0f,
+                        IncrementByV1Error::DelegateAllowanceExhausted => 0x10,
+                        IncrementByV1Error::DelegateAuthorityChanged => 0x11,
+                        IncrementByV1Error::AuthorityMismatch => 0x12,
+                        IncrementByV1Error::CounterFrozen => 0x13,
+                        IncrementByV1Error::MultisigAddressMismatch { .. } => 0x14,
+                        IncrementByV1Error::MultisigAccountDiscriminatorError(_) => 0x15,
+                        IncrementByV1Error::MultisigSignerMustBeSigner => 0x16,
+                        IncrementByV1Error::MultisigSignerNotMember => 0x17,
+                        IncrementByV1Error::MultisigDuplicateSigner => 0x18,
+                        IncrementByV1Error::MultisigThresholdNotMet { .. } => 0x19,
+                        IncrementByV1Error::CooldownNotElapsed => 0x1a,
+                        IncrementByV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::DecrementByV1(e) => ProgramError::Custom(
+                DECREMENT_BY_V1_OFFSET
+                    + match e {
+                        DecrementByV1Error::NotEnoughAccounts { .. } => 0x01,
+                        DecrementByV1Error::OwnerMustBeSigner => 0x02,
+                        DecrementByV1Error::CounterMustBeWriteable => 0x03,
+                        DecrementByV1Error::CounterAddressMismatch { .. } => 0x04,
+                        DecrementByV1Error::DeserializeError(_) => 0x05,
+                        DecrementByV1Error::SerializeError(_) => 0x06,
+                        DecrementByV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        DecrementByV1Error::AccountDiscriminatorError(_) => 0x08,
+                        DecrementByV1Error::CountAboveMaximum => 0x09,
+                        DecrementByV1Error::CountBelowMinimum => 0x0a,
+                        DecrementByV1Error::DelegateMustBeWriteable => 0x0b,
+                        DecrementByV1Error::DelegateAddressMismatch { .. } => 0x0c,
+                        DecrementByV1Error::DelegateAccountDiscriminatorError(_) => 0x0d,
+                        DecrementByV1Error::DelegateMismatch => 0x0e,
+                        DecrementByV1Error::DelegateExpired { .. } => 0x0f,
+                        DecrementByV1Error::DelegateAllowanceExhausted => 0x10,
+                        DecrementByV1Error::DelegateAuthorityChanged => 0x11,
+                        DecrementByV1Error::AuthorityMismatch => 0x12,
+                        DecrementByV1Error::CounterFrozen => 0x13,
+                        DecrementByV1Error::MultisigAddressMismatch { .. } => 0x14,
+                        DecrementByV1Error::MultisigAccountDiscriminatorError(_) => 0x15,
+                        DecrementByV1Error::MultisigSignerMustBeSigner => 0x16,
+                        DecrementByV1Error::MultisigSignerNotMember => 0x17,
+                        DecrementByV1Error::MultisigDuplicateSigner => 0x18,
+                        DecrementByV1Error::MultisigThresholdNotMet { .. } => 0x19,
+                        DecrementByV1Error::CooldownNotElapsed => 0x1a,
+                        DecrementByV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::SetCountPolicyV1(e) => ProgramError::Custom(
+                SET_COUNT_POLICY_V1_OFFSET
+                    + match e {
+                        SetCountPolicyV1Error::NotEnoughAccounts { .. } => 0x01,
+                        SetCountPolicyV1Error::OwnerMustBeSigner => 0x02,
+                        SetCountPolicyV1Error::OwnerMustBeWriteable => 0x03,
+                        SetCountPolicyV1Error::CounterMustBeWriteable => 0x04,
+                        SetCountPolicyV1Error::CounterAddressMismatch { .. } => 0x05,
+                        SetCountPolicyV1Error::SystemProgramAddressMismatch => 0x06,
+                        SetCountPolicyV1Error::DeserializeError(_) => 0x07,
+                        SetCountPolicyV1Error::SerializeError(_) => 0x08,
+                        SetCountPolicyV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        SetCountPolicyV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        SetCountPolicyV1Error::InvalidBounds { .. } => 0x0b,
+                        SetCountPolicyV1Error::CountOutOfBounds { .. } => 0x0c,
+                        SetCountPolicyV1Error::AuthorityMismatch => 0x0d,
+                        SetCountPolicyV1Error::MultisigAddressMismatch { .. } => 0x0e,
+                        SetCountPolicyV1Error::MultisigAccountDiscriminatorError(_) => 0x0f,
+                        SetCountPolicyV1Error::MultisigSignerMustBeSigner => 0x10,
+                        SetCountPolicyV1Error::MultisigSignerNotMember => 0x11,
+                        SetCountPolicyV1Error::MultisigDuplicateSigner => 0x12,
+                        SetCountPolicyV1Error::MultisigThresholdNotMet { .. } => 0x13,
+                        SetCountPolicyV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ApproveDelegateV1(e) => ProgramError::Custom(
+                APPROVE_DELEGATE_V1_OFFSET
+                    + match e {
+                        ApproveDelegateV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ApproveDelegateV1Error::OwnerMustBeSigner => 0x02,
+                        ApproveDelegateV1Error::OwnerMustBeWriteable => 0x03,
+                        ApproveDelegateV1Error::CounterAddressMismatch { .. } => 0x04,
+                        ApproveDelegateV1Error::DelegateMustBeWriteable => 0x05,
+                        ApproveDelegateV1Error::DelegateAddressMismatch { .. } => 0x06,
+                        ApproveDelegateV1Error::SystemProgramAddressMismatch => 0x07,
+                        ApproveDelegateV1Error::DeserializeError(_) => 0x08,
+                        ApproveDelegateV1Error::SerializeError(_) => 0x09,
+                        ApproveDelegateV1Error::SerializedSizeMismatch { .. } => 0x0a,
+                        ApproveDelegateV1Error::AccountDiscriminatorError(_) => 0x0b,
+                        ApproveDelegateV1Error::DelegateAccountDiscriminatorError(_) => 0x0c,
+                        ApproveDelegateV1Error::AuthorityMismatch => 0x0d,
+                        ApproveDelegateV1Error::MultisigAddressMismatch { .. } => 0x0e,
+                        ApproveDelegateV1Error::MultisigAccountDiscriminatorError(_) => 0x0f,
+                        ApproveDelegateV1Error::MultisigSignerMustBeSigner => 0x10,
+                        ApproveDelegateV1Error::MultisigSignerNotMember => 0x11,
+                        ApproveDelegateV1Error::MultisigDuplicateSigner => 0x12,
+                        ApproveDelegateV1Error::MultisigThresholdNotMet { .. } => 0x13,
+                        ApproveDelegateV1Error::MultisigSignerMustBeWriteable => 0x14,
+                        ApproveDelegateV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::RevokeDelegateV1(e) => ProgramError::Custom(
+                REVOKE_DELEGATE_V1_OFFSET
+                    + match e {
+                        RevokeDelegateV1Error::NotEnoughAccounts { .. } => 0x01,
+                        RevokeDelegateV1Error::OwnerMustBeSigner => 0x02,
+                        RevokeDelegateV1Error::OwnerMustBeWriteable => 0x03,
+                        RevokeDelegateV1Error::CounterAddressMismatch { .. } => 0x04,
+                        RevokeDelegateV1Error::DelegateMustBeWriteable => 0x05,
+                        RevokeDelegateV1Error::DelegateAddressMismatch { .. } => 0x06,
+                        RevokeDelegateV1Error::AccountDiscriminatorError(_) => 0x07,
+                        RevokeDelegateV1Error::DeserializeError(_) => 0x08,
+                        RevokeDelegateV1Error::AuthorityMismatch => 0x09,
+                        RevokeDelegateV1Error::MultisigAddressMismatch { .. } => 0x0a,
+                        RevokeDelegateV1Error::MultisigAccountDiscriminatorError(_) => 0x0b,
+                        RevokeDelegateV1Error::MultisigSignerMustBeSigner => 0x0c,
+                        RevokeDelegateV1Error::MultisigSignerNotMember => 0x0d,
+                        RevokeDelegateV1Error::MultisigDuplicateSigner => 0x0e,
+                        RevokeDelegateV1Error::MultisigThresholdNotMet { .. } => 0x0f,
+                        RevokeDelegateV1Error::MultisigSignerMustBeWriteable => 0x10,
+                        RevokeDelegateV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ProposeAuthorityV1(e) => ProgramError::Custom(
+                PROPOSE_AUTHORITY_V1_OFFSET
+                    + match e {
+                        ProposeAuthorityV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ProposeAuthorityV1Error::AuthorityMustBeSigner => 0x02,
+                        ProposeAuthorityV1Error::AuthorityMustBeWriteable => 0x03,
+                        ProposeAuthorityV1Error::CounterMustBeWriteable => 0x04,
+                        ProposeAuthorityV1Error::CounterAddressMismatch { .. } => 0x05,
+                        ProposeAuthorityV1Error::SystemProgramAddressMismatch => 0x06,
+                        ProposeAuthorityV1Error::DeserializeError(_) => 0x07,
+                        ProposeAuthorityV1Error::SerializeError(_) => 0x08,
+                        ProposeAuthorityV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        ProposeAuthorityV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        ProposeAuthorityV1Error::AuthorityMismatch => 0x0b,
+                        ProposeAuthorityV1Error::MultisigAddressMismatch { .. } => 0x0c,
+                        ProposeAuthorityV1Error::MultisigAccountDiscriminatorError(_) => 0x0d,
+                        ProposeAuthorityV1Error::MultisigSignerMustBeSigner => 0x0e,
+                        ProposeAuthorityV1Error::MultisigSignerNotMember => 0x0f,
+                        ProposeAuthorityV1Error::MultisigDuplicateSigner => 0x10,
+                        ProposeAuthorityV1Error::MultisigThresholdNotMet { .. } => 0x11,
+                        ProposeAuthorityV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::AcceptAuthorityV1(e) => ProgramError::Custom(
+                ACCEPT_AUTHORITY_V1_OFFSET
+                    + match e {
+                        AcceptAuthorityV1Error::NotEnoughAccounts { .. } => 0x01,
+                        AcceptAuthorityV1Error::NewAuthorityMustBeSigner => 0x02,
+                        AcceptAuthorityV1Error::CounterMustBeWriteable => 0x03,
+                        AcceptAuthorityV1Error::CounterAddressMismatch { .. } => 0x04,
+                        AcceptAuthorityV1Error::DeserializeError(_) => 0x05,
+                        AcceptAuthorityV1Error::SerializeError(_) => 0x06,
+                        AcceptAuthorityV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        AcceptAuthorityV1Error::AccountDiscriminatorError(_) => 0x08,
+                        AcceptAuthorityV1Error::NoPendingAuthority => 0x09,
+                        AcceptAuthorityV1Error::PendingAuthorityMismatch => 0x0a,
+                        AcceptAuthorityV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::InitializeIndexedCounterV1(e) => ProgramError::Custom(
+                INITIALIZE_INDEXED_COUNTER_V1_OFFSET
+                    + match e {
+                        InitializeIndexedCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        InitializeIndexedCounterV1Error::PayerMustBeSigner => 0x02,
+                        InitializeIndexedCounterV1Error::PayerMustBeWriteable => 0x03,
+                        InitializeIndexedCounterV1Error::CounterMustBeWriteable => 0x04,
+                        InitializeIndexedCounterV1Error::CounterAddressMismatch { .. } => 0x05,
+                        InitializeIndexedCounterV1Error::CounterMustBeEmpty => 0x06,
+                        // 0x07 reserved (retired: CounterMustHaveZeroLamports - pre-funded counters are topped up)
+                        InitializeIndexedCounterV1Error::CounterMustBeOwnedBySystemProgram => 0x08,
+                        InitializeIndexedCounterV1Error::SystemProgramAddressMismatch => 0x09,
+                        InitializeIndexedCounterV1Error::DeserializeError(_) => 0x0a,
+                        InitializeIndexedCounterV1Error::SerializeError(_) => 0x0b,
+                        InitializeIndexedCounterV1Error::SerializedSizeMismatch { .. } => 0x0c,
+                        InitializeIndexedCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::IncrementIndexedCounterV1(e) => ProgramError::Custom(
+                INCREMENT_INDEXED_COUNTER_V1_OFFSET
+                    + match e {
+                        IncrementIndexedCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        IncrementIndexedCounterV1Error::OwnerMustBeSigner => 0x02,
+                        IncrementIndexedCounterV1Error::CounterMustBeWriteable => 0x03,
+                        IncrementIndexedCounterV1Error::CounterAddressMismatch { .. } => 0x04,
+                        IncrementIndexedCounterV1Error::DeserializeError(_) => 0x05,
+                        IncrementIndexedCounterV1Error::SerializeError(_) => 0x06,
+                        IncrementIndexedCounterV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        IncrementIndexedCounterV1Error::AccountDiscriminatorError(_) => 0x08,
+                        IncrementIndexedCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::DecrementIndexedCounterV1(e) => ProgramError::Custom(
+                DECREMENT_INDEXED_COUNTER_V1_OFFSET
+                    + match e {
+                        DecrementIndexedCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        DecrementIndexedCounterV1Error::OwnerMustBeSigner => 0x02,
+                        DecrementIndexedCounterV1Error::CounterMustBeWriteable => 0x03,
+                        DecrementIndexedCounterV1Error::CounterAddressMismatch { .. } => 0x04,
+                        DecrementIndexedCounterV1Error::DeserializeError(_) => 0x05,
+                        DecrementIndexedCounterV1Error::SerializeError(_) => 0x06,
+                        DecrementIndexedCounterV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        DecrementIndexedCounterV1Error::AccountDiscriminatorError(_) => 0x08,
+                        DecrementIndexedCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::SetIndexedCountV1(e) => ProgramError::Custom(
+                SET_INDEXED_COUNT_V1_OFFSET
+                    + match e {
+                        SetIndexedCountV1Error::NotEnoughAccounts { .. } => 0x01,
+                        SetIndexedCountV1Error::OwnerMustBeSigner => 0x02,
+                        SetIndexedCountV1Error::CounterMustBeWriteable => 0x03,
+                        SetIndexedCountV1Error::CounterAddressMismatch { .. } => 0x04,
+                        SetIndexedCountV1Error::DeserializeError(_) => 0x05,
+                        SetIndexedCountV1Error::SerializeError(_) => 0x06,
+                        SetIndexedCountV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        SetIndexedCountV1Error::AccountDiscriminatorError(_) => 0x08,
+                        SetIndexedCountV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::DeactivateIndexedCounterV1(e) => ProgramError::Custom(
+                DEACTIVATE_INDEXED_COUNTER_V1_OFFSET
+                    + match e {
+                        DeactivateIndexedCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        DeactivateIndexedCounterV1Error::OwnerMustBeSigner => 0x02,
+                        DeactivateIndexedCounterV1Error::OwnerMustBeWriteable => 0x03,
+                        DeactivateIndexedCounterV1Error::CounterMustBeWriteable => 0x04,
+                        DeactivateIndexedCounterV1Error::CounterAddressMismatch { .. } => 0x05,
+                        DeactivateIndexedCounterV1Error::DeserializeError(_) => 0x06,
+                        DeactivateIndexedCounterV1Error::AccountDiscriminatorError(_) => 0x07,
+                        DeactivateIndexedCounterV1Error::SerializeError(_) => 0x08,
+                        DeactivateIndexedCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ReactivateIndexedCounterV1(e) => ProgramError::Custom(
+                REACTIVATE_INDEXED_COUNTER_V1_OFFSET
+                    + match e {
+                        ReactivateIndexedCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ReactivateIndexedCounterV1Error::PayerMustBeSigner => 0x02,
+                        ReactivateIndexedCounterV1Error::PayerMustBeWriteable => 0x03,
+                        ReactivateIndexedCounterV1Error::CounterMustBeWriteable => 0x04,
+                        ReactivateIndexedCounterV1Error::CounterAddressMismatch { .. } => 0x05,
+                        ReactivateIndexedCounterV1Error::SystemProgramAddressMismatch => 0x06,
+                        ReactivateIndexedCounterV1Error::DeserializeError(_) => 0x07,
+                        ReactivateIndexedCounterV1Error::SerializeError(_) => 0x08,
+                        ReactivateIndexedCounterV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        ReactivateIndexedCounterV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        ReactivateIndexedCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::InitializeSponsoredCounterV1(e) => ProgramError::Custom(
+                INITIALIZE_SPONSORED_COUNTER_V1_OFFSET
+                    + match e {
+                        InitializeSponsoredCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        InitializeSponsoredCounterV1Error::PayerMustBeSigner => 0x02,
+                        InitializeSponsoredCounterV1Error::PayerMustBeWriteable => 0x03,
+                        InitializeSponsoredCounterV1Error::OwnerMustBeSigner => 0x04,
+                        InitializeSponsoredCounterV1Error::CounterMustBeWriteable => 0x05,
+                        InitializeSponsoredCounterV1Error::CounterAddressMismatch { .. } => 0x06,
+                        InitializeSponsoredCounterV1Error::CounterMustBeEmpty => 0x07,
+                        InitializeSponsoredCounterV1Error::CounterMustBeOwnedBySystemProgram => {
+                            0x08
+                        }
+                        InitializeSponsoredCounterV1Error::SystemProgramAddressMismatch => 0x09,
+                        InitializeSponsoredCounterV1Error::SerializeError(_) => 0x0a,
+                        InitializeSponsoredCounterV1Error::SerializedSizeMismatch { .. } => 0x0b,
+                        InitializeSponsoredCounterV1Error::DeserializeError(_) => 0x0c,
+                        InitializeSponsoredCounterV1Error::TombstoneAddressMismatch { .. } => 0x0d,
+                        InitializeSponsoredCounterV1Error::AccountDiscriminatorError(_) => 0x0e,
+                        InitializeSponsoredCounterV1Error::TombstoneCounterMismatch { .. } => 0x0f,
+                        InitializeSponsoredCounterV1Error::CounterClosed => 0x10,
+                        InitializeSponsoredCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ReactivateSponsoredCounterV1(e) => ProgramError::Custom(
+                REACTIVATE_SPONSORED_COUNTER_V1_OFFSET
+                    + match e {
+                        ReactivateSponsoredCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ReactivateSponsoredCounterV1Error::PayerMustBeSigner => 0x02,
+                        ReactivateSponsoredCounterV1Error::PayerMustBeWriteable => 0x03,
+                        ReactivateSponsoredCounterV1Error::OwnerMustBeSigner => 0x04,
+                        ReactivateSponsoredCounterV1Error::CounterMustBeWriteable => 0x05,
+                        ReactivateSponsoredCounterV1Error::CounterAddressMismatch { .. } => 0x06,
+                        ReactivateSponsoredCounterV1Error::SystemProgramAddressMismatch => 0x07,
+                        ReactivateSponsoredCounterV1Error::SerializeError(_) => 0x08,
+                        ReactivateSponsoredCounterV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        ReactivateSponsoredCounterV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        ReactivateSponsoredCounterV1Error::DeserializeError(_) => 0x0b,
+                        ReactivateSponsoredCounterV1Error::AuthorityMustBeSigner => 0x0c,
+                        ReactivateSponsoredCounterV1Error::AuthorityMismatch => 0x0d,
+                        ReactivateSponsoredCounterV1Error::MultisigAddressMismatch { .. } => 0x0e,
+                        ReactivateSponsoredCounterV1Error::MultisigAccountDiscriminatorError(_) => {
+                            0x0f
+                        }
+                        ReactivateSponsoredCounterV1Error::MultisigSignerMustBeSigner => 0x10,
+                        ReactivateSponsoredCounterV1Error::MultisigSignerNotMember => 0x11,
+                        ReactivateSponsoredCounterV1Error::MultisigDuplicateSigner => 0x12,
+                        ReactivateSponsoredCounterV1Error::MultisigThresholdNotMet { .. } => 0x13,
+                        ReactivateSponsoredCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::GetCountV1(e) => ProgramError::Custom(
+                GET_COUNT_V1_OFFSET
+                    + match e {
+                        GetCountV1Error::NotEnoughAccounts { .. } => 0x01,
+                        GetCountV1Error::CounterAddressMismatch { .. } => 0x02,
+                        GetCountV1Error::DeserializeError(_) => 0x03,
+                        GetCountV1Error::AccountDiscriminatorError(_) => 0x04,
+                        GetCountV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::CompareAndSetCountV1(e) => ProgramError::Custom(
+                COMPARE_AND_SET_COUNT_V1_OFFSET
+                    + match e {
+                        CompareAndSetCountV1Error::NotEnoughAccounts { .. } => 0x01,
+                        CompareAndSetCountV1Error::OwnerMustBeSigner => 0x02,
+                        CompareAndSetCountV1Error::CounterMustBeWriteable => 0x03,
+                        CompareAndSetCountV1Error::CounterAddressMismatch { .. } => 0x04,
+                        CompareAndSetCountV1Error::DeserializeError(_) => 0x05,
+                        CompareAndSetCountV1Error::SerializeError(_) => 0x06,
+                        // 0x07 reserved (retired: SerializedSizeMismatch - counter is updated in place)
+                        CompareAndSetCountV1Error::AccountDiscriminatorError(_) => 0x08,
+                        CompareAndSetCountV1Error::CountAboveMaximum => 0x09,
+                        CompareAndSetCountV1Error::CountBelowMinimum => 0x0a,
+                        CompareAndSetCountV1Error::AuthorityMismatch => 0x0b,
+                        CompareAndSetCountV1Error::CountMismatch { .. } => 0x0c,
+                        CompareAndSetCountV1Error::CounterFrozen => 0x0d,
+                        CompareAndSetCountV1Error::MultisigAddressMismatch { .. } => 0x0e,
+                        CompareAndSetCountV1Error::MultisigAccountDiscriminatorError(_) => 0x0f,
+                        CompareAndSetCountV1Error::MultisigSignerMustBeSigner => 0x10,
+                        CompareAndSetCountV1Error::MultisigSignerNotMember => 0x11,
+                        CompareAndSetCountV1Error::MultisigDuplicateSigner => 0x12,
+                        CompareAndSetCountV1Error::MultisigThresholdNotMet { .. } => 0x13,
+                        CompareAndSetCountV1Error::CooldownNotElapsed => 0x14,
+                        CompareAndSetCountV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::CompareAndIncrementCountV1(e) => ProgramError::Custom(
+                COMPARE_AND_INCREMENT_COUNT_V1_OFFSET
+                    + match e {
+                        CompareAndIncrementCountV1Error::NotEnoughAccounts { .. } => 0x01,
+                        CompareAndIncrementCountV1Error::OwnerMustBeSigner => 0x02,
+                        CompareAndIncrementCountV1Error::CounterMustBeWriteable => 0x03,
+                        CompareAndIncrementCountV1Error::CounterAddressMismatch { .. } => 0x04,
+                        CompareAndIncrementCountV1Error::DeserializeError(_) => 0x05,
+                        CompareAndIncrementCountV1Error::SerializeError(_) => 0x06,
+                        // 0x07 reserved (retired: SerializedSizeMismatch - counter is updated in place)
+                        CompareAndIncrementCountV1Error::AccountDiscriminatorError(_) => 0x08,
+                        CompareAndIncrementCountV1Error::CountAboveMaximum => 0x09,
+                        CompareAndIncrementCountV1Error::CountBelowMinimum => 0x0a,
+                        CompareAndIncrementCountV1Error::AuthorityMismatch => 0x0b,
+                        CompareAndIncrementCountV1Error::CountMismatch { .. } => 0x0c,
+                        CompareAndIncrementCountV1Error::CounterFrozen => 0x0d,
+                        CompareAndIncrementCountV1Error::MultisigAddressMismatch { .. } => 0x0e,
+                        CompareAndIncrementCountV1Error::MultisigAccountDiscriminatorError(_) => {
+                            0x0f
+                        }
+                        CompareAndIncrementCountV1Error::MultisigSignerMustBeSigner => 0x10,
+                        CompareAndIncrementCountV1Error::MultisigSignerNotMember => 0x11,
+                        CompareAndIncrementCountV1Error::MultisigDuplicateSigner => 0x12,
+                        CompareAndIncrementCountV1Error::MultisigThresholdNotMet { .. } => 0x13,
+                        CompareAndIncrementCountV1Error::CooldownNotElapsed => 0x14,
+                        CompareAndIncrementCountV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::InitializeShardedCounterV1(e) => ProgramError::Custom(
+                INITIALIZE_SHARDED_COUNTER_V1_OFFSET + match e {
+                    InitializeShardedCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                    InitializeShardedCounterV1Error::PayerMustBeSigner => 0x02,
+                    InitializeShardedCounterV1Error::PayerMustBeWriteable => 0x03,
+                    InitializeShardedCounterV1Error::ShardedCounterMustBeWriteable => 0x04,
+                    InitializeShardedCounterV1Error::ShardedCounterAddressMismatch { .. } => 0x05,
+                    InitializeShardedCounterV1Error::ShardedCounterMustBeEmpty => 0x06,
+                    InitializeShardedCounterV1Error::ShardedCounterMustBeOwnedBySystemProgram => {
+                        0x07
+                    }
+                    InitializeShardedCounterV1Error::SystemProgramAddressMismatch => 0x08,
+                    InitializeShardedCounterV1Error::TooManyShards { .. } => 0x09,
+                    InitializeShardedCounterV1Error::ShardMustBeWriteable => 0x0a,
+                    InitializeShardedCounterV1Error::ShardAddressMismatch { .. } => 0x0b,
+                    InitializeShardedCounterV1Error::ShardMustBeEmpty => 0x0c,
+                    InitializeShardedCounterV1Error::ShardMustBeOwnedBySystemProgram => 0x0d,
+                    InitializeShardedCounterV1Error::SerializeError(_) => 0x0e,
+                    InitializeShardedCounterV1Error::SerializedSizeMismatch { .. } => 0x0f,
+                    InitializeShardedCounterV1Error::ProgramError(_) => {
+                        unreachable!("ProgramError variant should be extracted before this point")
+                    }
+                },
+            ),
+            InstructionError::IncrementCounterShardV1(e) => ProgramError::Custom(
+                INCREMENT_COUNTER_SHARD_V1_OFFSET
+                    + match e {
+                        IncrementCounterShardV1Error::NotEnoughAccounts { .. } => 0x01,
+                        IncrementCounterShardV1Error::ContributorMustBeSigner => 0x02,
+                        IncrementCounterShardV1Error::ShardMustBeWriteable => 0x03,
+                        IncrementCounterShardV1Error::ShardAddressMismatch { .. } => 0x04,
+                        IncrementCounterShardV1Error::DeserializeError(_) => 0x05,
+                        IncrementCounterShardV1Error::SerializeError(_) => 0x06,
+                        IncrementCounterShardV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        IncrementCounterShardV1Error::AccountDiscriminatorError(_) => 0x08,
+                        IncrementCounterShardV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ReconcileShardsV1(e) => ProgramError::Custom(
+                RECONCILE_SHARDS_V1_OFFSET
+                    + match e {
+                        ReconcileShardsV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ReconcileShardsV1Error::OwnerMustBeSigner => 0x02,
+                        ReconcileShardsV1Error::ShardedCounterMustBeWriteable => 0x03,
+                        ReconcileShardsV1Error::ShardedCounterAddressMismatch { .. } => 0x04,
+                        ReconcileShardsV1Error::OwnerMismatch => 0x05,
+                        ReconcileShardsV1Error::TooManyShards { .. } => 0x06,
+                        ReconcileShardsV1Error::ShardMustBeWriteable => 0x07,
+                        ReconcileShardsV1Error::ShardAddressMismatch { .. } => 0x08,
+                        ReconcileShardsV1Error::ShardedCounterMismatch => 0x09,
+                        ReconcileShardsV1Error::DeserializeError(_) => 0x0a,
+                        ReconcileShardsV1Error::SerializeError(_) => 0x0b,
+                        ReconcileShardsV1Error::SerializedSizeMismatch { .. } => 0x0c,
+                        ReconcileShardsV1Error::AccountDiscriminatorError(_) => 0x0d,
+                        ReconcileShardsV1Error::ShardAccountDiscriminatorError(_) => 0x0e,
+                        ReconcileShardsV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::SetAccessModeV1(e) => ProgramError::Custom(
+                SET_ACCESS_MODE_V1_OFFSET
+                    + match e {
+                        SetAccessModeV1Error::NotEnoughAccounts { .. } => 0x01,
+                        SetAccessModeV1Error::OwnerMustBeSigner => 0x02,
+                        SetAccessModeV1Error::OwnerMustBeWriteable => 0x03,
+                        SetAccessModeV1Error::CounterMustBeWriteable => 0x04,
+                        SetAccessModeV1Error::CounterAddressMismatch { .. } => 0x05,
+                        SetAccessModeV1Error::SystemProgramAddressMismatch => 0x06,
+                        SetAccessModeV1Error::DeserializeError(_) => 0x07,
+                        SetAccessModeV1Error::SerializeError(_) => 0x08,
+                        SetAccessModeV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        SetAccessModeV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        SetAccessModeV1Error::AuthorityMismatch => 0x0b,
+                        SetAccessModeV1Error::MultisigAddressMismatch { .. } => 0x0c,
+                        SetAccessModeV1Error::MultisigAccountDiscriminatorError(_) => 0x0d,
+                        SetAccessModeV1Error::MultisigSignerMustBeSigner => 0x0e,
+                        SetAccessModeV1Error::MultisigSignerNotMember => 0x0f,
+                        SetAccessModeV1Error::MultisigDuplicateSigner => 0x10,
+                        SetAccessModeV1Error::MultisigThresholdNotMet { .. } => 0x11,
+                        SetAccessModeV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::FreezeCounterV1(e) => ProgramError::Custom(
+                FREEZE_COUNTER_V1_OFFSET
+                    + match e {
+                        FreezeCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        FreezeCounterV1Error::OwnerMustBeSigner => 0x02,
+                        FreezeCounterV1Error::OwnerMustBeWriteable => 0x03,
+                        FreezeCounterV1Error::CounterMustBeWriteable => 0x04,
+                        FreezeCounterV1Error::CounterAddressMismatch { .. } => 0x05,
+                        FreezeCounterV1Error::SystemProgramAddressMismatch => 0x06,
+                        FreezeCounterV1Error::DeserializeError(_) => 0x07,
+                        FreezeCounterV1Error::SerializeError(_) => 0x08,
+                        FreezeCounterV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        FreezeCounterV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        FreezeCounterV1Error::AuthorityMismatch => 0x0b,
+                        FreezeCounterV1Error::CounterAlreadyFrozen => 0x0c,
+                        FreezeCounterV1Error::MultisigAddressMismatch { .. } => 0x0d,
+                        FreezeCounterV1Error::MultisigAccountDiscriminatorError(_) => 0x0e,
+                        FreezeCounterV1Error::MultisigSignerMustBeSigner => 0x0f,
+                        FreezeCounterV1Error::MultisigSignerNotMember => 0x10,
+                        FreezeCounterV1Error::MultisigDuplicateSigner => 0x11,
+                        FreezeCounterV1Error::MultisigThresholdNotMet { .. } => 0x12,
+                        FreezeCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ThawCounterV1(e) => ProgramError::Custom(
+                THAW_COUNTER_V1_OFFSET
+                    + match e {
+                        ThawCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ThawCounterV1Error::OwnerMustBeSigner => 0x02,
+                        ThawCounterV1Error::CounterMustBeWriteable => 0x03,
+                        ThawCounterV1Error::CounterAddressMismatch { .. } => 0x04,
+                        ThawCounterV1Error::DeserializeError(_) => 0x05,
+                        ThawCounterV1Error::SerializeError(_) => 0x06,
+                        ThawCounterV1Error::SerializedSizeMismatch { .. } => 0x07,
+                        ThawCounterV1Error::AccountDiscriminatorError(_) => 0x08,
+                        ThawCounterV1Error::AuthorityMismatch => 0x09,
+                        ThawCounterV1Error::CounterNotFrozen => 0x0a,
+                        ThawCounterV1Error::MultisigAddressMismatch { .. } => 0x0b,
+                        ThawCounterV1Error::MultisigAccountDiscriminatorError(_) => 0x0c,
+                        ThawCounterV1Error::MultisigSignerMustBeSigner => 0x0d,
+                        ThawCounterV1Error::MultisigSignerNotMember => 0x0e,
+                        ThawCounterV1Error::MultisigDuplicateSigner => 0x0f,
+                        ThawCounterV1Error::MultisigThresholdNotMet { .. } => 0x10,
+                        ThawCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::InitializeConfigV1(e) => ProgramError::Custom(
+                INITIALIZE_CONFIG_V1_OFFSET
+                    + match e {
+                        InitializeConfigV1Error::NotEnoughAccounts { .. } => 0x01,
+                        InitializeConfigV1Error::AuthorityMustBeSigner => 0x02,
+                        InitializeConfigV1Error::AuthorityMustBeWriteable => 0x03,
+                        InitializeConfigV1Error::ConfigMustBeWriteable => 0x04,
+                        InitializeConfigV1Error::ConfigAddressMismatch { .. } => 0x05,
+                        InitializeConfigV1Error::ConfigMustBeEmpty => 0x06,
+                        InitializeConfigV1Error::ConfigMustBeOwnedBySystemProgram => 0x07,
+                        InitializeConfigV1Error::ProgramDataAddressMismatch { .. } => 0x08,
+                        InitializeConfigV1Error::ProgramDataMustBeOwnedByLoader => 0x09,
+                        InitializeConfigV1Error::InvalidProgramData => 0x0a,
+                        InitializeConfigV1Error::UpgradeAuthorityMismatch => 0x0b,
+                        InitializeConfigV1Error::SystemProgramAddressMismatch => 0x0c,
+                        InitializeConfigV1Error::DeserializeError(_) => 0x0d,
+                        InitializeConfigV1Error::SerializeError(_) => 0x0e,
+                        InitializeConfigV1Error::SerializedSizeMismatch { .. } => 0x0f,
+                        InitializeConfigV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::SetPausedV1(e) => ProgramError::Custom(
+                SET_PAUSED_V1_OFFSET
+                    + match e {
+                        SetPausedV1Error::NotEnoughAccounts { .. } => 0x01,
+                        SetPausedV1Error::AdminMustBeSigner => 0x02,
+                        SetPausedV1Error::ConfigMustBeWriteable => 0x03,
+                        SetPausedV1Error::ConfigMustBeOwnedByProgram => 0x04,
+                        SetPausedV1Error::AccountDiscriminatorError(_) => 0x05,
+                        SetPausedV1Error::DeserializeError(_) => 0x06,
+                        SetPausedV1Error::AdminMismatch => 0x07,
+                        SetPausedV1Error::SerializeError(_) => 0x08,
+                        SetPausedV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        SetPausedV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::SetPauseExemptionV1(e) => ProgramError::Custom(
+                SET_PAUSE_EXEMPTION_V1_OFFSET
+                    + match e {
+                        SetPauseExemptionV1Error::NotEnoughAccounts { .. } => 0x01,
+                        SetPauseExemptionV1Error::AdminMustBeSigner => 0x02,
+                        SetPauseExemptionV1Error::ConfigMustBeWriteable => 0x03,
+                        SetPauseExemptionV1Error::ConfigMustBeOwnedByProgram => 0x04,
+                        SetPauseExemptionV1Error::AccountDiscriminatorError(_) => 0x05,
+                        SetPauseExemptionV1Error::DeserializeError(_) => 0x06,
+                        SetPauseExemptionV1Error::AdminMismatch => 0x07,
+                        SetPauseExemptionV1Error::SerializeError(_) => 0x08,
+                        SetPauseExemptionV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        SetPauseExemptionV1Error::InstructionNotPauseChecked => 0x0a,
+                        SetPauseExemptionV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::CloseCounterV1(e) => ProgramError::Custom(
+                CLOSE_COUNTER_V1_OFFSET
+                    + match e {
+                        CloseCounterV1Error::NotEnoughAccounts { .. } => 0x01,
+                        CloseCounterV1Error::OwnerMustBeSigner => 0x02,
+                        CloseCounterV1Error::OwnerMustBeWriteable => 0x03,
+                        CloseCounterV1Error::CounterMustBeWriteable => 0x04,
+                        CloseCounterV1Error::CounterAddressMismatch { .. } => 0x05,
+                        CloseCounterV1Error::DeserializeError(_) => 0x06,
+                        CloseCounterV1Error::AccountDiscriminatorError(_) => 0x07,
+                        CloseCounterV1Error::AuthorityMismatch => 0x08,
+                        CloseCounterV1Error::DestinationMustBeWriteable => 0x09,
+                        CloseCounterV1Error::DestinationMustNotBeCounter => 0x0a,
+                        CloseCounterV1Error::RentPayerMismatch { .. } => 0x0b,
+                        CloseCounterV1Error::TombstoneMustBeWriteable => 0x0c,
+                        CloseCounterV1Error::TombstoneAddressMismatch { .. } => 0x0d,
+                        CloseCounterV1Error::TombstoneCounterMismatch { .. } => 0x0e,
+                        CloseCounterV1Error::SystemProgramAddressMismatch => 0x0f,
+                        CloseCounterV1Error::SerializeError(_) => 0x10,
+                        CloseCounterV1Error::MultisigAddressMismatch { .. } => 0x11,
+                        CloseCounterV1Error::MultisigAccountDiscriminatorError(_) => 0x12,
+                        CloseCounterV1Error::MultisigSignerMustBeSigner => 0x13,
+                        CloseCounterV1Error::MultisigSignerNotMember => 0x14,
+                        CloseCounterV1Error::MultisigDuplicateSigner => 0x15,
+                        CloseCounterV1Error::MultisigThresholdNotMet { .. } => 0x16,
+                        CloseCounterV1Error::MultisigSignerMustBeWriteable => 0x17,
+                        CloseCounterV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::MigrateCounterV1ToV2(e) => ProgramError::Custom(
+                MIGRATE_COUNTER_V1_TO_V2_OFFSET
+                    + match e {
+                        MigrateCounterV1ToV2Error::NotEnoughAccounts { .. } => 0x01,
+                        MigrateCounterV1ToV2Error::PayerMustBeSigner => 0x02,
+                        MigrateCounterV1ToV2Error::PayerMustBeWriteable => 0x03,
+                        MigrateCounterV1ToV2Error::OwnerMustBeSigner => 0x04,
+                        MigrateCounterV1ToV2Error::CounterMustBeWriteable => 0x05,
+                        MigrateCounterV1ToV2Error::CounterAddressMismatch { .. } => 0x06,
+                        MigrateCounterV1ToV2Error::DeserializeError(_) => 0x07,
+                        MigrateCounterV1ToV2Error::AccountDiscriminatorError(_) => 0x08,
+                        MigrateCounterV1ToV2Error::AuthorityMismatch => 0x09,
+                        MigrateCounterV1ToV2Error::CounterAlreadyMigrated => 0x0a,
+                        MigrateCounterV1ToV2Error::SystemProgramAddressMismatch => 0x0b,
+                        MigrateCounterV1ToV2Error::SerializeError(_) => 0x0c,
+                        MigrateCounterV1ToV2Error::SerializedSizeMismatch { .. } => 0x0d,
+                        MigrateCounterV1ToV2Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::SetCooldownV1(e) => ProgramError::Custom(
+                SET_COOLDOWN_V1_OFFSET
+                    + match e {
+                        SetCooldownV1Error::NotEnoughAccounts { .. } => 0x01,
+                        SetCooldownV1Error::OwnerMustBeSigner => 0x02,
+                        SetCooldownV1Error::OwnerMustBeWriteable => 0x03,
+                        SetCooldownV1Error::CounterMustBeWriteable => 0x04,
+                        SetCooldownV1Error::CounterAddressMismatch { .. } => 0x05,
+                        SetCooldownV1Error::SystemProgramAddressMismatch => 0x06,
+                        SetCooldownV1Error::DeserializeError(_) => 0x07,
+                        SetCooldownV1Error::SerializeError(_) => 0x08,
+                        SetCooldownV1Error::SerializedSizeMismatch { .. } => 0x09,
+                        SetCooldownV1Error::AccountDiscriminatorError(_) => 0x0a,
+                        SetCooldownV1Error::AuthorityMismatch => 0x0b,
+                        SetCooldownV1Error::MultisigAddressMismatch { .. } => 0x0c,
+                        SetCooldownV1Error::MultisigAccountDiscriminatorError(_) => 0x0d,
+                        SetCooldownV1Error::MultisigSignerMustBeSigner => 0x0e,
+                        SetCooldownV1Error::MultisigSignerNotMember => 0x0f,
+                        SetCooldownV1Error::MultisigDuplicateSigner => 0x10,
+                        SetCooldownV1Error::MultisigThresholdNotMet { .. } => 0x11,
+                        SetCooldownV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::InitializeMultisigV1(e) => ProgramError::Custom(
+                INITIALIZE_MULTISIG_V1_OFFSET
+                    + match e {
+                        InitializeMultisigV1Error::NotEnoughAccounts { .. } => 0x01,
+                        InitializeMultisigV1Error::AuthorityMustBeSigner => 0x02,
+                        InitializeMultisigV1Error::AuthorityMustBeWriteable => 0x03,
+                        InitializeMultisigV1Error::CounterMustBeWriteable => 0x04,
+                        InitializeMultisigV1Error::CounterAddressMismatch { .. } => 0x05,
+                        InitializeMultisigV1Error::MultisigMustBeWriteable => 0x06,
+                        InitializeMultisigV1Error::MultisigAddressMismatch { .. } => 0x07,
+                        InitializeMultisigV1Error::SystemProgramAddressMismatch => 0x08,
+                        InitializeMultisigV1Error::DeserializeError(_) => 0x09,
+                        InitializeMultisigV1Error::SerializeError(_) => 0x0a,
+                        InitializeMultisigV1Error::SerializedSizeMismatch { .. } => 0x0b,
+                        InitializeMultisigV1Error::AccountDiscriminatorError(_) => 0x0c,
+                        InitializeMultisigV1Error::MultisigAccountDiscriminatorError(_) => 0x0d,
+                        InitializeMultisigV1Error::AuthorityMismatch => 0x0e,
+                        InitializeMultisigV1Error::TooManyMembers { .. } => 0x0f,
+                        InitializeMultisigV1Error::DuplicateMember => 0x10,
+                        InitializeMultisigV1Error::InvalidThreshold { .. } => 0x11,
+                        InitializeMultisigV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
+            InstructionError::ExecuteBatchV1(e) => ProgramError::Custom(
+                EXECUTE_BATCH_V1_OFFSET
+                    + match e {
+                        ExecuteBatchV1Error::NotEnoughAccounts { .. } => 0x01,
+                        ExecuteBatchV1Error::OwnerMustBeSigner => 0x02,
+                        ExecuteBatchV1Error::CounterMustBeWriteable => 0x03,
+                        ExecuteBatchV1Error::CounterAddressMismatch { .. } => 0x04,
+                        ExecuteBatchV1Error::DeserializeError(_) => 0x05,
+                        ExecuteBatchV1Error::SerializeError(_) => 0x06,
+                        ExecuteBatchV1Error::AccountDiscriminatorError(_) => 0x07,
+                        ExecuteBatchV1Error::CountAboveMaximum => 0x08,
+                        ExecuteBatchV1Error::CountBelowMinimum => 0x09,
+                        ExecuteBatchV1Error::AuthorityMismatch => 0x0a,
+                        ExecuteBatchV1Error::CounterFrozen => 0x0b,
+                        ExecuteBatchV1Error::CooldownNotElapsed => 0x0c,
+                        ExecuteBatchV1Error::EmptyBatch => 0x0d,
+                        ExecuteBatchV1Error::TooManyOperations { .. } => 0x0e,
+                        ExecuteBatchV1Error::OperationsLengthMismatch { .. } => 0x0f,
+                        ExecuteBatchV1Error::MultisigAddressMismatch { .. } => 0x10,
+                        ExecuteBatchV1Error::MultisigAccountDiscriminatorError(_) => 0x11,
+                        ExecuteBatchV1Error::MultisigSignerMustBeSigner => 0x12,
+                        ExecuteBatchV1Error::MultisigSignerNotMember => 0x13,
+                        ExecuteBatchV1Error::MultisigDuplicateSigner => 0x14,
+                        ExecuteBatchV1Error::MultisigThresholdNotMet { .. } => 0x15,
+                        ExecuteBatchV1Error::ProgramError(_) => {
+                            unreachable!(
+                                "ProgramError variant should be extracted before this point"
+                            )
+                        }
+                    },
+            ),
         }
     }
 }
@@ -179,6 +1131,27 @@ impl From<InstructionDiscriminatorError> for ProgramError {
     }
 }
 
+impl From<PauseCheckError> for ProgramError {
+    fn from(e: PauseCheckError) -> Self {
+        match e {
+            PauseCheckError::ProgramError(pe) => pe,
+            e => ProgramError::Custom(
+                PAUSE_CHECK_ERROR_OFFSET
+                    + match e {
+                        PauseCheckError::MissingConfigAccount => 0x01,
+                        PauseCheckError::ConfigAddressMismatch { .. } => 0x02,
+                        PauseCheckError::AccountDiscriminatorError(_) => 0x03,
+                        PauseCheckError::DeserializeError(_) => 0x04,
+                        PauseCheckError::ProgramPaused => 0x05,
+                        PauseCheckError::ProgramError(_) => {
+                            unreachable!("ProgramError variant is returned as is above")
+                        }
+                    },
+            ),
+        }
+    }
+}
+
 impl From<DeactivateCounterV1Error> for InstructionError {
     fn from(err: DeactivateCounterV1Error) -> Self {
         match err {
@@ -233,29 +1206,357 @@ impl From<ReactivateCounterV1Error> for InstructionError {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use {
-        super::*,
-        crate::{AccountDiscriminator, AccountDiscriminatorError},
-        wincode::{ReadError, WriteError},
-    };
-
-    #[test]
-    fn test_instruction_discriminator_error_codes() {
-        let test_cases = [
-            (0x001, InstructionDiscriminatorError::Missing),
-            (0x002, InstructionDiscriminatorError::Invalid(u8::MAX)),
-        ];
+impl From<IncrementByV1Error> for InstructionError {
+    fn from(err: IncrementByV1Error) -> Self {
+        match err {
+            IncrementByV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::IncrementByV1(err),
+        }
+    }
+}
 
-        for (expected_code, error) in test_cases {
-            let program_error: ProgramError = error.into();
-            assert!(matches!(program_error, ProgramError::Custom(code) if code == expected_code));
+impl From<DecrementByV1Error> for InstructionError {
+    fn from(err: DecrementByV1Error) -> Self {
+        match err {
+            DecrementByV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::DecrementByV1(err),
         }
     }
+}
 
-    #[test]
-    fn test_instruction_error_codes() {
+impl From<SetCountPolicyV1Error> for InstructionError {
+    fn from(err: SetCountPolicyV1Error) -> Self {
+        match err {
+            SetCountPolicyV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::SetCountPolicyV1(err),
+        }
+    }
+}
+
+impl From<ApproveDelegateV1Error> for InstructionError {
+    fn from(err: ApproveDelegateV1Error) -> Self {
+        match err {
+            ApproveDelegateV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::ApproveDelegateV1(err),
+        }
+    }
+}
+
+impl From<RevokeDelegateV1Error> for InstructionError {
+    fn from(err: RevokeDelegateV1Error) -> Self {
+        match err {
+            RevokeDelegateV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::RevokeDelegateV1(err),
+        }
+    }
+}
+
+impl From<ProposeAuthorityV1Error> for InstructionError {
+    fn from(err: ProposeAuthorityV1Error) -> Self {
+        match err {
+            ProposeAuthorityV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::ProposeAuthorityV1(err),
+        }
+    }
+}
+
+impl From<AcceptAuthorityV1Error> for InstructionError {
+    fn from(err: AcceptAuthorityV1Error) -> Self {
+        match err {
+            AcceptAuthorityV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::AcceptAuthorityV1(err),
+        }
+    }
+}
+
+impl From<InitializeIndexedCounterV1Error> for InstructionError {
+    fn from(err: InitializeIndexedCounterV1Error) -> Self {
+        match err {
+            InitializeIndexedCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::InitializeIndexedCounterV1(err),
+        }
+    }
+}
+
+impl From<IncrementIndexedCounterV1Error> for InstructionError {
+    fn from(err: IncrementIndexedCounterV1Error) -> Self {
+        match err {
+            IncrementIndexedCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::IncrementIndexedCounterV1(err),
+        }
+    }
+}
+
+impl From<DecrementIndexedCounterV1Error> for InstructionError {
+    fn from(err: DecrementIndexedCounterV1Error) -> Self {
+        match err {
+            DecrementIndexedCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::DecrementIndexedCounterV1(err),
+        }
+    }
+}
+
+impl From<SetIndexedCountV1Error> for InstructionError {
+    fn from(err: SetIndexedCountV1Error) -> Self {
+        match err {
+            SetIndexedCountV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::SetIndexedCountV1(err),
+        }
+    }
+}
+
+impl From<DeactivateIndexedCounterV1Error> for InstructionError {
+    fn from(err: DeactivateIndexedCounterV1Error) -> Self {
+        match err {
+            DeactivateIndexedCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::DeactivateIndexedCounterV1(err),
+        }
+    }
+}
+
+impl From<ReactivateIndexedCounterV1Error> for InstructionError {
+    fn from(err: ReactivateIndexedCounterV1Error) -> Self {
+        match err {
+            ReactivateIndexedCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::ReactivateIndexedCounterV1(err),
+        }
+    }
+}
+
+impl From<InitializeSponsoredCounterV1Error> for InstructionError {
+    fn from(err: InitializeSponsoredCounterV1Error) -> Self {
+        match err {
+            InitializeSponsoredCounterV1Error::ProgramError(pe) => {
+                InstructionError::ProgramError(pe)
+            }
+            _ => InstructionError::InitializeSponsoredCounterV1(err),
+        }
+    }
+}
+
+impl From<ReactivateSponsoredCounterV1Error> for InstructionError {
+    fn from(err: ReactivateSponsoredCounterV1Error) -> Self {
+        match err {
+            ReactivateSponsoredCounterV1Error::ProgramError(pe) => {
+                InstructionError::ProgramError(pe)
+            }
+            _ => InstructionError::ReactivateSponsoredCounterV1(err),
+        }
+    }
+}
+
+impl From<GetCountV1Error> for InstructionError {
+    fn from(err: GetCountV1Error) -> Self {
+        match err {
+            GetCountV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::GetCountV1(err),
+        }
+    }
+}
+
+impl From<CompareAndSetCountV1Error> for InstructionError {
+    fn from(err: CompareAndSetCountV1Error) -> Self {
+        match err {
+            CompareAndSetCountV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::CompareAndSetCountV1(err),
+        }
+    }
+}
+
+impl From<CompareAndIncrementCountV1Error> for InstructionError {
+    fn from(err: CompareAndIncrementCountV1Error) -> Self {
+        match err {
+            CompareAndIncrementCountV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::CompareAndIncrementCountV1(err),
+        }
+    }
+}
+
+impl From<InitializeShardedCounterV1Error> for InstructionError {
+    fn from(err: InitializeShardedCounterV1Error) -> Self {
+        match err {
+            InitializeShardedCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::InitializeShardedCounterV1(err),
+        }
+    }
+}
+
+impl From<IncrementCounterShardV1Error> for InstructionError {
+    fn from(err: IncrementCounterShardV1Error) -> Self {
+        match err {
+            IncrementCounterShardV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::IncrementCounterShardV1(err),
+        }
+    }
+}
+
+impl From<ReconcileShardsV1Error> for InstructionError {
+    fn from(err: ReconcileShardsV1Error) -> Self {
+        match err {
+            ReconcileShardsV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::ReconcileShardsV1(err),
+        }
+    }
+}
+
+impl From<SetAccessModeV1Error> for InstructionError {
+    fn from(err: SetAccessModeV1Error) -> Self {
+        match err {
+            SetAccessModeV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::SetAccessModeV1(err),
+        }
+    }
+}
+
+impl From<FreezeCounterV1Error> for InstructionError {
+    fn from(err: FreezeCounterV1Error) -> Self {
+        match err {
+            FreezeCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::FreezeCounterV1(err),
+        }
+    }
+}
+
+impl From<ThawCounterV1Error> for InstructionError {
+    fn from(err: ThawCounterV1Error) -> Self {
+        match err {
+            ThawCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::ThawCounterV1(err),
+        }
+    }
+}
+
+impl From<InitializeConfigV1Error> for InstructionError {
+    fn from(err: InitializeConfigV1Error) -> Self {
+        match err {
+            InitializeConfigV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::InitializeConfigV1(err),
+        }
+    }
+}
+
+impl From<SetPausedV1Error> for InstructionError {
+    fn from(err: SetPausedV1Error) -> Self {
+        match err {
+            SetPausedV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::SetPausedV1(err),
+        }
+    }
+}
+
+impl From<SetPauseExemptionV1Error> for InstructionError {
+    fn from(err: SetPauseExemptionV1Error) -> Self {
+        match err {
+            SetPauseExemptionV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::SetPauseExemptionV1(err),
+        }
+    }
+}
+
+impl From<CloseCounterV1Error> for InstructionError {
+    fn from(err: CloseCounterV1Error) -> Self {
+        match err {
+            CloseCounterV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::CloseCounterV1(err),
+        }
+    }
+}
+
+impl From<MigrateCounterV1ToV2Error> for InstructionError {
+    fn from(err: MigrateCounterV1ToV2Error) -> Self {
+        match err {
+            MigrateCounterV1ToV2Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::MigrateCounterV1ToV2(err),
+        }
+    }
+}
+
+impl From<SetCooldownV1Error> for InstructionError {
+    fn from(err: SetCooldownV1Error) -> Self {
+        match err {
+            SetCooldownV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::SetCooldownV1(err),
+        }
+    }
+}
+
+impl From<InitializeMultisigV1Error> for InstructionError {
+    fn from(err: InitializeMultisigV1Error) -> Self {
+        match err {
+            InitializeMultisigV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::InitializeMultisigV1(err),
+        }
+    }
+}
+
+impl From<ExecuteBatchV1Error> for InstructionError {
+    fn from(err: ExecuteBatchV1Error) -> Self {
+        match err {
+            ExecuteBatchV1Error::ProgramError(pe) => InstructionError::ProgramError(pe),
+            _ => InstructionError::ExecuteBatchV1(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{AccountDiscriminator, AccountDiscriminatorError},
+        wincode::{ReadError, WriteError},
+    };
+
+    #[test]
+    fn test_instruction_discriminator_error_codes() {
+        let test_cases = [
+            (0x001, InstructionDiscriminatorError::Missing),
+            (0x002, InstructionDiscriminatorError::Invalid(u8::MAX)),
+        ];
+
+        for (expected_code, error) in test_cases {
+            let program_error: ProgramError = error.into();
+            assert!(matches!(program_error, ProgramError::Custom(code) if code == expected_code));
+        }
+    }
+
+    #[test]
+    fn test_pause_check_error_codes() {
+        let test_cases = [
+            // 0x080 reserved
+            (0x081, PauseCheckError::MissingConfigAccount),
+            (
+                0x082,
+                PauseCheckError::ConfigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                },
+            ),
+            (
+                0x083,
+                PauseCheckError::AccountDiscriminatorError(AccountDiscriminatorError::Missing),
+            ),
+            (
+                0x084,
+                PauseCheckError::DeserializeError(ReadError::Custom("test")),
+            ),
+            (0x085, PauseCheckError::ProgramPaused),
+        ];
+
+        for (expected_code, error) in test_cases {
+            let program_error: ProgramError = error.into();
+            assert!(
+                matches!(program_error, ProgramError::Custom(code) if code == expected_code),
+                "Expected error code {expected_code:#x}, got {program_error:?}"
+            );
+        }
+
+        let program_error: ProgramError =
+            PauseCheckError::ProgramError(ProgramError::InvalidSeeds).into();
+        assert_eq!(program_error, ProgramError::InvalidSeeds);
+    }
+
+    #[test]
+    fn test_instruction_error_codes() {
         let test_cases = [
             // ==============================================================================
             // InitializeCounterV1 (0x100 range)
@@ -299,12 +1600,7 @@ mod tests {
                 0x106,
                 InstructionError::InitializeCounterV1(InitializeCounterV1Error::CounterMustBeEmpty),
             ),
-            (
-                0x107,
-                InstructionError::InitializeCounterV1(
-                    InitializeCounterV1Error::CounterMustHaveZeroLamports,
-                ),
-            ),
+            // 0x107 reserved (retired: CounterMustHaveZeroLamports - pre-funded counters are topped up)
             (
                 0x108,
                 InstructionError::InitializeCounterV1(
@@ -338,6 +1634,36 @@ mod tests {
                     },
                 ),
             ),
+            (
+                0x10d,
+                InstructionError::InitializeCounterV1(
+                    InitializeCounterV1Error::TombstoneAddressMismatch {
+                        expected: [1u8; 32],
+                        observed: [2u8; 32],
+                    },
+                ),
+            ),
+            (
+                0x10e,
+                InstructionError::InitializeCounterV1(
+                    InitializeCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x10f,
+                InstructionError::InitializeCounterV1(
+                    InitializeCounterV1Error::TombstoneCounterMismatch {
+                        expected: [1u8; 32],
+                        observed: [2u8; 32],
+                    },
+                ),
+            ),
+            (
+                0x110,
+                InstructionError::InitializeCounterV1(InitializeCounterV1Error::CounterClosed),
+            ),
             // ==============================================================================
             // DeactivateCounterV1 (0x200 range)
             // ==============================================================================
@@ -368,276 +1694,3801 @@ mod tests {
                 ),
             ),
             (
-                0x205,
-                InstructionError::DeactivateCounterV1(
-                    DeactivateCounterV1Error::CounterAddressMismatch {
-                        expected: Default::default(),
-                        observed: Default::default(),
-                    },
+                0x205,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            // 0x206 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
+            // 0x207 reserved (retired)
+            (
+                0x208,
+                InstructionError::DeactivateCounterV1(DeactivateCounterV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            // 0x209 reserved (retired)
+            // 0x20a reserved (retired: OwnerMismatch - redundant with address validation)
+            (
+                0x20b,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x20c,
+                InstructionError::DeactivateCounterV1(DeactivateCounterV1Error::AuthorityMismatch),
+            ),
+            (
+                0x20d,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::RentPayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x20e,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::RentPayerMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x20f,
+                InstructionError::DeactivateCounterV1(DeactivateCounterV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x210,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x211,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x212,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x213,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0x214,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0x215,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x216,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::MultisigSignerMustBeWriteable,
+                ),
+            ),
+            (
+                0x217,
+                InstructionError::DeactivateCounterV1(
+                    DeactivateCounterV1Error::SerializedSizeMismatch {
+                        expected: 98,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // IncrementCountV1 (0x300 range)
+            // ==============================================================================
+            // 0x300 reserved
+            (
+                0x301,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x302,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::OwnerMustBeSigner),
+            ),
+            // 0x303 reserved (retired)
+            (
+                0x304,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x305,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            // 0x306 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
+            (
+                0x307,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x308,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            // 0x309 reserved (retired: OwnerMismatch - redundant with address validation)
+            (
+                0x30a,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::SerializedSizeMismatch {
+                    expected: 100,
+                    observed: 50,
+                }),
+            ),
+            (
+                0x30b,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::DiscriminatorMismatch {
+                            expected: AccountDiscriminator::CounterV1Account,
+                            observed: AccountDiscriminator::DeactivatedAccount,
+                        },
+                    ),
+                ),
+            ),
+            (
+                0x30c,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::CountAboveMaximum),
+            ),
+            (
+                0x30d,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::CountBelowMinimum),
+            ),
+            (
+                0x30e,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::DelegateMustBeWriteable),
+            ),
+            (
+                0x30f,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::DelegateAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x310,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::DelegateAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x311,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::DelegateMismatch),
+            ),
+            (
+                0x312,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::DelegateExpired {
+                    expiry_slot: 1,
+                    current_slot: 2,
+                }),
+            ),
+            (
+                0x313,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::DelegateAllowanceExhausted,
+                ),
+            ),
+            (
+                0x314,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::DelegateAuthorityChanged),
+            ),
+            (
+                0x315,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::AuthorityMismatch),
+            ),
+            (
+                0x316,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::CounterFrozen),
+            ),
+            (
+                0x317,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::CooldownNotElapsed),
+            ),
+            (
+                0x318,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x319,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x31a,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x31b,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x31c,
+                InstructionError::IncrementCountV1(IncrementCountV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x31d,
+                InstructionError::IncrementCountV1(
+                    IncrementCountV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // DecrementCountV1 (0x400 range)
+            // ==============================================================================
+            // 0x400 reserved
+            (
+                0x401,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x402,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::OwnerMustBeSigner),
+            ),
+            // 0x403 reserved (retired)
+            (
+                0x404,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x405,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            // 0x406 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
+            (
+                0x407,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x408,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            // 0x409 reserved (retired: OwnerMismatch - redundant with address validation)
+            (
+                0x40a,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::SerializedSizeMismatch {
+                    expected: 100,
+                    observed: 50,
+                }),
+            ),
+            (
+                0x40b,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Invalid(0),
+                    ),
+                ),
+            ),
+            (
+                0x40c,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::CountAboveMaximum),
+            ),
+            (
+                0x40d,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::CountBelowMinimum),
+            ),
+            (
+                0x40e,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::DelegateMustBeWriteable),
+            ),
+            (
+                0x40f,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::DelegateAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x410,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::DelegateAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x411,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::DelegateMismatch),
+            ),
+            (
+                0x412,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::DelegateExpired {
+                    expiry_slot: 1,
+                    current_slot: 2,
+                }),
+            ),
+            (
+                0x413,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::DelegateAllowanceExhausted,
+                ),
+            ),
+            (
+                0x414,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::DelegateAuthorityChanged),
+            ),
+            (
+                0x415,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::AuthorityMismatch),
+            ),
+            (
+                0x416,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::CounterFrozen),
+            ),
+            (
+                0x417,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::CooldownNotElapsed),
+            ),
+            (
+                0x418,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x419,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x41a,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x41b,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x41c,
+                InstructionError::DecrementCountV1(DecrementCountV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x41d,
+                InstructionError::DecrementCountV1(
+                    DecrementCountV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // SetCountV1 (0x500 range)
+            // ==============================================================================
+            // 0x500 reserved
+            (
+                0x501,
+                InstructionError::SetCountV1(SetCountV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x502,
+                InstructionError::SetCountV1(SetCountV1Error::OwnerMustBeSigner),
+            ),
+            // 0x503 reserved (retired)
+            (
+                0x504,
+                InstructionError::SetCountV1(SetCountV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x505,
+                InstructionError::SetCountV1(SetCountV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            // 0x506 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
+            (
+                0x507,
+                InstructionError::SetCountV1(SetCountV1Error::DeserializeError(ReadError::Custom(
+                    "test",
+                ))),
+            ),
+            (
+                0x508,
+                InstructionError::SetCountV1(SetCountV1Error::SerializeError(WriteError::Custom(
+                    "test",
+                ))),
+            ),
+            // 0x509 reserved (retired: OwnerMismatch - redundant with address validation)
+            // 0x50a reserved (retired: SerializedSizeMismatch - counter is updated in place)
+            (
+                0x50b,
+                InstructionError::SetCountV1(SetCountV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x50c,
+                InstructionError::SetCountV1(SetCountV1Error::CountAboveMaximum),
+            ),
+            (
+                0x50d,
+                InstructionError::SetCountV1(SetCountV1Error::CountBelowMinimum),
+            ),
+            (
+                0x50e,
+                InstructionError::SetCountV1(SetCountV1Error::AuthorityMismatch),
+            ),
+            (
+                0x50f,
+                InstructionError::SetCountV1(SetCountV1Error::CounterFrozen),
+            ),
+            (
+                0x510,
+                InstructionError::SetCountV1(SetCountV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x511,
+                InstructionError::SetCountV1(SetCountV1Error::MultisigAccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x512,
+                InstructionError::SetCountV1(SetCountV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x513,
+                InstructionError::SetCountV1(SetCountV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x514,
+                InstructionError::SetCountV1(SetCountV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x515,
+                InstructionError::SetCountV1(SetCountV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x516,
+                InstructionError::SetCountV1(SetCountV1Error::CooldownNotElapsed),
+            ),
+            // ==============================================================================
+            // ReactivateCounterV1 (0x600 range)
+            // ==============================================================================
+            // 0x600 reserved
+            (
+                0x601,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::NotEnoughAccounts {
+                        expected: 3,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0x602,
+                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::PayerMustBeSigner),
+            ),
+            (
+                0x603,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x604,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x605,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            // 0x606 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
+            (
+                0x607,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x608,
+                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x609,
+                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            // 0x60a reserved to maintain existing error code mappings
+            (
+                0x60b,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::SerializedSizeMismatch {
+                        expected: 100,
+                        observed: 50,
+                    },
+                ),
+            ),
+            (
+                0x60c,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x60d,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::AuthorityMustBeSigner,
+                ),
+            ),
+            (
+                0x60e,
+                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::AuthorityMismatch),
+            ),
+            (
+                0x60f,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x610,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x611,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x612,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0x613,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0x614,
+                InstructionError::ReactivateCounterV1(
+                    ReactivateCounterV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // IncrementByV1 (0x700 range)
+            // ==============================================================================
+            // 0x700 reserved
+            (
+                0x701,
+                InstructionError::IncrementByV1(IncrementByV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x702,
+                InstructionError::IncrementByV1(IncrementByV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x703,
+                InstructionError::IncrementByV1(IncrementByV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x704,
+                InstructionError::IncrementByV1(IncrementByV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x705,
+                InstructionError::IncrementByV1(IncrementByV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x706,
+                InstructionError::IncrementByV1(IncrementByV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x707,
+                InstructionError::IncrementByV1(IncrementByV1Error::SerializedSizeMismatch {
+                    expected: 100,
+                    observed: 50,
+                }),
+            ),
+            (
+                0x708,
+                InstructionError::IncrementByV1(IncrementByV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x709,
+                InstructionError::IncrementByV1(IncrementByV1Error::CountAboveMaximum),
+            ),
+            (
+                0x70a,
+                InstructionError::IncrementByV1(IncrementByV1Error::CountBelowMinimum),
+            ),
+            (
+                0x70b,
+                InstructionError::IncrementByV1(IncrementByV1Error::DelegateMustBeWriteable),
+            ),
+            (
+                0x70c,
+                InstructionError::IncrementByV1(IncrementByV1Error::DelegateAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x70d,
+                InstructionError::IncrementByV1(
+                    IncrementByV1Error::DelegateAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x70e,
+                InstructionError::IncrementByV1(IncrementByV1Error::DelegateMismatch),
+            ),
+            (
+                0x70f,
+                InstructionError::IncrementByV1(IncrementByV1Error::DelegateExpired {
+                    expiry_slot: 1,
+                    current_slot: 2,
+                }),
+            ),
+            (
+                0x710,
+                InstructionError::IncrementByV1(IncrementByV1Error::DelegateAllowanceExhausted),
+            ),
+            (
+                0x711,
+                InstructionError::IncrementByV1(IncrementByV1Error::DelegateAuthorityChanged),
+            ),
+            (
+                0x712,
+                InstructionError::IncrementByV1(IncrementByV1Error::AuthorityMismatch),
+            ),
+            (
+                0x713,
+                InstructionError::IncrementByV1(IncrementByV1Error::CounterFrozen),
+            ),
+            (
+                0x714,
+                InstructionError::IncrementByV1(IncrementByV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x715,
+                InstructionError::IncrementByV1(
+                    IncrementByV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x716,
+                InstructionError::IncrementByV1(IncrementByV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x717,
+                InstructionError::IncrementByV1(IncrementByV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x718,
+                InstructionError::IncrementByV1(IncrementByV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x719,
+                InstructionError::IncrementByV1(IncrementByV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x71a,
+                InstructionError::IncrementByV1(IncrementByV1Error::CooldownNotElapsed),
+            ),
+            // ==============================================================================
+            // DecrementByV1 (0x800 range)
+            // ==============================================================================
+            // 0x800 reserved
+            (
+                0x801,
+                InstructionError::DecrementByV1(DecrementByV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x802,
+                InstructionError::DecrementByV1(DecrementByV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x803,
+                InstructionError::DecrementByV1(DecrementByV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x804,
+                InstructionError::DecrementByV1(DecrementByV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x805,
+                InstructionError::DecrementByV1(DecrementByV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x806,
+                InstructionError::DecrementByV1(DecrementByV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x807,
+                InstructionError::DecrementByV1(DecrementByV1Error::SerializedSizeMismatch {
+                    expected: 100,
+                    observed: 50,
+                }),
+            ),
+            (
+                0x808,
+                InstructionError::DecrementByV1(DecrementByV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x809,
+                InstructionError::DecrementByV1(DecrementByV1Error::CountAboveMaximum),
+            ),
+            (
+                0x80a,
+                InstructionError::DecrementByV1(DecrementByV1Error::CountBelowMinimum),
+            ),
+            (
+                0x80b,
+                InstructionError::DecrementByV1(DecrementByV1Error::DelegateMustBeWriteable),
+            ),
+            (
+                0x80c,
+                InstructionError::DecrementByV1(DecrementByV1Error::DelegateAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x80d,
+                InstructionError::DecrementByV1(
+                    DecrementByV1Error::DelegateAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x80e,
+                InstructionError::DecrementByV1(DecrementByV1Error::DelegateMismatch),
+            ),
+            (
+                0x80f,
+                InstructionError::DecrementByV1(DecrementByV1Error::DelegateExpired {
+                    expiry_slot: 1,
+                    current_slot: 2,
+                }),
+            ),
+            (
+                0x810,
+                InstructionError::DecrementByV1(DecrementByV1Error::DelegateAllowanceExhausted),
+            ),
+            (
+                0x811,
+                InstructionError::DecrementByV1(DecrementByV1Error::DelegateAuthorityChanged),
+            ),
+            (
+                0x812,
+                InstructionError::DecrementByV1(DecrementByV1Error::AuthorityMismatch),
+            ),
+            (
+                0x813,
+                InstructionError::DecrementByV1(DecrementByV1Error::CounterFrozen),
+            ),
+            (
+                0x814,
+                InstructionError::DecrementByV1(DecrementByV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x815,
+                InstructionError::DecrementByV1(
+                    DecrementByV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x816,
+                InstructionError::DecrementByV1(DecrementByV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x817,
+                InstructionError::DecrementByV1(DecrementByV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x818,
+                InstructionError::DecrementByV1(DecrementByV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x819,
+                InstructionError::DecrementByV1(DecrementByV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x81a,
+                InstructionError::DecrementByV1(DecrementByV1Error::CooldownNotElapsed),
+            ),
+            // ==============================================================================
+            // SetCountPolicyV1 (0x900 range)
+            // ==============================================================================
+            // 0x900 reserved
+            (
+                0x901,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x902,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x903,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::OwnerMustBeWriteable),
+            ),
+            (
+                0x904,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x905,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x906,
+                InstructionError::SetCountPolicyV1(
+                    SetCountPolicyV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x907,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x908,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x909,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::SerializedSizeMismatch {
+                    expected: 100,
+                    observed: 50,
+                }),
+            ),
+            (
+                0x90a,
+                InstructionError::SetCountPolicyV1(
+                    SetCountPolicyV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x90b,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::InvalidBounds {
+                    min_count: 2,
+                    max_count: 1,
+                }),
+            ),
+            (
+                0x90c,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::CountOutOfBounds {
+                    count: 3,
+                    min_count: 1,
+                    max_count: 2,
+                }),
+            ),
+            (
+                0x90d,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::AuthorityMismatch),
+            ),
+            (
+                0x90e,
+                InstructionError::SetCountPolicyV1(
+                    SetCountPolicyV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x90f,
+                InstructionError::SetCountPolicyV1(
+                    SetCountPolicyV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x910,
+                InstructionError::SetCountPolicyV1(
+                    SetCountPolicyV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x911,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x912,
+                InstructionError::SetCountPolicyV1(SetCountPolicyV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x913,
+                InstructionError::SetCountPolicyV1(
+                    SetCountPolicyV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // ApproveDelegateV1 (0xa00 range)
+            // ==============================================================================
+            // 0xa00 reserved
+            (
+                0xa01,
+                InstructionError::ApproveDelegateV1(ApproveDelegateV1Error::NotEnoughAccounts {
+                    expected: 4,
+                    observed: 3,
+                }),
+            ),
+            (
+                0xa02,
+                InstructionError::ApproveDelegateV1(ApproveDelegateV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0xa03,
+                InstructionError::ApproveDelegateV1(ApproveDelegateV1Error::OwnerMustBeWriteable),
+            ),
+            (
+                0xa04,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xa05,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::DelegateMustBeWriteable,
+                ),
+            ),
+            (
+                0xa06,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::DelegateAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xa07,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0xa08,
+                InstructionError::ApproveDelegateV1(ApproveDelegateV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0xa09,
+                InstructionError::ApproveDelegateV1(ApproveDelegateV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0xa0a,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::SerializedSizeMismatch {
+                        expected: 100,
+                        observed: 50,
+                    },
+                ),
+            ),
+            (
+                0xa0b,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xa0c,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::DelegateAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xa0d,
+                InstructionError::ApproveDelegateV1(ApproveDelegateV1Error::AuthorityMismatch),
+            ),
+            (
+                0xa0e,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xa0f,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xa10,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0xa11,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0xa12,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0xa13,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0xa14,
+                InstructionError::ApproveDelegateV1(
+                    ApproveDelegateV1Error::MultisigSignerMustBeWriteable,
+                ),
+            ),
+            // ==============================================================================
+            // RevokeDelegateV1 (0xb00 range)
+            // ==============================================================================
+            // 0xb00 reserved
+            (
+                0xb01,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
+                }),
+            ),
+            (
+                0xb02,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0xb03,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::OwnerMustBeWriteable),
+            ),
+            (
+                0xb04,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0xb05,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::DelegateMustBeWriteable),
+            ),
+            (
+                0xb06,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::DelegateAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xb07,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xb08,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0xb09,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::AuthorityMismatch),
+            ),
+            (
+                0xb0a,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xb0b,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xb0c,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0xb0d,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0xb0e,
+                InstructionError::RevokeDelegateV1(RevokeDelegateV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0xb0f,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0xb10,
+                InstructionError::RevokeDelegateV1(
+                    RevokeDelegateV1Error::MultisigSignerMustBeWriteable,
+                ),
+            ),
+            // ==============================================================================
+            // ProposeAuthorityV1 (0xc00 range)
+            // ==============================================================================
+            // 0xc00 reserved
+            (
+                0xc01,
+                InstructionError::ProposeAuthorityV1(ProposeAuthorityV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
+                }),
+            ),
+            (
+                0xc02,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::AuthorityMustBeSigner,
+                ),
+            ),
+            (
+                0xc03,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::AuthorityMustBeWriteable,
+                ),
+            ),
+            (
+                0xc04,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0xc05,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xc06,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0xc07,
+                InstructionError::ProposeAuthorityV1(ProposeAuthorityV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0xc08,
+                InstructionError::ProposeAuthorityV1(ProposeAuthorityV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0xc09,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::SerializedSizeMismatch {
+                        expected: 161,
+                        observed: 45,
+                    },
+                ),
+            ),
+            (
+                0xc0a,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xc0b,
+                InstructionError::ProposeAuthorityV1(ProposeAuthorityV1Error::AuthorityMismatch),
+            ),
+            (
+                0xc0c,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xc0d,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xc0e,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0xc0f,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0xc10,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0xc11,
+                InstructionError::ProposeAuthorityV1(
+                    ProposeAuthorityV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // AcceptAuthorityV1 (0xd00 range)
+            // ==============================================================================
+            // 0xd00 reserved
+            (
+                0xd01,
+                InstructionError::AcceptAuthorityV1(AcceptAuthorityV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0xd02,
+                InstructionError::AcceptAuthorityV1(
+                    AcceptAuthorityV1Error::NewAuthorityMustBeSigner,
+                ),
+            ),
+            (
+                0xd03,
+                InstructionError::AcceptAuthorityV1(AcceptAuthorityV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0xd04,
+                InstructionError::AcceptAuthorityV1(
+                    AcceptAuthorityV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xd05,
+                InstructionError::AcceptAuthorityV1(AcceptAuthorityV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0xd06,
+                InstructionError::AcceptAuthorityV1(AcceptAuthorityV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0xd07,
+                InstructionError::AcceptAuthorityV1(
+                    AcceptAuthorityV1Error::SerializedSizeMismatch {
+                        expected: 161,
+                        observed: 45,
+                    },
+                ),
+            ),
+            (
+                0xd08,
+                InstructionError::AcceptAuthorityV1(
+                    AcceptAuthorityV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0xd09,
+                InstructionError::AcceptAuthorityV1(AcceptAuthorityV1Error::NoPendingAuthority),
+            ),
+            (
+                0xd0a,
+                InstructionError::AcceptAuthorityV1(
+                    AcceptAuthorityV1Error::PendingAuthorityMismatch,
+                ),
+            ),
+            // ==============================================================================
+            // InitializeIndexedCounterV1 (0xe00 range)
+            // ==============================================================================
+            // 0xe00 reserved
+            (
+                0xe01,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::NotEnoughAccounts {
+                        expected: 3,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0xe02,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::PayerMustBeSigner,
+                ),
+            ),
+            (
+                0xe03,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0xe04,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0xe05,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xe06,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::CounterMustBeEmpty,
+                ),
+            ),
+            // 0xe07 reserved (retired: CounterMustHaveZeroLamports - pre-funded counters are topped up)
+            (
+                0xe08,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::CounterMustBeOwnedBySystemProgram,
+                ),
+            ),
+            (
+                0xe09,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0xe0a,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0xe0b,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0xe0c,
+                InstructionError::InitializeIndexedCounterV1(
+                    InitializeIndexedCounterV1Error::SerializedSizeMismatch {
+                        expected: 46,
+                        observed: 45,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // IncrementIndexedCounterV1 (0xf00 range)
+            // ==============================================================================
+            // 0xf00 reserved
+            (
+                0xf01,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0xf02,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0xf03,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0xf04,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0xf05,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0xf06,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0xf07,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::SerializedSizeMismatch {
+                        expected: 46,
+                        observed: 45,
+                    },
+                ),
+            ),
+            (
+                0xf08,
+                InstructionError::IncrementIndexedCounterV1(
+                    IncrementIndexedCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            // ==============================================================================
+            // DecrementIndexedCounterV1 (0x1000 range)
+            // ==============================================================================
+            // 0x1000 reserved
+            (
+                0x1001,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1002,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x1003,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1004,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1005,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x1006,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0x1007,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::SerializedSizeMismatch {
+                        expected: 46,
+                        observed: 45,
+                    },
+                ),
+            ),
+            (
+                0x1008,
+                InstructionError::DecrementIndexedCounterV1(
+                    DecrementIndexedCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            // ==============================================================================
+            // SetIndexedCountV1 (0x1100 range)
+            // ==============================================================================
+            // 0x1100 reserved
+            (
+                0x1101,
+                InstructionError::SetIndexedCountV1(SetIndexedCountV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x1102,
+                InstructionError::SetIndexedCountV1(SetIndexedCountV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x1103,
+                InstructionError::SetIndexedCountV1(SetIndexedCountV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x1104,
+                InstructionError::SetIndexedCountV1(
+                    SetIndexedCountV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1105,
+                InstructionError::SetIndexedCountV1(SetIndexedCountV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x1106,
+                InstructionError::SetIndexedCountV1(SetIndexedCountV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x1107,
+                InstructionError::SetIndexedCountV1(
+                    SetIndexedCountV1Error::SerializedSizeMismatch {
+                        expected: 46,
+                        observed: 45,
+                    },
+                ),
+            ),
+            (
+                0x1108,
+                InstructionError::SetIndexedCountV1(
+                    SetIndexedCountV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            // ==============================================================================
+            // DeactivateIndexedCounterV1 (0x1200 range)
+            // ==============================================================================
+            // 0x1200 reserved
+            (
+                0x1201,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1202,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x1203,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::OwnerMustBeWriteable,
+                ),
+            ),
+            (
+                0x1204,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1205,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1206,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x1207,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1208,
+                InstructionError::DeactivateIndexedCounterV1(
+                    DeactivateIndexedCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            // ==============================================================================
+            // ReactivateIndexedCounterV1 (0x1300 range)
+            // ==============================================================================
+            // 0x1300 reserved
+            (
+                0x1301,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::NotEnoughAccounts {
+                        expected: 3,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0x1302,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::PayerMustBeSigner,
+                ),
+            ),
+            (
+                0x1303,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x1304,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1305,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1306,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x1307,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x1308,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0x1309,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::SerializedSizeMismatch {
+                        expected: 46,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x130a,
+                InstructionError::ReactivateIndexedCounterV1(
+                    ReactivateIndexedCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            // ==============================================================================
+            // InitializeSponsoredCounterV1 (0x1400 range)
+            // ==============================================================================
+            // 0x1400 reserved
+            (
+                0x1401,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::NotEnoughAccounts {
+                        expected: 4,
+                        observed: 3,
+                    },
+                ),
+            ),
+            (
+                0x1402,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::PayerMustBeSigner,
+                ),
+            ),
+            (
+                0x1403,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x1404,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x1405,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1406,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1407,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::CounterMustBeEmpty,
+                ),
+            ),
+            (
+                0x1408,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::CounterMustBeOwnedBySystemProgram,
+                ),
+            ),
+            (
+                0x1409,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x140a,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0x140b,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::SerializedSizeMismatch {
+                        expected: 161,
+                        observed: 0,
+                    },
+                ),
+            ),
+            (
+                0x140c,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x140d,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::TombstoneAddressMismatch {
+                        expected: [1u8; 32],
+                        observed: [2u8; 32],
+                    },
+                ),
+            ),
+            (
+                0x140e,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x140f,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::TombstoneCounterMismatch {
+                        expected: [1u8; 32],
+                        observed: [2u8; 32],
+                    },
+                ),
+            ),
+            (
+                0x1410,
+                InstructionError::InitializeSponsoredCounterV1(
+                    InitializeSponsoredCounterV1Error::CounterClosed,
+                ),
+            ),
+            // ==============================================================================
+            // ReactivateSponsoredCounterV1 (0x1500 range)
+            // ==============================================================================
+            // 0x1500 reserved
+            (
+                0x1501,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::NotEnoughAccounts {
+                        expected: 4,
+                        observed: 3,
+                    },
+                ),
+            ),
+            (
+                0x1502,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::PayerMustBeSigner,
+                ),
+            ),
+            (
+                0x1503,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x1504,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x1505,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1506,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1507,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x1508,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0x1509,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::SerializedSizeMismatch {
+                        expected: 161,
+                        observed: 0,
+                    },
+                ),
+            ),
+            (
+                0x150a,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x150b,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x150c,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::AuthorityMustBeSigner,
+                ),
+            ),
+            (
+                0x150d,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::AuthorityMismatch,
+                ),
+            ),
+            (
+                0x150e,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x150f,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1510,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x1511,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0x1512,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0x1513,
+                InstructionError::ReactivateSponsoredCounterV1(
+                    ReactivateSponsoredCounterV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // GetCountV1 (0x1600 range)
+            // ==============================================================================
+            // 0x1600 reserved
+            (
+                0x1601,
+                InstructionError::GetCountV1(GetCountV1Error::NotEnoughAccounts {
+                    expected: 1,
+                    observed: 0,
+                }),
+            ),
+            (
+                0x1602,
+                InstructionError::GetCountV1(GetCountV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1603,
+                InstructionError::GetCountV1(GetCountV1Error::DeserializeError(ReadError::Custom(
+                    "test",
+                ))),
+            ),
+            (
+                0x1604,
+                InstructionError::GetCountV1(GetCountV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            // ==============================================================================
+            // CompareAndSetCountV1 (0x1700 range)
+            // ==============================================================================
+            // 0x1700 reserved
+            (
+                0x1701,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1702,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x1703,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1704,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1705,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x1706,
+                InstructionError::CompareAndSetCountV1(CompareAndSetCountV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            // 0x1707 reserved (retired: SerializedSizeMismatch - counter is updated in place)
+            (
+                0x1708,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1709,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::CountAboveMaximum,
+                ),
+            ),
+            (
+                0x170a,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::CountBelowMinimum,
+                ),
+            ),
+            (
+                0x170b,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::AuthorityMismatch,
+                ),
+            ),
+            (
+                0x170c,
+                InstructionError::CompareAndSetCountV1(CompareAndSetCountV1Error::CountMismatch {
+                    expected: 1,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x170d,
+                InstructionError::CompareAndSetCountV1(CompareAndSetCountV1Error::CounterFrozen),
+            ),
+            (
+                0x170e,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x170f,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1710,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x1711,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0x1712,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0x1713,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1714,
+                InstructionError::CompareAndSetCountV1(
+                    CompareAndSetCountV1Error::CooldownNotElapsed,
+                ),
+            ),
+            // ==============================================================================
+            // CompareAndIncrementCountV1 (0x1800 range)
+            // ==============================================================================
+            // 0x1800 reserved
+            (
+                0x1801,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1802,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x1803,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1804,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1805,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x1806,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            // 0x1807 reserved (retired: SerializedSizeMismatch - counter is updated in place)
+            (
+                0x1808,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1809,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CountAboveMaximum,
+                ),
+            ),
+            (
+                0x180a,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CountBelowMinimum,
+                ),
+            ),
+            (
+                0x180b,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::AuthorityMismatch,
+                ),
+            ),
+            (
+                0x180c,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CountMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0x180d,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CounterFrozen,
+                ),
+            ),
+            (
+                0x180e,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x180f,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1810,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::MultisigSignerMustBeSigner,
+                ),
+            ),
+            (
+                0x1811,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::MultisigSignerNotMember,
+                ),
+            ),
+            (
+                0x1812,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::MultisigDuplicateSigner,
+                ),
+            ),
+            (
+                0x1813,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::MultisigThresholdNotMet {
+                        threshold: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1814,
+                InstructionError::CompareAndIncrementCountV1(
+                    CompareAndIncrementCountV1Error::CooldownNotElapsed,
+                ),
+            ),
+            // ==============================================================================
+            // InitializeShardedCounterV1 (0x1900 range)
+            // ==============================================================================
+            // 0x1900 reserved
+            (
+                0x1901,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::NotEnoughAccounts {
+                        expected: 4,
+                        observed: 3,
+                    },
+                ),
+            ),
+            (
+                0x1902,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::PayerMustBeSigner,
+                ),
+            ),
+            (
+                0x1903,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x1904,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardedCounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1905,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardedCounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1906,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardedCounterMustBeEmpty,
+                ),
+            ),
+            (
+                0x1907,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardedCounterMustBeOwnedBySystemProgram,
+                ),
+            ),
+            (
+                0x1908,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x1909,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::TooManyShards {
+                        maximum: 16,
+                        observed: 17,
+                    },
+                ),
+            ),
+            (
+                0x190a,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardMustBeWriteable,
+                ),
+            ),
+            (
+                0x190b,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x190c,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardMustBeEmpty,
+                ),
+            ),
+            (
+                0x190d,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::ShardMustBeOwnedBySystemProgram,
+                ),
+            ),
+            (
+                0x190e,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0x190f,
+                InstructionError::InitializeShardedCounterV1(
+                    InitializeShardedCounterV1Error::SerializedSizeMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // IncrementCounterShardV1 (0x1a00 range)
+            // ==============================================================================
+            // 0x1a00 reserved
+            (
+                0x1a01,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x1a02,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::ContributorMustBeSigner,
+                ),
+            ),
+            (
+                0x1a03,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::ShardMustBeWriteable,
+                ),
+            ),
+            (
+                0x1a04,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::ShardAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1a05,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x1a06,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::SerializeError(WriteError::Custom("test")),
+                ),
+            ),
+            (
+                0x1a07,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::SerializedSizeMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0x1a08,
+                InstructionError::IncrementCounterShardV1(
+                    IncrementCounterShardV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            // ==============================================================================
+            // ReconcileShardsV1 (0x1b00 range)
+            // ==============================================================================
+            // 0x1b00 reserved
+            (
+                0x1b01,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x1b02,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x1b03,
+                InstructionError::ReconcileShardsV1(
+                    ReconcileShardsV1Error::ShardedCounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x1b04,
+                InstructionError::ReconcileShardsV1(
+                    ReconcileShardsV1Error::ShardedCounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1b05,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::OwnerMismatch),
+            ),
+            (
+                0x1b06,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::TooManyShards {
+                    maximum: 16,
+                    observed: 17,
+                }),
+            ),
+            (
+                0x1b07,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::ShardMustBeWriteable),
+            ),
+            (
+                0x1b08,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::ShardAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1b09,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::ShardedCounterMismatch),
+            ),
+            (
+                0x1b0a,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x1b0b,
+                InstructionError::ReconcileShardsV1(ReconcileShardsV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x1b0c,
+                InstructionError::ReconcileShardsV1(
+                    ReconcileShardsV1Error::SerializedSizeMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0x1b0d,
+                InstructionError::ReconcileShardsV1(
+                    ReconcileShardsV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1b0e,
+                InstructionError::ReconcileShardsV1(
+                    ReconcileShardsV1Error::ShardAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            // ==============================================================================
+            // SetAccessModeV1 (0x1c00 range)
+            // ==============================================================================
+            // 0x1c00 reserved
+            (
+                0x1c01,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x1c02,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x1c03,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::OwnerMustBeWriteable),
+            ),
+            (
+                0x1c04,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x1c05,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1c06,
+                InstructionError::SetAccessModeV1(
+                    SetAccessModeV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x1c07,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x1c08,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x1c09,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::SerializedSizeMismatch {
+                    expected: 1,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x1c0a,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x1c0b,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::AuthorityMismatch),
+            ),
+            (
+                0x1c0c,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1c0d,
+                InstructionError::SetAccessModeV1(
+                    SetAccessModeV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1c0e,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x1c0f,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x1c10,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x1c11,
+                InstructionError::SetAccessModeV1(SetAccessModeV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            // ==============================================================================
+            // FreezeCounterV1 (0x1d00 range)
+            // ==============================================================================
+            // 0x1d00 reserved
+            (
+                0x1d01,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x1d02,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x1d03,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::OwnerMustBeWriteable),
+            ),
+            (
+                0x1d04,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x1d05,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1d06,
+                InstructionError::FreezeCounterV1(
+                    FreezeCounterV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x1d07,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x1d08,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x1d09,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::SerializedSizeMismatch {
+                    expected: 1,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x1d0a,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x1d0b,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::AuthorityMismatch),
+            ),
+            (
+                0x1d0c,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::CounterAlreadyFrozen),
+            ),
+            (
+                0x1d0d,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1d0e,
+                InstructionError::FreezeCounterV1(
+                    FreezeCounterV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1d0f,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x1d10,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x1d11,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x1d12,
+                InstructionError::FreezeCounterV1(FreezeCounterV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            // ==============================================================================
+            // ThawCounterV1 (0x1e00 range)
+            // ==============================================================================
+            // 0x1e00 reserved
+            (
+                0x1e01,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x1e02,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x1e03,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x1e04,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::CounterAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1e05,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x1e06,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x1e07,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::SerializedSizeMismatch {
+                    expected: 1,
+                    observed: 2,
+                }),
+            ),
+            (
+                0x1e08,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x1e09,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::AuthorityMismatch),
+            ),
+            (
+                0x1e0a,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::CounterNotFrozen),
+            ),
+            (
+                0x1e0b,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x1e0c,
+                InstructionError::ThawCounterV1(
+                    ThawCounterV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x1e0d,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x1e0e,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x1e0f,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x1e10,
+                InstructionError::ThawCounterV1(ThawCounterV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            // ==============================================================================
+            // InitializeConfigV1 (0x1f00 range)
+            // ==============================================================================
+            // 0x1f00 reserved
+            (
+                0x1f01,
+                InstructionError::InitializeConfigV1(InitializeConfigV1Error::NotEnoughAccounts {
+                    expected: 4,
+                    observed: 3,
+                }),
+            ),
+            (
+                0x1f02,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::AuthorityMustBeSigner,
+                ),
+            ),
+            (
+                0x1f03,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::AuthorityMustBeWriteable,
+                ),
+            ),
+            (
+                0x1f04,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::ConfigMustBeWriteable,
+                ),
+            ),
+            (
+                0x1f05,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::ConfigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1f06,
+                InstructionError::InitializeConfigV1(InitializeConfigV1Error::ConfigMustBeEmpty),
+            ),
+            (
+                0x1f07,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::ConfigMustBeOwnedBySystemProgram,
+                ),
+            ),
+            (
+                0x1f08,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::ProgramDataAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
+            ),
+            (
+                0x1f09,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::ProgramDataMustBeOwnedByLoader,
+                ),
+            ),
+            (
+                0x1f0a,
+                InstructionError::InitializeConfigV1(InitializeConfigV1Error::InvalidProgramData),
+            ),
+            (
+                0x1f0b,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::UpgradeAuthorityMismatch,
+                ),
+            ),
+            (
+                0x1f0c,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::SystemProgramAddressMismatch,
+                ),
+            ),
+            (
+                0x1f0d,
+                InstructionError::InitializeConfigV1(InitializeConfigV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x1f0e,
+                InstructionError::InitializeConfigV1(InitializeConfigV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x1f0f,
+                InstructionError::InitializeConfigV1(
+                    InitializeConfigV1Error::SerializedSizeMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
+                ),
+            ),
+            // ==============================================================================
+            // SetPausedV1 (0x2000 range)
+            // ==============================================================================
+            // 0x2000 reserved
+            (
+                0x2001,
+                InstructionError::SetPausedV1(SetPausedV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x2002,
+                InstructionError::SetPausedV1(SetPausedV1Error::AdminMustBeSigner),
+            ),
+            (
+                0x2003,
+                InstructionError::SetPausedV1(SetPausedV1Error::ConfigMustBeWriteable),
+            ),
+            (
+                0x2004,
+                InstructionError::SetPausedV1(SetPausedV1Error::ConfigMustBeOwnedByProgram),
+            ),
+            (
+                0x2005,
+                InstructionError::SetPausedV1(SetPausedV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x2006,
+                InstructionError::SetPausedV1(SetPausedV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x2007,
+                InstructionError::SetPausedV1(SetPausedV1Error::AdminMismatch),
+            ),
+            (
+                0x2008,
+                InstructionError::SetPausedV1(SetPausedV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x2009,
+                InstructionError::SetPausedV1(SetPausedV1Error::SerializedSizeMismatch {
+                    expected: 1,
+                    observed: 2,
+                }),
+            ),
+            // ==============================================================================
+            // SetPauseExemptionV1 (0x2100 range)
+            // ==============================================================================
+            // 0x2100 reserved
+            (
+                0x2101,
+                InstructionError::SetPauseExemptionV1(
+                    SetPauseExemptionV1Error::NotEnoughAccounts {
+                        expected: 2,
+                        observed: 1,
+                    },
+                ),
+            ),
+            (
+                0x2102,
+                InstructionError::SetPauseExemptionV1(SetPauseExemptionV1Error::AdminMustBeSigner),
+            ),
+            (
+                0x2103,
+                InstructionError::SetPauseExemptionV1(
+                    SetPauseExemptionV1Error::ConfigMustBeWriteable,
+                ),
+            ),
+            (
+                0x2104,
+                InstructionError::SetPauseExemptionV1(
+                    SetPauseExemptionV1Error::ConfigMustBeOwnedByProgram,
+                ),
+            ),
+            (
+                0x2105,
+                InstructionError::SetPauseExemptionV1(
+                    SetPauseExemptionV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x2106,
+                InstructionError::SetPauseExemptionV1(SetPauseExemptionV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x2107,
+                InstructionError::SetPauseExemptionV1(SetPauseExemptionV1Error::AdminMismatch),
+            ),
+            (
+                0x2108,
+                InstructionError::SetPauseExemptionV1(SetPauseExemptionV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x2109,
+                InstructionError::SetPauseExemptionV1(
+                    SetPauseExemptionV1Error::SerializedSizeMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
+                ),
+            ),
+            (
+                0x210a,
+                InstructionError::SetPauseExemptionV1(
+                    SetPauseExemptionV1Error::InstructionNotPauseChecked,
+                ),
+            ),
+            // ==============================================================================
+            // CloseCounterV1 (0x2200 range)
+            // ==============================================================================
+            // 0x2200 reserved
+            (
+                0x2201,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::NotEnoughAccounts {
+                    expected: 5,
+                    observed: 4,
+                }),
+            ),
+            (
+                0x2202,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x2203,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::OwnerMustBeWriteable),
+            ),
+            (
+                0x2204,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x2205,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::CounterAddressMismatch {
+                    expected: [1u8; 32],
+                    observed: [2u8; 32],
+                }),
+            ),
+            (
+                0x2206,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::DeserializeError(
+                    ReadError::Custom("test"),
+                )),
+            ),
+            (
+                0x2207,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
+            ),
+            (
+                0x2208,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::AuthorityMismatch),
+            ),
+            (
+                0x2209,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::DestinationMustBeWriteable),
+            ),
+            (
+                0x220a,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::DestinationMustNotBeCounter),
+            ),
+            (
+                0x220b,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::RentPayerMismatch {
+                    expected: [1u8; 32],
+                    observed: [2u8; 32],
+                }),
+            ),
+            (
+                0x220c,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::TombstoneMustBeWriteable),
+            ),
+            (
+                0x220d,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::TombstoneAddressMismatch {
+                    expected: [1u8; 32],
+                    observed: [2u8; 32],
+                }),
+            ),
+            (
+                0x220e,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::TombstoneCounterMismatch {
+                    expected: [1u8; 32],
+                    observed: [2u8; 32],
+                }),
+            ),
+            (
+                0x220f,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::SystemProgramAddressMismatch),
+            ),
+            (
+                0x2210,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::SerializeError(
+                    WriteError::Custom("test"),
+                )),
+            ),
+            (
+                0x2211,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
+            ),
+            (
+                0x2212,
+                InstructionError::CloseCounterV1(
+                    CloseCounterV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x2213,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x2214,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x2215,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x2216,
+                InstructionError::CloseCounterV1(CloseCounterV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
+            (
+                0x2217,
+                InstructionError::CloseCounterV1(
+                    CloseCounterV1Error::MultisigSignerMustBeWriteable,
+                ),
+            ),
+            // ==============================================================================
+            // MigrateCounterV1ToV2 (0x2300 range)
+            // ==============================================================================
+            // 0x2300 reserved
+            (
+                0x2301,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::NotEnoughAccounts {
+                        expected: 4,
+                        observed: 3,
+                    },
+                ),
+            ),
+            (
+                0x2302,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::PayerMustBeSigner,
+                ),
+            ),
+            (
+                0x2303,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::PayerMustBeWriteable,
+                ),
+            ),
+            (
+                0x2304,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::OwnerMustBeSigner,
+                ),
+            ),
+            (
+                0x2305,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::CounterMustBeWriteable,
+                ),
+            ),
+            (
+                0x2306,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::CounterAddressMismatch {
+                        expected: [1u8; 32],
+                        observed: [2u8; 32],
+                    },
+                ),
+            ),
+            (
+                0x2307,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x2308,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
+            ),
+            (
+                0x2309,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::AuthorityMismatch,
+                ),
+            ),
+            (
+                0x230a,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::CounterAlreadyMigrated,
+                ),
+            ),
+            (
+                0x230b,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::SystemProgramAddressMismatch,
                 ),
             ),
-            // 0x206 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
-            // 0x207 reserved (retired)
             (
-                0x208,
-                InstructionError::DeactivateCounterV1(DeactivateCounterV1Error::DeserializeError(
-                    ReadError::Custom("test"),
+                0x230c,
+                InstructionError::MigrateCounterV1ToV2(MigrateCounterV1ToV2Error::SerializeError(
+                    WriteError::Custom("test"),
                 )),
             ),
-            // 0x209 reserved (retired)
-            // 0x20a reserved (retired: OwnerMismatch - redundant with address validation)
             (
-                0x20b,
-                InstructionError::DeactivateCounterV1(
-                    DeactivateCounterV1Error::AccountDiscriminatorError(
-                        AccountDiscriminatorError::Missing,
-                    ),
+                0x230d,
+                InstructionError::MigrateCounterV1ToV2(
+                    MigrateCounterV1ToV2Error::SerializedSizeMismatch {
+                        expected: 1,
+                        observed: 2,
+                    },
                 ),
             ),
             // ==============================================================================
-            // IncrementCountV1 (0x300 range)
+            // SetCooldownV1 (0x2400 range)
             // ==============================================================================
-            // 0x300 reserved
+            // 0x2400 reserved
             (
-                0x301,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::NotEnoughAccounts {
-                    expected: 2,
-                    observed: 1,
+                0x2401,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: 2,
                 }),
             ),
             (
-                0x302,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::OwnerMustBeSigner),
+                0x2402,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::OwnerMustBeSigner),
             ),
-            // 0x303 reserved (retired)
             (
-                0x304,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::CounterMustBeWriteable),
+                0x2403,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::OwnerMustBeWriteable),
             ),
             (
-                0x305,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::CounterAddressMismatch {
+                0x2404,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x2405,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::CounterAddressMismatch {
                     expected: Default::default(),
                     observed: Default::default(),
                 }),
             ),
-            // 0x306 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
             (
-                0x307,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::DeserializeError(
+                0x2406,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::SystemProgramAddressMismatch),
+            ),
+            (
+                0x2407,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::DeserializeError(
                     ReadError::Custom("test"),
                 )),
             ),
             (
-                0x308,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::SerializeError(
+                0x2408,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::SerializeError(
                     WriteError::Custom("test"),
                 )),
             ),
-            // 0x309 reserved (retired: OwnerMismatch - redundant with address validation)
             (
-                0x30a,
-                InstructionError::IncrementCountV1(IncrementCountV1Error::SerializedSizeMismatch {
-                    expected: 100,
-                    observed: 50,
+                0x2409,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::SerializedSizeMismatch {
+                    expected: 1,
+                    observed: 2,
                 }),
             ),
             (
-                0x30b,
-                InstructionError::IncrementCountV1(
-                    IncrementCountV1Error::AccountDiscriminatorError(
-                        AccountDiscriminatorError::DiscriminatorMismatch {
-                            expected: AccountDiscriminator::CounterV1Account,
-                            observed: AccountDiscriminator::DeactivatedAccount,
-                        },
-                    ),
-                ),
+                0x240a,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
             ),
-            // ==============================================================================
-            // DecrementCountV1 (0x400 range)
-            // ==============================================================================
-            // 0x400 reserved
             (
-                0x401,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::NotEnoughAccounts {
-                    expected: 2,
-                    observed: 1,
-                }),
+                0x240b,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::AuthorityMismatch),
             ),
             (
-                0x402,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::OwnerMustBeSigner),
+                0x240c,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::MultisigAddressMismatch {
+                    expected: Default::default(),
+                    observed: Default::default(),
+                }),
             ),
-            // 0x403 reserved (retired)
             (
-                0x404,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::CounterMustBeWriteable),
+                0x240d,
+                InstructionError::SetCooldownV1(
+                    SetCooldownV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
             ),
             (
-                0x405,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::CounterAddressMismatch {
-                    expected: Default::default(),
-                    observed: Default::default(),
-                }),
+                0x240e,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::MultisigSignerMustBeSigner),
             ),
-            // 0x406 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
             (
-                0x407,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::DeserializeError(
-                    ReadError::Custom("test"),
-                )),
+                0x240f,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::MultisigSignerNotMember),
             ),
             (
-                0x408,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::SerializeError(
-                    WriteError::Custom("test"),
-                )),
+                0x2410,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::MultisigDuplicateSigner),
             ),
-            // 0x409 reserved (retired: OwnerMismatch - redundant with address validation)
             (
-                0x40a,
-                InstructionError::DecrementCountV1(DecrementCountV1Error::SerializedSizeMismatch {
-                    expected: 100,
-                    observed: 50,
+                0x2411,
+                InstructionError::SetCooldownV1(SetCooldownV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
                 }),
             ),
+            // ==============================================================================
+            // InitializeMultisigV1 (0x2500 range)
+            // ==============================================================================
+            // 0x2500 reserved
             (
-                0x40b,
-                InstructionError::DecrementCountV1(
-                    DecrementCountV1Error::AccountDiscriminatorError(
-                        AccountDiscriminatorError::Invalid(0),
-                    ),
+                0x2501,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::NotEnoughAccounts {
+                        expected: 5,
+                        observed: 4,
+                    },
                 ),
             ),
-            // ==============================================================================
-            // SetCountV1 (0x500 range)
-            // ==============================================================================
-            // 0x500 reserved
             (
-                0x501,
-                InstructionError::SetCountV1(SetCountV1Error::NotEnoughAccounts {
-                    expected: 2,
-                    observed: 1,
-                }),
+                0x2502,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::AuthorityMustBeSigner,
+                ),
             ),
             (
-                0x502,
-                InstructionError::SetCountV1(SetCountV1Error::OwnerMustBeSigner),
+                0x2503,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::AuthorityMustBeWriteable,
+                ),
             ),
-            // 0x503 reserved (retired)
             (
-                0x504,
-                InstructionError::SetCountV1(SetCountV1Error::CounterMustBeWriteable),
+                0x2504,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::CounterMustBeWriteable,
+                ),
             ),
             (
-                0x505,
-                InstructionError::SetCountV1(SetCountV1Error::CounterAddressMismatch {
-                    expected: Default::default(),
-                    observed: Default::default(),
-                }),
+                0x2505,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::CounterAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
             ),
-            // 0x506 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
             (
-                0x507,
-                InstructionError::SetCountV1(SetCountV1Error::DeserializeError(ReadError::Custom(
-                    "test",
-                ))),
+                0x2506,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::MultisigMustBeWriteable,
+                ),
             ),
             (
-                0x508,
-                InstructionError::SetCountV1(SetCountV1Error::SerializeError(WriteError::Custom(
-                    "test",
-                ))),
+                0x2507,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::MultisigAddressMismatch {
+                        expected: Default::default(),
+                        observed: Default::default(),
+                    },
+                ),
             ),
-            // 0x509 reserved (retired: OwnerMismatch - redundant with address validation)
             (
-                0x50a,
-                InstructionError::SetCountV1(SetCountV1Error::SerializedSizeMismatch {
-                    expected: 100,
-                    observed: 50,
-                }),
+                0x2508,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::SystemProgramAddressMismatch,
+                ),
             ),
             (
-                0x50b,
-                InstructionError::SetCountV1(SetCountV1Error::AccountDiscriminatorError(
-                    AccountDiscriminatorError::Missing,
+                0x2509,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::DeserializeError(ReadError::Custom("test")),
+                ),
+            ),
+            (
+                0x250a,
+                InstructionError::InitializeMultisigV1(InitializeMultisigV1Error::SerializeError(
+                    WriteError::Custom("test"),
                 )),
             ),
-            // ==============================================================================
-            // ReactivateCounterV1 (0x600 range)
-            // ==============================================================================
-            // 0x600 reserved
             (
-                0x601,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::NotEnoughAccounts {
-                        expected: 3,
+                0x250b,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::SerializedSizeMismatch {
+                        expected: 1,
                         observed: 2,
                     },
                 ),
             ),
             (
-                0x602,
-                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::PayerMustBeSigner),
+                0x250c,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::AccountDiscriminatorError(
+                        AccountDiscriminatorError::Missing,
+                    ),
+                ),
             ),
             (
-                0x603,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::PayerMustBeWriteable,
+                0x250d,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::MultisigAccountDiscriminatorError(
+                        AccountDiscriminatorError::DiscriminatorMismatch {
+                            expected: AccountDiscriminator::MultisigV1Account,
+                            observed: AccountDiscriminator::CounterV1Account,
+                        },
+                    ),
                 ),
             ),
             (
-                0x604,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::CounterMustBeWriteable,
+                0x250e,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::AuthorityMismatch,
                 ),
             ),
             (
-                0x605,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::CounterAddressMismatch {
-                        expected: Default::default(),
-                        observed: Default::default(),
+                0x250f,
+                InstructionError::InitializeMultisigV1(InitializeMultisigV1Error::TooManyMembers {
+                    maximum: 10,
+                    observed: 11,
+                }),
+            ),
+            (
+                0x2510,
+                InstructionError::InitializeMultisigV1(InitializeMultisigV1Error::DuplicateMember),
+            ),
+            (
+                0x2511,
+                InstructionError::InitializeMultisigV1(
+                    InitializeMultisigV1Error::InvalidThreshold {
+                        threshold: 0,
+                        member_count: 1,
                     },
                 ),
             ),
-            // 0x606 reserved (retired: CounterMustBeOwnedByProgram - redundant with address validation)
+            // ==============================================================================
+            // ExecuteBatchV1 (0x2600 range)
+            // ==============================================================================
+            // 0x2600 reserved
             (
-                0x607,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::SystemProgramAddressMismatch,
-                ),
+                0x2601,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::NotEnoughAccounts {
+                    expected: 2,
+                    observed: 1,
+                }),
             ),
             (
-                0x608,
-                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::DeserializeError(
+                0x2602,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::OwnerMustBeSigner),
+            ),
+            (
+                0x2603,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::CounterMustBeWriteable),
+            ),
+            (
+                0x2604,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::CounterAddressMismatch {
+                    expected: [1; 32],
+                    observed: [2; 32],
+                }),
+            ),
+            (
+                0x2605,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::DeserializeError(
                     ReadError::Custom("test"),
                 )),
             ),
             (
-                0x609,
-                InstructionError::ReactivateCounterV1(ReactivateCounterV1Error::SerializeError(
+                0x2606,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::SerializeError(
                     WriteError::Custom("test"),
                 )),
             ),
-            // 0x60a reserved to maintain existing error code mappings
             (
-                0x60b,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::SerializedSizeMismatch {
-                        expected: 100,
-                        observed: 50,
-                    },
-                ),
+                0x2607,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::AccountDiscriminatorError(
+                    AccountDiscriminatorError::Missing,
+                )),
             ),
             (
-                0x60c,
-                InstructionError::ReactivateCounterV1(
-                    ReactivateCounterV1Error::AccountDiscriminatorError(
+                0x2608,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::CountAboveMaximum),
+            ),
+            (
+                0x2609,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::CountBelowMinimum),
+            ),
+            (
+                0x260a,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::AuthorityMismatch),
+            ),
+            (
+                0x260b,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::CounterFrozen),
+            ),
+            (
+                0x260c,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::CooldownNotElapsed),
+            ),
+            (
+                0x260d,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::EmptyBatch),
+            ),
+            (
+                0x260e,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::TooManyOperations {
+                    maximum: 32,
+                    observed: 33,
+                }),
+            ),
+            (
+                0x260f,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::OperationsLengthMismatch {
+                    expected: 9,
+                    observed: 8,
+                }),
+            ),
+            (
+                0x2610,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::MultisigAddressMismatch {
+                    expected: [1; 32],
+                    observed: [2; 32],
+                }),
+            ),
+            (
+                0x2611,
+                InstructionError::ExecuteBatchV1(
+                    ExecuteBatchV1Error::MultisigAccountDiscriminatorError(
                         AccountDiscriminatorError::Missing,
                     ),
                 ),
             ),
+            (
+                0x2612,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::MultisigSignerMustBeSigner),
+            ),
+            (
+                0x2613,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::MultisigSignerNotMember),
+            ),
+            (
+                0x2614,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::MultisigDuplicateSigner),
+            ),
+            (
+                0x2615,
+                InstructionError::ExecuteBatchV1(ExecuteBatchV1Error::MultisigThresholdNotMet {
+                    threshold: 2,
+                    observed: 1,
+                }),
+            ),
         ];
 
         for (expected_code, error) in test_cases {
@@ -0,0 +1,242 @@
+#[cfg(not(target_os = "solana"))]
+use alloc::{vec, vec::Vec};
+
+use {
+    pinocchio::{
+        log::sol_log_data,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{SchemaRead, SchemaWrite},
+};
+
+/// Leading byte of every event, identifying the kind of state change that emitted it.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventDiscriminator {
+    CounterInitializedV1 = 1,
+    CountIncrementedV1 = 2,
+    CountDecrementedV1 = 3,
+    CountSetV1 = 4,
+    CounterDeactivatedV1 = 5,
+    CounterReactivatedV1 = 6,
+    CounterClosedV1 = 7,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EventDiscriminatorError {
+    Missing,
+    Invalid(u8),
+}
+
+impl From<EventDiscriminator> for u8 {
+    fn from(discriminator: EventDiscriminator) -> Self {
+        match discriminator {
+            EventDiscriminator::CounterInitializedV1 => 1,
+            EventDiscriminator::CountIncrementedV1 => 2,
+            EventDiscriminator::CountDecrementedV1 => 3,
+            EventDiscriminator::CountSetV1 => 4,
+            EventDiscriminator::CounterDeactivatedV1 => 5,
+            EventDiscriminator::CounterReactivatedV1 => 6,
+            EventDiscriminator::CounterClosedV1 => 7,
+        }
+    }
+}
+
+impl TryFrom<u8> for EventDiscriminator {
+    type Error = EventDiscriminatorError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        match byte {
+            1 => Ok(EventDiscriminator::CounterInitializedV1),
+            2 => Ok(EventDiscriminator::CountIncrementedV1),
+            3 => Ok(EventDiscriminator::CountDecrementedV1),
+            4 => Ok(EventDiscriminator::CountSetV1),
+            5 => Ok(EventDiscriminator::CounterDeactivatedV1),
+            6 => Ok(EventDiscriminator::CounterReactivatedV1),
+            7 => Ok(EventDiscriminator::CounterClosedV1),
+            _ => Err(EventDiscriminatorError::Invalid(byte)),
+        }
+    }
+}
+
+impl EventDiscriminator {
+    /// Splits event data into its discriminator and payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EventDiscriminatorError`] if the data is empty or starts with an unknown byte.
+    pub fn parse(data: &[u8]) -> Result<(Self, &[u8]), EventDiscriminatorError> {
+        let (discriminator_byte, payload) =
+            data.split_first().ok_or(EventDiscriminatorError::Missing)?;
+        Ok((Self::try_from(*discriminator_byte)?, payload))
+    }
+}
+
+/// Size in bytes of an emitted event: the discriminator byte followed by a [`CounterEventV1`].
+const EVENT_SIZE: usize = 1 + CounterEventV1::size();
+
+/// Payload of the events emitted with `sol_log_data` whenever a counter is changed.
+///
+/// Every event is a single [`EventDiscriminator`] byte followed by this payload, so indexers can
+/// decode all of them with one layout. Instructions that create, deactivate or close a counter
+/// report a count of `0` on the side of the change where no active counter exists.
+#[repr(C)]
+#[derive(Debug, Clone, PartialEq, SchemaRead, SchemaWrite)]
+pub struct CounterEventV1 {
+    pub counter: Pubkey,
+    pub owner: Pubkey,
+    pub old_count: u64,
+    pub new_count: u64,
+    pub slot: u64,
+}
+
+impl CounterEventV1 {
+    /// Builds an event payload stamped with the current slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProgramError`] if the clock sysvar cannot be read.
+    pub fn new(
+        counter: &Pubkey,
+        owner: &Pubkey,
+        old_count: u64,
+        new_count: u64,
+    ) -> Result<Self, ProgramError> {
+        Ok(Self {
+            counter: *counter,
+            owner: *owner,
+            old_count,
+            new_count,
+            slot: Clock::get()?.slot,
+        })
+    }
+
+    /// Returns the size in bytes of a serialized [`CounterEventV1`] payload.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // CounterEventV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the event as its discriminator byte followed by the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self, discriminator: EventDiscriminator) -> wincode::WriteResult<Vec<u8>> {
+        let mut data = vec![0; EVENT_SIZE];
+        self.serialize_into(discriminator, &mut data)?;
+        Ok(data)
+    }
+
+    /// Serializes the event as its discriminator byte followed by the payload into the start of
+    /// `dst`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(
+        &self,
+        discriminator: EventDiscriminator,
+        mut dst: &mut [u8],
+    ) -> wincode::WriteResult<()> {
+        wincode::serialize_into(&mut dst, &u8::from(discriminator))?;
+        wincode::serialize_into(&mut dst, self)
+    }
+
+    /// Deserializes the event payload from bytes, without the discriminator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+
+    /// Writes the serialized event to the transaction log as program data.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    pub fn emit(&self, discriminator: EventDiscriminator) -> wincode::WriteResult<()> {
+        let mut data = [0; EVENT_SIZE];
+        self.serialize_into(discriminator, &mut data)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> CounterEventV1 {
+        CounterEventV1 {
+            counter: [1; 32],
+            owner: [2; 32],
+            old_count: 3,
+            new_count: 4,
+            slot: 5,
+        }
+    }
+
+    #[test]
+    fn test_event_discriminator_roundtrip() {
+        for byte in 1..=7u8 {
+            let discriminator = EventDiscriminator::try_from(byte).unwrap();
+            assert_eq!(u8::from(discriminator), byte);
+        }
+    }
+
+    #[test]
+    fn test_event_discriminator_parse_rejects_invalid_data() {
+        assert_eq!(
+            EventDiscriminator::parse(&[]),
+            Err(EventDiscriminatorError::Missing)
+        );
+        for byte in [0u8, 8u8, 255u8] {
+            assert_eq!(
+                EventDiscriminator::parse(&[byte, 0]),
+                Err(EventDiscriminatorError::Invalid(byte))
+            );
+        }
+    }
+
+    #[test]
+    fn test_counter_event_v1_size() {
+        assert_eq!(CounterEventV1::size(), 32 + 32 + 8 + 8 + 8);
+    }
+
+    #[test]
+    fn test_counter_event_v1_serialization_roundtrip() {
+        let original = event();
+
+        let serialized = original.serialize(EventDiscriminator::CountSetV1).unwrap();
+        assert_eq!(serialized.len(), 1 + CounterEventV1::size());
+
+        let (discriminator, payload) = EventDiscriminator::parse(&serialized).unwrap();
+        assert_eq!(discriminator, EventDiscriminator::CountSetV1);
+        assert_eq!(CounterEventV1::deserialize(payload).unwrap(), original);
+    }
+
+    #[test]
+    fn test_counter_event_v1_layout_is_discriminator_then_payload() {
+        let serialized = event()
+            .serialize(EventDiscriminator::CounterInitializedV1)
+            .unwrap();
+
+        assert_eq!(serialized[0], 1);
+        assert_eq!(&serialized[1..33], &[1; 32]);
+        assert_eq!(&serialized[33..65], &[2; 32]);
+        assert_eq!(&serialized[65..73], &3u64.to_le_bytes());
+        assert_eq!(&serialized[73..81], &4u64.to_le_bytes());
+        assert_eq!(&serialized[81..89], &5u64.to_le_bytes());
+    }
+}
@@ -0,0 +1,165 @@
+use {
+    crate::{
+        load_counter, AccountDiscriminatorError, CountReturnDataV1, Counter, CounterV2,
+        LoadCounterError, NO_PENDING_AUTHORITY,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{ReadError, WriteError},
+};
+
+pub struct AcceptAuthorityV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: AcceptAuthorityV1Accounts<'a>,
+}
+
+pub struct AcceptAuthorityV1Accounts<'a> {
+    pub new_authority: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+}
+
+#[derive(Debug)]
+pub enum AcceptAuthorityV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    NewAuthorityMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    NoPendingAuthority,
+    PendingAuthorityMismatch,
+}
+
+impl AcceptAuthorityV1<'_> {
+    /// Executes the accept authority instruction.
+    ///
+    /// Completes an authority transfer started by `ProposeAuthorityV1`: the signer, which must be
+    /// the pending authority, becomes the counter's authority and the pending authority is
+    /// cleared. The counter address is unchanged, since it is derived from the creator.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`AcceptAuthorityV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), AcceptAuthorityV1Error> {
+        let mut counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            match Counter::deserialize(&counter_data)? {
+                Counter::V1(_) => return Err(AcceptAuthorityV1Error::NoPendingAuthority),
+                Counter::V2(counter) => counter,
+            }
+        };
+
+        counter_state.authority = *self.accounts.new_authority.key();
+        counter_state.pending_authority = NO_PENDING_AUTHORITY;
+        counter_state.record_modification(&Clock::get()?);
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(AcceptAuthorityV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for AcceptAuthorityV1<'a> {
+    type Error = AcceptAuthorityV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = AcceptAuthorityV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for AcceptAuthorityV1Accounts<'a> {
+    type Error = AcceptAuthorityV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [new_authority, counter] = accounts else {
+            return Err(AcceptAuthorityV1Error::NotEnoughAccounts {
+                expected: 2,
+                observed: accounts.len(),
+            });
+        };
+
+        if !new_authority.is_signer() {
+            return Err(AcceptAuthorityV1Error::NewAuthorityMustBeSigner);
+        }
+
+        if !counter.is_writable() {
+            return Err(AcceptAuthorityV1Error::CounterMustBeWriteable);
+        }
+
+        let Counter::V2(counter_state) = load_counter(program_id, counter)? else {
+            return Err(AcceptAuthorityV1Error::NoPendingAuthority);
+        };
+
+        let Some(pending_authority) = counter_state.pending_authority() else {
+            return Err(AcceptAuthorityV1Error::NoPendingAuthority);
+        };
+
+        if pending_authority != new_authority.key() {
+            return Err(AcceptAuthorityV1Error::PendingAuthorityMismatch);
+        }
+
+        Ok(Self {
+            new_authority,
+            counter,
+        })
+    }
+}
+
+impl From<LoadCounterError> for AcceptAuthorityV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for AcceptAuthorityV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for AcceptAuthorityV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for AcceptAuthorityV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -0,0 +1,288 @@
+use {
+    crate::{
+        authorize_counter_authority, create_pda_account, find_delegate_v1, load_counter,
+        split_authority_accounts, AccountDiscriminator, AccountDiscriminatorError, DelegateV1,
+        LoadCounterError, MultisigAuthorizationError, DELEGATE_V1_SEED,
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, seeds,
+    },
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct ApproveDelegateV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: ApproveDelegateV1Accounts<'a>,
+    pub args: ApproveDelegateV1Args,
+}
+
+pub struct ApproveDelegateV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the system program instead.
+    pub owner: &'a AccountInfo,
+    /// Pays the delegate record's rent: the authority, or the first signing member of a multisig
+    /// authority, which cannot pay.
+    pub payer: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub delegate: &'a AccountInfo,
+    pub delegate_bump: u8,
+    pub system_program: &'a AccountInfo,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct ApproveDelegateV1Args {
+    pub delegate: Pubkey,
+    pub allowance: u64,
+    pub expiry_slot: u64,
+}
+
+#[derive(Debug)]
+pub enum ApproveDelegateV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    OwnerMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DelegateMustBeWriteable,
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DelegateAccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    MultisigSignerMustBeWriteable,
+}
+
+impl ApproveDelegateV1<'_> {
+    /// Executes the approve delegate instruction.
+    ///
+    /// Records `args.delegate` as the counter's delegate with `args.allowance` increments or
+    /// decrements remaining, valid until the current slot exceeds `args.expiry_slot`. Only the
+    /// authority may approve a delegate, with a multisig authority approving once enough of its
+    /// members sign, passed after the system program.
+    ///
+    /// The delegate record is created on first approval, with the authority, or the first signing
+    /// member of a multisig authority, paying its rent.
+    /// Approving again replaces the existing delegate, allowance and expiry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`ApproveDelegateV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), ApproveDelegateV1Error> {
+        let counter = self.accounts.counter.key();
+
+        if self.accounts.delegate.data_is_empty() {
+            let counter_ref = counter.as_ref();
+            let bump_ref = &[self.accounts.delegate_bump];
+            let seeds = seeds!(DELEGATE_V1_SEED, counter_ref, bump_ref);
+            let signer = Signer::from(&seeds);
+
+            create_pda_account(
+                self.accounts.payer,
+                self.accounts.delegate,
+                DelegateV1::size(),
+                self.program_id,
+                &[signer],
+            )?;
+        }
+
+        let state = DelegateV1 {
+            discriminator: AccountDiscriminator::DelegateV1Account,
+            counter: *counter,
+            delegate: self.args.delegate,
+            authority: *self.accounts.owner.key(),
+            bump: self.accounts.delegate_bump,
+            allowance: self.args.allowance,
+            expiry_slot: self.args.expiry_slot,
+        };
+
+        let written = state.serialize_into(&mut self.accounts.delegate.try_borrow_mut_data()?)?;
+
+        if written != DelegateV1::size() {
+            return Err(ApproveDelegateV1Error::SerializedSizeMismatch {
+                expected: DelegateV1::size(),
+                observed: written,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for ApproveDelegateV1<'a> {
+    type Error = ApproveDelegateV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ApproveDelegateV1Accounts::try_from((program_id, accounts))?;
+        let args = ApproveDelegateV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ApproveDelegateV1Accounts<'a> {
+    type Error = ApproveDelegateV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter, delegate, system_program], multisig_signers) =
+            split_authority_accounts(accounts)?;
+
+        // A multisig authority does not pay
+        if multisig_signers.is_none() && !owner.is_writable() {
+            return Err(ApproveDelegateV1Error::OwnerMustBeWriteable);
+        }
+
+        let counter_state = load_counter(program_id, counter)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        let payer = if let Some(multisig_signers) = multisig_signers {
+            let [payer, ..] = multisig_signers else {
+                return Err(ApproveDelegateV1Error::NotEnoughAccounts {
+                    expected: 5,
+                    observed: accounts.len(),
+                });
+            };
+            if !payer.is_writable() {
+                return Err(ApproveDelegateV1Error::MultisigSignerMustBeWriteable);
+            }
+
+            payer
+        } else {
+            owner
+        };
+
+        if !delegate.is_writable() {
+            return Err(ApproveDelegateV1Error::DelegateMustBeWriteable);
+        }
+
+        let (expected_delegate, delegate_bump) = find_delegate_v1(program_id, counter.key());
+        let observed_delegate = delegate.key();
+        if observed_delegate != &expected_delegate {
+            return Err(ApproveDelegateV1Error::DelegateAddressMismatch {
+                expected: expected_delegate,
+                observed: *observed_delegate,
+            });
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(ApproveDelegateV1Error::SystemProgramAddressMismatch);
+        }
+
+        if !delegate.data_is_empty() {
+            let delegate_data = delegate.try_borrow_data()?;
+            AccountDiscriminator::check(AccountDiscriminator::DelegateV1Account, &delegate_data)
+                .map_err(ApproveDelegateV1Error::DelegateAccountDiscriminatorError)?;
+        }
+
+        Ok(Self {
+            owner,
+            payer,
+            counter,
+            delegate,
+            delegate_bump,
+            system_program,
+        })
+    }
+}
+
+impl ApproveDelegateV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for ApproveDelegateV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for ApproveDelegateV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for ApproveDelegateV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for ApproveDelegateV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for ApproveDelegateV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for ApproveDelegateV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use {
+    crate::{
+        authorize_counter_authority, find_tombstone, load_counter, load_deactivated_counter,
+        record_tombstone, split_authority_accounts, AccountDiscriminator,
+        AccountDiscriminatorError, CountReturnDataV1, CounterEventV1, EventDiscriminator,
+        LoadCounterError, MultisigAuthorizationError, TombstoneError, TombstoneLookup,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::{ReadError, WriteError},
+};
+
+pub struct CloseCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: CloseCounterV1Accounts<'a>,
+}
+
+pub struct CloseCounterV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the system program instead.
+    pub owner: &'a AccountInfo,
+    /// Pays the tombstone's rent: the authority, or the first signing member of a multisig
+    /// authority, which cannot pay.
+    pub payer: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    /// The counter's creator, from which its address is derived.
+    pub creator: Pubkey,
+    /// The count reported as final, `0` for a counter that was already deactivated.
+    pub final_count: u64,
+    pub destination: &'a AccountInfo,
+    pub tombstone: &'a AccountInfo,
+    pub tombstone_lookup: TombstoneLookup,
+    pub system_program: &'a AccountInfo,
+}
+
+#[derive(Debug)]
+pub enum CloseCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    OwnerMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    DestinationMustBeWriteable,
+    DestinationMustNotBeCounter,
+    RentPayerMismatch { expected: Pubkey, observed: Pubkey },
+    TombstoneMustBeWriteable,
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
+    TombstoneCounterMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    SerializeError(WriteError),
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    MultisigSignerMustBeWriteable,
+}
+
+impl CloseCounterV1<'_> {
+    /// Executes the close counter instruction.
+    ///
+    /// Closes a counter account by:
+    /// - Creating the counter's tombstone
+    /// - Transferring every lamport in the counter to the destination
+    /// - Clearing the counter's data and handing it back to the system program
+    ///
+    /// Unlike `DeactivateCounterV1`, nothing is left at the counter address. The tombstone record
+    /// is what stops `InitializeCounterV1` and `InitializeSponsoredCounterV1` from creating a new
+    /// counter there. The owner pays the tombstone's rent. A multisig authority closes once enough
+    /// of its members sign, passed after the system program, and the first of them pays instead.
+    ///
+    /// A counter already deactivated with `DeactivateCounterV1` can be closed too, by the
+    /// authority kept in its deactivated record, releasing its remaining rent to the destination.
+    ///
+    /// Emits a `CounterClosedV1` event reporting the final count.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`CloseCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), CloseCounterV1Error> {
+        record_tombstone(
+            self.program_id,
+            self.accounts.payer,
+            self.accounts.tombstone,
+            self.accounts.counter.key(),
+            self.accounts.tombstone_lookup,
+        )?;
+
+        {
+            let mut counter_lamports = self.accounts.counter.try_borrow_mut_lamports()?;
+            *self.accounts.destination.try_borrow_mut_lamports()? += *counter_lamports;
+            *counter_lamports = 0;
+        }
+
+        self.accounts.counter.close()?;
+
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            &self.accounts.creator,
+            self.accounts.final_count,
+            0,
+        )?
+        .emit(EventDiscriminator::CounterClosedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for CloseCounterV1<'a> {
+    type Error = CloseCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CloseCounterV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for CloseCounterV1Accounts<'a> {
+    type Error = CloseCounterV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter, destination, tombstone, system_program], multisig_signers) =
+            split_authority_accounts(accounts)?;
+
+        // A multisig authority does not pay
+        if multisig_signers.is_none() && !owner.is_writable() {
+            return Err(CloseCounterV1Error::OwnerMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(CloseCounterV1Error::CounterMustBeWriteable);
+        }
+
+        // A deactivated counter keeps its authority, so the same authority closes it, and its
+        // sponsor was already refunded when it was deactivated
+        let is_deactivated = {
+            let counter_data = counter.try_borrow_data()?;
+            AccountDiscriminator::check_deactivated_counter(&counter_data).is_ok()
+        };
+        let (creator, final_count, authority, rent_payer) = if is_deactivated {
+            let deactivated = load_deactivated_counter(program_id, counter, owner.key())?;
+            (deactivated.owner, 0, deactivated.authority, None)
+        } else {
+            let counter_state = load_counter(program_id, counter)?;
+            (
+                *counter_state.owner(),
+                counter_state.count(),
+                *counter_state.authority(),
+                counter_state.rent_payer().copied(),
+            )
+        };
+
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            &authority,
+            owner,
+            multisig_signers,
+        )?;
+
+        let payer = if let Some(multisig_signers) = multisig_signers {
+            let [payer, ..] = multisig_signers else {
+                return Err(CloseCounterV1Error::NotEnoughAccounts {
+                    expected: 6,
+                    observed: accounts.len(),
+                });
+            };
+            if !payer.is_writable() {
+                return Err(CloseCounterV1Error::MultisigSignerMustBeWriteable);
+            }
+
+            payer
+        } else {
+            owner
+        };
+
+        if !destination.is_writable() {
+            return Err(CloseCounterV1Error::DestinationMustBeWriteable);
+        }
+
+        // The counter's lamports would be wiped along with it.
+        if destination.key() == counter.key() {
+            return Err(CloseCounterV1Error::DestinationMustNotBeCounter);
+        }
+
+        // A sponsor other than the authority paid the rent, so only the sponsor can receive it.
+        if let Some(rent_payer) = rent_payer.filter(|rent_payer| rent_payer != owner.key()) {
+            if destination.key() != &rent_payer {
+                return Err(CloseCounterV1Error::RentPayerMismatch {
+                    expected: rent_payer,
+                    observed: *destination.key(),
+                });
+            }
+        }
+
+        if !tombstone.is_writable() {
+            return Err(CloseCounterV1Error::TombstoneMustBeWriteable);
+        }
+
+        let tombstone_lookup = find_tombstone(program_id, tombstone, counter.key())?;
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(CloseCounterV1Error::SystemProgramAddressMismatch);
+        }
+
+        Ok(Self {
+            owner,
+            payer,
+            counter,
+            creator,
+            final_count,
+            destination,
+            tombstone,
+            tombstone_lookup,
+            system_program,
+        })
+    }
+}
+
+impl From<AccountDiscriminatorError> for CloseCounterV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for CloseCounterV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<TombstoneError> for CloseCounterV1Error {
+    fn from(err: TombstoneError) -> Self {
+        match err {
+            TombstoneError::ProgramError(err) => Self::ProgramError(err),
+            TombstoneError::TombstoneAddressMismatch { expected, observed } => {
+                Self::TombstoneAddressMismatch { expected, observed }
+            }
+            TombstoneError::AccountDiscriminatorError(err) => Self::AccountDiscriminatorError(err),
+            TombstoneError::DeserializeError(err) => Self::DeserializeError(err),
+            TombstoneError::TombstoneCounterMismatch { expected, observed } => {
+                Self::TombstoneCounterMismatch { expected, observed }
+            }
+            TombstoneError::SerializeError(err) => Self::SerializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for CloseCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for CloseCounterV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for CloseCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for CloseCounterV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use {
+    crate::{
+        authorize_counter_authority, load_counter_view, split_authority_accounts,
+        AccountDiscriminatorError, CountPolicyError, CountReturnDataV1, CounterEventV1,
+        CounterV1Mut, EventDiscriminator, LoadCounterError, MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct CompareAndSetCountV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: CompareAndSetCountV1Accounts<'a>,
+    pub args: CompareAndSetCountV1Args,
+}
+
+pub struct CompareAndSetCountV1Accounts<'a> {
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct CompareAndSetCountV1Args {
+    /// The count the client last observed. The instruction fails if the stored count differs.
+    pub expected_count: u64,
+    pub new_count: u64,
+}
+
+#[derive(Debug)]
+pub enum CompareAndSetCountV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    CountAboveMaximum,
+    CountBelowMinimum,
+    AuthorityMismatch,
+    CountMismatch { expected: u64, observed: u64 },
+    CounterFrozen,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    CooldownNotElapsed,
+}
+
+impl CompareAndSetCountV1<'_> {
+    /// Executes the compare and set count instruction.
+    ///
+    /// Sets the counter's count to `new_count` only if the stored count equals
+    /// `expected_count`, so clients that read, modify and write the count cannot silently
+    /// overwrite a concurrent update. Only the counter authority, or enough members of a multisig
+    /// authority, may set the count, and the counter's overflow policy is applied to the new value.
+    /// Emits a `CountSetV1` event.
+    ///
+    /// Rejected while the counter is frozen by `FreezeCounterV1`, or until the cooldown set by
+    /// `SetCooldownV1` has passed since the counter was last modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`CompareAndSetCountV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), CompareAndSetCountV1Error> {
+        let mut counter_data = self.accounts.counter.try_borrow_mut_data()?;
+        let mut counter_state = CounterV1Mut::try_from_bytes(&mut counter_data)?;
+
+        let old_count = counter_state.count();
+        if old_count != self.args.expected_count {
+            return Err(CompareAndSetCountV1Error::CountMismatch {
+                expected: self.args.expected_count,
+                observed: old_count,
+            });
+        }
+
+        let count = counter_state.policy()?.set(self.args.new_count)?;
+        counter_state.set_count(count);
+        counter_state.record_modification(&Clock::get()?);
+
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            counter_state.owner(),
+            old_count,
+            count,
+        )?
+        .emit(EventDiscriminator::CountSetV1)?;
+
+        CountReturnDataV1 { count }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for CompareAndSetCountV1<'a> {
+    type Error = CompareAndSetCountV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CompareAndSetCountV1Accounts::try_from((program_id, accounts))?;
+        let args = CompareAndSetCountV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for CompareAndSetCountV1Accounts<'a> {
+    type Error = CompareAndSetCountV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter], multisig_signers) = split_authority_accounts(accounts)?;
+
+        if !counter.is_writable() {
+            return Err(CompareAndSetCountV1Error::CounterMustBeWriteable);
+        }
+
+        let counter_data = counter.try_borrow_data()?;
+        let counter_state = load_counter_view(program_id, counter, &counter_data)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        if counter_state.is_frozen() {
+            return Err(CompareAndSetCountV1Error::CounterFrozen);
+        }
+
+        if counter_state.has_cooldown() && !counter_state.cooldown_elapsed(&Clock::get()?) {
+            return Err(CompareAndSetCountV1Error::CooldownNotElapsed);
+        }
+
+        Ok(Self { owner, counter })
+    }
+}
+
+impl CompareAndSetCountV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for CompareAndSetCountV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        CompareAndSetCountV1Error::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for CompareAndSetCountV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for CompareAndSetCountV1Error {
+    fn from(err: ProgramError) -> Self {
+        CompareAndSetCountV1Error::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for CompareAndSetCountV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for CompareAndSetCountV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<CountPolicyError> for CompareAndSetCountV1Error {
+    fn from(err: CountPolicyError) -> Self {
+        match err {
+            CountPolicyError::AboveMaximum => Self::CountAboveMaximum,
+            CountPolicyError::BelowMinimum => Self::CountBelowMinimum,
+        }
+    }
+}
+
+impl From<MultisigAuthorizationError> for CompareAndSetCountV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use {
     crate::{
-        find_counter_v1, AccountDiscriminator, AccountDiscriminatorError, DEACTIVATED_ACCOUNT_SIZE,
+        authorize_counter_authority, load_counter, split_authority_accounts,
+        AccountDiscriminatorError, CountReturnDataV1, Counter, CounterEventV1,
+        DeactivatedCounterV1, EventDiscriminator, LoadCounterError, MultisigAuthorizationError,
     },
     pinocchio::{
         account_info::AccountInfo,
@@ -8,7 +10,7 @@ use {
         pubkey::Pubkey,
         sysvars::{rent::Rent, Sysvar},
     },
-    wincode::ReadError,
+    wincode::{ReadError, WriteError},
 };
 
 pub struct DeactivateCounterV1<'a> {
@@ -17,9 +19,14 @@ pub struct DeactivateCounterV1<'a> {
 }
 
 pub struct DeactivateCounterV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the counter, or the sponsor when there is one, instead.
     pub owner: &'a AccountInfo,
     pub counter: &'a AccountInfo,
-    pub counter_bump: u8,
+    /// Receives the refund: the sponsor recorded as the counter's rent payer when it is not the
+    /// authority, and otherwise the authority, or the first signing member of a multisig
+    /// authority.
+    pub recipient: &'a AccountInfo,
 }
 
 #[derive(Debug)]
@@ -32,42 +39,87 @@ pub enum DeactivateCounterV1Error {
     CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
     DeserializeError(ReadError),
     AccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    RentPayerMustBeWriteable,
+    RentPayerMismatch { expected: Pubkey, observed: Pubkey },
+    SerializeError(WriteError),
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    MultisigSignerMustBeWriteable,
+    SerializedSizeMismatch { expected: usize, observed: usize },
 }
 
 impl DeactivateCounterV1<'_> {
     /// Executes the deactivate counter instruction.
     ///
     /// Deactivates a counter account by:
-    /// - Verifying the account discriminator is `CounterV1Account`
-    /// - Marking the account as deactivated with the `DeactivatedAccount` discriminator
-    /// - Resizing the account to 1 byte (discriminator only)
-    /// - Transferring all non-rent-exempt lamports to the owner
+    /// - Verifying the account discriminator is `CounterV1Account` or `CounterV2Account`
+    /// - Replacing the counter with a [`DeactivatedCounterV1`] record of its creator, bump seed,
+    ///   authority and pending authority
+    /// - Transferring all non-rent-exempt lamports to the counter's rent payer
     ///
-    /// The account remains with 1 byte of data and the rent-exempt minimum balance,
-    /// preventing reinitialization attacks while allowing the owner to reclaim most lamports.
+    /// The rent payer is the sponsor recorded by `InitializeSponsoredCounterV1` or
+    /// `ReactivateSponsoredCounterV1`, and otherwise the counter's current authority. A multisig
+    /// authority deactivates once enough of its members sign, and since it cannot receive
+    /// lamports, the first of them is refunded in its place.
+    ///
+    /// The account remains with the record and its rent-exempt minimum balance, preventing
+    /// reinitialization attacks while allowing the rent payer to reclaim most lamports. The
+    /// record keeps the authority, so the counter is only reactivated with its approval and
+    /// does not fall back to its creator.
+    ///
+    /// Emits a `CounterDeactivatedV1` event reporting the final count.
     ///
     /// # Errors
     ///
     /// Returns a [`Result`] containing a [`DeactivateCounterV1Error`] if execution fails.
     pub fn execute(&self) -> Result<(), DeactivateCounterV1Error> {
-        {
-            let mut data = self.accounts.counter.try_borrow_mut_data()?;
-            data[0] = u8::from(AccountDiscriminator::DeactivatedAccount);
-        }
+        let (deactivated, old_count) = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            let counter_state = Counter::deserialize(&counter_data)?;
+            (
+                DeactivatedCounterV1::from_counter(&counter_state),
+                counter_state.count(),
+            )
+        };
 
         let rent = Rent::get()?;
-        let rent_exempt_minimum = rent.minimum_balance(DEACTIVATED_ACCOUNT_SIZE);
+        let rent_exempt_minimum = rent.minimum_balance(DeactivatedCounterV1::size());
 
-        self.accounts.counter.resize(DEACTIVATED_ACCOUNT_SIZE)?;
+        self.accounts.counter.resize(DeactivatedCounterV1::size())?;
+
+        let written =
+            deactivated.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != DeactivatedCounterV1::size() {
+            return Err(DeactivateCounterV1Error::SerializedSizeMismatch {
+                expected: DeactivatedCounterV1::size(),
+                observed: written,
+            });
+        }
 
         let total_lamports = *self.accounts.counter.try_borrow_lamports()?;
         let lamports_to_transfer = total_lamports.saturating_sub(rent_exempt_minimum);
 
         {
             *self.accounts.counter.try_borrow_mut_lamports()? -= lamports_to_transfer;
-            *self.accounts.owner.try_borrow_mut_lamports()? += lamports_to_transfer;
+            *self.accounts.recipient.try_borrow_mut_lamports()? += lamports_to_transfer;
         }
 
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            &deactivated.owner,
+            old_count,
+            0,
+        )?
+        .emit(EventDiscriminator::CounterDeactivatedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
         Ok(())
     }
 }
@@ -90,18 +142,18 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for DeactivateCounterV1Accounts<'
     type Error = DeactivateCounterV1Error;
 
     fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let [owner, counter] = accounts else {
-            return Err(DeactivateCounterV1Error::NotEnoughAccounts {
-                expected: 2,
-                observed: accounts.len(),
-            });
+        let (owner, counter, rent_payer, multisig_accounts) = match accounts {
+            [owner, counter, rent_payer] if owner.is_signer() => {
+                (owner, counter, Some(rent_payer), None)
+            }
+            _ => {
+                let ([owner, counter], multisig_accounts) = split_authority_accounts(accounts)?;
+                (owner, counter, None, multisig_accounts)
+            }
         };
 
-        if !owner.is_signer() {
-            return Err(DeactivateCounterV1Error::OwnerMustBeSigner);
-        }
-
-        if !owner.is_writable() {
+        // A multisig authority does not receive the refund
+        if multisig_accounts.is_none() && !owner.is_writable() {
             return Err(DeactivateCounterV1Error::OwnerMustBeWriteable);
         }
 
@@ -109,22 +161,74 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for DeactivateCounterV1Accounts<'
             return Err(DeactivateCounterV1Error::CounterMustBeWriteable);
         }
 
-        let (expected_counter, counter_bump) = find_counter_v1(program_id, owner.key());
-        let observed_counter = counter.key();
-        if observed_counter != &expected_counter {
-            return Err(DeactivateCounterV1Error::CounterAddressMismatch {
-                expected: expected_counter,
-                observed: *observed_counter,
-            });
-        }
+        let counter_state = load_counter(program_id, counter)?;
+
+        // The refund goes to the owner account unless a sponsor other than the authority paid
+        // the rent, in which case the sponsor must be passed as the third account.
+        let expected_rent_payer = counter_state
+            .rent_payer()
+            .filter(|rent_payer| *rent_payer != owner.key());
+
+        // The sponsor, when there is one, comes before the signing members
+        let (rent_payer, multisig_signers) = match (expected_rent_payer, multisig_accounts) {
+            (Some(_), Some([rent_payer, multisig_signers @ ..])) => {
+                (Some(rent_payer), Some(multisig_signers))
+            }
+            (_, multisig_accounts) => (rent_payer, multisig_accounts),
+        };
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        let owner_recipient = if let Some(multisig_signers) = multisig_signers {
+            let [first_signer, ..] = multisig_signers else {
+                return Err(DeactivateCounterV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: accounts.len(),
+                });
+            };
+            if rent_payer.is_none() && !first_signer.is_writable() {
+                return Err(DeactivateCounterV1Error::MultisigSignerMustBeWriteable);
+            }
+
+            first_signer
+        } else {
+            owner
+        };
 
-        let counter_data = counter.try_borrow_data()?;
-        AccountDiscriminator::check(AccountDiscriminator::CounterV1Account, &counter_data)?;
+        let recipient = match (expected_rent_payer, rent_payer) {
+            (None, None) => owner_recipient,
+            (Some(_), None) => {
+                return Err(DeactivateCounterV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: accounts.len(),
+                })
+            }
+            (expected, Some(rent_payer)) => {
+                let expected_rent_payer = *expected.unwrap_or(owner.key());
+                if rent_payer.key() != &expected_rent_payer {
+                    return Err(DeactivateCounterV1Error::RentPayerMismatch {
+                        expected: expected_rent_payer,
+                        observed: *rent_payer.key(),
+                    });
+                }
+
+                if !rent_payer.is_writable() {
+                    return Err(DeactivateCounterV1Error::RentPayerMustBeWriteable);
+                }
+
+                rent_payer
+            }
+        };
 
         Ok(Self {
             owner,
             counter,
-            counter_bump,
+            recipient,
         })
     }
 }
@@ -135,6 +239,21 @@ impl From<AccountDiscriminatorError> for DeactivateCounterV1Error {
     }
 }
 
+impl From<LoadCounterError> for DeactivateCounterV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
 impl From<ProgramError> for DeactivateCounterV1Error {
     fn from(err: ProgramError) -> Self {
         Self::ProgramError(err)
@@ -146,3 +265,40 @@ impl From<ReadError> for DeactivateCounterV1Error {
         Self::DeserializeError(err)
     }
 }
+
+impl From<WriteError> for DeactivateCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for DeactivateCounterV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use {
+    crate::{
+        find_indexed_counter_v1, AccountDiscriminator, AccountDiscriminatorError,
+        CountReturnDataV1, CounterEventV1, EventDiscriminator, IndexedCounterV1,
+        DEACTIVATED_ACCOUNT_SIZE,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{rent::Rent, Sysvar},
+    },
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct DeactivateIndexedCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: DeactivateIndexedCounterV1Accounts<'a>,
+    pub args: DeactivateIndexedCounterV1Args,
+}
+
+pub struct DeactivateIndexedCounterV1Accounts<'a> {
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct DeactivateIndexedCounterV1Args {
+    pub index: u32,
+}
+
+#[derive(Debug)]
+pub enum DeactivateIndexedCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    OwnerMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    SerializeError(WriteError),
+}
+
+impl DeactivateIndexedCounterV1<'_> {
+    /// Executes the deactivate indexed counter instruction.
+    ///
+    /// Deactivates an indexed counter account the same way `DeactivateCounterV1` deactivates a
+    /// counter: the account is marked with the `DeactivatedAccount` discriminator, resized to
+    /// 1 byte, and all non-rent-exempt lamports are transferred to the owner.
+    ///
+    /// Emits a `CounterDeactivatedV1` event reporting the final count.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`DeactivateIndexedCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), DeactivateIndexedCounterV1Error> {
+        let counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            IndexedCounterV1::deserialize(&counter_data)?
+        };
+
+        {
+            let mut data = self.accounts.counter.try_borrow_mut_data()?;
+            data[0] = u8::from(AccountDiscriminator::DeactivatedAccount);
+        }
+
+        let rent = Rent::get()?;
+        let rent_exempt_minimum = rent.minimum_balance(DEACTIVATED_ACCOUNT_SIZE);
+
+        self.accounts.counter.resize(DEACTIVATED_ACCOUNT_SIZE)?;
+
+        let total_lamports = *self.accounts.counter.try_borrow_lamports()?;
+        let lamports_to_transfer = total_lamports.saturating_sub(rent_exempt_minimum);
+
+        {
+            *self.accounts.counter.try_borrow_mut_lamports()? -= lamports_to_transfer;
+            *self.accounts.owner.try_borrow_mut_lamports()? += lamports_to_transfer;
+        }
+
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            &counter_state.owner,
+            counter_state.count,
+            0,
+        )?
+        .emit(EventDiscriminator::CounterDeactivatedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for DeactivateIndexedCounterV1<'a> {
+    type Error = DeactivateIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let args = DeactivateIndexedCounterV1Args::deserialize(args)?;
+        let accounts = DeactivateIndexedCounterV1Accounts::try_from((program_id, accounts, &args))?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo], &DeactivateIndexedCounterV1Args)>
+    for DeactivateIndexedCounterV1Accounts<'a>
+{
+    type Error = DeactivateIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&Pubkey, &'a [AccountInfo], &DeactivateIndexedCounterV1Args),
+    ) -> Result<Self, Self::Error> {
+        let [owner, counter] = accounts else {
+            return Err(DeactivateIndexedCounterV1Error::NotEnoughAccounts {
+                expected: 2,
+                observed: accounts.len(),
+            });
+        };
+
+        if !owner.is_signer() {
+            return Err(DeactivateIndexedCounterV1Error::OwnerMustBeSigner);
+        }
+
+        if !owner.is_writable() {
+            return Err(DeactivateIndexedCounterV1Error::OwnerMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(DeactivateIndexedCounterV1Error::CounterMustBeWriteable);
+        }
+
+        let (expected_counter, _bump) =
+            find_indexed_counter_v1(program_id, owner.key(), args.index);
+        let observed_counter = counter.key();
+        if observed_counter != &expected_counter {
+            return Err(DeactivateIndexedCounterV1Error::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: *observed_counter,
+            });
+        }
+
+        let counter_data = counter.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::IndexedCounterV1Account, &counter_data)?;
+
+        Ok(Self { owner, counter })
+    }
+}
+
+impl DeactivateIndexedCounterV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for DeactivateIndexedCounterV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for DeactivateIndexedCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for DeactivateIndexedCounterV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for DeactivateIndexedCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
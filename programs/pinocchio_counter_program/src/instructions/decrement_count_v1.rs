@@ -1,6 +1,16 @@
 use {
-    crate::{find_counter_v1, AccountDiscriminator, AccountDiscriminatorError, CounterV1},
-    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    crate::{
+        authorize_counter_authority, authorize_delegate, consume_delegate_allowance,
+        load_counter_view, split_authority_accounts, AccountDiscriminatorError, CountPolicyError,
+        CountReturnDataV1, CounterEventV1, CounterV1Mut, DelegateAuthorizationError,
+        EventDiscriminator, LoadCounterError, MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
     wincode::{ReadError, WriteError},
 };
 
@@ -10,8 +20,12 @@ pub struct DecrementCountV1<'a> {
 }
 
 pub struct DecrementCountV1Accounts<'a> {
-    pub owner: &'a AccountInfo,
+    /// The counter authority, any signer of a public counter, or the approved delegate when
+    /// `delegate` is present. A multisig authority does not sign, and its signing members follow
+    /// the counter instead.
+    pub signer: &'a AccountInfo,
     pub counter: &'a AccountInfo,
+    pub delegate: Option<&'a AccountInfo>,
 }
 
 #[derive(Debug)]
@@ -25,38 +39,65 @@ pub enum DecrementCountV1Error {
     SerializeError(WriteError),
     SerializedSizeMismatch { expected: usize, observed: usize },
     AccountDiscriminatorError(AccountDiscriminatorError),
+    CountAboveMaximum,
+    CountBelowMinimum,
+    DelegateMustBeWriteable,
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DelegateAccountDiscriminatorError(AccountDiscriminatorError),
+    DelegateMismatch,
+    DelegateExpired { expiry_slot: u64, current_slot: u64 },
+    DelegateAllowanceExhausted,
+    DelegateAuthorityChanged,
+    AuthorityMismatch,
+    CounterFrozen,
+    CooldownNotElapsed,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
 }
 
 impl DecrementCountV1<'_> {
-    /// Decrements count by 1. Only the owner may decrement.
+    /// Decrements count by 1. Only the counter authority, or an approved delegate with
+    /// remaining allowance, may decrement, unless the counter is public, in which case any signer
+    /// may. A delegate consumes one unit of allowance per call. A multisig authority decrements
+    /// as in `IncrementCountV1`.
     ///
-    /// Count saturates at `0` and will not underflow.
-    /// If the count is `0`, the count will remain at `0`.
+    /// The counter's overflow policy is applied when the count would drop below its minimum.
+    /// `CounterV1` accounts use the default policy, so the count saturates at `0`.
+    ///
+    /// Emits a `CountDecrementedV1` event.
+    ///
+    /// Rejected while the counter is frozen by `FreezeCounterV1`, or until the cooldown set by
+    /// `SetCooldownV1` has passed since the counter was last modified.
     ///
     /// # Errors
     ///
     /// Returns a [`Result`] containing a [`DecrementCountV1Error`] if execution fails.
     pub fn execute(&self) -> Result<(), DecrementCountV1Error> {
-        let mut counter_state = {
-            let counter_data = self.accounts.counter.try_borrow_data()?;
-            CounterV1::deserialize(&counter_data)?
-        };
+        let mut counter_data = self.accounts.counter.try_borrow_mut_data()?;
+        let mut counter_state = CounterV1Mut::try_from_bytes(&mut counter_data)?;
 
-        counter_state.count = counter_state.count.saturating_sub(1);
+        let old_count = counter_state.count();
+        let count = counter_state.policy()?.decrement(old_count, 1)?;
+        counter_state.set_count(count);
+        counter_state.record_modification(&Clock::get()?);
 
-        let serialized = counter_state.serialize()?;
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            counter_state.owner(),
+            old_count,
+            count,
+        )?
+        .emit(EventDiscriminator::CountDecrementedV1)?;
 
-        if serialized.len() != CounterV1::size() {
-            return Err(DecrementCountV1Error::SerializedSizeMismatch {
-                expected: CounterV1::size(),
-                observed: serialized.len(),
-            });
+        if let Some(delegate) = self.accounts.delegate {
+            consume_delegate_allowance(delegate)?;
         }
 
-        self.accounts
-            .counter
-            .try_borrow_mut_data()?
-            .copy_from_slice(&serialized);
+        CountReturnDataV1 { count }.set();
 
         Ok(())
     }
@@ -80,34 +121,56 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for DecrementCountV1Accounts<'a>
     type Error = DecrementCountV1Error;
 
     fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let [owner, counter] = accounts else {
-            return Err(DecrementCountV1Error::NotEnoughAccounts {
-                expected: 2,
-                observed: accounts.len(),
-            });
+        let (signer, counter, delegate, multisig_signers) = match accounts {
+            [delegate_signer, counter, delegate] if delegate_signer.is_signer() => {
+                (delegate_signer, counter, Some(delegate), None)
+            }
+            _ => {
+                let ([owner, counter], multisig_signers) = split_authority_accounts(accounts)?;
+                (owner, counter, None, multisig_signers)
+            }
         };
 
-        if !owner.is_signer() {
-            return Err(DecrementCountV1Error::OwnerMustBeSigner);
-        }
-
         if !counter.is_writable() {
             return Err(DecrementCountV1Error::CounterMustBeWriteable);
         }
 
-        let (expected_counter, _bump) = find_counter_v1(program_id, owner.key());
-        let observed_counter = counter.key();
-        if observed_counter != &expected_counter {
-            return Err(DecrementCountV1Error::CounterAddressMismatch {
-                expected: expected_counter,
-                observed: *observed_counter,
-            });
+        let counter_data = counter.try_borrow_data()?;
+        let counter_state = load_counter_view(program_id, counter, &counter_data)?;
+
+        if multisig_signers.is_some() {
+            authorize_counter_authority(
+                program_id,
+                counter.key(),
+                counter_state.authority(),
+                signer,
+                multisig_signers,
+            )?;
+        } else if let Some(delegate) = delegate {
+            authorize_delegate(
+                program_id,
+                counter.key(),
+                counter_state.authority(),
+                signer.key(),
+                delegate,
+            )?;
+        } else if !counter_state.allows_count_update_by(signer.key())? {
+            return Err(DecrementCountV1Error::AuthorityMismatch);
         }
 
-        let counter_data = counter.try_borrow_data()?;
-        AccountDiscriminator::check(AccountDiscriminator::CounterV1Account, &counter_data)?;
+        if counter_state.is_frozen() {
+            return Err(DecrementCountV1Error::CounterFrozen);
+        }
+
+        if counter_state.has_cooldown() && !counter_state.cooldown_elapsed(&Clock::get()?) {
+            return Err(DecrementCountV1Error::CooldownNotElapsed);
+        }
 
-        Ok(Self { owner, counter })
+        Ok(Self {
+            signer,
+            counter,
+            delegate,
+        })
     }
 }
 
@@ -123,6 +186,21 @@ impl From<ProgramError> for DecrementCountV1Error {
     }
 }
 
+impl From<LoadCounterError> for DecrementCountV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
 impl From<ReadError> for DecrementCountV1Error {
     fn from(err: ReadError) -> Self {
         Self::DeserializeError(err)
@@ -134,3 +212,75 @@ impl From<WriteError> for DecrementCountV1Error {
         Self::SerializeError(err)
     }
 }
+
+impl From<CountPolicyError> for DecrementCountV1Error {
+    fn from(err: CountPolicyError) -> Self {
+        match err {
+            CountPolicyError::AboveMaximum => Self::CountAboveMaximum,
+            CountPolicyError::BelowMinimum => Self::CountBelowMinimum,
+        }
+    }
+}
+
+impl From<DelegateAuthorizationError> for DecrementCountV1Error {
+    fn from(err: DelegateAuthorizationError) -> Self {
+        match err {
+            DelegateAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            DelegateAuthorizationError::DelegateMustBeWriteable => Self::DelegateMustBeWriteable,
+            DelegateAuthorizationError::DelegateAddressMismatch { expected, observed } => {
+                Self::DelegateAddressMismatch { expected, observed }
+            }
+            DelegateAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::DelegateAccountDiscriminatorError(err)
+            }
+            DelegateAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            DelegateAuthorizationError::SerializeError(err) => Self::SerializeError(err),
+            DelegateAuthorizationError::SerializedSizeMismatch { expected, observed } => {
+                Self::SerializedSizeMismatch { expected, observed }
+            }
+            DelegateAuthorizationError::DelegateMismatch => Self::DelegateMismatch,
+            DelegateAuthorizationError::DelegateAuthorityChanged => Self::DelegateAuthorityChanged,
+            DelegateAuthorizationError::DelegateExpired {
+                expiry_slot,
+                current_slot,
+            } => Self::DelegateExpired {
+                expiry_slot,
+                current_slot,
+            },
+            DelegateAuthorizationError::DelegateAllowanceExhausted => {
+                Self::DelegateAllowanceExhausted
+            }
+        }
+    }
+}
+
+impl From<MultisigAuthorizationError> for DecrementCountV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use {
+    crate::{
+        authorize_counter_authority, load_counter_view, split_authority_accounts,
+        AccountDiscriminatorError, BatchOperationV1, CountPolicyError, CountReturnDataV1,
+        CounterEventV1, CounterV1Mut, EventDiscriminator, LoadCounterError,
+        MultisigAuthorizationError, MAX_BATCH_OPERATIONS,
+    },
+    core::mem::size_of,
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{ReadError, WriteError},
+};
+
+/// Size of the length prefix wincode writes ahead of the elements of a `Vec`.
+const LENGTH_PREFIX_SIZE: usize = size_of::<u64>();
+
+pub struct ExecuteBatchV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: ExecuteBatchV1Accounts<'a>,
+    pub args: ExecuteBatchV1Args<'a>,
+}
+
+pub struct ExecuteBatchV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the counter instead.
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    /// `true` while the cooldown set by `SetCooldownV1` has not passed, which rejects batches
+    /// containing any operation that changes the count.
+    pub cooldown_pending: bool,
+}
+
+/// The operations of an `ExecuteBatchV1` instruction, wincode-encoded as a
+/// `Vec<BatchOperationV1>`.
+///
+/// The program has no heap, so the operations are decoded one at a time from the instruction data
+/// instead of into a `Vec`.
+pub struct ExecuteBatchV1Args<'a> {
+    operations: &'a [u8],
+}
+
+#[derive(Debug)]
+pub enum ExecuteBatchV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    CountAboveMaximum,
+    CountBelowMinimum,
+    AuthorityMismatch,
+    CounterFrozen,
+    CooldownNotElapsed,
+    EmptyBatch,
+    TooManyOperations { maximum: u8, observed: u64 },
+    OperationsLengthMismatch { expected: usize, observed: usize },
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+}
+
+impl ExecuteBatchV1<'_> {
+    /// Executes the execute batch instruction.
+    ///
+    /// Applies each operation in `args` to the count in order, then writes the result once. The
+    /// accounts are validated once for the whole batch, and only the counter authority may
+    /// execute a batch, with a multisig authority approving as in `IncrementCountV1`.
+    ///
+    /// The counter's overflow policy is applied to every operation. If any operation is rejected,
+    /// the whole batch fails and the count is left unchanged. Emits a single `CountSetV1` event
+    /// from the original to the final count.
+    ///
+    /// Rejected while the counter is frozen by `FreezeCounterV1`. A batch containing an operation
+    /// that changes the count is also rejected until the cooldown set by `SetCooldownV1` has
+    /// passed, and the batch counts as a single modification.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`ExecuteBatchV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), ExecuteBatchV1Error> {
+        let mut counter_data = self.accounts.counter.try_borrow_mut_data()?;
+        let mut counter_state = CounterV1Mut::try_from_bytes(&mut counter_data)?;
+
+        let policy = counter_state.policy()?;
+        let old_count = counter_state.count();
+        let mut count = old_count;
+        for operation in self.args.operations() {
+            let operation = operation?;
+            if self.accounts.cooldown_pending && operation.is_cooldown_limited() {
+                return Err(ExecuteBatchV1Error::CooldownNotElapsed);
+            }
+            count = operation.apply(&policy, count)?;
+        }
+
+        counter_state.set_count(count);
+        counter_state.record_modification(&Clock::get()?);
+
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            counter_state.owner(),
+            old_count,
+            count,
+        )?
+        .emit(EventDiscriminator::CountSetV1)?;
+
+        CountReturnDataV1 { count }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &'a [u8])> for ExecuteBatchV1<'a> {
+    type Error = ExecuteBatchV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &'a [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ExecuteBatchV1Accounts::try_from((program_id, accounts))?;
+        let args = ExecuteBatchV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ExecuteBatchV1Accounts<'a> {
+    type Error = ExecuteBatchV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter], multisig_signers) = split_authority_accounts(accounts)?;
+
+        if !counter.is_writable() {
+            return Err(ExecuteBatchV1Error::CounterMustBeWriteable);
+        }
+
+        let counter_data = counter.try_borrow_data()?;
+        let counter_state = load_counter_view(program_id, counter, &counter_data)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        if counter_state.is_frozen() {
+            return Err(ExecuteBatchV1Error::CounterFrozen);
+        }
+
+        let cooldown_pending =
+            counter_state.has_cooldown() && !counter_state.cooldown_elapsed(&Clock::get()?);
+
+        Ok(Self {
+            owner,
+            counter,
+            cooldown_pending,
+        })
+    }
+}
+
+impl<'a> ExecuteBatchV1Args<'a> {
+    /// Reads the operations from a wincode-encoded `Vec<BatchOperationV1>`, without decoding
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExecuteBatchV1Error`] if the batch is empty, holds more than
+    /// [`MAX_BATCH_OPERATIONS`] operations, or its length does not match its length prefix.
+    pub fn deserialize(src: &'a [u8]) -> Result<Self, ExecuteBatchV1Error> {
+        let Some((operation_count, operations)) = src.split_first_chunk::<LENGTH_PREFIX_SIZE>()
+        else {
+            return Err(ExecuteBatchV1Error::OperationsLengthMismatch {
+                expected: LENGTH_PREFIX_SIZE,
+                observed: src.len(),
+            });
+        };
+
+        let operation_count = u64::from_le_bytes(*operation_count);
+        if operation_count == 0 {
+            return Err(ExecuteBatchV1Error::EmptyBatch);
+        }
+
+        if operation_count > u64::from(MAX_BATCH_OPERATIONS) {
+            return Err(ExecuteBatchV1Error::TooManyOperations {
+                maximum: MAX_BATCH_OPERATIONS,
+                observed: operation_count,
+            });
+        }
+
+        // Bounded by MAX_BATCH_OPERATIONS above
+        let expected_len = operation_count as usize * BatchOperationV1::size();
+        if operations.len() != expected_len {
+            return Err(ExecuteBatchV1Error::OperationsLengthMismatch {
+                expected: expected_len,
+                observed: operations.len(),
+            });
+        }
+
+        Ok(Self { operations })
+    }
+
+    /// Returns the number of operations in the batch.
+    #[must_use]
+    pub const fn operation_count(&self) -> usize {
+        self.operations.len() / BatchOperationV1::size()
+    }
+
+    /// Decodes the operations in order.
+    pub fn operations(&self) -> impl Iterator<Item = Result<BatchOperationV1, ReadError>> + 'a {
+        self.operations
+            .chunks_exact(BatchOperationV1::size())
+            .map(BatchOperationV1::deserialize)
+    }
+}
+
+impl From<AccountDiscriminatorError> for ExecuteBatchV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for ExecuteBatchV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for ExecuteBatchV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for ExecuteBatchV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for ExecuteBatchV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<CountPolicyError> for ExecuteBatchV1Error {
+    fn from(err: CountPolicyError) -> Self {
+        match err {
+            CountPolicyError::AboveMaximum => Self::CountAboveMaximum,
+            CountPolicyError::BelowMinimum => Self::CountBelowMinimum,
+        }
+    }
+}
+
+impl From<MultisigAuthorizationError> for ExecuteBatchV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use {
+    crate::{load_counter_view, AccountDiscriminatorError, CountReturnDataV1, LoadCounterError},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::ReadError,
+};
+
+pub struct GetCountV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: GetCountV1Accounts<'a>,
+}
+
+pub struct GetCountV1Accounts<'a> {
+    pub counter: &'a AccountInfo,
+    pub count: u64,
+}
+
+#[derive(Debug)]
+pub enum GetCountV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    AccountDiscriminatorError(AccountDiscriminatorError),
+}
+
+impl GetCountV1<'_> {
+    /// Executes the get count instruction.
+    ///
+    /// Sets the counter's current count as the return data without modifying any account, so
+    /// the count can be read through CPI or simulation. Anyone may call it and no signer is
+    /// required.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`GetCountV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), GetCountV1Error> {
+        CountReturnDataV1 {
+            count: self.accounts.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for GetCountV1<'a> {
+    type Error = GetCountV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = GetCountV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for GetCountV1Accounts<'a> {
+    type Error = GetCountV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [counter] = accounts else {
+            return Err(GetCountV1Error::NotEnoughAccounts {
+                expected: 1,
+                observed: accounts.len(),
+            });
+        };
+
+        let counter_data = counter.try_borrow_data()?;
+        let counter_state = load_counter_view(program_id, counter, &counter_data)?;
+
+        Ok(Self {
+            counter,
+            count: counter_state.count(),
+        })
+    }
+}
+
+impl From<ProgramError> for GetCountV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<LoadCounterError> for GetCountV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
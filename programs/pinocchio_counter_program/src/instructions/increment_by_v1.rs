@@ -0,0 +1,307 @@
+use {
+    crate::{
+        authorize_counter_authority, authorize_delegate, consume_delegate_allowance,
+        load_counter_view, split_authority_accounts, AccountDiscriminatorError, CountPolicyError,
+        CountReturnDataV1, CounterEventV1, CounterV1Mut, DelegateAuthorizationError,
+        EventDiscriminator, LoadCounterError, MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct IncrementByV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: IncrementByV1Accounts<'a>,
+    pub args: IncrementByV1Args,
+}
+
+pub struct IncrementByV1Accounts<'a> {
+    /// The counter authority, any signer of a public counter, or the approved delegate when
+    /// `delegate` is present. A multisig authority does not sign, and its signing members follow
+    /// the counter instead.
+    pub signer: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub delegate: Option<&'a AccountInfo>,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct IncrementByV1Args {
+    pub amount: u64,
+}
+
+#[derive(Debug)]
+pub enum IncrementByV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    CountAboveMaximum,
+    CountBelowMinimum,
+    DelegateMustBeWriteable,
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DelegateAccountDiscriminatorError(AccountDiscriminatorError),
+    DelegateMismatch,
+    DelegateExpired { expiry_slot: u64, current_slot: u64 },
+    DelegateAllowanceExhausted,
+    DelegateAuthorityChanged,
+    AuthorityMismatch,
+    CounterFrozen,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    CooldownNotElapsed,
+}
+
+impl IncrementByV1<'_> {
+    /// Increments the count by `amount`. Only the counter authority, or an approved delegate with
+    /// remaining allowance, may increment, unless the counter is public, in which case any signer
+    /// may. A delegate consumes one unit of allowance per call.
+    ///
+    /// The counter's overflow policy is applied, and a multisig authority approves, as in
+    /// `IncrementCountV1`.
+    ///
+    /// Emits a `CountIncrementedV1` event.
+    ///
+    /// Rejected while the counter is frozen by `FreezeCounterV1`, or until the cooldown set by
+    /// `SetCooldownV1` has passed since the counter was last modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`IncrementByV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), IncrementByV1Error> {
+        let mut counter_data = self.accounts.counter.try_borrow_mut_data()?;
+        let mut counter_state = CounterV1Mut::try_from_bytes(&mut counter_data)?;
+
+        let old_count = counter_state.count();
+        let count = counter_state
+            .policy()?
+            .increment(old_count, self.args.amount)?;
+        counter_state.set_count(count);
+        counter_state.record_modification(&Clock::get()?);
+
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            counter_state.owner(),
+            old_count,
+            count,
+        )?
+        .emit(EventDiscriminator::CountIncrementedV1)?;
+
+        if let Some(delegate) = self.accounts.delegate {
+            consume_delegate_allowance(delegate)?;
+        }
+
+        CountReturnDataV1 { count }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for IncrementByV1<'a> {
+    type Error = IncrementByV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = IncrementByV1Accounts::try_from((program_id, accounts))?;
+        let args = IncrementByV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for IncrementByV1Accounts<'a> {
+    type Error = IncrementByV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let (signer, counter, delegate, multisig_signers) = match accounts {
+            [delegate_signer, counter, delegate] if delegate_signer.is_signer() => {
+                (delegate_signer, counter, Some(delegate), None)
+            }
+            _ => {
+                let ([owner, counter], multisig_signers) = split_authority_accounts(accounts)?;
+                (owner, counter, None, multisig_signers)
+            }
+        };
+
+        if !counter.is_writable() {
+            return Err(IncrementByV1Error::CounterMustBeWriteable);
+        }
+
+        let counter_data = counter.try_borrow_data()?;
+        let counter_state = load_counter_view(program_id, counter, &counter_data)?;
+
+        if multisig_signers.is_some() {
+            authorize_counter_authority(
+                program_id,
+                counter.key(),
+                counter_state.authority(),
+                signer,
+                multisig_signers,
+            )?;
+        } else if let Some(delegate) = delegate {
+            authorize_delegate(
+                program_id,
+                counter.key(),
+                counter_state.authority(),
+                signer.key(),
+                delegate,
+            )?;
+        } else if !counter_state.allows_count_update_by(signer.key())? {
+            return Err(IncrementByV1Error::AuthorityMismatch);
+        }
+
+        if counter_state.is_frozen() {
+            return Err(IncrementByV1Error::CounterFrozen);
+        }
+
+        if counter_state.has_cooldown() && !counter_state.cooldown_elapsed(&Clock::get()?) {
+            return Err(IncrementByV1Error::CooldownNotElapsed);
+        }
+
+        Ok(Self {
+            signer,
+            counter,
+            delegate,
+        })
+    }
+}
+
+impl IncrementByV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for IncrementByV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        IncrementByV1Error::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for IncrementByV1Error {
+    fn from(err: ProgramError) -> Self {
+        IncrementByV1Error::ProgramError(err)
+    }
+}
+
+impl From<LoadCounterError> for IncrementByV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ReadError> for IncrementByV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for IncrementByV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<CountPolicyError> for IncrementByV1Error {
+    fn from(err: CountPolicyError) -> Self {
+        match err {
+            CountPolicyError::AboveMaximum => Self::CountAboveMaximum,
+            CountPolicyError::BelowMinimum => Self::CountBelowMinimum,
+        }
+    }
+}
+
+impl From<DelegateAuthorizationError> for IncrementByV1Error {
+    fn from(err: DelegateAuthorizationError) -> Self {
+        match err {
+            DelegateAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            DelegateAuthorizationError::DelegateMustBeWriteable => Self::DelegateMustBeWriteable,
+            DelegateAuthorizationError::DelegateAddressMismatch { expected, observed } => {
+                Self::DelegateAddressMismatch { expected, observed }
+            }
+            DelegateAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::DelegateAccountDiscriminatorError(err)
+            }
+            DelegateAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            DelegateAuthorizationError::SerializeError(err) => Self::SerializeError(err),
+            DelegateAuthorizationError::SerializedSizeMismatch { expected, observed } => {
+                Self::SerializedSizeMismatch { expected, observed }
+            }
+            DelegateAuthorizationError::DelegateMismatch => Self::DelegateMismatch,
+            DelegateAuthorizationError::DelegateAuthorityChanged => Self::DelegateAuthorityChanged,
+            DelegateAuthorizationError::DelegateExpired {
+                expiry_slot,
+                current_slot,
+            } => Self::DelegateExpired {
+                expiry_slot,
+                current_slot,
+            },
+            DelegateAuthorizationError::DelegateAllowanceExhausted => {
+                Self::DelegateAllowanceExhausted
+            }
+        }
+    }
+}
+
+impl From<MultisigAuthorizationError> for IncrementByV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use {
+    crate::{
+        create_counter_shard_v1_address, AccountDiscriminator, AccountDiscriminatorError,
+        CountReturnDataV1, CounterShardV1,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::{ReadError, WriteError},
+};
+
+pub struct IncrementCounterShardV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: IncrementCounterShardV1Accounts<'a>,
+}
+
+pub struct IncrementCounterShardV1Accounts<'a> {
+    /// Any signer. Sharded counters count contributions from many keys, which usually pick the
+    /// shard they write by hashing their own key.
+    pub contributor: &'a AccountInfo,
+    pub shard: &'a AccountInfo,
+}
+
+#[derive(Debug)]
+pub enum IncrementCounterShardV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    ContributorMustBeSigner,
+    ShardMustBeWriteable,
+    ShardAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+}
+
+impl IncrementCounterShardV1<'_> {
+    /// Increments one shard of a sharded counter by 1, saturating at `u64::MAX`.
+    ///
+    /// Only the shard is written, so increments of different shards of the same sharded counter
+    /// do not contend for a write lock. The shard's count is moved into the sharded counter by
+    /// `ReconcileShardsV1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`IncrementCounterShardV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), IncrementCounterShardV1Error> {
+        let mut shard_state = {
+            let shard_data = self.accounts.shard.try_borrow_data()?;
+            CounterShardV1::deserialize(&shard_data)?
+        };
+
+        shard_state.count = shard_state.count.saturating_add(1);
+
+        let written =
+            shard_state.serialize_into(&mut self.accounts.shard.try_borrow_mut_data()?)?;
+
+        if written != CounterShardV1::size() {
+            return Err(IncrementCounterShardV1Error::SerializedSizeMismatch {
+                expected: CounterShardV1::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: shard_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for IncrementCounterShardV1<'a> {
+    type Error = IncrementCounterShardV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = IncrementCounterShardV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for IncrementCounterShardV1Accounts<'a> {
+    type Error = IncrementCounterShardV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [contributor, shard] = accounts else {
+            return Err(IncrementCounterShardV1Error::NotEnoughAccounts {
+                expected: 2,
+                observed: accounts.len(),
+            });
+        };
+
+        if !contributor.is_signer() {
+            return Err(IncrementCounterShardV1Error::ContributorMustBeSigner);
+        }
+
+        if !shard.is_writable() {
+            return Err(IncrementCounterShardV1Error::ShardMustBeWriteable);
+        }
+
+        let shard_state = {
+            let shard_data = shard.try_borrow_data()?;
+            AccountDiscriminator::check(AccountDiscriminator::CounterShardV1Account, &shard_data)?;
+            CounterShardV1::deserialize(&shard_data)?
+        };
+
+        let expected_shard = create_counter_shard_v1_address(
+            program_id,
+            &shard_state.sharded_counter,
+            shard_state.index,
+            shard_state.bump,
+        )?;
+        let observed_shard = shard.key();
+        if observed_shard != &expected_shard {
+            return Err(IncrementCounterShardV1Error::ShardAddressMismatch {
+                expected: expected_shard,
+                observed: *observed_shard,
+            });
+        }
+
+        Ok(Self { contributor, shard })
+    }
+}
+
+impl From<AccountDiscriminatorError> for IncrementCounterShardV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for IncrementCounterShardV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for IncrementCounterShardV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for IncrementCounterShardV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
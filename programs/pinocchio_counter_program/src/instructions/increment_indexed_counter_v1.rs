@@ -0,0 +1,173 @@
+use {
+    crate::{
+        find_indexed_counter_v1, AccountDiscriminator, AccountDiscriminatorError,
+        CountReturnDataV1, CounterEventV1, EventDiscriminator, IndexedCounterV1,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct IncrementIndexedCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: IncrementIndexedCounterV1Accounts<'a>,
+    pub args: IncrementIndexedCounterV1Args,
+}
+
+pub struct IncrementIndexedCounterV1Accounts<'a> {
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct IncrementIndexedCounterV1Args {
+    pub index: u32,
+}
+
+#[derive(Debug)]
+pub enum IncrementIndexedCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+}
+
+impl IncrementIndexedCounterV1<'_> {
+    /// Increments the indexed counter's count by 1, saturating at `u64::MAX`.
+    ///
+    /// Emits a `CountIncrementedV1` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`IncrementIndexedCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), IncrementIndexedCounterV1Error> {
+        let mut counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            IndexedCounterV1::deserialize(&counter_data)?
+        };
+
+        let old_count = counter_state.count;
+        counter_state.count = counter_state.count.saturating_add(1);
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != IndexedCounterV1::size() {
+            return Err(IncrementIndexedCounterV1Error::SerializedSizeMismatch {
+                expected: IndexedCounterV1::size(),
+                observed: written,
+            });
+        }
+
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            &counter_state.owner,
+            old_count,
+            counter_state.count,
+        )?
+        .emit(EventDiscriminator::CountIncrementedV1)?;
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for IncrementIndexedCounterV1<'a> {
+    type Error = IncrementIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let args = IncrementIndexedCounterV1Args::deserialize(args)?;
+        let accounts = IncrementIndexedCounterV1Accounts::try_from((program_id, accounts, &args))?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo], &IncrementIndexedCounterV1Args)>
+    for IncrementIndexedCounterV1Accounts<'a>
+{
+    type Error = IncrementIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&Pubkey, &'a [AccountInfo], &IncrementIndexedCounterV1Args),
+    ) -> Result<Self, Self::Error> {
+        let [owner, counter] = accounts else {
+            return Err(IncrementIndexedCounterV1Error::NotEnoughAccounts {
+                expected: 2,
+                observed: accounts.len(),
+            });
+        };
+
+        if !owner.is_signer() {
+            return Err(IncrementIndexedCounterV1Error::OwnerMustBeSigner);
+        }
+
+        if !counter.is_writable() {
+            return Err(IncrementIndexedCounterV1Error::CounterMustBeWriteable);
+        }
+
+        let (expected_counter, _bump) =
+            find_indexed_counter_v1(program_id, owner.key(), args.index);
+        let observed_counter = counter.key();
+        if observed_counter != &expected_counter {
+            return Err(IncrementIndexedCounterV1Error::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: *observed_counter,
+            });
+        }
+
+        let counter_data = counter.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::IndexedCounterV1Account, &counter_data)?;
+
+        Ok(Self { owner, counter })
+    }
+}
+
+impl IncrementIndexedCounterV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for IncrementIndexedCounterV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for IncrementIndexedCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for IncrementIndexedCounterV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for IncrementIndexedCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
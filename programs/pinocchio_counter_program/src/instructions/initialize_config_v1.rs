@@ -0,0 +1,250 @@
+use {
+    crate::{
+        create_pda_account, find_config_v1, try_find_program_data_address, AccountDiscriminator,
+        ConfigV1, BPF_LOADER_UPGRADEABLE_ID, CONFIG_V1_SEED,
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, seeds,
+    },
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+/// `UpgradeableLoaderState::ProgramData` variant tag, little-endian.
+const PROGRAM_DATA_STATE_TAG: [u8; 4] = [3, 0, 0, 0];
+
+/// Offset of the `Option<Pubkey>` upgrade authority in a program data account, after the state tag
+/// and the deployment slot.
+const PROGRAM_DATA_AUTHORITY_OFFSET: usize = 12;
+
+/// Size of the program data metadata that precedes the program's ELF.
+const PROGRAM_DATA_METADATA_SIZE: usize = PROGRAM_DATA_AUTHORITY_OFFSET + 1 + 32;
+
+pub struct InitializeConfigV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: InitializeConfigV1Accounts<'a>,
+    pub args: InitializeConfigV1Args,
+}
+
+pub struct InitializeConfigV1Accounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    pub config_bump: u8,
+    pub program_data: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+/// Arguments for [`InitializeConfigV1`].
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct InitializeConfigV1Args {
+    /// The key allowed to pause the program and to change its pause exemptions.
+    pub admin: Pubkey,
+}
+
+#[derive(Debug)]
+pub enum InitializeConfigV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    AuthorityMustBeSigner,
+    AuthorityMustBeWriteable,
+    ConfigMustBeWriteable,
+    ConfigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    ConfigMustBeEmpty,
+    ConfigMustBeOwnedBySystemProgram,
+    ProgramDataAddressMismatch { expected: Pubkey, observed: Pubkey },
+    ProgramDataMustBeOwnedByLoader,
+    InvalidProgramData,
+    UpgradeAuthorityMismatch,
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+}
+
+impl InitializeConfigV1<'_> {
+    /// Executes the initialize config instruction.
+    ///
+    /// Creates the program's config account, paid for by the upgrade authority, with the given
+    /// admin. The program starts unpaused, with no instruction exempt from the pause.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`InitializeConfigV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), InitializeConfigV1Error> {
+        let bump_ref = &[self.accounts.config_bump];
+        let seeds = seeds!(CONFIG_V1_SEED, bump_ref);
+        let signer = Signer::from(&seeds);
+
+        create_pda_account(
+            self.accounts.authority,
+            self.accounts.config,
+            ConfigV1::size(),
+            self.program_id,
+            &[signer],
+        )?;
+
+        let state = ConfigV1 {
+            discriminator: AccountDiscriminator::ConfigV1Account,
+            bump: self.accounts.config_bump,
+            admin: self.args.admin,
+            paused: false,
+            pause_exemptions: [0; 32],
+        };
+
+        let written = state.serialize_into(&mut self.accounts.config.try_borrow_mut_data()?)?;
+
+        if written != ConfigV1::size() {
+            return Err(InitializeConfigV1Error::SerializedSizeMismatch {
+                expected: ConfigV1::size(),
+                observed: written,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for InitializeConfigV1<'a> {
+    type Error = InitializeConfigV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitializeConfigV1Accounts::try_from((program_id, accounts))?;
+        let args = InitializeConfigV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeConfigV1Accounts<'a> {
+    type Error = InitializeConfigV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [authority, config, program_data, system_program] = accounts else {
+            return Err(InitializeConfigV1Error::NotEnoughAccounts {
+                expected: 4,
+                observed: accounts.len(),
+            });
+        };
+
+        if !authority.is_signer() {
+            return Err(InitializeConfigV1Error::AuthorityMustBeSigner);
+        }
+
+        if !authority.is_writable() {
+            return Err(InitializeConfigV1Error::AuthorityMustBeWriteable);
+        }
+
+        if !config.is_writable() {
+            return Err(InitializeConfigV1Error::ConfigMustBeWriteable);
+        }
+
+        let (expected_config, config_bump) = find_config_v1(program_id);
+        let observed_config = config.key();
+        if observed_config != &expected_config {
+            return Err(InitializeConfigV1Error::ConfigAddressMismatch {
+                expected: expected_config,
+                observed: *observed_config,
+            });
+        }
+
+        if !config.data_is_empty() {
+            return Err(InitializeConfigV1Error::ConfigMustBeEmpty);
+        }
+
+        if !config.is_owned_by(&pinocchio_system::ID) {
+            return Err(InitializeConfigV1Error::ConfigMustBeOwnedBySystemProgram);
+        }
+
+        check_upgrade_authority(program_id, program_data, authority.key())?;
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(InitializeConfigV1Error::SystemProgramAddressMismatch);
+        }
+
+        Ok(Self {
+            authority,
+            config,
+            config_bump,
+            program_data,
+            system_program,
+        })
+    }
+}
+
+/// Checks that `authority` is the upgrade authority recorded in the program's program data
+/// account.
+///
+/// The BPF upgradeable loader keeps a program's upgrade authority in a program data account
+/// derived from the program ID. Its data starts with the `ProgramData` state tag, the deployment
+/// slot and an optional upgrade authority. A program without an upgrade authority is immutable,
+/// and nobody may initialize its config.
+fn check_upgrade_authority(
+    program_id: &Pubkey,
+    program_data: &AccountInfo,
+    authority: &Pubkey,
+) -> Result<(), InitializeConfigV1Error> {
+    let expected_program_data =
+        try_find_program_data_address(program_id).ok_or(ProgramError::InvalidSeeds)?;
+    let observed_program_data = program_data.key();
+    if observed_program_data != &expected_program_data {
+        return Err(InitializeConfigV1Error::ProgramDataAddressMismatch {
+            expected: expected_program_data,
+            observed: *observed_program_data,
+        });
+    }
+
+    if !program_data.is_owned_by(&BPF_LOADER_UPGRADEABLE_ID) {
+        return Err(InitializeConfigV1Error::ProgramDataMustBeOwnedByLoader);
+    }
+
+    let program_data = program_data.try_borrow_data()?;
+    let Some(metadata) = program_data.get(..PROGRAM_DATA_METADATA_SIZE) else {
+        return Err(InitializeConfigV1Error::InvalidProgramData);
+    };
+
+    if metadata[..PROGRAM_DATA_STATE_TAG.len()] != PROGRAM_DATA_STATE_TAG {
+        return Err(InitializeConfigV1Error::InvalidProgramData);
+    }
+
+    let (has_authority, upgrade_authority) = metadata[PROGRAM_DATA_AUTHORITY_OFFSET..].split_at(1);
+    if has_authority != [1] || upgrade_authority != authority.as_ref() {
+        return Err(InitializeConfigV1Error::UpgradeAuthorityMismatch);
+    }
+
+    Ok(())
+}
+
+impl InitializeConfigV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<ProgramError> for InitializeConfigV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for InitializeConfigV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for InitializeConfigV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -1,10 +1,13 @@
 use {
-    crate::{find_counter_v1, AccountDiscriminator, CounterV1, COUNTER_V1_SEED},
+    crate::{
+        create_pda_account, find_counter_v1, find_tombstone, AccountDiscriminator,
+        AccountDiscriminatorError, CountReturnDataV1, CounterEventV1, CounterV1,
+        EventDiscriminator, TombstoneError, TombstoneLookup, COUNTER_V1_SEED,
+    },
     pinocchio::{
         account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
         pubkey::Pubkey, seeds,
     },
-    pinocchio_system::create_account_with_minimum_balance_signed,
     wincode::{ReadError, WriteError},
 };
 
@@ -18,6 +21,7 @@ pub struct InitializeCounterV1Accounts<'a> {
     pub counter: &'a AccountInfo,
     pub counter_bump: u8,
     pub system_program: &'a AccountInfo,
+    pub tombstone: &'a AccountInfo,
 }
 
 #[derive(Debug)]
@@ -29,12 +33,15 @@ pub enum InitializeCounterV1Error {
     CounterMustBeWriteable,
     CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
     CounterMustBeEmpty,
-    CounterMustHaveZeroLamports,
     CounterMustBeOwnedBySystemProgram,
     SystemProgramAddressMismatch,
     DeserializeError(ReadError),
     SerializeError(WriteError),
     SerializedSizeMismatch { expected: usize, observed: usize },
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    TombstoneCounterMismatch { expected: Pubkey, observed: Pubkey },
+    CounterClosed,
 }
 
 impl InitializeCounterV1<'_> {
@@ -42,6 +49,13 @@ impl InitializeCounterV1<'_> {
     ///
     /// Initializes a new counter account owned by the program with the payer as the owner.
     ///
+    /// The counter address may already hold lamports sent by anyone, since it is predictable. In
+    /// that case the payer only tops it up to the rent-exempt minimum before it is allocated.
+    ///
+    /// A counter that `CloseCounterV1` has closed has a tombstone and cannot be created again.
+    ///
+    /// Emits a `CounterInitializedV1` event.
+    ///
     /// # Errors
     ///
     /// Returns a [`Result`] containing a [`InitializeCounterV1Error`] if execution fails.
@@ -52,12 +66,11 @@ impl InitializeCounterV1<'_> {
         let seeds = seeds!(COUNTER_V1_SEED, owner_ref, bump_ref);
         let signer = Signer::from(&seeds);
 
-        create_account_with_minimum_balance_signed(
-            self.accounts.counter, // account
-            CounterV1::size(),     // space,
-            self.program_id,       // account owner
+        create_pda_account(
             self.accounts.payer,
-            None,
+            self.accounts.counter,
+            CounterV1::size(),
+            self.program_id,
             &[signer],
         )?;
 
@@ -68,19 +81,19 @@ impl InitializeCounterV1<'_> {
             count: 0,
         };
 
-        let serialized = state.serialize()?;
+        let written = state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
 
-        if serialized.len() != CounterV1::size() {
+        if written != CounterV1::size() {
             return Err(InitializeCounterV1Error::SerializedSizeMismatch {
                 expected: CounterV1::size(),
-                observed: serialized.len(),
+                observed: written,
             });
         }
 
-        self.accounts
-            .counter
-            .try_borrow_mut_data()?
-            .copy_from_slice(&serialized);
+        CounterEventV1::new(self.accounts.counter.key(), owner, 0, 0)?
+            .emit(EventDiscriminator::CounterInitializedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
 
         Ok(())
     }
@@ -104,9 +117,9 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeCounterV1Accounts<'
     type Error = InitializeCounterV1Error;
 
     fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let [payer, counter, system_program] = accounts else {
+        let [payer, counter, system_program, tombstone] = accounts else {
             return Err(InitializeCounterV1Error::NotEnoughAccounts {
-                expected: 3,
+                expected: 4,
                 observed: accounts.len(),
             });
         };
@@ -136,10 +149,6 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeCounterV1Accounts<'
             return Err(InitializeCounterV1Error::CounterMustBeEmpty);
         }
 
-        if counter.lamports() > 0 {
-            return Err(InitializeCounterV1Error::CounterMustHaveZeroLamports);
-        }
-
         if !counter.is_owned_by(&pinocchio_system::ID) {
             return Err(InitializeCounterV1Error::CounterMustBeOwnedBySystemProgram);
         }
@@ -148,15 +157,37 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeCounterV1Accounts<'
             return Err(InitializeCounterV1Error::SystemProgramAddressMismatch);
         }
 
+        if find_tombstone(program_id, tombstone, counter.key())? == TombstoneLookup::Found {
+            return Err(InitializeCounterV1Error::CounterClosed);
+        }
+
         Ok(Self {
             payer,
             counter,
             counter_bump,
             system_program,
+            tombstone,
         })
     }
 }
 
+impl From<TombstoneError> for InitializeCounterV1Error {
+    fn from(err: TombstoneError) -> Self {
+        match err {
+            TombstoneError::ProgramError(err) => Self::ProgramError(err),
+            TombstoneError::TombstoneAddressMismatch { expected, observed } => {
+                Self::TombstoneAddressMismatch { expected, observed }
+            }
+            TombstoneError::AccountDiscriminatorError(err) => Self::AccountDiscriminatorError(err),
+            TombstoneError::DeserializeError(err) => Self::DeserializeError(err),
+            TombstoneError::TombstoneCounterMismatch { expected, observed } => {
+                Self::TombstoneCounterMismatch { expected, observed }
+            }
+            TombstoneError::SerializeError(err) => Self::SerializeError(err),
+        }
+    }
+}
+
 impl From<ProgramError> for InitializeCounterV1Error {
     fn from(err: ProgramError) -> Self {
         InitializeCounterV1Error::ProgramError(err)
@@ -0,0 +1,203 @@
+use {
+    crate::{
+        create_pda_account, find_indexed_counter_v1, AccountDiscriminator, CountReturnDataV1,
+        CounterEventV1, EventDiscriminator, IndexedCounterV1, INDEXED_COUNTER_V1_SEED,
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, seeds,
+    },
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct InitializeIndexedCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: InitializeIndexedCounterV1Accounts<'a>,
+    pub args: InitializeIndexedCounterV1Args,
+}
+
+pub struct InitializeIndexedCounterV1Accounts<'a> {
+    pub payer: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub counter_bump: u8,
+    pub system_program: &'a AccountInfo,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct InitializeIndexedCounterV1Args {
+    pub index: u32,
+}
+
+#[derive(Debug)]
+pub enum InitializeIndexedCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    PayerMustBeSigner,
+    PayerMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    CounterMustBeEmpty,
+    CounterMustBeOwnedBySystemProgram,
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+}
+
+impl InitializeIndexedCounterV1<'_> {
+    /// Executes the initialize indexed counter instruction.
+    ///
+    /// Initializes a new counter account at the address derived from the payer and
+    /// `args.index`, with the payer as the owner. A counter address that already holds lamports
+    /// is topped up to the rent-exempt minimum instead of failing.
+    ///
+    /// Emits a `CounterInitializedV1` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`InitializeIndexedCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), InitializeIndexedCounterV1Error> {
+        let owner = self.accounts.payer.key();
+        let owner_ref = owner.as_ref();
+        let index_ref = &self.args.index.to_le_bytes();
+        let bump_ref = &[self.accounts.counter_bump];
+        let seeds = seeds!(INDEXED_COUNTER_V1_SEED, owner_ref, index_ref, bump_ref);
+        let signer = Signer::from(&seeds);
+
+        create_pda_account(
+            self.accounts.payer,
+            self.accounts.counter,
+            IndexedCounterV1::size(),
+            self.program_id,
+            &[signer],
+        )?;
+
+        let state = IndexedCounterV1 {
+            discriminator: AccountDiscriminator::IndexedCounterV1Account,
+            owner: *owner,
+            index: self.args.index,
+            bump: self.accounts.counter_bump,
+            count: 0,
+        };
+
+        let written = state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != IndexedCounterV1::size() {
+            return Err(InitializeIndexedCounterV1Error::SerializedSizeMismatch {
+                expected: IndexedCounterV1::size(),
+                observed: written,
+            });
+        }
+
+        CounterEventV1::new(self.accounts.counter.key(), owner, 0, 0)?
+            .emit(EventDiscriminator::CounterInitializedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for InitializeIndexedCounterV1<'a> {
+    type Error = InitializeIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let args = InitializeIndexedCounterV1Args::deserialize(args)?;
+        let accounts = InitializeIndexedCounterV1Accounts::try_from((program_id, accounts, &args))?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo], &InitializeIndexedCounterV1Args)>
+    for InitializeIndexedCounterV1Accounts<'a>
+{
+    type Error = InitializeIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&Pubkey, &'a [AccountInfo], &InitializeIndexedCounterV1Args),
+    ) -> Result<Self, Self::Error> {
+        let [payer, counter, system_program] = accounts else {
+            return Err(InitializeIndexedCounterV1Error::NotEnoughAccounts {
+                expected: 3,
+                observed: accounts.len(),
+            });
+        };
+
+        if !payer.is_signer() {
+            return Err(InitializeIndexedCounterV1Error::PayerMustBeSigner);
+        }
+
+        if !payer.is_writable() {
+            return Err(InitializeIndexedCounterV1Error::PayerMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(InitializeIndexedCounterV1Error::CounterMustBeWriteable);
+        }
+
+        let (expected_counter, counter_bump) =
+            find_indexed_counter_v1(program_id, payer.key(), args.index);
+        let observed_counter = counter.key();
+        if observed_counter != &expected_counter {
+            return Err(InitializeIndexedCounterV1Error::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: *observed_counter,
+            });
+        }
+
+        if !counter.data_is_empty() {
+            return Err(InitializeIndexedCounterV1Error::CounterMustBeEmpty);
+        }
+
+        if !counter.is_owned_by(&pinocchio_system::ID) {
+            return Err(InitializeIndexedCounterV1Error::CounterMustBeOwnedBySystemProgram);
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(InitializeIndexedCounterV1Error::SystemProgramAddressMismatch);
+        }
+
+        Ok(Self {
+            payer,
+            counter,
+            counter_bump,
+            system_program,
+        })
+    }
+}
+
+impl InitializeIndexedCounterV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<ProgramError> for InitializeIndexedCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for InitializeIndexedCounterV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for InitializeIndexedCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
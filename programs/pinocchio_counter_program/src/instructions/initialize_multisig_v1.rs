@@ -0,0 +1,342 @@
+use {
+    crate::{
+        create_pda_account, find_multisig_v1, load_counter, AccountDiscriminator,
+        AccountDiscriminatorError, CountReturnDataV1, Counter, CounterV2, LoadCounterError,
+        MultisigV1, MAX_MULTISIG_MEMBERS, MULTISIG_V1_SEED, NO_PENDING_AUTHORITY,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct InitializeMultisigV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: InitializeMultisigV1Accounts<'a>,
+    pub args: InitializeMultisigV1Args,
+}
+
+pub struct InitializeMultisigV1Accounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub multisig: &'a AccountInfo,
+    pub multisig_bump: u8,
+    pub system_program: &'a AccountInfo,
+    /// The members of the multisig, which do not need to sign.
+    pub members: &'a [AccountInfo],
+}
+
+#[derive(SchemaRead, SchemaWrite)]
+pub struct InitializeMultisigV1Args {
+    pub threshold: u8,
+}
+
+#[derive(Debug)]
+pub enum InitializeMultisigV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    AuthorityMustBeSigner,
+    AuthorityMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigMustBeWriteable,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    TooManyMembers { maximum: usize, observed: usize },
+    DuplicateMember,
+    InvalidThreshold { threshold: u8, member_count: u8 },
+}
+
+impl InitializeMultisigV1<'_> {
+    /// Executes the initialize multisig instruction.
+    ///
+    /// Records the member accounts and `args.threshold` in the counter's multisig, then makes the
+    /// multisig the counter's authority and clears any pending authority. From then on the count
+    /// updates and `ProposeAuthorityV1` need at least `threshold` distinct members to sign. Only
+    /// the authority may initialize the multisig, and the threshold must lie between 1 and the
+    /// number of members.
+    ///
+    /// The multisig is created on first use, with the authority paying its rent, and replaced if
+    /// it already exists. A `CounterV1` account is upgraded in place to `CounterV2`, exactly as in
+    /// `SetCountPolicyV1`, with the authority covering the increased rent requirement.
+    ///
+    /// To change the members of a multisig, hand the authority to a single key with
+    /// `ProposeAuthorityV1` and `AcceptAuthorityV1`, then initialize the multisig again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`InitializeMultisigV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), InitializeMultisigV1Error> {
+        let member_count = self.member_count()?;
+        if self.args.threshold == 0 || self.args.threshold > member_count {
+            return Err(InitializeMultisigV1Error::InvalidThreshold {
+                threshold: self.args.threshold,
+                member_count,
+            });
+        }
+
+        let counter = self.accounts.counter.key();
+
+        if self.accounts.multisig.data_is_empty() {
+            let counter_ref = counter.as_ref();
+            let bump_ref = &[self.accounts.multisig_bump];
+            let seeds = seeds!(MULTISIG_V1_SEED, counter_ref, bump_ref);
+            let signer = Signer::from(&seeds);
+
+            create_pda_account(
+                self.accounts.authority,
+                self.accounts.multisig,
+                MultisigV1::size(),
+                self.program_id,
+                &[signer],
+            )?;
+        }
+
+        let mut members = [[0; 32]; MAX_MULTISIG_MEMBERS as usize];
+        for (member, account) in members.iter_mut().zip(self.accounts.members) {
+            *member = *account.key();
+        }
+
+        let multisig_state = MultisigV1 {
+            discriminator: AccountDiscriminator::MultisigV1Account,
+            counter: *counter,
+            bump: self.accounts.multisig_bump,
+            threshold: self.args.threshold,
+            member_count,
+            members,
+        };
+
+        let written =
+            multisig_state.serialize_into(&mut self.accounts.multisig.try_borrow_mut_data()?)?;
+
+        if written != MultisigV1::size() {
+            return Err(InitializeMultisigV1Error::SerializedSizeMismatch {
+                expected: MultisigV1::size(),
+                observed: written,
+            });
+        }
+
+        let clock = Clock::get()?;
+
+        let mut counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            match Counter::deserialize(&counter_data)? {
+                Counter::V1(counter) => CounterV2::from_v1(&counter, &clock),
+                Counter::V2(counter) => counter,
+            }
+        };
+
+        counter_state.authority = *self.accounts.multisig.key();
+        counter_state.pending_authority = NO_PENDING_AUTHORITY;
+
+        counter_state.record_modification(&clock);
+
+        if self.accounts.counter.data_len() != CounterV2::size() {
+            let rent = Rent::get()?;
+            let rent_exempt_minimum_counter = rent.minimum_balance(CounterV2::size());
+
+            let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+            let additional_lamports_needed =
+                rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+            if additional_lamports_needed > 0 {
+                Transfer {
+                    from: self.accounts.authority,
+                    to: self.accounts.counter,
+                    lamports: additional_lamports_needed,
+                }
+                .invoke()?;
+            }
+
+            self.accounts.counter.resize(CounterV2::size())?;
+        }
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(InitializeMultisigV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+
+    fn member_count(&self) -> Result<u8, InitializeMultisigV1Error> {
+        let observed = self.accounts.members.len();
+        u8::try_from(observed).map_err(|_| InitializeMultisigV1Error::TooManyMembers {
+            maximum: usize::from(MAX_MULTISIG_MEMBERS),
+            observed,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for InitializeMultisigV1<'a> {
+    type Error = InitializeMultisigV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitializeMultisigV1Accounts::try_from((program_id, accounts))?;
+        let args = InitializeMultisigV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeMultisigV1Accounts<'a> {
+    type Error = InitializeMultisigV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [authority, counter, multisig, system_program, members @ ..] = accounts else {
+            return Err(InitializeMultisigV1Error::NotEnoughAccounts {
+                expected: 5,
+                observed: accounts.len(),
+            });
+        };
+
+        if members.is_empty() {
+            return Err(InitializeMultisigV1Error::NotEnoughAccounts {
+                expected: 5,
+                observed: accounts.len(),
+            });
+        }
+
+        if members.len() > usize::from(MAX_MULTISIG_MEMBERS) {
+            return Err(InitializeMultisigV1Error::TooManyMembers {
+                maximum: usize::from(MAX_MULTISIG_MEMBERS),
+                observed: members.len(),
+            });
+        }
+
+        if !authority.is_signer() {
+            return Err(InitializeMultisigV1Error::AuthorityMustBeSigner);
+        }
+
+        if !authority.is_writable() {
+            return Err(InitializeMultisigV1Error::AuthorityMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(InitializeMultisigV1Error::CounterMustBeWriteable);
+        }
+
+        let counter_state = load_counter(program_id, counter)?;
+        if counter_state.authority() != authority.key() {
+            return Err(InitializeMultisigV1Error::AuthorityMismatch);
+        }
+
+        if !multisig.is_writable() {
+            return Err(InitializeMultisigV1Error::MultisigMustBeWriteable);
+        }
+
+        let (expected_multisig, multisig_bump) = find_multisig_v1(program_id, counter.key());
+        let observed_multisig = multisig.key();
+        if observed_multisig != &expected_multisig {
+            return Err(InitializeMultisigV1Error::MultisigAddressMismatch {
+                expected: expected_multisig,
+                observed: *observed_multisig,
+            });
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(InitializeMultisigV1Error::SystemProgramAddressMismatch);
+        }
+
+        if !multisig.data_is_empty() {
+            let multisig_data = multisig.try_borrow_data()?;
+            AccountDiscriminator::check(AccountDiscriminator::MultisigV1Account, &multisig_data)
+                .map_err(InitializeMultisigV1Error::MultisigAccountDiscriminatorError)?;
+        }
+
+        for (index, member) in members.iter().enumerate() {
+            if members[..index]
+                .iter()
+                .any(|previous| previous.key() == member.key())
+            {
+                return Err(InitializeMultisigV1Error::DuplicateMember);
+            }
+        }
+
+        Ok(Self {
+            authority,
+            counter,
+            multisig,
+            multisig_bump,
+            system_program,
+            members,
+        })
+    }
+}
+
+impl InitializeMultisigV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for InitializeMultisigV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for InitializeMultisigV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for InitializeMultisigV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for InitializeMultisigV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for InitializeMultisigV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
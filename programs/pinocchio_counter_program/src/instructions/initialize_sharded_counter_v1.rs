@@ -0,0 +1,282 @@
+use {
+    crate::{
+        create_pda_account, find_counter_shard_v1, find_sharded_counter_v1, AccountDiscriminator,
+        CountReturnDataV1, CounterShardV1, ShardedCounterV1, COUNTER_SHARD_V1_SEED,
+        MAX_COUNTER_SHARDS, SHARDED_COUNTER_V1_SEED,
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, seeds,
+    },
+    wincode::WriteError,
+};
+
+pub struct InitializeShardedCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: InitializeShardedCounterV1Accounts<'a>,
+}
+
+pub struct InitializeShardedCounterV1Accounts<'a> {
+    pub payer: &'a AccountInfo,
+    pub sharded_counter: &'a AccountInfo,
+    pub sharded_counter_bump: u8,
+    pub system_program: &'a AccountInfo,
+    /// The shards to create, in index order.
+    pub shards: &'a [AccountInfo],
+    /// Bump seed of each shard address, by shard index.
+    pub shard_bumps: [u8; MAX_COUNTER_SHARDS as usize],
+}
+
+#[derive(Debug)]
+pub enum InitializeShardedCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    PayerMustBeSigner,
+    PayerMustBeWriteable,
+    ShardedCounterMustBeWriteable,
+    ShardedCounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    ShardedCounterMustBeEmpty,
+    ShardedCounterMustBeOwnedBySystemProgram,
+    SystemProgramAddressMismatch,
+    TooManyShards { maximum: usize, observed: usize },
+    ShardMustBeWriteable,
+    ShardAddressMismatch { expected: Pubkey, observed: Pubkey },
+    ShardMustBeEmpty,
+    ShardMustBeOwnedBySystemProgram,
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+}
+
+impl InitializeShardedCounterV1<'_> {
+    /// Executes the initialize sharded counter instruction.
+    ///
+    /// Creates the sharded counter at the address derived from the payer, with the payer as the
+    /// owner, and one shard for every shard account passed after the system program. Shard
+    /// accounts must be passed in index order, starting at index `0`. Addresses that already hold
+    /// lamports are topped up to the rent-exempt minimum instead of failing.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`InitializeShardedCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), InitializeShardedCounterV1Error> {
+        let owner = self.accounts.payer.key();
+        let owner_ref = owner.as_ref();
+        let bump_ref = &[self.accounts.sharded_counter_bump];
+        let seeds = seeds!(SHARDED_COUNTER_V1_SEED, owner_ref, bump_ref);
+        let signer = Signer::from(&seeds);
+
+        create_pda_account(
+            self.accounts.payer,
+            self.accounts.sharded_counter,
+            ShardedCounterV1::size(),
+            self.program_id,
+            &[signer],
+        )?;
+
+        let state = ShardedCounterV1 {
+            discriminator: AccountDiscriminator::ShardedCounterV1Account,
+            owner: *owner,
+            bump: self.accounts.sharded_counter_bump,
+            shard_count: self.shard_count(),
+            count: 0,
+        };
+
+        let written =
+            state.serialize_into(&mut self.accounts.sharded_counter.try_borrow_mut_data()?)?;
+
+        if written != ShardedCounterV1::size() {
+            return Err(InitializeShardedCounterV1Error::SerializedSizeMismatch {
+                expected: ShardedCounterV1::size(),
+                observed: written,
+            });
+        }
+
+        let sharded_counter = self.accounts.sharded_counter.key();
+        for ((index, shard), bump) in (0u8..)
+            .zip(self.accounts.shards)
+            .zip(self.accounts.shard_bumps)
+        {
+            self.initialize_shard(sharded_counter, index, bump, shard)?;
+        }
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+
+    fn initialize_shard(
+        &self,
+        sharded_counter: &Pubkey,
+        index: u8,
+        bump: u8,
+        shard: &AccountInfo,
+    ) -> Result<(), InitializeShardedCounterV1Error> {
+        let sharded_counter_ref = sharded_counter.as_ref();
+        let index_ref = &[index];
+        let bump_ref = &[bump];
+        let seeds = seeds!(
+            COUNTER_SHARD_V1_SEED,
+            sharded_counter_ref,
+            index_ref,
+            bump_ref
+        );
+        let signer = Signer::from(&seeds);
+
+        create_pda_account(
+            self.accounts.payer,
+            shard,
+            CounterShardV1::size(),
+            self.program_id,
+            &[signer],
+        )?;
+
+        let state = CounterShardV1 {
+            discriminator: AccountDiscriminator::CounterShardV1Account,
+            sharded_counter: *sharded_counter,
+            index,
+            bump,
+            count: 0,
+        };
+
+        let written = state.serialize_into(&mut shard.try_borrow_mut_data()?)?;
+
+        if written != CounterShardV1::size() {
+            return Err(InitializeShardedCounterV1Error::SerializedSizeMismatch {
+                expected: CounterShardV1::size(),
+                observed: written,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn shard_count(&self) -> u8 {
+        u8::try_from(self.accounts.shards.len())
+            .expect("shard count is limited to MAX_COUNTER_SHARDS when accounts are validated")
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for InitializeShardedCounterV1<'a> {
+    type Error = InitializeShardedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitializeShardedCounterV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeShardedCounterV1Accounts<'a> {
+    type Error = InitializeShardedCounterV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [payer, sharded_counter, system_program, shards @ ..] = accounts else {
+            return Err(InitializeShardedCounterV1Error::NotEnoughAccounts {
+                expected: 4,
+                observed: accounts.len(),
+            });
+        };
+
+        if shards.is_empty() {
+            return Err(InitializeShardedCounterV1Error::NotEnoughAccounts {
+                expected: 4,
+                observed: accounts.len(),
+            });
+        }
+
+        if shards.len() > usize::from(MAX_COUNTER_SHARDS) {
+            return Err(InitializeShardedCounterV1Error::TooManyShards {
+                maximum: usize::from(MAX_COUNTER_SHARDS),
+                observed: shards.len(),
+            });
+        }
+
+        if !payer.is_signer() {
+            return Err(InitializeShardedCounterV1Error::PayerMustBeSigner);
+        }
+
+        if !payer.is_writable() {
+            return Err(InitializeShardedCounterV1Error::PayerMustBeWriteable);
+        }
+
+        if !sharded_counter.is_writable() {
+            return Err(InitializeShardedCounterV1Error::ShardedCounterMustBeWriteable);
+        }
+
+        let (expected_sharded_counter, sharded_counter_bump) =
+            find_sharded_counter_v1(program_id, payer.key());
+        let observed_sharded_counter = sharded_counter.key();
+        if observed_sharded_counter != &expected_sharded_counter {
+            return Err(
+                InitializeShardedCounterV1Error::ShardedCounterAddressMismatch {
+                    expected: expected_sharded_counter,
+                    observed: *observed_sharded_counter,
+                },
+            );
+        }
+
+        if !sharded_counter.data_is_empty() {
+            return Err(InitializeShardedCounterV1Error::ShardedCounterMustBeEmpty);
+        }
+
+        if !sharded_counter.is_owned_by(&pinocchio_system::ID) {
+            return Err(InitializeShardedCounterV1Error::ShardedCounterMustBeOwnedBySystemProgram);
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(InitializeShardedCounterV1Error::SystemProgramAddressMismatch);
+        }
+
+        let mut shard_bumps = [0; MAX_COUNTER_SHARDS as usize];
+        for ((index, shard), shard_bump) in (0u8..).zip(shards).zip(&mut shard_bumps) {
+            if !shard.is_writable() {
+                return Err(InitializeShardedCounterV1Error::ShardMustBeWriteable);
+            }
+
+            let (expected_shard, bump) =
+                find_counter_shard_v1(program_id, &expected_sharded_counter, index);
+            let observed_shard = shard.key();
+            if observed_shard != &expected_shard {
+                return Err(InitializeShardedCounterV1Error::ShardAddressMismatch {
+                    expected: expected_shard,
+                    observed: *observed_shard,
+                });
+            }
+
+            if !shard.data_is_empty() {
+                return Err(InitializeShardedCounterV1Error::ShardMustBeEmpty);
+            }
+
+            if !shard.is_owned_by(&pinocchio_system::ID) {
+                return Err(InitializeShardedCounterV1Error::ShardMustBeOwnedBySystemProgram);
+            }
+
+            *shard_bump = bump;
+        }
+
+        Ok(Self {
+            payer,
+            sharded_counter,
+            sharded_counter_bump,
+            system_program,
+            shards,
+            shard_bumps,
+        })
+    }
+}
+
+impl From<ProgramError> for InitializeShardedCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<WriteError> for InitializeShardedCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -0,0 +1,229 @@
+use {
+    crate::{
+        create_pda_account, find_counter_v1, find_tombstone, AccessMode, AccountDiscriminator,
+        AccountDiscriminatorError, CountPolicy, CountReturnDataV1, CounterEventV1, CounterV2,
+        EventDiscriminator, TombstoneError, TombstoneLookup, COUNTER_V1_SEED, NO_PENDING_AUTHORITY,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        seeds,
+        sysvars::{clock::Clock, Sysvar},
+    },
+    wincode::{ReadError, WriteError},
+};
+
+pub struct InitializeSponsoredCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: InitializeSponsoredCounterV1Accounts<'a>,
+}
+
+pub struct InitializeSponsoredCounterV1Accounts<'a> {
+    pub payer: &'a AccountInfo,
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub counter_bump: u8,
+    pub system_program: &'a AccountInfo,
+    pub tombstone: &'a AccountInfo,
+}
+
+#[derive(Debug)]
+pub enum InitializeSponsoredCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    PayerMustBeSigner,
+    PayerMustBeWriteable,
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    CounterMustBeEmpty,
+    CounterMustBeOwnedBySystemProgram,
+    SystemProgramAddressMismatch,
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    DeserializeError(ReadError),
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    TombstoneCounterMismatch { expected: Pubkey, observed: Pubkey },
+    CounterClosed,
+}
+
+impl InitializeSponsoredCounterV1<'_> {
+    /// Executes the initialize sponsored counter instruction.
+    ///
+    /// Initializes a new counter at the address derived from the owner, with the rent paid by a
+    /// separate payer. The payer is recorded as the counter's rent payer so that
+    /// `DeactivateCounterV1` refunds the reclaimed lamports to it rather than to the owner.
+    ///
+    /// Only [`CounterV2`] can record a rent payer, so the counter is created with that layout and
+    /// the default count policy.
+    ///
+    /// A counter that `CloseCounterV1` has closed has a tombstone and cannot be created again.
+    ///
+    /// Emits a `CounterInitializedV1` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`InitializeSponsoredCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), InitializeSponsoredCounterV1Error> {
+        let owner = self.accounts.owner.key();
+        let owner_ref = owner.as_ref();
+        let bump_ref = &[self.accounts.counter_bump];
+        let seeds = seeds!(COUNTER_V1_SEED, owner_ref, bump_ref);
+        let signer = Signer::from(&seeds);
+
+        create_pda_account(
+            self.accounts.payer,
+            self.accounts.counter,
+            CounterV2::size(),
+            self.program_id,
+            &[signer],
+        )?;
+
+        let clock = Clock::get()?;
+        let policy = CountPolicy::default();
+        let state = CounterV2 {
+            discriminator: AccountDiscriminator::CounterV2Account,
+            owner: *owner,
+            bump: self.accounts.counter_bump,
+            count: 0,
+            overflow_policy: policy.overflow_policy,
+            min_count: policy.min_count,
+            max_count: policy.max_count,
+            authority: *owner,
+            pending_authority: NO_PENDING_AUTHORITY,
+            rent_payer: *self.accounts.payer.key(),
+            access_mode: AccessMode::Private,
+            frozen: false,
+            created_slot: clock.slot,
+            last_modified_slot: clock.slot,
+            last_modified_unix_timestamp: clock.unix_timestamp,
+            cooldown_slots: 0,
+            cooldown_seconds: 0,
+        };
+
+        let written = state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(InitializeSponsoredCounterV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CounterEventV1::new(self.accounts.counter.key(), owner, 0, 0)?
+            .emit(EventDiscriminator::CounterInitializedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for InitializeSponsoredCounterV1<'a> {
+    type Error = InitializeSponsoredCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = InitializeSponsoredCounterV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for InitializeSponsoredCounterV1Accounts<'a> {
+    type Error = InitializeSponsoredCounterV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [payer, owner, counter, system_program, tombstone] = accounts else {
+            return Err(InitializeSponsoredCounterV1Error::NotEnoughAccounts {
+                expected: 5,
+                observed: accounts.len(),
+            });
+        };
+
+        if !payer.is_signer() {
+            return Err(InitializeSponsoredCounterV1Error::PayerMustBeSigner);
+        }
+
+        if !payer.is_writable() {
+            return Err(InitializeSponsoredCounterV1Error::PayerMustBeWriteable);
+        }
+
+        if !owner.is_signer() {
+            return Err(InitializeSponsoredCounterV1Error::OwnerMustBeSigner);
+        }
+
+        if !counter.is_writable() {
+            return Err(InitializeSponsoredCounterV1Error::CounterMustBeWriteable);
+        }
+
+        let (expected_counter, counter_bump) = find_counter_v1(program_id, owner.key());
+        let observed_counter = counter.key();
+        if observed_counter != &expected_counter {
+            return Err(InitializeSponsoredCounterV1Error::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: *observed_counter,
+            });
+        }
+
+        if !counter.data_is_empty() {
+            return Err(InitializeSponsoredCounterV1Error::CounterMustBeEmpty);
+        }
+
+        if !counter.is_owned_by(&pinocchio_system::ID) {
+            return Err(InitializeSponsoredCounterV1Error::CounterMustBeOwnedBySystemProgram);
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(InitializeSponsoredCounterV1Error::SystemProgramAddressMismatch);
+        }
+
+        if find_tombstone(program_id, tombstone, counter.key())? == TombstoneLookup::Found {
+            return Err(InitializeSponsoredCounterV1Error::CounterClosed);
+        }
+
+        Ok(Self {
+            payer,
+            owner,
+            counter,
+            counter_bump,
+            system_program,
+            tombstone,
+        })
+    }
+}
+
+impl From<TombstoneError> for InitializeSponsoredCounterV1Error {
+    fn from(err: TombstoneError) -> Self {
+        match err {
+            TombstoneError::ProgramError(err) => Self::ProgramError(err),
+            TombstoneError::TombstoneAddressMismatch { expected, observed } => {
+                Self::TombstoneAddressMismatch { expected, observed }
+            }
+            TombstoneError::AccountDiscriminatorError(err) => Self::AccountDiscriminatorError(err),
+            TombstoneError::DeserializeError(err) => Self::DeserializeError(err),
+            TombstoneError::TombstoneCounterMismatch { expected, observed } => {
+                Self::TombstoneCounterMismatch { expected, observed }
+            }
+            TombstoneError::SerializeError(err) => Self::SerializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for InitializeSponsoredCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<WriteError> for InitializeSponsoredCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
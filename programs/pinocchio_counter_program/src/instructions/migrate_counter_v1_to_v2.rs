@@ -0,0 +1,203 @@
+use {
+    crate::{
+        load_counter, AccountDiscriminatorError, CountReturnDataV1, Counter, CounterV1, CounterV2,
+        LoadCounterError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, WriteError},
+};
+
+pub struct MigrateCounterV1ToV2<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: MigrateCounterV1ToV2Accounts<'a>,
+}
+
+pub struct MigrateCounterV1ToV2Accounts<'a> {
+    pub payer: &'a AccountInfo,
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub counter_state: CounterV1,
+    pub system_program: &'a AccountInfo,
+}
+
+#[derive(Debug)]
+pub enum MigrateCounterV1ToV2Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    PayerMustBeSigner,
+    PayerMustBeWriteable,
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DeserializeError(ReadError),
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    CounterAlreadyMigrated,
+    SystemProgramAddressMismatch,
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+}
+
+impl MigrateCounterV1ToV2<'_> {
+    /// Executes the migrate counter instruction.
+    ///
+    /// Rewrites a `CounterV1` account as a `CounterV2` at the same address, keeping its owner,
+    /// bump and count, with the default count policy, a private access mode and the owner as
+    /// authority. The current slot is recorded as the counter's creation slot, since
+    /// `CounterV1` does not store one. The account is resized to the larger layout, with the
+    /// payer covering the increased rent requirement.
+    ///
+    /// The payer is not recorded as the counter's rent payer: it only topped up the rent, so
+    /// deactivating the counter still refunds the owner.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`MigrateCounterV1ToV2Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), MigrateCounterV1ToV2Error> {
+        let counter_state = CounterV2::from_v1(&self.accounts.counter_state, &Clock::get()?);
+
+        let rent = Rent::get()?;
+        let rent_exempt_minimum_counter = rent.minimum_balance(CounterV2::size());
+
+        let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+        let additional_lamports_needed =
+            rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+        if additional_lamports_needed > 0 {
+            Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.counter,
+                lamports: additional_lamports_needed,
+            }
+            .invoke()?;
+        }
+
+        self.accounts.counter.resize(CounterV2::size())?;
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(MigrateCounterV1ToV2Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for MigrateCounterV1ToV2<'a> {
+    type Error = MigrateCounterV1ToV2Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = MigrateCounterV1ToV2Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for MigrateCounterV1ToV2Accounts<'a> {
+    type Error = MigrateCounterV1ToV2Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [payer, owner, counter, system_program] = accounts else {
+            return Err(MigrateCounterV1ToV2Error::NotEnoughAccounts {
+                expected: 4,
+                observed: accounts.len(),
+            });
+        };
+
+        if !payer.is_signer() {
+            return Err(MigrateCounterV1ToV2Error::PayerMustBeSigner);
+        }
+
+        if !payer.is_writable() {
+            return Err(MigrateCounterV1ToV2Error::PayerMustBeWriteable);
+        }
+
+        if !owner.is_signer() {
+            return Err(MigrateCounterV1ToV2Error::OwnerMustBeSigner);
+        }
+
+        if !counter.is_writable() {
+            return Err(MigrateCounterV1ToV2Error::CounterMustBeWriteable);
+        }
+
+        let counter_state = load_counter(program_id, counter)?;
+        if counter_state.authority() != owner.key() {
+            return Err(MigrateCounterV1ToV2Error::AuthorityMismatch);
+        }
+
+        let Counter::V1(counter_state) = counter_state else {
+            return Err(MigrateCounterV1ToV2Error::CounterAlreadyMigrated);
+        };
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(MigrateCounterV1ToV2Error::SystemProgramAddressMismatch);
+        }
+
+        Ok(Self {
+            payer,
+            owner,
+            counter,
+            counter_state,
+            system_program,
+        })
+    }
+}
+
+impl From<AccountDiscriminatorError> for MigrateCounterV1ToV2Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for MigrateCounterV1ToV2Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for MigrateCounterV1ToV2Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for MigrateCounterV1ToV2Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for MigrateCounterV1ToV2Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
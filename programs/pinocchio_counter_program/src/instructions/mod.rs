@@ -1,16 +1,102 @@
+mod accept_authority_v1;
+mod approve_delegate_v1;
+mod close_counter_v1;
+mod compare_and_increment_count_v1;
+mod compare_and_set_count_v1;
 mod deactivate_counter_v1;
-mod initialize_counter_v1;
-mod reactivate_counter_v1;
-
+mod deactivate_indexed_counter_v1;
+mod decrement_by_v1;
 mod decrement_count_v1;
+mod decrement_indexed_counter_v1;
+mod execute_batch_v1;
+mod freeze_counter_v1;
+mod get_count_v1;
+mod increment_by_v1;
 mod increment_count_v1;
+mod increment_counter_shard_v1;
+mod increment_indexed_counter_v1;
+mod initialize_config_v1;
+mod initialize_counter_v1;
+
+mod initialize_indexed_counter_v1;
+mod initialize_multisig_v1;
+mod initialize_sharded_counter_v1;
+mod initialize_sponsored_counter_v1;
+mod migrate_counter_v1_to_v2;
+mod propose_authority_v1;
+mod reactivate_counter_v1;
+mod reactivate_indexed_counter_v1;
+mod reactivate_sponsored_counter_v1;
+mod reconcile_shards_v1;
+mod revoke_delegate_v1;
+mod set_access_mode_v1;
+mod set_cooldown_v1;
+mod set_count_policy_v1;
 mod set_count_v1;
+mod set_indexed_count_v1;
+mod set_pause_exemption_v1;
+mod set_paused_v1;
+mod thaw_counter_v1;
 
 pub use {
+    accept_authority_v1::{AcceptAuthorityV1, AcceptAuthorityV1Error},
+    approve_delegate_v1::{ApproveDelegateV1, ApproveDelegateV1Args, ApproveDelegateV1Error},
+    close_counter_v1::{CloseCounterV1, CloseCounterV1Error},
+    compare_and_increment_count_v1::{
+        CompareAndIncrementCountV1, CompareAndIncrementCountV1Args, CompareAndIncrementCountV1Error,
+    },
+    compare_and_set_count_v1::{
+        CompareAndSetCountV1, CompareAndSetCountV1Args, CompareAndSetCountV1Error,
+    },
     deactivate_counter_v1::{DeactivateCounterV1, DeactivateCounterV1Error},
+    deactivate_indexed_counter_v1::{
+        DeactivateIndexedCounterV1, DeactivateIndexedCounterV1Args, DeactivateIndexedCounterV1Error,
+    },
+    decrement_by_v1::{DecrementByV1, DecrementByV1Args, DecrementByV1Error},
     decrement_count_v1::{DecrementCountV1, DecrementCountV1Error},
+    decrement_indexed_counter_v1::{
+        DecrementIndexedCounterV1, DecrementIndexedCounterV1Args, DecrementIndexedCounterV1Error,
+    },
+    execute_batch_v1::{ExecuteBatchV1, ExecuteBatchV1Args, ExecuteBatchV1Error},
+    freeze_counter_v1::{FreezeCounterV1, FreezeCounterV1Error},
+    get_count_v1::{GetCountV1, GetCountV1Error},
+    increment_by_v1::{IncrementByV1, IncrementByV1Args, IncrementByV1Error},
     increment_count_v1::{IncrementCountV1, IncrementCountV1Error},
+    increment_counter_shard_v1::{IncrementCounterShardV1, IncrementCounterShardV1Error},
+    increment_indexed_counter_v1::{
+        IncrementIndexedCounterV1, IncrementIndexedCounterV1Args, IncrementIndexedCounterV1Error,
+    },
+    initialize_config_v1::{InitializeConfigV1, InitializeConfigV1Args, InitializeConfigV1Error},
     initialize_counter_v1::{InitializeCounterV1, InitializeCounterV1Error},
+    initialize_indexed_counter_v1::{
+        InitializeIndexedCounterV1, InitializeIndexedCounterV1Args, InitializeIndexedCounterV1Error,
+    },
+    initialize_multisig_v1::{
+        InitializeMultisigV1, InitializeMultisigV1Args, InitializeMultisigV1Error,
+    },
+    initialize_sharded_counter_v1::{InitializeShardedCounterV1, InitializeShardedCounterV1Error},
+    initialize_sponsored_counter_v1::{
+        InitializeSponsoredCounterV1, InitializeSponsoredCounterV1Error,
+    },
+    migrate_counter_v1_to_v2::{MigrateCounterV1ToV2, MigrateCounterV1ToV2Error},
+    propose_authority_v1::{ProposeAuthorityV1, ProposeAuthorityV1Args, ProposeAuthorityV1Error},
     reactivate_counter_v1::{ReactivateCounterV1, ReactivateCounterV1Error},
+    reactivate_indexed_counter_v1::{
+        ReactivateIndexedCounterV1, ReactivateIndexedCounterV1Args, ReactivateIndexedCounterV1Error,
+    },
+    reactivate_sponsored_counter_v1::{
+        ReactivateSponsoredCounterV1, ReactivateSponsoredCounterV1Error,
+    },
+    reconcile_shards_v1::{ReconcileShardsV1, ReconcileShardsV1Error},
+    revoke_delegate_v1::{RevokeDelegateV1, RevokeDelegateV1Error},
+    set_access_mode_v1::{SetAccessModeV1, SetAccessModeV1Args, SetAccessModeV1Error},
+    set_cooldown_v1::{SetCooldownV1, SetCooldownV1Args, SetCooldownV1Error},
+    set_count_policy_v1::{SetCountPolicyV1, SetCountPolicyV1Args, SetCountPolicyV1Error},
     set_count_v1::{SetCountV1, SetCountV1Args, SetCountV1Error},
+    set_indexed_count_v1::{SetIndexedCountV1, SetIndexedCountV1Args, SetIndexedCountV1Error},
+    set_pause_exemption_v1::{
+        SetPauseExemptionV1, SetPauseExemptionV1Args, SetPauseExemptionV1Error,
+    },
+    set_paused_v1::{SetPausedV1, SetPausedV1Args, SetPausedV1Error},
+    thaw_counter_v1::{ThawCounterV1, ThawCounterV1Error},
 };
@@ -0,0 +1,256 @@
+use {
+    crate::{
+        authorize_counter_authority, load_counter, split_authority_accounts,
+        AccountDiscriminatorError, CountReturnDataV1, Counter, CounterV2, LoadCounterError,
+        MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct ProposeAuthorityV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: ProposeAuthorityV1Accounts<'a>,
+    pub args: ProposeAuthorityV1Args,
+}
+
+pub struct ProposeAuthorityV1Accounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+/// Arguments for [`ProposeAuthorityV1`].
+///
+/// Proposing [`NO_PENDING_AUTHORITY`](crate::NO_PENDING_AUTHORITY) cancels a pending transfer.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct ProposeAuthorityV1Args {
+    pub new_authority: Pubkey,
+}
+
+#[derive(Debug)]
+pub enum ProposeAuthorityV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    AuthorityMustBeSigner,
+    AuthorityMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+}
+
+impl ProposeAuthorityV1<'_> {
+    /// Executes the propose authority instruction.
+    ///
+    /// Records `args.new_authority` as the counter's pending authority. The transfer only takes
+    /// effect once the proposed key signs `AcceptAuthorityV1`, so a mistyped key never locks the
+    /// counter. Proposing again replaces the pending authority. Only the current authority may
+    /// propose. A multisig authority proposes once enough of its members sign, passed after the
+    /// system program, which is how a multisig hands the counter back to a single key.
+    ///
+    /// A `CounterV1` account is upgraded in place to `CounterV2`, as in `SetCountPolicyV1`, since
+    /// only `CounterV2` can store an authority that differs from the creator.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`ProposeAuthorityV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), ProposeAuthorityV1Error> {
+        let clock = Clock::get()?;
+
+        let mut counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            match Counter::deserialize(&counter_data)? {
+                Counter::V1(counter) => CounterV2::from_v1(&counter, &clock),
+                Counter::V2(counter) => counter,
+            }
+        };
+
+        counter_state.pending_authority = self.args.new_authority;
+
+        counter_state.record_modification(&clock);
+
+        if self.accounts.counter.data_len() != CounterV2::size() {
+            let rent = Rent::get()?;
+            let rent_exempt_minimum_counter = rent.minimum_balance(CounterV2::size());
+
+            let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+            let additional_lamports_needed =
+                rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+            if additional_lamports_needed > 0 {
+                Transfer {
+                    from: self.accounts.authority,
+                    to: self.accounts.counter,
+                    lamports: additional_lamports_needed,
+                }
+                .invoke()?;
+            }
+
+            self.accounts.counter.resize(CounterV2::size())?;
+        }
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(ProposeAuthorityV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for ProposeAuthorityV1<'a> {
+    type Error = ProposeAuthorityV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ProposeAuthorityV1Accounts::try_from((program_id, accounts))?;
+        let args = ProposeAuthorityV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ProposeAuthorityV1Accounts<'a> {
+    type Error = ProposeAuthorityV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([authority, counter, system_program], multisig_signers) =
+            split_authority_accounts(accounts)?;
+
+        // A multisig authority does not pay, since only a `CounterV2` account, which is never
+        // upgraded here, can have one
+        if multisig_signers.is_none() && !authority.is_writable() {
+            return Err(ProposeAuthorityV1Error::AuthorityMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(ProposeAuthorityV1Error::CounterMustBeWriteable);
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(ProposeAuthorityV1Error::SystemProgramAddressMismatch);
+        }
+
+        let counter_state = load_counter(program_id, counter)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            authority,
+            multisig_signers,
+        )?;
+
+        Ok(Self {
+            authority,
+            counter,
+            system_program,
+        })
+    }
+}
+
+impl ProposeAuthorityV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<LoadCounterError> for ProposeAuthorityV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for ProposeAuthorityV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for ProposeAuthorityV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for ProposeAuthorityV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for ProposeAuthorityV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::AuthorityMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
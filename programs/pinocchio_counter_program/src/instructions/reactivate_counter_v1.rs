@@ -1,10 +1,14 @@
 use {
-    crate::{find_counter_v1, AccountDiscriminator, AccountDiscriminatorError, CounterV1},
+    crate::{
+        authorize_counter_authority, find_counter_v1, AccountDiscriminator,
+        AccountDiscriminatorError, CountReturnDataV1, Counter, CounterEventV1, CounterV1,
+        CounterV2, DeactivatedCounterV1, EventDiscriminator, MultisigAuthorizationError,
+    },
     pinocchio::{
         account_info::AccountInfo,
         program_error::ProgramError,
         pubkey::Pubkey,
-        sysvars::{rent::Rent, Sysvar},
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
     },
     pinocchio_system::instructions::Transfer,
     wincode::{ReadError, WriteError},
@@ -20,6 +24,8 @@ pub struct ReactivateCounterV1Accounts<'a> {
     pub counter: &'a AccountInfo,
     pub counter_bump: u8,
     pub system_program: &'a AccountInfo,
+    /// What the counter kept of its authority when it was deactivated.
+    pub deactivated: DeactivatedCounterV1,
 }
 
 #[derive(Debug)]
@@ -35,6 +41,14 @@ pub enum ReactivateCounterV1Error {
     SerializeError(WriteError),
     SerializedSizeMismatch { expected: usize, observed: usize },
     AccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMustBeSigner,
+    AuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
 }
 
 impl ReactivateCounterV1<'_> {
@@ -42,18 +56,41 @@ impl ReactivateCounterV1<'_> {
     ///
     /// Reactivates a deactivated counter account by:
     /// - Verifying the account discriminator is `DeactivatedAccount`
-    /// - Resizing the account from 1 byte to `CounterV1::size()`
+    /// - Resizing the account to the size of the restored counter
     /// - Transferring additional lamports from the payer to cover the increased rent requirement
-    /// - Initializing the account with `CounterV1Account` discriminator and default state (count = 0)
+    /// - Initializing the account with default state (count = 0)
+    ///
+    /// A counter still held by its creator is restored as a [`CounterV1`]. One whose authority
+    /// was rotated, or is being transferred, is restored as a [`CounterV2`] keeping the authority
+    /// and pending authority it had when it was deactivated, and its authority must have signed.
+    /// A multisig authority approves once enough of its members sign.
     ///
-    /// The counter is restored to its initial state with count = 0.
+    /// Emits a `CounterReactivatedV1` event.
     ///
     /// # Errors
     ///
     /// Returns a [`Result`] containing a [`ReactivateCounterV1Error`] if execution fails.
     pub fn execute(&self) -> Result<(), ReactivateCounterV1Error> {
+        let owner = self.accounts.payer.key();
+        let counter_v1 = CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: *owner,
+            bump: self.accounts.counter_bump,
+            count: 0,
+        };
+        let deactivated = &self.accounts.deactivated;
+        let state = if deactivated.is_creator_owned() {
+            Counter::V1(counter_v1)
+        } else {
+            let mut counter_v2 = CounterV2::from_v1(&counter_v1, &Clock::get()?);
+            counter_v2.authority = deactivated.authority;
+            counter_v2.pending_authority = deactivated.pending_authority;
+            Counter::V2(counter_v2)
+        };
+        let counter_size = state.size();
+
         let rent = Rent::get()?;
-        let rent_exempt_minimum_counter = rent.minimum_balance(CounterV1::size());
+        let rent_exempt_minimum_counter = rent.minimum_balance(counter_size);
 
         // Calculate additional lamports needed
         let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
@@ -70,31 +107,22 @@ impl ReactivateCounterV1<'_> {
             .invoke()?;
         }
 
-        // Resize the account from 1 byte to CounterV1::size()
-        self.accounts.counter.resize(CounterV1::size())?;
-
-        // Initialize the counter state
-        let owner = self.accounts.payer.key();
-        let state = CounterV1 {
-            discriminator: AccountDiscriminator::CounterV1Account,
-            owner: *owner,
-            bump: self.accounts.counter_bump,
-            count: 0,
-        };
+        // Resize the account from the deactivated record to the restored counter
+        self.accounts.counter.resize(counter_size)?;
 
-        let serialized = state.serialize()?;
+        let written = state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
 
-        if serialized.len() != CounterV1::size() {
+        if written != counter_size {
             return Err(ReactivateCounterV1Error::SerializedSizeMismatch {
-                expected: CounterV1::size(),
-                observed: serialized.len(),
+                expected: counter_size,
+                observed: written,
             });
         }
 
-        self.accounts
-            .counter
-            .try_borrow_mut_data()?
-            .copy_from_slice(&serialized);
+        CounterEventV1::new(self.accounts.counter.key(), owner, 0, 0)?
+            .emit(EventDiscriminator::CounterReactivatedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
 
         Ok(())
     }
@@ -118,11 +146,28 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ReactivateCounterV1Accounts<'
     type Error = ReactivateCounterV1Error;
 
     fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let [payer, counter, system_program] = accounts else {
-            return Err(ReactivateCounterV1Error::NotEnoughAccounts {
-                expected: 3,
-                observed: accounts.len(),
-            });
+        let (payer, counter, system_program, authority, multisig_signers) = match accounts {
+            [payer, counter, system_program] => (payer, counter, system_program, None, None),
+            [payer, counter, system_program, authority] => {
+                (payer, counter, system_program, Some(authority), None)
+            }
+            [payer, counter, system_program, multisig, multisig_signers @ ..]
+                if !multisig.is_signer() =>
+            {
+                (
+                    payer,
+                    counter,
+                    system_program,
+                    Some(multisig),
+                    Some(multisig_signers),
+                )
+            }
+            _ => {
+                return Err(ReactivateCounterV1Error::NotEnoughAccounts {
+                    expected: 3,
+                    observed: accounts.len(),
+                })
+            }
         };
 
         if !payer.is_signer() {
@@ -150,14 +195,35 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ReactivateCounterV1Accounts<'
             return Err(ReactivateCounterV1Error::SystemProgramAddressMismatch);
         }
 
-        let counter_data = counter.try_borrow_data()?;
-        AccountDiscriminator::check(AccountDiscriminator::DeactivatedAccount, &counter_data)?;
+        let deactivated = {
+            let counter_data = counter.try_borrow_data()?;
+            AccountDiscriminator::check_deactivated_counter(&counter_data)?;
+            DeactivatedCounterV1::from_account_data(&counter_data, payer.key(), counter_bump)?
+        };
+
+        // A counter whose authority moved away from its creator is only reactivated with the
+        // approval of that authority, passed after the system program. A multisig authority does
+        // not sign, and its signing members follow it instead.
+        match authority {
+            Some(authority) => authorize_counter_authority(
+                program_id,
+                counter.key(),
+                &deactivated.authority,
+                authority,
+                multisig_signers,
+            )?,
+            None if &deactivated.authority != payer.key() => {
+                return Err(ReactivateCounterV1Error::AuthorityMustBeSigner);
+            }
+            None => {}
+        }
 
         Ok(Self {
             payer,
             counter,
             counter_bump,
             system_program,
+            deactivated,
         })
     }
 }
@@ -185,3 +251,34 @@ impl From<WriteError> for ReactivateCounterV1Error {
         Self::SerializeError(err)
     }
 }
+
+impl From<MultisigAuthorizationError> for ReactivateCounterV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner => Self::AuthorityMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use {
+    crate::{
+        find_indexed_counter_v1, AccountDiscriminator, AccountDiscriminatorError,
+        CountReturnDataV1, CounterEventV1, EventDiscriminator, IndexedCounterV1,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct ReactivateIndexedCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: ReactivateIndexedCounterV1Accounts<'a>,
+    pub args: ReactivateIndexedCounterV1Args,
+}
+
+pub struct ReactivateIndexedCounterV1Accounts<'a> {
+    pub payer: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub counter_bump: u8,
+    pub system_program: &'a AccountInfo,
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct ReactivateIndexedCounterV1Args {
+    pub index: u32,
+}
+
+#[derive(Debug)]
+pub enum ReactivateIndexedCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    PayerMustBeSigner,
+    PayerMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+}
+
+impl ReactivateIndexedCounterV1<'_> {
+    /// Executes the reactivate indexed counter instruction.
+    ///
+    /// Reactivates a deactivated indexed counter account by:
+    /// - Transferring additional lamports from the payer to cover the increased rent requirement
+    /// - Resizing the account from 1 byte to `IndexedCounterV1::size()`
+    /// - Initializing the account with `IndexedCounterV1Account` discriminator and count = 0
+    ///
+    /// Emits a `CounterReactivatedV1` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`ReactivateIndexedCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), ReactivateIndexedCounterV1Error> {
+        let rent = Rent::get()?;
+        let rent_exempt_minimum_counter = rent.minimum_balance(IndexedCounterV1::size());
+
+        let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+        let additional_lamports_needed =
+            rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+        if additional_lamports_needed > 0 {
+            Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.counter,
+                lamports: additional_lamports_needed,
+            }
+            .invoke()?;
+        }
+
+        self.accounts.counter.resize(IndexedCounterV1::size())?;
+
+        let owner = self.accounts.payer.key();
+        let state = IndexedCounterV1 {
+            discriminator: AccountDiscriminator::IndexedCounterV1Account,
+            owner: *owner,
+            index: self.args.index,
+            bump: self.accounts.counter_bump,
+            count: 0,
+        };
+
+        let written = state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != IndexedCounterV1::size() {
+            return Err(ReactivateIndexedCounterV1Error::SerializedSizeMismatch {
+                expected: IndexedCounterV1::size(),
+                observed: written,
+            });
+        }
+
+        CounterEventV1::new(self.accounts.counter.key(), owner, 0, 0)?
+            .emit(EventDiscriminator::CounterReactivatedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for ReactivateIndexedCounterV1<'a> {
+    type Error = ReactivateIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let args = ReactivateIndexedCounterV1Args::deserialize(args)?;
+        let accounts = ReactivateIndexedCounterV1Accounts::try_from((program_id, accounts, &args))?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo], &ReactivateIndexedCounterV1Args)>
+    for ReactivateIndexedCounterV1Accounts<'a>
+{
+    type Error = ReactivateIndexedCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&Pubkey, &'a [AccountInfo], &ReactivateIndexedCounterV1Args),
+    ) -> Result<Self, Self::Error> {
+        let [payer, counter, system_program] = accounts else {
+            return Err(ReactivateIndexedCounterV1Error::NotEnoughAccounts {
+                expected: 3,
+                observed: accounts.len(),
+            });
+        };
+
+        if !payer.is_signer() {
+            return Err(ReactivateIndexedCounterV1Error::PayerMustBeSigner);
+        }
+
+        if !payer.is_writable() {
+            return Err(ReactivateIndexedCounterV1Error::PayerMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(ReactivateIndexedCounterV1Error::CounterMustBeWriteable);
+        }
+
+        let (expected_counter, counter_bump) =
+            find_indexed_counter_v1(program_id, payer.key(), args.index);
+        let observed_counter = counter.key();
+        if observed_counter != &expected_counter {
+            return Err(ReactivateIndexedCounterV1Error::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: *observed_counter,
+            });
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(ReactivateIndexedCounterV1Error::SystemProgramAddressMismatch);
+        }
+
+        let counter_data = counter.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::DeactivatedAccount, &counter_data)?;
+
+        Ok(Self {
+            payer,
+            counter,
+            counter_bump,
+            system_program,
+        })
+    }
+}
+
+impl ReactivateIndexedCounterV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for ReactivateIndexedCounterV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for ReactivateIndexedCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for ReactivateIndexedCounterV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for ReactivateIndexedCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -0,0 +1,291 @@
+use {
+    crate::{
+        authorize_counter_authority, find_counter_v1, AccessMode, AccountDiscriminator,
+        AccountDiscriminatorError, CountPolicy, CountReturnDataV1, CounterEventV1, CounterV2,
+        DeactivatedCounterV1, EventDiscriminator, MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, WriteError},
+};
+
+pub struct ReactivateSponsoredCounterV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: ReactivateSponsoredCounterV1Accounts<'a>,
+}
+
+pub struct ReactivateSponsoredCounterV1Accounts<'a> {
+    pub payer: &'a AccountInfo,
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub counter_bump: u8,
+    pub system_program: &'a AccountInfo,
+    /// What the counter kept of its authority when it was deactivated.
+    pub deactivated: DeactivatedCounterV1,
+}
+
+#[derive(Debug)]
+pub enum ReactivateSponsoredCounterV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    PayerMustBeSigner,
+    PayerMustBeWriteable,
+    OwnerMustBeSigner,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    AuthorityMustBeSigner,
+    AuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+}
+
+impl ReactivateSponsoredCounterV1<'_> {
+    /// Executes the reactivate sponsored counter instruction.
+    ///
+    /// Reactivates a deactivated counter as in `ReactivateCounterV1`, except that the payer
+    /// covering the increased rent requirement is separate from the owner and is recorded as the
+    /// counter's rent payer. The counter is restored as a [`CounterV2`] with count = 0, the
+    /// default count policy, and the authority and pending authority it had when it was
+    /// deactivated, whose approval is required as in `ReactivateCounterV1`.
+    ///
+    /// Emits a `CounterReactivatedV1` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`ReactivateSponsoredCounterV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), ReactivateSponsoredCounterV1Error> {
+        let rent = Rent::get()?;
+        let rent_exempt_minimum_counter = rent.minimum_balance(CounterV2::size());
+
+        let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+        let additional_lamports_needed =
+            rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+        if additional_lamports_needed > 0 {
+            Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.counter,
+                lamports: additional_lamports_needed,
+            }
+            .invoke()?;
+        }
+
+        self.accounts.counter.resize(CounterV2::size())?;
+
+        let owner = self.accounts.owner.key();
+        let clock = Clock::get()?;
+        let policy = CountPolicy::default();
+        let state = CounterV2 {
+            discriminator: AccountDiscriminator::CounterV2Account,
+            owner: *owner,
+            bump: self.accounts.counter_bump,
+            count: 0,
+            overflow_policy: policy.overflow_policy,
+            min_count: policy.min_count,
+            max_count: policy.max_count,
+            authority: self.accounts.deactivated.authority,
+            pending_authority: self.accounts.deactivated.pending_authority,
+            rent_payer: *self.accounts.payer.key(),
+            access_mode: AccessMode::Private,
+            frozen: false,
+            created_slot: clock.slot,
+            last_modified_slot: clock.slot,
+            last_modified_unix_timestamp: clock.unix_timestamp,
+            cooldown_slots: 0,
+            cooldown_seconds: 0,
+        };
+
+        let written = state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(ReactivateSponsoredCounterV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CounterEventV1::new(self.accounts.counter.key(), owner, 0, 0)?
+            .emit(EventDiscriminator::CounterReactivatedV1)?;
+
+        CountReturnDataV1 { count: 0 }.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for ReactivateSponsoredCounterV1<'a> {
+    type Error = ReactivateSponsoredCounterV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ReactivateSponsoredCounterV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ReactivateSponsoredCounterV1Accounts<'a> {
+    type Error = ReactivateSponsoredCounterV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let (payer, owner, counter, system_program, authority, multisig_signers) = match accounts {
+            [payer, owner, counter, system_program] => {
+                (payer, owner, counter, system_program, None, None)
+            }
+            [payer, owner, counter, system_program, authority] => {
+                (payer, owner, counter, system_program, Some(authority), None)
+            }
+            [payer, owner, counter, system_program, multisig, multisig_signers @ ..]
+                if !multisig.is_signer() =>
+            {
+                (
+                    payer,
+                    owner,
+                    counter,
+                    system_program,
+                    Some(multisig),
+                    Some(multisig_signers),
+                )
+            }
+            _ => {
+                return Err(ReactivateSponsoredCounterV1Error::NotEnoughAccounts {
+                    expected: 4,
+                    observed: accounts.len(),
+                })
+            }
+        };
+
+        if !payer.is_signer() {
+            return Err(ReactivateSponsoredCounterV1Error::PayerMustBeSigner);
+        }
+
+        if !payer.is_writable() {
+            return Err(ReactivateSponsoredCounterV1Error::PayerMustBeWriteable);
+        }
+
+        if !owner.is_signer() {
+            return Err(ReactivateSponsoredCounterV1Error::OwnerMustBeSigner);
+        }
+
+        if !counter.is_writable() {
+            return Err(ReactivateSponsoredCounterV1Error::CounterMustBeWriteable);
+        }
+
+        let (expected_counter, counter_bump) = find_counter_v1(program_id, owner.key());
+        let observed_counter = counter.key();
+        if observed_counter != &expected_counter {
+            return Err(ReactivateSponsoredCounterV1Error::CounterAddressMismatch {
+                expected: expected_counter,
+                observed: *observed_counter,
+            });
+        }
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(ReactivateSponsoredCounterV1Error::SystemProgramAddressMismatch);
+        }
+
+        let deactivated = {
+            let counter_data = counter.try_borrow_data()?;
+            AccountDiscriminator::check_deactivated_counter(&counter_data)?;
+            DeactivatedCounterV1::from_account_data(&counter_data, owner.key(), counter_bump)?
+        };
+
+        // A counter whose authority moved away from its creator is only reactivated with the
+        // approval of that authority, passed after the system program. A multisig authority does
+        // not sign, and its signing members follow it instead.
+        match authority {
+            Some(authority) => authorize_counter_authority(
+                program_id,
+                counter.key(),
+                &deactivated.authority,
+                authority,
+                multisig_signers,
+            )?,
+            None if &deactivated.authority != owner.key() => {
+                return Err(ReactivateSponsoredCounterV1Error::AuthorityMustBeSigner);
+            }
+            None => {}
+        }
+
+        Ok(Self {
+            payer,
+            owner,
+            counter,
+            counter_bump,
+            system_program,
+            deactivated,
+        })
+    }
+}
+
+impl From<AccountDiscriminatorError> for ReactivateSponsoredCounterV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for ReactivateSponsoredCounterV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for ReactivateSponsoredCounterV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for ReactivateSponsoredCounterV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for ReactivateSponsoredCounterV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner => Self::AuthorityMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
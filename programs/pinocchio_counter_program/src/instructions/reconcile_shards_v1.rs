@@ -0,0 +1,236 @@
+use {
+    crate::{
+        create_counter_shard_v1_address, create_sharded_counter_v1_address, AccountDiscriminator,
+        AccountDiscriminatorError, CountReturnDataV1, CounterShardV1, ShardedCounterV1,
+        MAX_COUNTER_SHARDS,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::{ReadError, WriteError},
+};
+
+pub struct ReconcileShardsV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: ReconcileShardsV1Accounts<'a>,
+}
+
+pub struct ReconcileShardsV1Accounts<'a> {
+    pub owner: &'a AccountInfo,
+    pub sharded_counter: &'a AccountInfo,
+    /// The shards to reconcile, in any order. Each must belong to `sharded_counter`.
+    pub shards: &'a [AccountInfo],
+}
+
+#[derive(Debug)]
+pub enum ReconcileShardsV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    ShardedCounterMustBeWriteable,
+    ShardedCounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    OwnerMismatch,
+    TooManyShards { maximum: usize, observed: usize },
+    ShardMustBeWriteable,
+    ShardAddressMismatch { expected: Pubkey, observed: Pubkey },
+    ShardedCounterMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    ShardAccountDiscriminatorError(AccountDiscriminatorError),
+}
+
+impl ReconcileShardsV1<'_> {
+    /// Moves the count of every given shard into the sharded counter.
+    ///
+    /// Each shard's count is added to the sharded counter's count, saturating at `u64::MAX`, and
+    /// the shard is reset to `0`, so the total count is unchanged. Any subset of the shards can be
+    /// reconciled, and a shard passed more than once is only counted once. Only the owner may
+    /// reconcile.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`ReconcileShardsV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), ReconcileShardsV1Error> {
+        let mut sharded_counter_state = {
+            let sharded_counter_data = self.accounts.sharded_counter.try_borrow_data()?;
+            ShardedCounterV1::deserialize(&sharded_counter_data)?
+        };
+
+        for shard in self.accounts.shards {
+            let mut shard_state = {
+                let shard_data = shard.try_borrow_data()?;
+                CounterShardV1::deserialize(&shard_data)?
+            };
+
+            sharded_counter_state.count = sharded_counter_state
+                .count
+                .saturating_add(shard_state.count);
+            shard_state.count = 0;
+
+            let written = shard_state.serialize_into(&mut shard.try_borrow_mut_data()?)?;
+
+            if written != CounterShardV1::size() {
+                return Err(ReconcileShardsV1Error::SerializedSizeMismatch {
+                    expected: CounterShardV1::size(),
+                    observed: written,
+                });
+            }
+        }
+
+        let written = sharded_counter_state
+            .serialize_into(&mut self.accounts.sharded_counter.try_borrow_mut_data()?)?;
+
+        if written != ShardedCounterV1::size() {
+            return Err(ReconcileShardsV1Error::SerializedSizeMismatch {
+                expected: ShardedCounterV1::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: sharded_counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for ReconcileShardsV1<'a> {
+    type Error = ReconcileShardsV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = ReconcileShardsV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for ReconcileShardsV1Accounts<'a> {
+    type Error = ReconcileShardsV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [owner, sharded_counter, shards @ ..] = accounts else {
+            return Err(ReconcileShardsV1Error::NotEnoughAccounts {
+                expected: 3,
+                observed: accounts.len(),
+            });
+        };
+
+        if shards.is_empty() {
+            return Err(ReconcileShardsV1Error::NotEnoughAccounts {
+                expected: 3,
+                observed: accounts.len(),
+            });
+        }
+
+        if shards.len() > usize::from(MAX_COUNTER_SHARDS) {
+            return Err(ReconcileShardsV1Error::TooManyShards {
+                maximum: usize::from(MAX_COUNTER_SHARDS),
+                observed: shards.len(),
+            });
+        }
+
+        if !owner.is_signer() {
+            return Err(ReconcileShardsV1Error::OwnerMustBeSigner);
+        }
+
+        if !sharded_counter.is_writable() {
+            return Err(ReconcileShardsV1Error::ShardedCounterMustBeWriteable);
+        }
+
+        let sharded_counter_state = {
+            let sharded_counter_data = sharded_counter.try_borrow_data()?;
+            AccountDiscriminator::check(
+                AccountDiscriminator::ShardedCounterV1Account,
+                &sharded_counter_data,
+            )?;
+            ShardedCounterV1::deserialize(&sharded_counter_data)?
+        };
+
+        let expected_sharded_counter = create_sharded_counter_v1_address(
+            program_id,
+            &sharded_counter_state.owner,
+            sharded_counter_state.bump,
+        )?;
+        let observed_sharded_counter = sharded_counter.key();
+        if observed_sharded_counter != &expected_sharded_counter {
+            return Err(ReconcileShardsV1Error::ShardedCounterAddressMismatch {
+                expected: expected_sharded_counter,
+                observed: *observed_sharded_counter,
+            });
+        }
+
+        if &sharded_counter_state.owner != owner.key() {
+            return Err(ReconcileShardsV1Error::OwnerMismatch);
+        }
+
+        for shard in shards {
+            if !shard.is_writable() {
+                return Err(ReconcileShardsV1Error::ShardMustBeWriteable);
+            }
+
+            let shard_state = {
+                let shard_data = shard.try_borrow_data()?;
+                AccountDiscriminator::check(
+                    AccountDiscriminator::CounterShardV1Account,
+                    &shard_data,
+                )
+                .map_err(ReconcileShardsV1Error::ShardAccountDiscriminatorError)?;
+                CounterShardV1::deserialize(&shard_data)?
+            };
+
+            if &shard_state.sharded_counter != observed_sharded_counter {
+                return Err(ReconcileShardsV1Error::ShardedCounterMismatch);
+            }
+
+            let expected_shard = create_counter_shard_v1_address(
+                program_id,
+                &shard_state.sharded_counter,
+                shard_state.index,
+                shard_state.bump,
+            )?;
+            let observed_shard = shard.key();
+            if observed_shard != &expected_shard {
+                return Err(ReconcileShardsV1Error::ShardAddressMismatch {
+                    expected: expected_shard,
+                    observed: *observed_shard,
+                });
+            }
+        }
+
+        Ok(Self {
+            owner,
+            sharded_counter,
+            shards,
+        })
+    }
+}
+
+impl From<AccountDiscriminatorError> for ReconcileShardsV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for ReconcileShardsV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for ReconcileShardsV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for ReconcileShardsV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -0,0 +1,218 @@
+use {
+    crate::{
+        authorize_counter_authority, find_delegate_v1, load_counter, load_deactivated_counter,
+        split_authority_accounts, AccountDiscriminator, AccountDiscriminatorError,
+        LoadCounterError, MultisigAuthorizationError,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::ReadError,
+};
+
+pub struct RevokeDelegateV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: RevokeDelegateV1Accounts<'a>,
+}
+
+pub struct RevokeDelegateV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the delegate record instead.
+    pub owner: &'a AccountInfo,
+    /// Receives the delegate record's lamports: the signer, or the first signing member of a
+    /// multisig authority.
+    pub recipient: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub delegate: &'a AccountInfo,
+}
+
+#[derive(Debug)]
+pub enum RevokeDelegateV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    OwnerMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    DelegateMustBeWriteable,
+    DelegateAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    AuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    MultisigSignerMustBeWriteable,
+}
+
+impl RevokeDelegateV1<'_> {
+    /// Executes the revoke delegate instruction.
+    ///
+    /// Closes the counter's delegate record and refunds its lamports to the signer. Only the
+    /// counter authority may revoke. A multisig authority revokes once enough of its members
+    /// sign, passed after the delegate record, and the first of them is refunded. The counter
+    /// itself is not required to be active, so a delegate can still be revoked after the counter
+    /// has been deactivated, by the authority the counter had when it was deactivated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`RevokeDelegateV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), RevokeDelegateV1Error> {
+        let delegate_lamports = self.accounts.delegate.lamports();
+
+        {
+            *self.accounts.delegate.try_borrow_mut_lamports()? = 0;
+            *self.accounts.recipient.try_borrow_mut_lamports()? += delegate_lamports;
+        }
+
+        self.accounts.delegate.close()?;
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for RevokeDelegateV1<'a> {
+    type Error = RevokeDelegateV1Error;
+
+    fn try_from(
+        (program_id, accounts, _args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RevokeDelegateV1Accounts::try_from((program_id, accounts))?;
+        Ok(Self {
+            program_id,
+            accounts,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for RevokeDelegateV1Accounts<'a> {
+    type Error = RevokeDelegateV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter, delegate], multisig_signers) = split_authority_accounts(accounts)?;
+
+        // A multisig authority does not receive the refund
+        if multisig_signers.is_none() && !owner.is_writable() {
+            return Err(RevokeDelegateV1Error::OwnerMustBeWriteable);
+        }
+
+        // The counter itself is not required to be active. A deactivated counter keeps its
+        // authority, so the same authority revokes either way.
+        let counter_authority = {
+            let is_deactivated = {
+                let counter_data = counter.try_borrow_data()?;
+                AccountDiscriminator::check_deactivated_counter(&counter_data).is_ok()
+            };
+            if is_deactivated {
+                load_deactivated_counter(program_id, counter, owner.key())?.authority
+            } else {
+                *load_counter(program_id, counter)?.authority()
+            }
+        };
+
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            &counter_authority,
+            owner,
+            multisig_signers,
+        )?;
+
+        let recipient = if let Some(multisig_signers) = multisig_signers {
+            let [recipient, ..] = multisig_signers else {
+                return Err(RevokeDelegateV1Error::NotEnoughAccounts {
+                    expected: 4,
+                    observed: accounts.len(),
+                });
+            };
+            if !recipient.is_writable() {
+                return Err(RevokeDelegateV1Error::MultisigSignerMustBeWriteable);
+            }
+
+            recipient
+        } else {
+            owner
+        };
+
+        if !delegate.is_writable() {
+            return Err(RevokeDelegateV1Error::DelegateMustBeWriteable);
+        }
+
+        let (expected_delegate, _bump) = find_delegate_v1(program_id, counter.key());
+        let observed_delegate = delegate.key();
+        if observed_delegate != &expected_delegate {
+            return Err(RevokeDelegateV1Error::DelegateAddressMismatch {
+                expected: expected_delegate,
+                observed: *observed_delegate,
+            });
+        }
+
+        let delegate_data = delegate.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::DelegateV1Account, &delegate_data)?;
+
+        Ok(Self {
+            owner,
+            recipient,
+            counter,
+            delegate,
+        })
+    }
+}
+
+impl From<AccountDiscriminatorError> for RevokeDelegateV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for RevokeDelegateV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for RevokeDelegateV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for RevokeDelegateV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
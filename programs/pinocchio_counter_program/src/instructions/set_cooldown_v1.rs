@@ -0,0 +1,261 @@
+use {
+    crate::{
+        authorize_counter_authority, load_counter, split_authority_accounts,
+        AccountDiscriminatorError, CountReturnDataV1, Counter, CounterV2, LoadCounterError,
+        MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct SetCooldownV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: SetCooldownV1Accounts<'a>,
+    pub args: SetCooldownV1Args,
+}
+
+pub struct SetCooldownV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the system program instead.
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+#[derive(SchemaRead, SchemaWrite)]
+pub struct SetCooldownV1Args {
+    pub cooldown_slots: u64,
+    pub cooldown_seconds: u64,
+}
+
+#[derive(Debug)]
+pub enum SetCooldownV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    OwnerMustBeSigner,
+    OwnerMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    AuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+}
+
+impl SetCooldownV1<'_> {
+    /// Executes the set cooldown instruction.
+    ///
+    /// Rate limits the counter: every instruction that changes the count is rejected until
+    /// `cooldown_slots` slots and `cooldown_seconds` seconds have passed since the counter was last
+    /// modified, with `0` disabling either limit. Setting the cooldown is itself a modification,
+    /// so it applies from this slot. Only the authority may change the cooldown, with a multisig
+    /// authority approving as in `SetCountPolicyV1`. A `CounterV1` account is upgraded in place to
+    /// `CounterV2`, exactly as in `SetCountPolicyV1`, with the authority covering the increased
+    /// rent requirement.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`SetCooldownV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), SetCooldownV1Error> {
+        let clock = Clock::get()?;
+
+        let mut counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            match Counter::deserialize(&counter_data)? {
+                Counter::V1(counter) => CounterV2::from_v1(&counter, &clock),
+                Counter::V2(counter) => counter,
+            }
+        };
+
+        counter_state.cooldown_slots = self.args.cooldown_slots;
+        counter_state.cooldown_seconds = self.args.cooldown_seconds;
+
+        counter_state.record_modification(&clock);
+
+        if self.accounts.counter.data_len() != CounterV2::size() {
+            let rent = Rent::get()?;
+            let rent_exempt_minimum_counter = rent.minimum_balance(CounterV2::size());
+
+            let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+            let additional_lamports_needed =
+                rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+            if additional_lamports_needed > 0 {
+                Transfer {
+                    from: self.accounts.owner,
+                    to: self.accounts.counter,
+                    lamports: additional_lamports_needed,
+                }
+                .invoke()?;
+            }
+
+            self.accounts.counter.resize(CounterV2::size())?;
+        }
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(SetCooldownV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for SetCooldownV1<'a> {
+    type Error = SetCooldownV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetCooldownV1Accounts::try_from((program_id, accounts))?;
+        let args = SetCooldownV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for SetCooldownV1Accounts<'a> {
+    type Error = SetCooldownV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter, system_program], multisig_signers) =
+            split_authority_accounts(accounts)?;
+
+        // A multisig authority does not pay, since only a `CounterV2` account, which is never
+        // upgraded here, can have one
+        if multisig_signers.is_none() && !owner.is_writable() {
+            return Err(SetCooldownV1Error::OwnerMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(SetCooldownV1Error::CounterMustBeWriteable);
+        }
+
+        let counter_state = load_counter(program_id, counter)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(SetCooldownV1Error::SystemProgramAddressMismatch);
+        }
+
+        Ok(Self {
+            owner,
+            counter,
+            system_program,
+        })
+    }
+}
+
+impl SetCooldownV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for SetCooldownV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for SetCooldownV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for SetCooldownV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for SetCooldownV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for SetCooldownV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for SetCooldownV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use {
+    crate::{
+        authorize_counter_authority, load_counter, split_authority_accounts,
+        AccountDiscriminatorError, CountPolicy, CountReturnDataV1, Counter, CounterV2,
+        LoadCounterError, MultisigAuthorizationError, OverflowPolicy,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+    },
+    pinocchio_system::instructions::Transfer,
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct SetCountPolicyV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: SetCountPolicyV1Accounts<'a>,
+    pub args: SetCountPolicyV1Args,
+}
+
+pub struct SetCountPolicyV1Accounts<'a> {
+    /// The counter authority. A multisig authority does not sign, and its signing members follow
+    /// the system program instead.
+    pub owner: &'a AccountInfo,
+    pub counter: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+/// Arguments for [`SetCountPolicyV1`].
+///
+/// Omitted bounds default to the full `u64` range (`0` and `u64::MAX`).
+#[derive(SchemaRead, SchemaWrite)]
+pub struct SetCountPolicyV1Args {
+    pub overflow_policy: OverflowPolicy,
+    pub min_count: Option<u64>,
+    pub max_count: Option<u64>,
+}
+
+#[derive(Debug)]
+pub enum SetCountPolicyV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts {
+        expected: usize,
+        observed: usize,
+    },
+    OwnerMustBeSigner,
+    OwnerMustBeWriteable,
+    CounterMustBeWriteable,
+    CounterAddressMismatch {
+        expected: Pubkey,
+        observed: Pubkey,
+    },
+    SystemProgramAddressMismatch,
+    DeserializeError(ReadError),
+    SerializeError(WriteError),
+    SerializedSizeMismatch {
+        expected: usize,
+        observed: usize,
+    },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    InvalidBounds {
+        min_count: u64,
+        max_count: u64,
+    },
+    CountOutOfBounds {
+        count: u64,
+        min_count: u64,
+        max_count: u64,
+    },
+    AuthorityMismatch,
+    MultisigAddressMismatch {
+        expected: Pubkey,
+        observed: Pubkey,
+    },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet {
+        threshold: u8,
+        observed: usize,
+    },
+}
+
+impl SetCountPolicyV1<'_> {
+    /// Executes the set count policy instruction.
+    ///
+    /// Configures the overflow policy and inclusive bounds of a counter. Only the authority may
+    /// configure the policy, with a multisig authority approving once enough of its members sign,
+    /// passed after the system program. A `CounterV1` account is upgraded in place to `CounterV2`:
+    /// - Transferring additional lamports from the authority to cover the increased rent
+    ///   requirement
+    /// - Resizing the account from `CounterV1::size()` to `CounterV2::size()`
+    /// - Rewriting the account with the `CounterV2Account` discriminator, keeping its count
+    ///
+    /// The current count must already lie within the new bounds; it is never adjusted here.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`SetCountPolicyV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), SetCountPolicyV1Error> {
+        let policy = self.args.policy();
+        if policy.min_count > policy.max_count {
+            return Err(SetCountPolicyV1Error::InvalidBounds {
+                min_count: policy.min_count,
+                max_count: policy.max_count,
+            });
+        }
+
+        let clock = Clock::get()?;
+
+        let mut counter_state = {
+            let counter_data = self.accounts.counter.try_borrow_data()?;
+            match Counter::deserialize(&counter_data)? {
+                Counter::V1(counter) => CounterV2::from_v1(&counter, &clock),
+                Counter::V2(counter) => counter,
+            }
+        };
+
+        if !policy.contains(counter_state.count) {
+            return Err(SetCountPolicyV1Error::CountOutOfBounds {
+                count: counter_state.count,
+                min_count: policy.min_count,
+                max_count: policy.max_count,
+            });
+        }
+
+        counter_state.overflow_policy = policy.overflow_policy;
+        counter_state.min_count = policy.min_count;
+        counter_state.max_count = policy.max_count;
+
+        counter_state.record_modification(&clock);
+
+        if self.accounts.counter.data_len() != CounterV2::size() {
+            let rent = Rent::get()?;
+            let rent_exempt_minimum_counter = rent.minimum_balance(CounterV2::size());
+
+            let current_lamports = *self.accounts.counter.try_borrow_lamports()?;
+            let additional_lamports_needed =
+                rent_exempt_minimum_counter.saturating_sub(current_lamports);
+
+            if additional_lamports_needed > 0 {
+                Transfer {
+                    from: self.accounts.owner,
+                    to: self.accounts.counter,
+                    lamports: additional_lamports_needed,
+                }
+                .invoke()?;
+            }
+
+            self.accounts.counter.resize(CounterV2::size())?;
+        }
+
+        let written =
+            counter_state.serialize_into(&mut self.accounts.counter.try_borrow_mut_data()?)?;
+
+        if written != CounterV2::size() {
+            return Err(SetCountPolicyV1Error::SerializedSizeMismatch {
+                expected: CounterV2::size(),
+                observed: written,
+            });
+        }
+
+        CountReturnDataV1 {
+            count: counter_state.count,
+        }
+        .set();
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for SetCountPolicyV1<'a> {
+    type Error = SetCountPolicyV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetCountPolicyV1Accounts::try_from((program_id, accounts))?;
+        let args = SetCountPolicyV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for SetCountPolicyV1Accounts<'a> {
+    type Error = SetCountPolicyV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let ([owner, counter, system_program], multisig_signers) =
+            split_authority_accounts(accounts)?;
+
+        // A multisig authority does not pay, since only a `CounterV2` account, which is never
+        // upgraded here, can have one
+        if multisig_signers.is_none() && !owner.is_writable() {
+            return Err(SetCountPolicyV1Error::OwnerMustBeWriteable);
+        }
+
+        if !counter.is_writable() {
+            return Err(SetCountPolicyV1Error::CounterMustBeWriteable);
+        }
+
+        let counter_state = load_counter(program_id, counter)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        if system_program.key() != &pinocchio_system::ID {
+            return Err(SetCountPolicyV1Error::SystemProgramAddressMismatch);
+        }
+
+        Ok(Self {
+            owner,
+            counter,
+            system_program,
+        })
+    }
+}
+
+impl SetCountPolicyV1Args {
+    /// Returns the count policy described by the arguments, filling in omitted bounds.
+    #[must_use]
+    pub fn policy(&self) -> CountPolicy {
+        let default = CountPolicy::default();
+        CountPolicy {
+            overflow_policy: self.overflow_policy,
+            min_count: self.min_count.unwrap_or(default.min_count),
+            max_count: self.max_count.unwrap_or(default.max_count),
+        }
+    }
+
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<AccountDiscriminatorError> for SetCountPolicyV1Error {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<LoadCounterError> for SetCountPolicyV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for SetCountPolicyV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for SetCountPolicyV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for SetCountPolicyV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
+
+impl From<MultisigAuthorizationError> for SetCountPolicyV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
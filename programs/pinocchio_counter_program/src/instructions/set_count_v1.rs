@@ -1,6 +1,15 @@
 use {
-    crate::{find_counter_v1, AccountDiscriminator, AccountDiscriminatorError, CounterV1},
-    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    crate::{
+        authorize_counter_authority, load_counter_view, split_authority_accounts,
+        AccountDiscriminatorError, CountPolicyError, CountReturnDataV1, CounterEventV1,
+        CounterV1Mut, EventDiscriminator, LoadCounterError, MultisigAuthorizationError,
+    },
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{clock::Clock, Sysvar},
+    },
     wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
 };
 
@@ -30,39 +39,53 @@ pub enum SetCountV1Error {
     CounterAddressMismatch { expected: Pubkey, observed: Pubkey },
     DeserializeError(ReadError),
     SerializeError(WriteError),
-    SerializedSizeMismatch { expected: usize, observed: usize },
     AccountDiscriminatorError(AccountDiscriminatorError),
+    CountAboveMaximum,
+    CountBelowMinimum,
+    AuthorityMismatch,
+    CounterFrozen,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    MultisigAccountDiscriminatorError(AccountDiscriminatorError),
+    MultisigSignerMustBeSigner,
+    MultisigSignerNotMember,
+    MultisigDuplicateSigner,
+    MultisigThresholdNotMet { threshold: u8, observed: usize },
+    CooldownNotElapsed,
 }
 
 impl SetCountV1<'_> {
     /// Executes the set count instruction.
     ///
-    /// Sets the counter's count to the specified value. Only the counter's owner may set the count.
+    /// Sets the counter's count to the specified value. Only the counter authority may set the
+    /// count, with a multisig authority approving through its signing members as in
+    /// `IncrementCountV1`.
+    /// The counter's overflow policy is applied if the value lies outside of its bounds.
+    /// Emits a `CountSetV1` event.
+    ///
+    /// Rejected while the counter is frozen by `FreezeCounterV1`, or until the cooldown set by
+    /// `SetCooldownV1` has passed since the counter was last modified.
     ///
     /// # Errors
     ///
     /// Returns a [`Result`] containing a [`SetCountV1Error`] if execution fails.
     pub fn execute(&self) -> Result<(), SetCountV1Error> {
-        let mut counter_state = {
-            let counter_data = self.accounts.counter.try_borrow_data()?;
-            CounterV1::deserialize(&counter_data)?
-        };
-
-        counter_state.count = self.args.count;
+        let mut counter_data = self.accounts.counter.try_borrow_mut_data()?;
+        let mut counter_state = CounterV1Mut::try_from_bytes(&mut counter_data)?;
 
-        let serialized = counter_state.serialize()?;
+        let old_count = counter_state.count();
+        let count = counter_state.policy()?.set(self.args.count)?;
+        counter_state.set_count(count);
+        counter_state.record_modification(&Clock::get()?);
 
-        if serialized.len() != CounterV1::size() {
-            return Err(SetCountV1Error::SerializedSizeMismatch {
-                expected: CounterV1::size(),
-                observed: serialized.len(),
-            });
-        }
+        CounterEventV1::new(
+            self.accounts.counter.key(),
+            counter_state.owner(),
+            old_count,
+            count,
+        )?
+        .emit(EventDiscriminator::CountSetV1)?;
 
-        self.accounts
-            .counter
-            .try_borrow_mut_data()?
-            .copy_from_slice(&serialized);
+        CountReturnDataV1 { count }.set();
 
         Ok(())
     }
@@ -88,32 +111,29 @@ impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for SetCountV1Accounts<'a> {
     type Error = SetCountV1Error;
 
     fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let [owner, counter] = accounts else {
-            return Err(SetCountV1Error::NotEnoughAccounts {
-                expected: 2,
-                observed: accounts.len(),
-            });
-        };
-
-        if !owner.is_signer() {
-            return Err(SetCountV1Error::OwnerMustBeSigner);
-        }
+        let ([owner, counter], multisig_signers) = split_authority_accounts(accounts)?;
 
         if !counter.is_writable() {
             return Err(SetCountV1Error::CounterMustBeWriteable);
         }
 
-        let (expected_counter, _bump) = find_counter_v1(program_id, owner.key());
-        let observed_counter = counter.key();
-        if observed_counter != &expected_counter {
-            return Err(SetCountV1Error::CounterAddressMismatch {
-                expected: expected_counter,
-                observed: *observed_counter,
-            });
+        let counter_data = counter.try_borrow_data()?;
+        let counter_state = load_counter_view(program_id, counter, &counter_data)?;
+        authorize_counter_authority(
+            program_id,
+            counter.key(),
+            counter_state.authority(),
+            owner,
+            multisig_signers,
+        )?;
+
+        if counter_state.is_frozen() {
+            return Err(SetCountV1Error::CounterFrozen);
         }
 
-        let counter_data = counter.try_borrow_data()?;
-        AccountDiscriminator::check(AccountDiscriminator::CounterV1Account, &counter_data)?;
+        if counter_state.has_cooldown() && !counter_state.cooldown_elapsed(&Clock::get()?) {
+            return Err(SetCountV1Error::CooldownNotElapsed);
+        }
 
         Ok(Self { owner, counter })
     }
@@ -136,6 +156,21 @@ impl From<AccountDiscriminatorError> for SetCountV1Error {
     }
 }
 
+impl From<LoadCounterError> for SetCountV1Error {
+    fn from(err: LoadCounterError) -> Self {
+        match err {
+            LoadCounterError::ProgramError(err) => Self::ProgramError(err),
+            LoadCounterError::CounterAddressMismatch { expected, observed } => {
+                Self::CounterAddressMismatch { expected, observed }
+            }
+            LoadCounterError::AccountDiscriminatorError(err) => {
+                Self::AccountDiscriminatorError(err)
+            }
+            LoadCounterError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
 impl From<ProgramError> for SetCountV1Error {
     fn from(err: ProgramError) -> Self {
         SetCountV1Error::ProgramError(err)
@@ -153,3 +188,43 @@ impl From<WriteError> for SetCountV1Error {
         Self::SerializeError(err)
     }
 }
+
+impl From<CountPolicyError> for SetCountV1Error {
+    fn from(err: CountPolicyError) -> Self {
+        match err {
+            CountPolicyError::AboveMaximum => Self::CountAboveMaximum,
+            CountPolicyError::BelowMinimum => Self::CountBelowMinimum,
+        }
+    }
+}
+
+impl From<MultisigAuthorizationError> for SetCountV1Error {
+    fn from(err: MultisigAuthorizationError) -> Self {
+        match err {
+            MultisigAuthorizationError::ProgramError(err) => Self::ProgramError(err),
+            MultisigAuthorizationError::NotEnoughAccounts { expected, observed } => {
+                Self::NotEnoughAccounts { expected, observed }
+            }
+            MultisigAuthorizationError::AuthorityMustBeSigner
+            | MultisigAuthorizationError::MultisigAuthorityMismatch => Self::OwnerMustBeSigner,
+            MultisigAuthorizationError::AuthorityMismatch => Self::AuthorityMismatch,
+            MultisigAuthorizationError::MultisigAddressMismatch { expected, observed } => {
+                Self::MultisigAddressMismatch { expected, observed }
+            }
+            MultisigAuthorizationError::AccountDiscriminatorError(err) => {
+                Self::MultisigAccountDiscriminatorError(err)
+            }
+            MultisigAuthorizationError::DeserializeError(err) => Self::DeserializeError(err),
+            MultisigAuthorizationError::SignerMustBeSigner => Self::MultisigSignerMustBeSigner,
+            MultisigAuthorizationError::SignerNotMember => Self::MultisigSignerNotMember,
+            MultisigAuthorizationError::DuplicateSigner => Self::MultisigDuplicateSigner,
+            MultisigAuthorizationError::ThresholdNotMet {
+                threshold,
+                observed,
+            } => Self::MultisigThresholdNotMet {
+                threshold,
+                observed,
+            },
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use {
+    crate::{
+        load_config, AccountDiscriminatorError, ConfigV1, InstructionDiscriminator, LoadConfigError,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct SetPauseExemptionV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: SetPauseExemptionV1Accounts<'a>,
+    pub args: SetPauseExemptionV1Args,
+}
+
+pub struct SetPauseExemptionV1Accounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+}
+
+#[derive(SchemaRead, SchemaWrite)]
+pub struct SetPauseExemptionV1Args {
+    /// The instruction to exempt from the pause, or to subject to it again.
+    pub instruction: InstructionDiscriminator,
+    pub exempt: bool,
+}
+
+#[derive(Debug)]
+pub enum SetPauseExemptionV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    AdminMustBeSigner,
+    ConfigMustBeWriteable,
+    ConfigMustBeOwnedByProgram,
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    AdminMismatch,
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+    InstructionNotPauseChecked,
+}
+
+impl SetPauseExemptionV1<'_> {
+    /// Executes the set pause exemption instruction.
+    ///
+    /// Lets an instruction keep running while the program is paused, or subjects it to the pause
+    /// again. Exemptions can be changed whether or not the program is paused, so the admin can
+    /// prepare them ahead of a pause or re-enable single instructions during one. Only the admin
+    /// may change exemptions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`SetPauseExemptionV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), SetPauseExemptionV1Error> {
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+
+        let mut config_state = ConfigV1::deserialize(&config_data)?;
+        config_state.set_pause_exempt(self.args.instruction.into(), self.args.exempt);
+
+        let written = config_state.serialize_into(&mut config_data)?;
+
+        if written != ConfigV1::size() {
+            return Err(SetPauseExemptionV1Error::SerializedSizeMismatch {
+                expected: ConfigV1::size(),
+                observed: written,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for SetPauseExemptionV1<'a> {
+    type Error = SetPauseExemptionV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetPauseExemptionV1Accounts::try_from((program_id, accounts))?;
+        let args = SetPauseExemptionV1Args::deserialize(args)?;
+
+        // The config instructions are never paused, so exempting them would have no effect.
+        if !args.instruction.is_pause_checked() {
+            return Err(SetPauseExemptionV1Error::InstructionNotPauseChecked);
+        }
+
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for SetPauseExemptionV1Accounts<'a> {
+    type Error = SetPauseExemptionV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [admin, config] = accounts else {
+            return Err(SetPauseExemptionV1Error::NotEnoughAccounts {
+                expected: 2,
+                observed: accounts.len(),
+            });
+        };
+
+        if !admin.is_signer() {
+            return Err(SetPauseExemptionV1Error::AdminMustBeSigner);
+        }
+
+        if !config.is_writable() {
+            return Err(SetPauseExemptionV1Error::ConfigMustBeWriteable);
+        }
+
+        let config_state = load_config(program_id, config)?;
+        if &config_state.admin != admin.key() {
+            return Err(SetPauseExemptionV1Error::AdminMismatch);
+        }
+
+        Ok(Self { admin, config })
+    }
+}
+
+impl SetPauseExemptionV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<LoadConfigError> for SetPauseExemptionV1Error {
+    fn from(err: LoadConfigError) -> Self {
+        match err {
+            LoadConfigError::ProgramError(err) => Self::ProgramError(err),
+            LoadConfigError::ConfigMustBeOwnedByProgram => Self::ConfigMustBeOwnedByProgram,
+            LoadConfigError::AccountDiscriminatorError(err) => Self::AccountDiscriminatorError(err),
+            LoadConfigError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for SetPauseExemptionV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for SetPauseExemptionV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for SetPauseExemptionV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -0,0 +1,148 @@
+use {
+    crate::{load_config, AccountDiscriminatorError, ConfigV1, LoadConfigError},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::{ReadError, SchemaRead, SchemaWrite, WriteError},
+};
+
+pub struct SetPausedV1<'a> {
+    pub program_id: &'a Pubkey,
+    pub accounts: SetPausedV1Accounts<'a>,
+    pub args: SetPausedV1Args,
+}
+
+pub struct SetPausedV1Accounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+}
+
+#[derive(SchemaRead, SchemaWrite)]
+pub struct SetPausedV1Args {
+    pub paused: bool,
+}
+
+#[derive(Debug)]
+pub enum SetPausedV1Error {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    AdminMustBeSigner,
+    ConfigMustBeWriteable,
+    ConfigMustBeOwnedByProgram,
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    AdminMismatch,
+    SerializeError(WriteError),
+    SerializedSizeMismatch { expected: usize, observed: usize },
+}
+
+impl SetPausedV1<'_> {
+    /// Executes the set paused instruction.
+    ///
+    /// Pauses or unpauses the program. While paused, every instruction except the config
+    /// instructions and the ones the admin has exempted fails. Only the admin may pause the
+    /// program. Setting the current value again succeeds without changing anything.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Result`] containing a [`SetPausedV1Error`] if execution fails.
+    pub fn execute(&self) -> Result<(), SetPausedV1Error> {
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+
+        let mut config_state = ConfigV1::deserialize(&config_data)?;
+        config_state.paused = self.args.paused;
+
+        let written = config_state.serialize_into(&mut config_data)?;
+
+        if written != ConfigV1::size() {
+            return Err(SetPausedV1Error::SerializedSizeMismatch {
+                expected: ConfigV1::size(),
+                observed: written,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<(&'a Pubkey, &'a [AccountInfo], &[u8])> for SetPausedV1<'a> {
+    type Error = SetPausedV1Error;
+
+    fn try_from(
+        (program_id, accounts, args): (&'a Pubkey, &'a [AccountInfo], &[u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = SetPausedV1Accounts::try_from((program_id, accounts))?;
+        let args = SetPausedV1Args::deserialize(args)?;
+        Ok(Self {
+            program_id,
+            accounts,
+            args,
+        })
+    }
+}
+
+impl<'a> TryFrom<(&Pubkey, &'a [AccountInfo])> for SetPausedV1Accounts<'a> {
+    type Error = SetPausedV1Error;
+
+    fn try_from((program_id, accounts): (&Pubkey, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [admin, config] = accounts else {
+            return Err(SetPausedV1Error::NotEnoughAccounts {
+                expected: 2,
+                observed: accounts.len(),
+            });
+        };
+
+        if !admin.is_signer() {
+            return Err(SetPausedV1Error::AdminMustBeSigner);
+        }
+
+        if !config.is_writable() {
+            return Err(SetPausedV1Error::ConfigMustBeWriteable);
+        }
+
+        let config_state = load_config(program_id, config)?;
+        if &config_state.admin != admin.key() {
+            return Err(SetPausedV1Error::AdminMismatch);
+        }
+
+        Ok(Self { admin, config })
+    }
+}
+
+impl SetPausedV1Args {
+    /// Deserializes the instruction arguments from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+impl From<LoadConfigError> for SetPausedV1Error {
+    fn from(err: LoadConfigError) -> Self {
+        match err {
+            LoadConfigError::ProgramError(err) => Self::ProgramError(err),
+            LoadConfigError::ConfigMustBeOwnedByProgram => Self::ConfigMustBeOwnedByProgram,
+            LoadConfigError::AccountDiscriminatorError(err) => Self::AccountDiscriminatorError(err),
+            LoadConfigError::DeserializeError(err) => Self::DeserializeError(err),
+        }
+    }
+}
+
+impl From<ProgramError> for SetPausedV1Error {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for SetPausedV1Error {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for SetPausedV1Error {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -1,7 +1,7 @@
 use wincode::{SchemaRead, SchemaWrite};
 
 #[repr(u8)]
-#[derive(Debug, PartialEq, SchemaRead, SchemaWrite)]
+#[derive(Debug, Clone, Copy, PartialEq, SchemaRead, SchemaWrite)]
 pub enum InstructionDiscriminator {
     #[wincode(tag = 1)]
     InitializeCounterV1 = 1,
@@ -20,6 +20,102 @@ pub enum InstructionDiscriminator {
 
     #[wincode(tag = 6)]
     ReactivateCounterV1 = 6,
+
+    #[wincode(tag = 7)]
+    IncrementByV1 = 7,
+
+    #[wincode(tag = 8)]
+    DecrementByV1 = 8,
+
+    #[wincode(tag = 9)]
+    SetCountPolicyV1 = 9,
+
+    #[wincode(tag = 10)]
+    ApproveDelegateV1 = 10,
+
+    #[wincode(tag = 11)]
+    RevokeDelegateV1 = 11,
+
+    #[wincode(tag = 12)]
+    ProposeAuthorityV1 = 12,
+
+    #[wincode(tag = 13)]
+    AcceptAuthorityV1 = 13,
+
+    #[wincode(tag = 14)]
+    InitializeIndexedCounterV1 = 14,
+
+    #[wincode(tag = 15)]
+    IncrementIndexedCounterV1 = 15,
+
+    #[wincode(tag = 16)]
+    DecrementIndexedCounterV1 = 16,
+
+    #[wincode(tag = 17)]
+    SetIndexedCountV1 = 17,
+
+    #[wincode(tag = 18)]
+    DeactivateIndexedCounterV1 = 18,
+
+    #[wincode(tag = 19)]
+    ReactivateIndexedCounterV1 = 19,
+
+    #[wincode(tag = 20)]
+    InitializeSponsoredCounterV1 = 20,
+
+    #[wincode(tag = 21)]
+    ReactivateSponsoredCounterV1 = 21,
+
+    #[wincode(tag = 22)]
+    GetCountV1 = 22,
+
+    #[wincode(tag = 23)]
+    CompareAndSetCountV1 = 23,
+
+    #[wincode(tag = 24)]
+    CompareAndIncrementCountV1 = 24,
+
+    #[wincode(tag = 25)]
+    InitializeShardedCounterV1 = 25,
+
+    #[wincode(tag = 26)]
+    IncrementCounterShardV1 = 26,
+
+    #[wincode(tag = 27)]
+    ReconcileShardsV1 = 27,
+
+    #[wincode(tag = 28)]
+    SetAccessModeV1 = 28,
+
+    #[wincode(tag = 29)]
+    FreezeCounterV1 = 29,
+
+    #[wincode(tag = 30)]
+    ThawCounterV1 = 30,
+
+    #[wincode(tag = 31)]
+    InitializeConfigV1 = 31,
+
+    #[wincode(tag = 32)]
+    SetPausedV1 = 32,
+
+    #[wincode(tag = 33)]
+    SetPauseExemptionV1 = 33,
+
+    #[wincode(tag = 34)]
+    CloseCounterV1 = 34,
+
+    #[wincode(tag = 35)]
+    MigrateCounterV1ToV2 = 35,
+
+    #[wincode(tag = 36)]
+    SetCooldownV1 = 36,
+
+    #[wincode(tag = 37)]
+    InitializeMultisigV1 = 37,
+
+    #[wincode(tag = 38)]
+    ExecuteBatchV1 = 38,
 }
 
 #[derive(Debug)]
@@ -44,6 +140,63 @@ impl InstructionDiscriminator {
 
         Ok((Self::try_from(first)?, rest))
     }
+
+    /// Returns the name of the instruction, for use in program logs.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::InitializeCounterV1 => "InitializeCounterV1",
+            Self::DeactivateCounterV1 => "DeactivateCounterV1",
+            Self::DecrementCountV1 => "DecrementCountV1",
+            Self::IncrementCountV1 => "IncrementCountV1",
+            Self::SetCountV1 => "SetCountV1",
+            Self::ReactivateCounterV1 => "ReactivateCounterV1",
+            Self::IncrementByV1 => "IncrementByV1",
+            Self::DecrementByV1 => "DecrementByV1",
+            Self::SetCountPolicyV1 => "SetCountPolicyV1",
+            Self::ApproveDelegateV1 => "ApproveDelegateV1",
+            Self::RevokeDelegateV1 => "RevokeDelegateV1",
+            Self::ProposeAuthorityV1 => "ProposeAuthorityV1",
+            Self::AcceptAuthorityV1 => "AcceptAuthorityV1",
+            Self::InitializeIndexedCounterV1 => "InitializeIndexedCounterV1",
+            Self::IncrementIndexedCounterV1 => "IncrementIndexedCounterV1",
+            Self::DecrementIndexedCounterV1 => "DecrementIndexedCounterV1",
+            Self::SetIndexedCountV1 => "SetIndexedCountV1",
+            Self::DeactivateIndexedCounterV1 => "DeactivateIndexedCounterV1",
+            Self::ReactivateIndexedCounterV1 => "ReactivateIndexedCounterV1",
+            Self::InitializeSponsoredCounterV1 => "InitializeSponsoredCounterV1",
+            Self::ReactivateSponsoredCounterV1 => "ReactivateSponsoredCounterV1",
+            Self::GetCountV1 => "GetCountV1",
+            Self::CompareAndSetCountV1 => "CompareAndSetCountV1",
+            Self::CompareAndIncrementCountV1 => "CompareAndIncrementCountV1",
+            Self::InitializeShardedCounterV1 => "InitializeShardedCounterV1",
+            Self::IncrementCounterShardV1 => "IncrementCounterShardV1",
+            Self::ReconcileShardsV1 => "ReconcileShardsV1",
+            Self::SetAccessModeV1 => "SetAccessModeV1",
+            Self::FreezeCounterV1 => "FreezeCounterV1",
+            Self::ThawCounterV1 => "ThawCounterV1",
+            Self::InitializeConfigV1 => "InitializeConfigV1",
+            Self::SetPausedV1 => "SetPausedV1",
+            Self::SetPauseExemptionV1 => "SetPauseExemptionV1",
+            Self::CloseCounterV1 => "CloseCounterV1",
+            Self::MigrateCounterV1ToV2 => "MigrateCounterV1ToV2",
+            Self::SetCooldownV1 => "SetCooldownV1",
+            Self::InitializeMultisigV1 => "InitializeMultisigV1",
+            Self::ExecuteBatchV1 => "ExecuteBatchV1",
+        }
+    }
+
+    /// Returns `true` if the instruction takes the config account last and is rejected while the
+    /// program is paused, unless the admin has exempted it.
+    ///
+    /// The config instructions are never paused, so the admin can always lift a pause.
+    #[must_use]
+    pub const fn is_pause_checked(&self) -> bool {
+        !matches!(
+            self,
+            Self::InitializeConfigV1 | Self::SetPausedV1 | Self::SetPauseExemptionV1
+        )
+    }
 }
 
 impl TryFrom<&u8> for InstructionDiscriminator {
@@ -57,6 +210,38 @@ impl TryFrom<&u8> for InstructionDiscriminator {
             4 => Ok(InstructionDiscriminator::IncrementCountV1),
             5 => Ok(InstructionDiscriminator::SetCountV1),
             6 => Ok(InstructionDiscriminator::ReactivateCounterV1),
+            7 => Ok(InstructionDiscriminator::IncrementByV1),
+            8 => Ok(InstructionDiscriminator::DecrementByV1),
+            9 => Ok(InstructionDiscriminator::SetCountPolicyV1),
+            10 => Ok(InstructionDiscriminator::ApproveDelegateV1),
+            11 => Ok(InstructionDiscriminator::RevokeDelegateV1),
+            12 => Ok(InstructionDiscriminator::ProposeAuthorityV1),
+            13 => Ok(InstructionDiscriminator::AcceptAuthorityV1),
+            14 => Ok(InstructionDiscriminator::InitializeIndexedCounterV1),
+            15 => Ok(InstructionDiscriminator::IncrementIndexedCounterV1),
+            16 => Ok(InstructionDiscriminator::DecrementIndexedCounterV1),
+            17 => Ok(InstructionDiscriminator::SetIndexedCountV1),
+            18 => Ok(InstructionDiscriminator::DeactivateIndexedCounterV1),
+            19 => Ok(InstructionDiscriminator::ReactivateIndexedCounterV1),
+            20 => Ok(InstructionDiscriminator::InitializeSponsoredCounterV1),
+            21 => Ok(InstructionDiscriminator::ReactivateSponsoredCounterV1),
+            22 => Ok(InstructionDiscriminator::GetCountV1),
+            23 => Ok(InstructionDiscriminator::CompareAndSetCountV1),
+            24 => Ok(InstructionDiscriminator::CompareAndIncrementCountV1),
+            25 => Ok(InstructionDiscriminator::InitializeShardedCounterV1),
+            26 => Ok(InstructionDiscriminator::IncrementCounterShardV1),
+            27 => Ok(InstructionDiscriminator::ReconcileShardsV1),
+            28 => Ok(InstructionDiscriminator::SetAccessModeV1),
+            29 => Ok(InstructionDiscriminator::FreezeCounterV1),
+            30 => Ok(InstructionDiscriminator::ThawCounterV1),
+            31 => Ok(InstructionDiscriminator::InitializeConfigV1),
+            32 => Ok(InstructionDiscriminator::SetPausedV1),
+            33 => Ok(InstructionDiscriminator::SetPauseExemptionV1),
+            34 => Ok(InstructionDiscriminator::CloseCounterV1),
+            35 => Ok(InstructionDiscriminator::MigrateCounterV1ToV2),
+            36 => Ok(InstructionDiscriminator::SetCooldownV1),
+            37 => Ok(InstructionDiscriminator::InitializeMultisigV1),
+            38 => Ok(InstructionDiscriminator::ExecuteBatchV1),
             _ => Err(InstructionDiscriminatorError::Invalid(*byte)),
         }
     }
@@ -71,6 +256,38 @@ impl From<InstructionDiscriminator> for u8 {
             InstructionDiscriminator::IncrementCountV1 => 4,
             InstructionDiscriminator::SetCountV1 => 5,
             InstructionDiscriminator::ReactivateCounterV1 => 6,
+            InstructionDiscriminator::IncrementByV1 => 7,
+            InstructionDiscriminator::DecrementByV1 => 8,
+            InstructionDiscriminator::SetCountPolicyV1 => 9,
+            InstructionDiscriminator::ApproveDelegateV1 => 10,
+            InstructionDiscriminator::RevokeDelegateV1 => 11,
+            InstructionDiscriminator::ProposeAuthorityV1 => 12,
+            InstructionDiscriminator::AcceptAuthorityV1 => 13,
+            InstructionDiscriminator::InitializeIndexedCounterV1 => 14,
+            InstructionDiscriminator::IncrementIndexedCounterV1 => 15,
+            InstructionDiscriminator::DecrementIndexedCounterV1 => 16,
+            InstructionDiscriminator::SetIndexedCountV1 => 17,
+            InstructionDiscriminator::DeactivateIndexedCounterV1 => 18,
+            InstructionDiscriminator::ReactivateIndexedCounterV1 => 19,
+            InstructionDiscriminator::InitializeSponsoredCounterV1 => 20,
+            InstructionDiscriminator::ReactivateSponsoredCounterV1 => 21,
+            InstructionDiscriminator::GetCountV1 => 22,
+            InstructionDiscriminator::CompareAndSetCountV1 => 23,
+            InstructionDiscriminator::CompareAndIncrementCountV1 => 24,
+            InstructionDiscriminator::InitializeShardedCounterV1 => 25,
+            InstructionDiscriminator::IncrementCounterShardV1 => 26,
+            InstructionDiscriminator::ReconcileShardsV1 => 27,
+            InstructionDiscriminator::SetAccessModeV1 => 28,
+            InstructionDiscriminator::FreezeCounterV1 => 29,
+            InstructionDiscriminator::ThawCounterV1 => 30,
+            InstructionDiscriminator::InitializeConfigV1 => 31,
+            InstructionDiscriminator::SetPausedV1 => 32,
+            InstructionDiscriminator::SetPauseExemptionV1 => 33,
+            InstructionDiscriminator::CloseCounterV1 => 34,
+            InstructionDiscriminator::MigrateCounterV1ToV2 => 35,
+            InstructionDiscriminator::SetCooldownV1 => 36,
+            InstructionDiscriminator::InitializeMultisigV1 => 37,
+            InstructionDiscriminator::ExecuteBatchV1 => 38,
         }
     }
 }
@@ -98,6 +315,38 @@ mod tests {
             (4u8, InstructionDiscriminator::IncrementCountV1),
             (5u8, InstructionDiscriminator::SetCountV1),
             (6u8, InstructionDiscriminator::ReactivateCounterV1),
+            (7u8, InstructionDiscriminator::IncrementByV1),
+            (8u8, InstructionDiscriminator::DecrementByV1),
+            (9u8, InstructionDiscriminator::SetCountPolicyV1),
+            (10u8, InstructionDiscriminator::ApproveDelegateV1),
+            (11u8, InstructionDiscriminator::RevokeDelegateV1),
+            (12u8, InstructionDiscriminator::ProposeAuthorityV1),
+            (13u8, InstructionDiscriminator::AcceptAuthorityV1),
+            (14u8, InstructionDiscriminator::InitializeIndexedCounterV1),
+            (15u8, InstructionDiscriminator::IncrementIndexedCounterV1),
+            (16u8, InstructionDiscriminator::DecrementIndexedCounterV1),
+            (17u8, InstructionDiscriminator::SetIndexedCountV1),
+            (18u8, InstructionDiscriminator::DeactivateIndexedCounterV1),
+            (19u8, InstructionDiscriminator::ReactivateIndexedCounterV1),
+            (20u8, InstructionDiscriminator::InitializeSponsoredCounterV1),
+            (21u8, InstructionDiscriminator::ReactivateSponsoredCounterV1),
+            (22u8, InstructionDiscriminator::GetCountV1),
+            (23u8, InstructionDiscriminator::CompareAndSetCountV1),
+            (24u8, InstructionDiscriminator::CompareAndIncrementCountV1),
+            (25u8, InstructionDiscriminator::InitializeShardedCounterV1),
+            (26u8, InstructionDiscriminator::IncrementCounterShardV1),
+            (27u8, InstructionDiscriminator::ReconcileShardsV1),
+            (28u8, InstructionDiscriminator::SetAccessModeV1),
+            (29u8, InstructionDiscriminator::FreezeCounterV1),
+            (30u8, InstructionDiscriminator::ThawCounterV1),
+            (31u8, InstructionDiscriminator::InitializeConfigV1),
+            (32u8, InstructionDiscriminator::SetPausedV1),
+            (33u8, InstructionDiscriminator::SetPauseExemptionV1),
+            (34u8, InstructionDiscriminator::CloseCounterV1),
+            (35u8, InstructionDiscriminator::MigrateCounterV1ToV2),
+            (36u8, InstructionDiscriminator::SetCooldownV1),
+            (37u8, InstructionDiscriminator::InitializeMultisigV1),
+            (38u8, InstructionDiscriminator::ExecuteBatchV1),
         ];
 
         for (byte, expected) in test_cases {
@@ -106,6 +355,7 @@ mod tests {
 
             assert_eq!(discriminator, expected);
             assert_eq!(args, &[0x42, 0x43]);
+            assert_eq!(discriminator.name(), format!("{discriminator:?}"));
         }
     }
 
@@ -122,7 +372,7 @@ mod tests {
 
     #[test]
     fn test_parse_invalid_discriminator() {
-        let invalid_discriminators = [7u8, 255u8];
+        let invalid_discriminators = [39u8, 255u8];
 
         for invalid_byte in invalid_discriminators {
             let instruction_data = [invalid_byte, 0x42];
@@ -136,6 +386,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_config_instructions_are_not_pause_checked() {
+        assert!(!InstructionDiscriminator::InitializeConfigV1.is_pause_checked());
+        assert!(!InstructionDiscriminator::SetPausedV1.is_pause_checked());
+        assert!(!InstructionDiscriminator::SetPauseExemptionV1.is_pause_checked());
+
+        assert!(InstructionDiscriminator::InitializeCounterV1.is_pause_checked());
+        assert!(InstructionDiscriminator::IncrementCountV1.is_pause_checked());
+        assert!(InstructionDiscriminator::ThawCounterV1.is_pause_checked());
+    }
+
     #[test]
     fn test_parse_single_byte_valid() {
         // Test that parsing works even when there's no additional data
@@ -0,0 +1,137 @@
+//! Entrypoint built on pinocchio's lazy entrypoint, selected with the `lazy-entrypoint` feature.
+//!
+//! The eager entrypoint reserves room for the maximum number of transaction accounts and turns
+//! every account into an [`AccountInfo`] before the program runs. The runtime places the
+//! instruction data after the accounts, so this entrypoint still steps over every account to
+//! reach the discriminator, but it only keeps as many accounts as the largest instruction takes.
+//! Input with more accounts than that is kept in full on a slower path, and dispatch is shared with
+//! the eager entrypoint, so behavior and error codes are identical.
+
+use {
+    crate::{entrypoint::process_instruction, MAX_COUNTER_SHARDS, MAX_MULTISIG_MEMBERS},
+    core::mem::MaybeUninit,
+    pinocchio::{
+        account_info::AccountInfo,
+        entrypoint::lazy::{InstructionContext, MaybeAccount},
+        lazy_program_entrypoint,
+        program_error::ProgramError,
+        ProgramResult, MAX_TX_ACCOUNTS,
+    },
+};
+
+/// Config account taken by every pause-checked instruction on top of its own accounts.
+const CONFIG_ACCOUNTS: usize = 1;
+
+/// Largest number of accounts taken by any instruction, which sizes the accounts kept on the common
+/// path. Fixed-size instructions take far fewer accounts than the instructions that end with a
+/// variable-length list, so only those are compared. Input with more accounts still reaches the
+/// instruction, through [`process_oversized_instruction`], so a limit left behind by a new
+/// instruction only costs compute units.
+const MAX_INSTRUCTION_ACCOUNTS: usize = max(
+    max(
+        // InitializeShardedCounterV1: payer, sharded counter and system program, then its shards
+        CONFIG_ACCOUNTS + 3 + MAX_COUNTER_SHARDS as usize,
+        // ReconcileShardsV1: owner and sharded counter, then its shards
+        CONFIG_ACCOUNTS + 2 + MAX_COUNTER_SHARDS as usize,
+    ),
+    max(
+        // InitializeMultisigV1: authority, counter, multisig and system program, then its members
+        CONFIG_ACCOUNTS + 4 + MAX_MULTISIG_MEMBERS as usize,
+        // Multisig-approved instructions, at most CloseCounterV1: multisig, counter, destination,
+        // tombstone and system program, then one signer per member
+        CONFIG_ACCOUNTS + 5 + MAX_MULTISIG_MEMBERS as usize,
+    ),
+);
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+lazy_program_entrypoint!(process_lazy_instruction);
+
+/// Reads the accounts an instruction can use from the input and dispatches the instruction.
+///
+/// # Errors
+///
+/// Returns the [`ProgramError`] of the failed instruction, as the eager entrypoint does.
+pub fn process_lazy_instruction(mut context: InstructionContext) -> ProgramResult {
+    let mut accounts = [const { MaybeUninit::<AccountInfo>::uninit() }; MAX_INSTRUCTION_ACCOUNTS];
+    let accounts_len = read_accounts(&mut context, &mut accounts, 0)?;
+
+    // No instruction takes this many accounts, but the instruction still sees every one of them
+    // and fails with its own error, as under the eager entrypoint.
+    if context.remaining() > 0 {
+        return process_oversized_instruction(context, &accounts);
+    }
+
+    // SAFETY: The first `accounts_len` accounts are initialized, and `MaybeUninit<AccountInfo>`
+    // has the same layout as `AccountInfo`.
+    let accounts = unsafe {
+        core::slice::from_raw_parts(accounts.as_ptr().cast::<AccountInfo>(), accounts_len)
+    };
+
+    process_instruction(context.program_id()?, accounts, context.instruction_data()?)
+}
+
+/// Dispatches an instruction whose input holds more than [`MAX_INSTRUCTION_ACCOUNTS`] accounts,
+/// the first of which were already read into `read`.
+///
+/// Kept out of line so the common path does not reserve room for every transaction account. As
+/// with the eager entrypoint, accounts past [`MAX_TX_ACCOUNTS`] are skipped.
+///
+/// # Errors
+///
+/// Returns the [`ProgramError`] of the failed instruction.
+#[cold]
+#[inline(never)]
+fn process_oversized_instruction(
+    mut context: InstructionContext,
+    read: &[MaybeUninit<AccountInfo>],
+) -> ProgramResult {
+    let mut accounts = [const { MaybeUninit::<AccountInfo>::uninit() }; MAX_TX_ACCOUNTS];
+    accounts[..read.len()].copy_from_slice(read);
+    let accounts_len = read_accounts(&mut context, &mut accounts, read.len())?;
+
+    while context.remaining() > 0 {
+        context.next_account()?;
+    }
+
+    // SAFETY: The first `accounts_len` accounts are initialized, and `MaybeUninit<AccountInfo>`
+    // has the same layout as `AccountInfo`.
+    let accounts = unsafe {
+        core::slice::from_raw_parts(accounts.as_ptr().cast::<AccountInfo>(), accounts_len)
+    };
+
+    process_instruction(context.program_id()?, accounts, context.instruction_data()?)
+}
+
+/// Reads accounts from the input into `accounts`, after the first `accounts_len` already there,
+/// until either runs out. Returns the number of accounts initialized.
+///
+/// # Errors
+///
+/// Returns a [`ProgramError`] if the input cannot be read.
+fn read_accounts(
+    context: &mut InstructionContext,
+    accounts: &mut [MaybeUninit<AccountInfo>],
+    mut accounts_len: usize,
+) -> Result<usize, ProgramError> {
+    while context.remaining() > 0 && accounts_len < accounts.len() {
+        let account = match context.next_account()? {
+            MaybeAccount::Account(account) => account,
+            // SAFETY: A duplicate refers to an earlier account in the input, and every earlier
+            // account has been kept and initialized.
+            MaybeAccount::Duplicated(index) => unsafe {
+                *accounts[usize::from(index)].assume_init_ref()
+            },
+        };
+        accounts[accounts_len].write(account);
+        accounts_len += 1;
+    }
+
+    Ok(accounts_len)
+}
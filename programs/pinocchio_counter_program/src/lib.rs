@@ -1,25 +1,123 @@
+// The program runs without a heap allocator. Helpers that return a `Vec` are only compiled
+// off-chain, for clients and tests.
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(not(target_os = "solana"))]
+extern crate alloc;
+// Off-chain builds link `std` for its allocator and panic handler, which the entrypoint only
+// replaces on-chain.
+#[cfg(not(target_os = "solana"))]
+extern crate std;
+
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
 
+mod access_mode;
 mod account_discriminator;
+mod batch_operation;
+mod config_account;
+mod count_policy;
+mod counter_account;
+mod counter_view;
+mod delegate;
 mod error;
+mod event;
 mod instructions;
 mod instructions_discriminator;
+#[cfg(all(feature = "lazy-entrypoint", not(feature = "no-entrypoint")))]
+mod lazy_entrypoint;
+#[cfg(not(feature = "no-entrypoint"))]
+mod log;
+mod multisig;
+#[cfg(test)]
+mod no_heap_tests;
+mod pause;
+mod pda_account;
+mod return_data;
 mod state;
+mod tombstone;
 
-use pinocchio::pubkey::{try_find_program_address, Pubkey};
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{create_program_address, try_find_program_address, Pubkey},
+};
 pub use {
+    access_mode::AccessMode,
     account_discriminator::{AccountDiscriminator, AccountDiscriminatorError},
+    batch_operation::{BatchOperationKindV1, BatchOperationV1},
+    config_account::LoadConfigError,
+    count_policy::{CountPolicy, CountPolicyError, OverflowPolicy},
+    counter_account::LoadCounterError,
+    counter_view::{CounterV1Mut, CounterV1Ref},
+    delegate::DelegateAuthorizationError,
     error::{InstructionError, InstructionResult},
+    event::{CounterEventV1, EventDiscriminator, EventDiscriminatorError},
     instructions::{
-        DeactivateCounterV1, DecrementCountV1, IncrementCountV1, InitializeCounterV1,
-        ReactivateCounterV1, SetCountV1, SetCountV1Args,
+        AcceptAuthorityV1, ApproveDelegateV1, ApproveDelegateV1Args, CloseCounterV1,
+        CompareAndIncrementCountV1, CompareAndIncrementCountV1Args, CompareAndSetCountV1,
+        CompareAndSetCountV1Args, DeactivateCounterV1, DeactivateIndexedCounterV1,
+        DeactivateIndexedCounterV1Args, DecrementByV1, DecrementByV1Args, DecrementCountV1,
+        DecrementIndexedCounterV1, DecrementIndexedCounterV1Args, ExecuteBatchV1,
+        ExecuteBatchV1Args, FreezeCounterV1, GetCountV1, IncrementByV1, IncrementByV1Args,
+        IncrementCountV1, IncrementCounterShardV1, IncrementIndexedCounterV1,
+        IncrementIndexedCounterV1Args, InitializeConfigV1, InitializeConfigV1Args,
+        InitializeCounterV1, InitializeIndexedCounterV1, InitializeIndexedCounterV1Args,
+        InitializeMultisigV1, InitializeMultisigV1Args, InitializeShardedCounterV1,
+        InitializeSponsoredCounterV1, MigrateCounterV1ToV2, ProposeAuthorityV1,
+        ProposeAuthorityV1Args, ReactivateCounterV1, ReactivateIndexedCounterV1,
+        ReactivateIndexedCounterV1Args, ReactivateSponsoredCounterV1, ReconcileShardsV1,
+        RevokeDelegateV1, SetAccessModeV1, SetAccessModeV1Args, SetCooldownV1, SetCooldownV1Args,
+        SetCountPolicyV1, SetCountPolicyV1Args, SetCountV1, SetCountV1Args, SetIndexedCountV1,
+        SetIndexedCountV1Args, SetPauseExemptionV1, SetPauseExemptionV1Args, SetPausedV1,
+        SetPausedV1Args, ThawCounterV1,
     },
     instructions_discriminator::{InstructionDiscriminator, InstructionDiscriminatorError},
-    state::{CounterV1, DEACTIVATED_ACCOUNT_SIZE},
+    multisig::MultisigAuthorizationError,
+    pause::PauseCheckError,
+    return_data::CountReturnDataV1,
+    state::{
+        ConfigV1, Counter, CounterShardV1, CounterV1, CounterV2, DeactivatedCounterV1, DelegateV1,
+        IndexedCounterV1, MultisigV1, ShardedCounterV1, TombstoneV1, DEACTIVATED_ACCOUNT_SIZE,
+        NO_PENDING_AUTHORITY, NO_RENT_PAYER,
+    },
+    tombstone::{TombstoneError, TombstoneLookup},
+};
+pub(crate) use {
+    config_account::load_config,
+    counter_account::{load_counter, load_counter_view, load_deactivated_counter},
+    delegate::{authorize_delegate, consume_delegate_allowance},
+    multisig::{authorize_counter_authority, split_authority_accounts},
+    pda_account::create_pda_account,
+    tombstone::{find_tombstone, record_tombstone},
 };
 
 pub const COUNTER_V1_SEED: &[u8] = b"counter_v1";
+pub const DELEGATE_V1_SEED: &[u8] = b"delegate_v1";
+pub const INDEXED_COUNTER_V1_SEED: &[u8] = b"indexed_counter_v1";
+pub const SHARDED_COUNTER_V1_SEED: &[u8] = b"sharded_counter_v1";
+pub const COUNTER_SHARD_V1_SEED: &[u8] = b"counter_shard_v1";
+pub const CONFIG_V1_SEED: &[u8] = b"config_v1";
+pub const TOMBSTONE_V1_SEED: &[u8] = b"tombstone_v1";
+pub const MULTISIG_V1_SEED: &[u8] = b"multisig_v1";
+
+/// Address of the BPF upgradeable loader, `BPFLoaderUpgradeab1e11111111111111111111111`, which
+/// owns the program data account holding the program's upgrade authority.
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey = [
+    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61, 22,
+    193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+];
+
+/// Largest number of shards a sharded counter can have. `ReconcileShardsV1` takes up to this many
+/// shards in one instruction.
+pub const MAX_COUNTER_SHARDS: u8 = 16;
+
+/// Largest number of members a multisig authority can have. The count updates take the signing
+/// members after their other accounts, so this bounds how many accounts they take.
+pub const MAX_MULTISIG_MEMBERS: u8 = 10;
+
+/// Largest number of operations an `ExecuteBatchV1` instruction can apply, which bounds the
+/// compute it uses.
+pub const MAX_BATCH_OPERATIONS: u8 = 32;
 
 /// Finds the program-derived address for a counter account.
 ///
@@ -54,3 +152,276 @@ pub fn try_find_counter_v1(program_id: &Pubkey, owner: &Pubkey) -> Option<(Pubke
     let seeds = &[COUNTER_V1_SEED, owner.as_ref()];
     try_find_program_address(seeds, program_id)
 }
+
+/// Re-creates the program-derived address for a counter account from a known bump seed.
+///
+/// This derives the same address as [`find_counter_v1`] without searching for the bump seed, so
+/// it is the cheaper way to verify the address of an existing counter using the bump stored in
+/// its data.
+///
+/// # Errors
+///
+/// Returns [`ProgramError`] if the seeds and bump seed do not produce a valid program address.
+pub fn create_counter_v1_address(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let seeds = &[COUNTER_V1_SEED, owner.as_ref(), &[bump]];
+    create_program_address(seeds, program_id)
+}
+
+/// Finds the program-derived address for a counter's delegate record.
+///
+/// The address is derived using `[DELEGATE_V1_SEED, counter]` as seeds, so each counter has
+/// exactly one delegate record address.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_delegate_v1(program_id: &Pubkey, counter: &Pubkey) -> (Pubkey, u8) {
+    try_find_delegate_v1(program_id, counter)
+        .expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for a counter's delegate record.
+///
+/// This is a fallible version of [`find_delegate_v1`] that returns `None` instead of panicking
+/// if no viable bump seed can be found.
+pub fn try_find_delegate_v1(program_id: &Pubkey, counter: &Pubkey) -> Option<(Pubkey, u8)> {
+    let seeds = &[DELEGATE_V1_SEED, counter.as_ref()];
+    try_find_program_address(seeds, program_id)
+}
+
+/// Finds the program-derived address for an indexed counter account.
+///
+/// The address is derived using `[INDEXED_COUNTER_V1_SEED, owner, index]` as seeds, with `index`
+/// encoded as little-endian bytes. Each owner can therefore hold one counter per index, and
+/// counters with different indices never share an address.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_indexed_counter_v1(program_id: &Pubkey, owner: &Pubkey, index: u32) -> (Pubkey, u8) {
+    try_find_indexed_counter_v1(program_id, owner, index)
+        .expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for an indexed counter account.
+///
+/// This is a fallible version of [`find_indexed_counter_v1`] that returns `None` instead of
+/// panicking if no viable bump seed can be found.
+pub fn try_find_indexed_counter_v1(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    index: u32,
+) -> Option<(Pubkey, u8)> {
+    let index_bytes = index.to_le_bytes();
+    let seeds = &[
+        INDEXED_COUNTER_V1_SEED,
+        owner.as_ref(),
+        index_bytes.as_ref(),
+    ];
+    try_find_program_address(seeds, program_id)
+}
+
+/// Finds the program-derived address for a sharded counter's root account.
+///
+/// The address is derived using `[SHARDED_COUNTER_V1_SEED, owner]` as seeds, so each owner has
+/// one sharded counter, independent of its regular counter.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_sharded_counter_v1(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    try_find_sharded_counter_v1(program_id, owner)
+        .expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for a sharded counter's root account.
+///
+/// This is a fallible version of [`find_sharded_counter_v1`] that returns `None` instead of
+/// panicking if no viable bump seed can be found.
+pub fn try_find_sharded_counter_v1(program_id: &Pubkey, owner: &Pubkey) -> Option<(Pubkey, u8)> {
+    let seeds = &[SHARDED_COUNTER_V1_SEED, owner.as_ref()];
+    try_find_program_address(seeds, program_id)
+}
+
+/// Re-creates the program-derived address for a sharded counter's root account from a known
+/// bump seed.
+///
+/// # Errors
+///
+/// Returns [`ProgramError`] if the seeds and bump seed do not produce a valid program address.
+pub fn create_sharded_counter_v1_address(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let seeds = &[SHARDED_COUNTER_V1_SEED, owner.as_ref(), &[bump]];
+    create_program_address(seeds, program_id)
+}
+
+/// Finds the program-derived address for one shard of a sharded counter.
+///
+/// The address is derived using `[COUNTER_SHARD_V1_SEED, sharded_counter, index]` as seeds, so
+/// every shard index of a sharded counter has its own address.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_counter_shard_v1(
+    program_id: &Pubkey,
+    sharded_counter: &Pubkey,
+    index: u8,
+) -> (Pubkey, u8) {
+    try_find_counter_shard_v1(program_id, sharded_counter, index)
+        .expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for one shard of a sharded counter.
+///
+/// This is a fallible version of [`find_counter_shard_v1`] that returns `None` instead of
+/// panicking if no viable bump seed can be found.
+pub fn try_find_counter_shard_v1(
+    program_id: &Pubkey,
+    sharded_counter: &Pubkey,
+    index: u8,
+) -> Option<(Pubkey, u8)> {
+    let seeds = &[COUNTER_SHARD_V1_SEED, sharded_counter.as_ref(), &[index]];
+    try_find_program_address(seeds, program_id)
+}
+
+/// Re-creates the program-derived address for one shard of a sharded counter from a known bump
+/// seed, as stored in the shard.
+///
+/// # Errors
+///
+/// Returns [`ProgramError`] if the seeds and bump seed do not produce a valid program address.
+pub fn create_counter_shard_v1_address(
+    program_id: &Pubkey,
+    sharded_counter: &Pubkey,
+    index: u8,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let seeds = &[
+        COUNTER_SHARD_V1_SEED,
+        sharded_counter.as_ref(),
+        &[index],
+        &[bump],
+    ];
+    create_program_address(seeds, program_id)
+}
+
+/// Finds the program-derived address for the program's config account.
+///
+/// The address is derived using `[CONFIG_V1_SEED]` as the only seed, so the program has exactly
+/// one config account.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_config_v1(program_id: &Pubkey) -> (Pubkey, u8) {
+    try_find_config_v1(program_id).expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for the program's config account.
+///
+/// This is a fallible version of [`find_config_v1`] that returns `None` instead of panicking
+/// if no viable bump seed can be found.
+pub fn try_find_config_v1(program_id: &Pubkey) -> Option<(Pubkey, u8)> {
+    try_find_program_address(&[CONFIG_V1_SEED], program_id)
+}
+
+/// Finds the program-derived address for a closed counter's tombstone.
+///
+/// The address is derived using `[TOMBSTONE_V1_SEED, counter]` as seeds, so each counter has
+/// exactly one tombstone address, which `CloseCounterV1` creates when the counter is closed.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_tombstone_v1(program_id: &Pubkey, counter: &Pubkey) -> (Pubkey, u8) {
+    try_find_tombstone_v1(program_id, counter)
+        .expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for a closed counter's tombstone.
+///
+/// This is a fallible version of [`find_tombstone_v1`] that returns `None` instead of panicking
+/// if no viable bump seed can be found.
+pub fn try_find_tombstone_v1(program_id: &Pubkey, counter: &Pubkey) -> Option<(Pubkey, u8)> {
+    let seeds = &[TOMBSTONE_V1_SEED, counter.as_ref()];
+    try_find_program_address(seeds, program_id)
+}
+
+/// Finds the program-derived address for a counter's multisig authority.
+///
+/// The address is derived using `[MULTISIG_V1_SEED, counter]` as seeds, so each counter has
+/// exactly one multisig address, which `InitializeMultisigV1` makes the counter's authority.
+///
+/// Returns the address and bump seed used to derive it.
+///
+/// # Panics
+///
+/// Panics if a viable program address bump seed cannot be found. This is
+/// statistically very unlikely in practice.
+pub fn find_multisig_v1(program_id: &Pubkey, counter: &Pubkey) -> (Pubkey, u8) {
+    try_find_multisig_v1(program_id, counter)
+        .expect("Unable to find a viable program address bump seed")
+}
+
+/// Tries to find the program-derived address for a counter's multisig authority.
+///
+/// This is a fallible version of [`find_multisig_v1`] that returns `None` instead of panicking
+/// if no viable bump seed can be found.
+pub fn try_find_multisig_v1(program_id: &Pubkey, counter: &Pubkey) -> Option<(Pubkey, u8)> {
+    let seeds = &[MULTISIG_V1_SEED, counter.as_ref()];
+    try_find_program_address(seeds, program_id)
+}
+
+/// Re-creates the program-derived address for a counter's multisig authority from a known bump
+/// seed, as stored in the multisig.
+///
+/// # Errors
+///
+/// Returns [`ProgramError`] if the seeds and bump seed do not produce a valid program address.
+pub fn create_multisig_v1_address(
+    program_id: &Pubkey,
+    counter: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let seeds = &[MULTISIG_V1_SEED, counter.as_ref(), &[bump]];
+    create_program_address(seeds, program_id)
+}
+
+/// Tries to find the address of the program data account that the BPF upgradeable loader keeps
+/// for `program_id`.
+///
+/// The program data account is derived from `[program_id]` under the loader, and holds the
+/// program's upgrade authority.
+///
+/// Returns the address, or `None` if a viable program address bump seed cannot be found
+/// (statistically very unlikely).
+pub fn try_find_program_data_address(program_id: &Pubkey) -> Option<Pubkey> {
+    try_find_program_address(&[program_id.as_ref()], &BPF_LOADER_UPGRADEABLE_ID)
+        .map(|(address, _)| address)
+}
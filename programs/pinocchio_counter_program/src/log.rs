@@ -0,0 +1,178 @@
+//! Program logging, compiled in only with the `logging` feature.
+//!
+//! Error lines are written into a fixed-size stack buffer rather than built with `format!`, so
+//! logging a failure never allocates. The `verbose-logging` feature additionally logs the `Debug`
+//! representation of the error, which runs the formatting machinery and is meant for debugging.
+
+#[cfg(feature = "verbose-logging")]
+use core::fmt::Write;
+
+#[cfg(feature = "logging")]
+use pinocchio::log::sol_log;
+
+/// Capacity of an error line. Longer lines are truncated.
+#[cfg(any(feature = "logging", test))]
+const ERROR_LINE_CAPACITY: usize = 96;
+
+/// Capacity of an error details line, which can hold the `Debug` representation of addresses.
+#[cfg(feature = "verbose-logging")]
+const DETAILS_LINE_CAPACITY: usize = 512;
+
+/// Logs an instruction failure as `Instruction Error: <instruction> 0x<code>`.
+#[inline]
+pub(crate) fn log_instruction_error(instruction: &str, code: u64) {
+    #[cfg(feature = "logging")]
+    sol_log(
+        LogLine::<ERROR_LINE_CAPACITY>::new()
+            .push_str("Instruction Error: ")
+            .push_str(instruction)
+            .push_str(" ")
+            .push_hex(code)
+            .as_str(),
+    );
+
+    #[cfg(not(feature = "logging"))]
+    let _ = (instruction, code);
+}
+
+/// Logs a failure to parse the instruction discriminator as
+/// `Instruction Discriminator Error: 0x<code>`.
+#[inline]
+pub(crate) fn log_instruction_discriminator_error(code: u64) {
+    #[cfg(feature = "logging")]
+    sol_log(
+        LogLine::<ERROR_LINE_CAPACITY>::new()
+            .push_str("Instruction Discriminator Error: ")
+            .push_hex(code)
+            .as_str(),
+    );
+
+    #[cfg(not(feature = "logging"))]
+    let _ = code;
+}
+
+/// Logs `label` followed by the `Debug` representation of `err`, with the `verbose-logging`
+/// feature only.
+#[inline]
+pub(crate) fn log_error_details<E: core::fmt::Debug>(label: &str, err: &E) {
+    #[cfg(feature = "verbose-logging")]
+    {
+        let mut line = LogLine::<DETAILS_LINE_CAPACITY>::new();
+        line.push_str(label);
+        // Writing to a `LogLine` never fails, it truncates instead
+        let _ = write!(line, "{err:?}");
+        sol_log(line.as_str());
+    }
+
+    #[cfg(not(feature = "verbose-logging"))]
+    let _ = (label, err);
+}
+
+/// Fixed-capacity ASCII line assembled on the stack.
+struct LogLine<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+// Only `logging` builds write log lines, but the formatting is tested in every build.
+#[cfg_attr(not(feature = "logging"), allow(dead_code))]
+impl<const N: usize> LogLine<N> {
+    const fn new() -> Self {
+        Self {
+            buffer: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Appends the ASCII bytes of `s`, truncating at capacity. Non-ASCII bytes are replaced with
+    /// `?` so that the line always stays valid UTF-8.
+    fn push_str(&mut self, s: &str) -> &mut Self {
+        for byte in s.bytes() {
+            self.push_byte(if byte.is_ascii() { byte } else { b'?' });
+        }
+        self
+    }
+
+    /// Appends `value` as lowercase hexadecimal with a `0x` prefix and no leading zeros.
+    fn push_hex(&mut self, value: u64) -> &mut Self {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        self.push_str("0x");
+        let digits = (u64::BITS - value.leading_zeros()).div_ceil(4).max(1);
+        for shift in (0..digits).rev() {
+            // The mask keeps the index below 16
+            #[allow(clippy::cast_possible_truncation)]
+            let nibble = ((value >> (shift * 4)) & 0xf) as usize;
+            self.push_byte(DIGITS[nibble]);
+        }
+        self
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        if let Some(slot) = self.buffer.get_mut(self.len) {
+            *slot = byte;
+            self.len += 1;
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only ASCII bytes are ever written, so the line is always valid UTF-8
+        core::str::from_utf8(&self.buffer[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "verbose-logging")]
+impl<const N: usize> Write for LogLine<N> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_line_formats_instruction_error() {
+        let mut line = LogLine::<ERROR_LINE_CAPACITY>::new();
+        line.push_str("Instruction Error: ")
+            .push_str("IncrementCountV1")
+            .push_str(" ")
+            .push_hex(0x305);
+
+        assert_eq!(line.as_str(), "Instruction Error: IncrementCountV1 0x305");
+    }
+
+    #[test]
+    fn test_log_line_formats_hex_without_leading_zeros() {
+        for (value, expected) in [
+            (0, "0x0"),
+            (0xa, "0xa"),
+            (0x1800, "0x1800"),
+            (u64::from(u32::MAX), "0xffffffff"),
+            (u64::MAX, "0xffffffffffffffff"),
+        ] {
+            let mut line = LogLine::<ERROR_LINE_CAPACITY>::new();
+            line.push_hex(value);
+            assert_eq!(line.as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_log_line_truncates_at_capacity() {
+        let mut line = LogLine::<ERROR_LINE_CAPACITY>::new();
+        line.push_str(&"a".repeat(ERROR_LINE_CAPACITY + 10))
+            .push_hex(0x305);
+
+        assert_eq!(line.as_str().len(), ERROR_LINE_CAPACITY);
+    }
+
+    #[test]
+    fn test_log_line_replaces_non_ascii_bytes() {
+        let mut line = LogLine::<ERROR_LINE_CAPACITY>::new();
+        line.push_str("café");
+
+        assert_eq!(line.as_str(), "caf??");
+    }
+}
@@ -0,0 +1,176 @@
+use {
+    crate::{
+        create_multisig_v1_address, AccountDiscriminator, AccountDiscriminatorError, MultisigV1,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::ReadError,
+};
+
+/// Errors raised while authorizing an update by a counter's authority, which may be a multisig.
+///
+/// Each instruction that accepts a multisig maps these into its own error type so that every
+/// failure keeps a stable, instruction-specific error code.
+#[derive(Debug)]
+pub enum MultisigAuthorizationError {
+    ProgramError(ProgramError),
+    NotEnoughAccounts { expected: usize, observed: usize },
+    AuthorityMustBeSigner,
+    AuthorityMismatch,
+    MultisigAuthorityMismatch,
+    MultisigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    SignerMustBeSigner,
+    SignerNotMember,
+    DuplicateSigner,
+    ThresholdNotMet { threshold: u8, observed: usize },
+}
+
+/// Splits the accounts of an instruction gated by the counter's authority into the first `N`
+/// accounts and the signers of a multisig authority.
+///
+/// The authority comes first. It either signs, with no accounts after the first `N`, or is the
+/// unsigned multisig record of the counter followed by its signing members. An unsigned first
+/// account is only accepted as the counter's multisig authority, which
+/// [`authorize_counter_authority`] checks once the counter is loaded.
+///
+/// # Errors
+///
+/// Returns [`MultisigAuthorizationError::NotEnoughAccounts`] if the accounts match neither shape,
+/// or [`MultisigAuthorizationError::AuthorityMustBeSigner`] if exactly `N` accounts are passed
+/// and the first did not sign.
+pub(crate) fn split_authority_accounts<const N: usize>(
+    accounts: &[AccountInfo],
+) -> Result<(&[AccountInfo; N], Option<&[AccountInfo]>), MultisigAuthorizationError> {
+    let not_enough_accounts = MultisigAuthorizationError::NotEnoughAccounts {
+        expected: N,
+        observed: accounts.len(),
+    };
+    let Some((fixed_accounts, multisig_signers)) = accounts.split_first_chunk::<N>() else {
+        return Err(not_enough_accounts);
+    };
+    let authority_is_signer = fixed_accounts
+        .first()
+        .is_some_and(|authority| authority.is_signer());
+
+    match (authority_is_signer, multisig_signers) {
+        (true, []) => Ok((fixed_accounts, None)),
+        (false, []) => Err(MultisigAuthorizationError::AuthorityMustBeSigner),
+        (false, multisig_signers) => Ok((fixed_accounts, Some(multisig_signers))),
+        (true, _) => Err(not_enough_accounts),
+    }
+}
+
+/// Checks that `owner` acts for `authority`, the authority stored in `counter`.
+///
+/// Without `multisig_signers`, `owner` must be the authority and must have signed. Otherwise
+/// `owner` must be the counter's multisig authority and `multisig_signers` must approve, as
+/// checked by [`authorize_multisig`].
+///
+/// # Errors
+///
+/// Returns [`MultisigAuthorizationError`] if any of the checks fail.
+pub(crate) fn authorize_counter_authority(
+    program_id: &Pubkey,
+    counter: &Pubkey,
+    authority: &Pubkey,
+    owner: &AccountInfo,
+    multisig_signers: Option<&[AccountInfo]>,
+) -> Result<(), MultisigAuthorizationError> {
+    let Some(multisig_signers) = multisig_signers else {
+        if authority != owner.key() {
+            return Err(MultisigAuthorizationError::AuthorityMismatch);
+        }
+
+        if !owner.is_signer() {
+            return Err(MultisigAuthorizationError::AuthorityMustBeSigner);
+        }
+
+        return Ok(());
+    };
+
+    if authority != owner.key() {
+        return Err(MultisigAuthorizationError::MultisigAuthorityMismatch);
+    }
+
+    authorize_multisig(program_id, counter, owner, multisig_signers)
+}
+
+/// Checks that `multisig` is the multisig record of `counter` and that `signers` approve.
+///
+/// The caller must have checked that `multisig` is the counter's authority.
+///
+/// Validates:
+/// - The multisig record has the `MultisigV1Account` discriminator
+/// - The multisig record address is derived from the counter
+/// - Every account in `signers` signed the transaction, is a member and appears only once
+/// - At least `threshold` members signed
+///
+/// # Errors
+///
+/// Returns [`MultisigAuthorizationError`] if any of the checks fail.
+fn authorize_multisig(
+    program_id: &Pubkey,
+    counter: &Pubkey,
+    multisig: &AccountInfo,
+    signers: &[AccountInfo],
+) -> Result<(), MultisigAuthorizationError> {
+    let multisig_state = {
+        let multisig_data = multisig.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::MultisigV1Account, &multisig_data)?;
+        MultisigV1::deserialize(&multisig_data)?
+    };
+
+    let expected_multisig = create_multisig_v1_address(program_id, counter, multisig_state.bump)?;
+    let observed_multisig = multisig.key();
+    if observed_multisig != &expected_multisig {
+        return Err(MultisigAuthorizationError::MultisigAddressMismatch {
+            expected: expected_multisig,
+            observed: *observed_multisig,
+        });
+    }
+
+    for (index, signer) in signers.iter().enumerate() {
+        if !signer.is_signer() {
+            return Err(MultisigAuthorizationError::SignerMustBeSigner);
+        }
+
+        if !multisig_state.is_member(signer.key()) {
+            return Err(MultisigAuthorizationError::SignerNotMember);
+        }
+
+        if signers[..index]
+            .iter()
+            .any(|previous| previous.key() == signer.key())
+        {
+            return Err(MultisigAuthorizationError::DuplicateSigner);
+        }
+    }
+
+    if signers.len() < usize::from(multisig_state.threshold) {
+        return Err(MultisigAuthorizationError::ThresholdNotMet {
+            threshold: multisig_state.threshold,
+            observed: signers.len(),
+        });
+    }
+
+    Ok(())
+}
+
+impl From<AccountDiscriminatorError> for MultisigAuthorizationError {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for MultisigAuthorizationError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for MultisigAuthorizationError {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
@@ -0,0 +1,251 @@
+//! Checks that the on-chain code paths never call the allocator.
+//!
+//! The program is built with `no_allocator!`, which aborts on any allocation, so these tests
+//! install a counting allocator for the test binary and run the serialization, event, return data
+//! and error reporting code the instruction handlers use.
+
+use {
+    crate::{
+        instructions::IncrementCountV1Error, state::test_clock, AccountDiscriminator,
+        BatchOperationV1, ConfigV1, CountPolicy, CountReturnDataV1, Counter, CounterEventV1,
+        CounterV1, CounterV1Mut, CounterV2, DeactivatedCounterV1, DelegateV1, EventDiscriminator,
+        ExecuteBatchV1Args, IndexedCounterV1, InstructionDiscriminator,
+        InstructionDiscriminatorError, InstructionError, MultisigV1, PauseCheckError, TombstoneV1,
+        MAX_MULTISIG_MEMBERS,
+    },
+    pinocchio::program_error::ProgramError,
+    std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    },
+};
+
+struct CountingAllocator;
+
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: Every call is forwarded to the system allocator unchanged.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // The thread-local is unavailable while the thread is torn down
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f` and returns the number of allocations it made on the current thread.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn counter_v1() -> CounterV1 {
+    CounterV1 {
+        discriminator: AccountDiscriminator::CounterV1Account,
+        owner: [1; 32],
+        bump: 254,
+        count: 42,
+    }
+}
+
+#[test]
+fn test_account_serialization_does_not_allocate() {
+    let counter_v1 = counter_v1();
+    let counter_v2 = CounterV2::from_v1(&counter_v1, &test_clock(0, 0));
+    let indexed_counter = IndexedCounterV1 {
+        discriminator: AccountDiscriminator::IndexedCounterV1Account,
+        owner: [2; 32],
+        index: 3,
+        bump: 253,
+        count: 4,
+    };
+    let delegate = DelegateV1 {
+        discriminator: AccountDiscriminator::DelegateV1Account,
+        counter: [3; 32],
+        delegate: [4; 32],
+        authority: [5; 32],
+        bump: 252,
+        allowance: 5,
+        expiry_slot: 6,
+    };
+    let config = ConfigV1 {
+        discriminator: AccountDiscriminator::ConfigV1Account,
+        bump: 251,
+        admin: [5; 32],
+        paused: true,
+        pause_exemptions: [0; 32],
+    };
+    let tombstone = TombstoneV1 {
+        discriminator: AccountDiscriminator::TombstoneV1Account,
+        bump: 250,
+        counter: [6; 32],
+    };
+    let multisig = MultisigV1 {
+        discriminator: AccountDiscriminator::MultisigV1Account,
+        counter: [6; 32],
+        bump: 249,
+        threshold: 2,
+        member_count: 3,
+        members: [[7; 32]; MAX_MULTISIG_MEMBERS as usize],
+    };
+
+    let allocations = count_allocations(|| {
+        let mut counter_v1_data = [0; CounterV1::size()];
+        counter_v1.serialize_into(&mut counter_v1_data).unwrap();
+        CounterV1::deserialize(&counter_v1_data).unwrap();
+        Counter::deserialize(&counter_v1_data).unwrap();
+
+        let mut counter_v2_data = [0; CounterV2::size()];
+        Counter::V2(counter_v2)
+            .serialize_into(&mut counter_v2_data)
+            .unwrap();
+        CounterV2::deserialize(&counter_v2_data).unwrap();
+
+        let deactivated =
+            DeactivatedCounterV1::from_counter(&Counter::deserialize(&counter_v2_data).unwrap());
+        let mut deactivated_data = [0; DeactivatedCounterV1::size()];
+        deactivated.serialize_into(&mut deactivated_data).unwrap();
+        DeactivatedCounterV1::from_account_data(&deactivated_data, &[1; 32], 255).unwrap();
+
+        let mut view = CounterV1Mut::try_from_bytes(&mut counter_v2_data).unwrap();
+        view.set_count(view.count() + 1);
+        view.record_modification(&test_clock(0, 0));
+        view.policy().unwrap();
+
+        let mut indexed_counter_data = [0; IndexedCounterV1::size()];
+        indexed_counter
+            .serialize_into(&mut indexed_counter_data)
+            .unwrap();
+        IndexedCounterV1::deserialize(&indexed_counter_data).unwrap();
+
+        let mut delegate_data = [0; DelegateV1::size()];
+        delegate.serialize_into(&mut delegate_data).unwrap();
+        DelegateV1::deserialize(&delegate_data).unwrap();
+
+        let mut config_data = [0; ConfigV1::size()];
+        config.serialize_into(&mut config_data).unwrap();
+        let config = ConfigV1::deserialize(&config_data).unwrap();
+        assert!(config.blocks(InstructionDiscriminator::IncrementCountV1.into()));
+
+        let mut tombstone_data = [0; TombstoneV1::size()];
+        tombstone.serialize_into(&mut tombstone_data).unwrap();
+        AccountDiscriminator::check(AccountDiscriminator::TombstoneV1Account, &tombstone_data)
+            .unwrap();
+        TombstoneV1::deserialize(&tombstone_data).unwrap();
+
+        let mut multisig_data = [0; MultisigV1::size()];
+        multisig.serialize_into(&mut multisig_data).unwrap();
+        AccountDiscriminator::check(AccountDiscriminator::MultisigV1Account, &multisig_data)
+            .unwrap();
+        assert!(MultisigV1::deserialize(&multisig_data)
+            .unwrap()
+            .is_member(&[7; 32]));
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn test_batch_operations_do_not_allocate() {
+    // Encoded as clients encode it, as a wincode `Vec`
+    let data = wincode::serialize(&vec![
+        BatchOperationV1::set(10),
+        BatchOperationV1::increment(),
+        BatchOperationV1::increment_by(3),
+        BatchOperationV1::decrement(),
+    ])
+    .unwrap();
+
+    let allocations = count_allocations(|| {
+        let args = ExecuteBatchV1Args::deserialize(&data).unwrap();
+        assert_eq!(args.operation_count(), 4);
+
+        let policy = CountPolicy::default();
+        let count = args
+            .operations()
+            .try_fold(0, |count, operation| {
+                operation.unwrap().apply(&policy, count)
+            })
+            .unwrap();
+        assert_eq!(count, 13);
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn test_events_and_return_data_do_not_allocate() {
+    let event = CounterEventV1 {
+        counter: [1; 32],
+        owner: [2; 32],
+        old_count: 3,
+        new_count: 4,
+        slot: 5,
+    };
+
+    let allocations = count_allocations(|| {
+        event.emit(EventDiscriminator::CountIncrementedV1).unwrap();
+        CountReturnDataV1 { count: 4 }.set();
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[test]
+fn test_error_conversion_does_not_allocate() {
+    let allocations = count_allocations(|| {
+        let program_error: ProgramError =
+            InstructionError::IncrementCountV1(IncrementCountV1Error::CounterAddressMismatch {
+                expected: [1; 32],
+                observed: [2; 32],
+            })
+            .into();
+        assert_eq!(program_error, ProgramError::Custom(0x305));
+
+        let program_error: ProgramError = InstructionDiscriminatorError::Invalid(255).into();
+        assert!(matches!(program_error, ProgramError::Custom(_)));
+
+        let program_error: ProgramError = PauseCheckError::ProgramPaused.into();
+        assert_eq!(program_error, ProgramError::Custom(0x85));
+
+        assert_eq!(
+            InstructionDiscriminator::IncrementCountV1.name(),
+            "IncrementCountV1"
+        );
+    });
+
+    assert_eq!(allocations, 0);
+}
+
+#[cfg(not(feature = "no-entrypoint"))]
+#[test]
+fn test_failing_instructions_do_not_allocate() {
+    use crate::entrypoint::process_instruction;
+
+    let program_id = [9; 32];
+
+    let allocations = count_allocations(|| {
+        // Unknown discriminator
+        assert!(process_instruction(&program_id, &[], &[255]).is_err());
+
+        // Missing config account, reported by the pause check
+        let increment = [u8::from(InstructionDiscriminator::IncrementCountV1)];
+        assert!(process_instruction(&program_id, &[], &increment).is_err());
+
+        // Missing accounts, reported through the instruction's error enum
+        let set_paused = [u8::from(InstructionDiscriminator::SetPausedV1), 1];
+        assert!(process_instruction(&program_id, &[], &set_paused).is_err());
+    });
+
+    assert_eq!(allocations, 0);
+}
@@ -0,0 +1,96 @@
+use {
+    crate::{
+        try_find_config_v1, AccountDiscriminator, AccountDiscriminatorError, ConfigV1,
+        InstructionDiscriminator,
+    },
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey},
+    wincode::ReadError,
+};
+
+/// Errors raised while checking the program's emergency pause before an instruction runs.
+#[derive(Debug)]
+pub enum PauseCheckError {
+    ProgramError(ProgramError),
+    MissingConfigAccount,
+    ConfigAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    ProgramPaused,
+}
+
+/// Checks that the program is not paused for `discriminator` and returns the instruction's own
+/// accounts.
+///
+/// Every pause-checked instruction takes the config account last, after its own accounts, so the
+/// instruction's accounts keep the positions they had before the pause was introduced.
+///
+/// Until `InitializeConfigV1` has run, the config address is not owned by the program and the
+/// program is treated as running, after checking that the account really is the config address.
+/// Once the config exists, only the program can write to it and it only ever creates it at the
+/// config address, so the owner and discriminator are enough to trust it without deriving the
+/// address again.
+///
+/// Validates:
+/// - The config account is present
+/// - An uninitialized config account lives at the config address
+/// - An initialized config has the `ConfigV1Account` discriminator and size
+/// - The program is not paused, or the admin has exempted the instruction from the pause
+///
+/// # Errors
+///
+/// Returns [`PauseCheckError`] if any of the checks fail.
+// Only the entrypoint checks the pause, and `no-entrypoint` builds leave it out.
+#[cfg_attr(feature = "no-entrypoint", allow(dead_code))]
+pub(crate) fn check_not_paused<'a>(
+    program_id: &Pubkey,
+    discriminator: InstructionDiscriminator,
+    accounts: &'a [AccountInfo],
+) -> Result<&'a [AccountInfo], PauseCheckError> {
+    let Some((config, instruction_accounts)) = accounts.split_last() else {
+        return Err(PauseCheckError::MissingConfigAccount);
+    };
+
+    if !config.is_owned_by(program_id) {
+        let (expected_config, _) =
+            try_find_config_v1(program_id).ok_or(ProgramError::InvalidSeeds)?;
+        let observed_config = config.key();
+        if observed_config != &expected_config {
+            return Err(PauseCheckError::ConfigAddressMismatch {
+                expected: expected_config,
+                observed: *observed_config,
+            });
+        }
+
+        return Ok(instruction_accounts);
+    }
+
+    let config_state = {
+        let config_data = config.try_borrow_data()?;
+        AccountDiscriminator::check(AccountDiscriminator::ConfigV1Account, &config_data)?;
+        ConfigV1::deserialize(&config_data)?
+    };
+
+    if config_state.blocks(discriminator.into()) {
+        return Err(PauseCheckError::ProgramPaused);
+    }
+
+    Ok(instruction_accounts)
+}
+
+impl From<AccountDiscriminatorError> for PauseCheckError {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for PauseCheckError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for PauseCheckError {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
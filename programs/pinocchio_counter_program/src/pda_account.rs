@@ -0,0 +1,62 @@
+use {
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::Signer,
+        pubkey::Pubkey,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer},
+};
+
+/// Creates a rent-exempt program-derived account of `space` bytes owned by `owner`.
+///
+/// PDA addresses are predictable, so anyone can transfer lamports to one before it is created.
+/// `CreateAccount` fails for an account that already holds lamports, which would let a single
+/// lamport block the account forever. When the account is already funded it is instead topped
+/// up to the rent-exempt minimum by `payer`, then allocated and assigned with the PDA `signers`.
+///
+/// The caller must have checked that `account` has no data and is owned by the system program.
+///
+/// # Errors
+///
+/// Returns a [`pinocchio::program_error::ProgramError`] if reading the rent sysvar or any of
+/// the system program CPIs fails.
+pub(crate) fn create_pda_account(
+    payer: &AccountInfo,
+    account: &AccountInfo,
+    space: usize,
+    owner: &Pubkey,
+    signers: &[Signer],
+) -> ProgramResult {
+    let minimum_balance = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return CreateAccount {
+            from: payer,
+            to: account,
+            lamports: minimum_balance,
+            space: space as u64,
+            owner,
+        }
+        .invoke_signed(signers);
+    }
+
+    let required_lamports = minimum_balance.saturating_sub(account.lamports());
+    if required_lamports > 0 {
+        Transfer {
+            from: payer,
+            to: account,
+            lamports: required_lamports,
+        }
+        .invoke()?;
+    }
+
+    Allocate {
+        account,
+        space: space as u64,
+    }
+    .invoke_signed(signers)?;
+
+    Assign { account, owner }.invoke_signed(signers)
+}
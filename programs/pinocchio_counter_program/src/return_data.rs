@@ -0,0 +1,79 @@
+#[cfg(not(target_os = "solana"))]
+use alloc::vec::Vec;
+
+use {
+    pinocchio::cpi::set_return_data,
+    wincode::{SchemaRead, SchemaWrite},
+};
+
+/// Return data set by `GetCountV1` and by every instruction that writes a counter account.
+///
+/// Holds the counter's count after the instruction, so CPI callers and simulations can read it
+/// without fetching the account. Instructions that deactivate a counter return `0`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, SchemaRead, SchemaWrite)]
+pub struct CountReturnDataV1 {
+    pub count: u64,
+}
+
+impl CountReturnDataV1 {
+    /// Returns the size in bytes of a serialized [`CountReturnDataV1`].
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // CountReturnDataV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the return data to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Deserializes the return data from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+
+    /// Sets this value as the return data of the current instruction.
+    ///
+    /// The count is written as little-endian bytes, which is exactly its wincode encoding, so
+    /// setting return data cannot fail and needs no allocation.
+    pub fn set(&self) {
+        set_return_data(&self.count.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_return_data_v1_size() {
+        assert_eq!(CountReturnDataV1::size(), 8);
+    }
+
+    #[test]
+    fn test_count_return_data_v1_wincode_matches_le_bytes() {
+        for count in [0, 1, 42, u64::MAX] {
+            let serialized = CountReturnDataV1 { count }.serialize().unwrap();
+            assert_eq!(serialized, count.to_le_bytes());
+            assert_eq!(
+                CountReturnDataV1::deserialize(&serialized).unwrap(),
+                CountReturnDataV1 { count }
+            );
+        }
+    }
+}
@@ -1,42 +1,983 @@
+#[cfg(not(target_os = "solana"))]
+use alloc::vec::Vec;
+
 use {
-    crate::AccountDiscriminator,
-    pinocchio::pubkey::Pubkey,
+    crate::{AccessMode, AccountDiscriminator, CountPolicy, OverflowPolicy, MAX_MULTISIG_MEMBERS},
+    pinocchio::{pubkey::Pubkey, sysvars::clock::Clock},
     wincode::{SchemaRead, SchemaWrite},
 };
 
-pub const DEACTIVATED_ACCOUNT_SIZE: usize = 1;
+pub const DEACTIVATED_ACCOUNT_SIZE: usize = 1;
+
+/// Serializes `src` into the start of `dst` without allocating, returning the number of bytes
+/// written.
+fn serialize_into<T>(src: &T, mut dst: &mut [u8]) -> wincode::WriteResult<usize>
+where
+    T: SchemaWrite<Src = T>,
+{
+    let capacity = dst.len();
+    wincode::serialize_into(&mut dst, src)?;
+    Ok(capacity - dst.len())
+}
+
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct CounterV1 {
+    pub discriminator: AccountDiscriminator,
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub count: u64,
+}
+
+impl CounterV1 {
+    /// Returns the size in bytes required to store a [`CounterV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // CounterV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the counter state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the counter state into the start of `dst`, returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the counter state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// Counter layout that additionally stores an overflow policy, inclusive count bounds, a
+/// rotatable authority, an access mode, a frozen flag, when the counter was created and last
+/// modified, and a cooldown between count updates.
+///
+/// Lives at the same address as [`CounterV1`] and shares its leading fields. A [`CounterV1`] is
+/// migrated in place by `MigrateCounterV1ToV2`, or upgraded on demand by `SetCountPolicyV1`,
+/// `ProposeAuthorityV1`, `SetAccessModeV1`, `FreezeCounterV1`, `SetCooldownV1` or
+/// `InitializeMultisigV1`. Sponsored counters, created by `InitializeSponsoredCounterV1` or
+/// `ReactivateSponsoredCounterV1`, always use this layout.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct CounterV2 {
+    pub discriminator: AccountDiscriminator,
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub count: u64,
+    pub overflow_policy: OverflowPolicy,
+    pub min_count: u64,
+    pub max_count: u64,
+    /// The key allowed to operate the counter. Starts as `owner`, the counter's creator, whose
+    /// key the counter address is derived from, and is a [`MultisigV1`] address once
+    /// `InitializeMultisigV1` runs.
+    pub authority: Pubkey,
+    /// The key proposed as the next authority, or [`NO_PENDING_AUTHORITY`].
+    pub pending_authority: Pubkey,
+    /// The key that paid the counter's rent and is refunded when it is deactivated, or
+    /// [`NO_RENT_PAYER`] when the refund goes to the authority.
+    pub rent_payer: Pubkey,
+    /// Whether only the authority, or any signer, may increment and decrement the count.
+    pub access_mode: AccessMode,
+    /// Whether the count is frozen by `FreezeCounterV1`, rejecting every count update until
+    /// `ThawCounterV1`.
+    pub frozen: bool,
+    /// Slot in which the counter was created, or in which it was upgraded from a [`CounterV1`],
+    /// which does not record it.
+    pub created_slot: u64,
+    /// Slot of the last instruction that changed the counter.
+    pub last_modified_slot: u64,
+    /// Unix timestamp, from the `Clock` sysvar, of the last instruction that changed the counter.
+    pub last_modified_unix_timestamp: i64,
+    /// Slots that must pass after the last modification before the count may change again, or
+    /// `0` for no slot cooldown. Set by `SetCooldownV1`.
+    pub cooldown_slots: u64,
+    /// Seconds, by the `Clock` sysvar's Unix timestamp, that must pass after the last
+    /// modification before the count may change again, or `0` for no time cooldown. Set by
+    /// `SetCooldownV1`.
+    pub cooldown_seconds: u64,
+}
+
+/// Value of [`CounterV2::pending_authority`] when no authority transfer is pending.
+///
+/// This is the system program address, which can never sign a transaction.
+pub const NO_PENDING_AUTHORITY: Pubkey = [0; 32];
+
+/// Value of [`CounterV2::rent_payer`] for counters funded by their own authority.
+pub const NO_RENT_PAYER: Pubkey = [0; 32];
+
+impl CounterV2 {
+    /// Returns the size in bytes required to store a [`CounterV2`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // CounterV2 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Upgrades a [`CounterV1`] to a [`CounterV2`] with the default count policy and the
+    /// creator as authority, recording `clock` as both its creation and last modification.
+    #[must_use]
+    pub fn from_v1(counter: &CounterV1, clock: &Clock) -> Self {
+        let policy = CountPolicy::default();
+        Self {
+            discriminator: AccountDiscriminator::CounterV2Account,
+            owner: counter.owner,
+            bump: counter.bump,
+            count: counter.count,
+            overflow_policy: policy.overflow_policy,
+            min_count: policy.min_count,
+            max_count: policy.max_count,
+            authority: counter.owner,
+            pending_authority: NO_PENDING_AUTHORITY,
+            rent_payer: NO_RENT_PAYER,
+            access_mode: AccessMode::Private,
+            frozen: false,
+            created_slot: clock.slot,
+            last_modified_slot: clock.slot,
+            last_modified_unix_timestamp: clock.unix_timestamp,
+            cooldown_slots: 0,
+            cooldown_seconds: 0,
+        }
+    }
+
+    /// Records `clock` as the time of the counter's last modification.
+    pub fn record_modification(&mut self, clock: &Clock) {
+        self.last_modified_slot = clock.slot;
+        self.last_modified_unix_timestamp = clock.unix_timestamp;
+    }
+
+    /// Returns `true` if a slot or time cooldown is configured.
+    #[must_use]
+    pub const fn has_cooldown(&self) -> bool {
+        self.cooldown_slots != 0 || self.cooldown_seconds != 0
+    }
+
+    /// Returns `true` if both cooldowns have passed since the last modification at `clock`.
+    #[must_use]
+    pub fn cooldown_elapsed(&self, clock: &Clock) -> bool {
+        let slots_elapsed = self.cooldown_slots == 0
+            || clock.slot >= self.last_modified_slot.saturating_add(self.cooldown_slots);
+        let seconds_elapsed = self.cooldown_seconds == 0
+            || clock.unix_timestamp
+                >= self
+                    .last_modified_unix_timestamp
+                    .saturating_add_unsigned(self.cooldown_seconds);
+        slots_elapsed && seconds_elapsed
+    }
+
+    /// Returns the proposed next authority, if an authority transfer is pending.
+    #[must_use]
+    pub fn pending_authority(&self) -> Option<&Pubkey> {
+        (self.pending_authority != NO_PENDING_AUTHORITY).then_some(&self.pending_authority)
+    }
+
+    /// Returns the key refunded on deactivation, if the rent was paid by a sponsor.
+    #[must_use]
+    pub fn rent_payer(&self) -> Option<&Pubkey> {
+        (self.rent_payer != NO_RENT_PAYER).then_some(&self.rent_payer)
+    }
+
+    /// Returns the count policy stored in the counter.
+    #[must_use]
+    pub fn policy(&self) -> CountPolicy {
+        CountPolicy {
+            overflow_policy: self.overflow_policy,
+            min_count: self.min_count,
+            max_count: self.max_count,
+        }
+    }
+
+    /// Serializes the counter state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the counter state into the start of `dst`, returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the counter state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// An active counter in any of the layouts accepted by the counter instructions.
+pub enum Counter {
+    V1(CounterV1),
+    V2(CounterV2),
+}
+
+impl Counter {
+    /// Deserializes a counter, selecting the layout from the account discriminator.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails or the discriminator is not
+    /// an active counter layout.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        match wincode::deserialize::<AccountDiscriminator>(src)? {
+            AccountDiscriminator::CounterV1Account => CounterV1::deserialize(src).map(Self::V1),
+            AccountDiscriminator::CounterV2Account => CounterV2::deserialize(src).map(Self::V2),
+            AccountDiscriminator::DelegateV1Account
+            | AccountDiscriminator::IndexedCounterV1Account
+            | AccountDiscriminator::ShardedCounterV1Account
+            | AccountDiscriminator::CounterShardV1Account
+            | AccountDiscriminator::ConfigV1Account
+            | AccountDiscriminator::TombstoneV1Account
+            | AccountDiscriminator::MultisigV1Account
+            | AccountDiscriminator::DeactivatedAccount => Err(wincode::ReadError::Custom(
+                "account is not an active counter",
+            )),
+        }
+    }
+
+    /// Serializes the counter state to bytes using its current layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        match self {
+            Self::V1(counter) => counter.serialize(),
+            Self::V2(counter) => counter.serialize(),
+        }
+    }
+
+    /// Serializes the counter state into the start of `dst` using its current layout, returning
+    /// the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        match self {
+            Self::V1(counter) => counter.serialize_into(dst),
+            Self::V2(counter) => counter.serialize_into(dst),
+        }
+    }
+
+    /// Returns the size in bytes of the counter's current layout.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::V1(_) => CounterV1::size(),
+            Self::V2(_) => CounterV2::size(),
+        }
+    }
+
+    /// Returns the counter's creator, whose key the counter address is derived from.
+    #[must_use]
+    pub const fn owner(&self) -> &Pubkey {
+        match self {
+            Self::V1(counter) => &counter.owner,
+            Self::V2(counter) => &counter.owner,
+        }
+    }
+
+    /// Returns the bump seed of the counter address, as found when the counter was created.
+    #[must_use]
+    pub const fn bump(&self) -> u8 {
+        match self {
+            Self::V1(counter) => counter.bump,
+            Self::V2(counter) => counter.bump,
+        }
+    }
+
+    /// Returns the key allowed to operate the counter. For [`CounterV1`] this is the creator.
+    #[must_use]
+    pub const fn authority(&self) -> &Pubkey {
+        match self {
+            Self::V1(counter) => &counter.owner,
+            Self::V2(counter) => &counter.authority,
+        }
+    }
+
+    /// Returns the key refunded on deactivation when it is not the authority. Only sponsored
+    /// counters, which always use the [`CounterV2`] layout, record a rent payer.
+    #[must_use]
+    pub fn rent_payer(&self) -> Option<&Pubkey> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(counter) => counter.rent_payer(),
+        }
+    }
+
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        match self {
+            Self::V1(counter) => counter.count,
+            Self::V2(counter) => counter.count,
+        }
+    }
+
+    pub fn set_count(&mut self, count: u64) {
+        match self {
+            Self::V1(counter) => counter.count = count,
+            Self::V2(counter) => counter.count = count,
+        }
+    }
+
+    /// Returns the count policy of the counter. [`CounterV1`] always uses the default policy.
+    #[must_use]
+    pub fn policy(&self) -> CountPolicy {
+        match self {
+            Self::V1(_) => CountPolicy::default(),
+            Self::V2(counter) => counter.policy(),
+        }
+    }
+
+    /// Returns the access mode of the counter. [`CounterV1`] is always private.
+    #[must_use]
+    pub const fn access_mode(&self) -> AccessMode {
+        match self {
+            Self::V1(_) => AccessMode::Private,
+            Self::V2(counter) => counter.access_mode,
+        }
+    }
+
+    /// Returns `true` if `signer` may increment or decrement the count without a delegate: the
+    /// authority always may, and any signer may while the counter is public.
+    #[must_use]
+    pub fn allows_count_update_by(&self, signer: &Pubkey) -> bool {
+        self.access_mode().is_public() || self.authority() == signer
+    }
+
+    /// Returns `true` if the count is frozen. [`CounterV1`] is never frozen.
+    #[must_use]
+    pub const fn is_frozen(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(counter) => counter.frozen,
+        }
+    }
+
+    /// Returns `true` if count updates are rate limited. [`CounterV1`] has no cooldown.
+    #[must_use]
+    pub const fn has_cooldown(&self) -> bool {
+        match self {
+            Self::V1(_) => false,
+            Self::V2(counter) => counter.has_cooldown(),
+        }
+    }
+
+    /// Returns `true` if the counter's cooldown has passed at `clock`. [`CounterV1`] has no
+    /// cooldown.
+    #[must_use]
+    pub fn cooldown_elapsed(&self, clock: &Clock) -> bool {
+        match self {
+            Self::V1(_) => true,
+            Self::V2(counter) => counter.cooldown_elapsed(clock),
+        }
+    }
+
+    /// Returns the proposed next authority, if an authority transfer is pending. [`CounterV1`]
+    /// never has one.
+    #[must_use]
+    pub fn pending_authority(&self) -> Option<&Pubkey> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(counter) => counter.pending_authority(),
+        }
+    }
+}
+
+/// What a deactivated counter keeps of its creator and authority.
+///
+/// `DeactivateCounterV1` shrinks the counter to this record, so that its address can still be
+/// checked from the stored creator and bump seed, and the reactivate instructions require
+/// `authority` to approve and restore it along with `pending_authority`. Deactivating a counter
+/// therefore never hands it back to its creator once its authority has been rotated or given to
+/// a multisig. Counters deactivated before this record existed keep only the `DeactivatedAccount`
+/// discriminator, [`DEACTIVATED_ACCOUNT_SIZE`] bytes, and are treated as belonging to their
+/// creator.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct DeactivatedCounterV1 {
+    pub discriminator: AccountDiscriminator,
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub authority: Pubkey,
+    /// The key proposed as the next authority, or [`NO_PENDING_AUTHORITY`].
+    pub pending_authority: Pubkey,
+}
+
+impl DeactivatedCounterV1 {
+    /// Returns the size in bytes of a serialized [`DeactivatedCounterV1`].
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // DeactivatedCounterV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Records the creator, bump seed and authority of `counter` as it is deactivated.
+    #[must_use]
+    pub fn from_counter(counter: &Counter) -> Self {
+        Self {
+            discriminator: AccountDiscriminator::DeactivatedAccount,
+            owner: *counter.owner(),
+            bump: counter.bump(),
+            authority: *counter.authority(),
+            pending_authority: counter
+                .pending_authority()
+                .copied()
+                .unwrap_or(NO_PENDING_AUTHORITY),
+        }
+    }
+
+    /// Reads a deactivated counter created by `owner` at the address derived with `bump`.
+    ///
+    /// A counter deactivated before this record existed holds only its discriminator, and is
+    /// read as belonging to `owner` with no pending authority transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn from_account_data(
+        src: &[u8],
+        owner: &Pubkey,
+        bump: u8,
+    ) -> Result<Self, wincode::ReadError> {
+        if src.len() == DEACTIVATED_ACCOUNT_SIZE {
+            return Ok(Self {
+                discriminator: AccountDiscriminator::DeactivatedAccount,
+                owner: *owner,
+                bump,
+                authority: *owner,
+                pending_authority: NO_PENDING_AUTHORITY,
+            });
+        }
+        Self::deserialize(src)
+    }
+
+    /// Returns the proposed next authority, if an authority transfer was pending.
+    #[must_use]
+    pub fn pending_authority(&self) -> Option<&Pubkey> {
+        (self.pending_authority != NO_PENDING_AUTHORITY).then_some(&self.pending_authority)
+    }
+
+    /// Returns `true` if reactivating restores the counter as a plain [`CounterV1`], which is
+    /// only possible while the creator is still its authority and no transfer is pending.
+    #[must_use]
+    pub fn is_creator_owned(&self) -> bool {
+        self.authority == self.owner && self.pending_authority().is_none()
+    }
+
+    /// Serializes the record to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the record into the start of `dst`, returning the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the record from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// Counter addressed by its owner and a caller-chosen index, so one owner can hold many counters.
+///
+/// Lives at the PDA derived from `[INDEXED_COUNTER_V1_SEED, owner, index]`. Indexed counters are
+/// operated only by their owner through the `*IndexedCounterV1` instructions, and their count
+/// saturates at `0` and `u64::MAX`.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct IndexedCounterV1 {
+    pub discriminator: AccountDiscriminator,
+    pub owner: Pubkey,
+    pub index: u32,
+    pub bump: u8,
+    pub count: u64,
+}
+
+impl IndexedCounterV1 {
+    /// Returns the size in bytes required to store an [`IndexedCounterV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // IndexedCounterV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the counter state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the counter state into the start of `dst`, returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the counter state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// Delegation record allowing `delegate` to increment and decrement `counter` on the owner's behalf.
+///
+/// Lives at the PDA derived from `[DELEGATE_V1_SEED, counter]`, so each counter has at most one
+/// delegate. Every increment or decrement signed by the delegate consumes one unit of `allowance`,
+/// and the delegation stops being accepted once the current slot exceeds `expiry_slot`, or once
+/// the counter's authority is no longer `authority`.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct DelegateV1 {
+    pub discriminator: AccountDiscriminator,
+    pub counter: Pubkey,
+    pub delegate: Pubkey,
+    /// The counter authority that approved the delegation.
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub allowance: u64,
+    pub expiry_slot: u64,
+}
+
+impl DelegateV1 {
+    /// Returns the size in bytes required to store a [`DelegateV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // DelegateV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the delegate state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the delegate state into the start of `dst`, returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the delegate state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// Root of a sharded counter, holding the count reconciled from its shards.
+///
+/// Lives at the PDA derived from `[SHARDED_COUNTER_V1_SEED, owner]`. Increments are written to
+/// the counter's [`CounterShardV1`] accounts, so they do not all write-lock one account, and
+/// `ReconcileShardsV1` moves shard counts into `count`. The total count is `count` plus the counts
+/// of all shards.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct ShardedCounterV1 {
+    pub discriminator: AccountDiscriminator,
+    pub owner: Pubkey,
+    pub bump: u8,
+    /// Number of shards, which live at indices `0..shard_count`.
+    pub shard_count: u8,
+    /// Count moved out of the shards by `ReconcileShardsV1`.
+    pub count: u64,
+}
+
+impl ShardedCounterV1 {
+    /// Returns the size in bytes required to store a [`ShardedCounterV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // ShardedCounterV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the sharded counter state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the sharded counter state into the start of `dst`, returning the number of
+    /// bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the sharded counter state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// One shard of a [`ShardedCounterV1`], counting the increments written to it since it was last
+/// reconciled.
+///
+/// Lives at the PDA derived from `[COUNTER_SHARD_V1_SEED, sharded_counter, index]`. Any signer may
+/// increment a shard, so writers usually pick one by hashing their key to spread the write locks
+/// across shards.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct CounterShardV1 {
+    pub discriminator: AccountDiscriminator,
+    pub sharded_counter: Pubkey,
+    pub index: u8,
+    pub bump: u8,
+    pub count: u64,
+}
+
+impl CounterShardV1 {
+    /// Returns the size in bytes required to store a [`CounterShardV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // CounterShardV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Serializes the shard state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the shard state into the start of `dst`, returning the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the shard state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// Program-wide configuration, holding the admin and the emergency pause.
+///
+/// Lives at the PDA derived from `[CONFIG_V1_SEED]` and is created once by `InitializeConfigV1`,
+/// which only the program's upgrade authority may call. While `paused` is set the entrypoint
+/// rejects every instruction except the config instructions and the ones marked in
+/// `pause_exemptions`.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct ConfigV1 {
+    pub discriminator: AccountDiscriminator,
+    pub bump: u8,
+    /// The key allowed to pause the program and to change its pause exemptions.
+    pub admin: Pubkey,
+    pub paused: bool,
+    /// Bitmap of the instruction discriminators that keep running while the program is paused,
+    /// with bit `d % 8` of byte `d / 8` set for discriminator `d`.
+    pub pause_exemptions: [u8; 32],
+}
+
+impl ConfigV1 {
+    /// Returns the size in bytes required to store a [`ConfigV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // ConfigV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Returns `true` if the instruction with `discriminator` keeps running while the program is
+    /// paused.
+    #[must_use]
+    pub fn is_pause_exempt(&self, discriminator: u8) -> bool {
+        let byte = self.pause_exemptions[usize::from(discriminator / 8)];
+        byte & (1 << (discriminator % 8)) != 0
+    }
+
+    /// Marks the instruction with `discriminator` as exempt from the pause, or clears the mark.
+    pub fn set_pause_exempt(&mut self, discriminator: u8, exempt: bool) {
+        let byte = &mut self.pause_exemptions[usize::from(discriminator / 8)];
+        let mask = 1 << (discriminator % 8);
+        if exempt {
+            *byte |= mask;
+        } else {
+            *byte &= !mask;
+        }
+    }
+
+    /// Returns `true` if the instruction with `discriminator` must be rejected: the program is
+    /// paused and the instruction is not exempt.
+    #[must_use]
+    pub fn blocks(&self, discriminator: u8) -> bool {
+        self.paused && !self.is_pause_exempt(discriminator)
+    }
+
+    /// Serializes the config state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the config state into the start of `dst`, returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the config state from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
 
+/// Record that a counter has been closed.
+///
+/// Lives at the PDA derived from `[TOMBSTONE_V1_SEED, counter]`. `CloseCounterV1` creates it when
+/// the counter is closed, and the initialize instructions refuse to create a counter that has a
+/// tombstone, so a closed counter address cannot be reused.
 #[repr(C)]
 #[derive(SchemaRead, SchemaWrite)]
-pub struct CounterV1 {
+pub struct TombstoneV1 {
     pub discriminator: AccountDiscriminator,
-    pub owner: Pubkey,
     pub bump: u8,
-    pub count: u64,
+    pub counter: Pubkey,
 }
 
-impl CounterV1 {
-    /// Returns the size in bytes required to store a [`CounterV1`] account.
+impl TombstoneV1 {
+    /// Returns the size in bytes of a serialized [`TombstoneV1`].
     #[must_use]
     pub const fn size() -> usize {
         if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
             size
         } else {
-            // CounterV1 has a fixed layout, so TYPE_META is always Static.
+            // TombstoneV1 has a fixed layout, so TYPE_META is always Static.
             unreachable!()
         }
     }
 
-    /// Serializes the counter state to bytes.
+    /// Serializes the tombstone to bytes.
     ///
     /// # Errors
     ///
     /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
     pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
         wincode::serialize(self)
     }
 
-    /// Deserializes the counter state from bytes.
+    /// Serializes the tombstone into the start of `dst`, returning the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the tombstone from the start of `src`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::ReadError`] if deserialization fails.
+    pub fn deserialize(src: &[u8]) -> Result<Self, wincode::ReadError> {
+        wincode::deserialize(src)
+    }
+}
+
+/// M-of-N signer set acting as the authority of a counter.
+///
+/// Lives at the PDA derived from `[MULTISIG_V1_SEED, counter]` and is created by
+/// `InitializeMultisigV1`, which also makes its address the counter's authority. The multisig
+/// cannot sign, so the count updates and `ProposeAuthorityV1` take it in place of the authority
+/// signer, followed by at least `threshold` distinct members signing the transaction. Only the
+/// first `member_count` entries of `members` are in use.
+#[repr(C)]
+#[derive(SchemaRead, SchemaWrite)]
+pub struct MultisigV1 {
+    pub discriminator: AccountDiscriminator,
+    pub counter: Pubkey,
+    pub bump: u8,
+    pub threshold: u8,
+    pub member_count: u8,
+    pub members: [Pubkey; MAX_MULTISIG_MEMBERS as usize],
+}
+
+impl MultisigV1 {
+    /// Returns the size in bytes required to store a [`MultisigV1`] account.
+    #[must_use]
+    pub const fn size() -> usize {
+        if let wincode::TypeMeta::Static { size, .. } = <Self as wincode::SchemaWrite>::TYPE_META {
+            size
+        } else {
+            // MultisigV1 has a fixed layout, so TYPE_META is always Static.
+            unreachable!()
+        }
+    }
+
+    /// Returns the members in use, ignoring the unused tail of `members`.
+    #[must_use]
+    pub fn members(&self) -> &[Pubkey] {
+        let member_count = usize::from(self.member_count).min(self.members.len());
+        &self.members[..member_count]
+    }
+
+    /// Returns `true` if `key` is one of the members.
+    #[must_use]
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members().contains(key)
+    }
+
+    /// Serializes the multisig state to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if serialization fails.
+    #[cfg(not(target_os = "solana"))]
+    pub fn serialize(&self) -> wincode::WriteResult<Vec<u8>> {
+        wincode::serialize(self)
+    }
+
+    /// Serializes the multisig state into the start of `dst`, returning the number of bytes
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`wincode::WriteResult`] if `dst` is too small or serialization fails.
+    pub fn serialize_into(&self, dst: &mut [u8]) -> wincode::WriteResult<usize> {
+        serialize_into(self, dst)
+    }
+
+    /// Deserializes the multisig state from bytes.
     ///
     /// # Errors
     ///
@@ -46,6 +987,19 @@ impl CounterV1 {
     }
 }
 
+/// Returns a `Clock` at `slot` and `unix_timestamp`. pinocchio's `Clock` does not implement
+/// `Default`, so tests set every field here.
+#[cfg(test)]
+pub(crate) const fn test_clock(slot: u64, unix_timestamp: i64) -> Clock {
+    Clock {
+        slot,
+        epoch_start_timestamp: 0,
+        epoch: 0,
+        leader_schedule_epoch: 0,
+        unix_timestamp,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,4 +1032,442 @@ mod tests {
         assert_eq!(CONST_SIZE, CounterV1::size());
         const { assert!(CONST_SIZE > 0) }
     }
+
+    #[test]
+    fn test_counter_v2_serialization_roundtrip() -> wincode::Result<()> {
+        let original = CounterV2 {
+            discriminator: AccountDiscriminator::CounterV2Account,
+            owner: [3; 32],
+            bump: 254,
+            count: 42,
+            overflow_policy: OverflowPolicy::Wrap,
+            min_count: 10,
+            max_count: 10_000,
+            authority: [8; 32],
+            pending_authority: [9; 32],
+            rent_payer: [10; 32],
+            access_mode: AccessMode::Public,
+            frozen: true,
+            created_slot: 11,
+            last_modified_slot: 12,
+            last_modified_unix_timestamp: -13,
+            cooldown_slots: 14,
+            cooldown_seconds: 15,
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), CounterV2::size());
+
+        let deserialized = CounterV2::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.owner, deserialized.owner);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.count, deserialized.count);
+        assert_eq!(original.policy(), deserialized.policy());
+        assert_eq!(original.authority, deserialized.authority);
+        assert_eq!(deserialized.pending_authority(), Some(&[9; 32]));
+        assert_eq!(deserialized.rent_payer(), Some(&[10; 32]));
+        assert_eq!(deserialized.access_mode, AccessMode::Public);
+        assert!(deserialized.frozen);
+        assert_eq!(deserialized.created_slot, 11);
+        assert_eq!(deserialized.last_modified_slot, 12);
+        assert_eq!(deserialized.last_modified_unix_timestamp, -13);
+        assert_eq!(deserialized.cooldown_slots, 14);
+        assert_eq!(deserialized.cooldown_seconds, 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_counter_v2_shares_counter_v1_prefix() -> wincode::Result<()> {
+        let v1 = CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: [4; 32],
+            bump: 253,
+            count: 7,
+        };
+        let clock = test_clock(300, 1_700_000_000);
+        let v2 = CounterV2::from_v1(&v1, &clock);
+
+        let v1_bytes = v1.serialize()?;
+        let v2_bytes = v2.serialize()?;
+        // Only the discriminator differs within the shared prefix.
+        assert_eq!(v1_bytes[1..], v2_bytes[1..CounterV1::size()]);
+        assert_eq!(v2.policy(), CountPolicy::default());
+        assert_eq!(v2.authority, v1.owner);
+        assert_eq!(v2.pending_authority(), None);
+        assert_eq!(v2.rent_payer(), None);
+        assert_eq!(v2.access_mode, AccessMode::Private);
+        assert!(!v2.frozen);
+        assert_eq!(v2.created_slot, 300);
+        assert_eq!(v2.last_modified_slot, 300);
+        assert_eq!(v2.last_modified_unix_timestamp, 1_700_000_000);
+        assert!(!v2.has_cooldown());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_counter_v2_record_modification_keeps_created_slot() {
+        let v1 = CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: [4; 32],
+            bump: 253,
+            count: 7,
+        };
+        let created = test_clock(300, 1_700_000_000);
+        let mut v2 = CounterV2::from_v1(&v1, &created);
+
+        let modified = test_clock(450, 1_700_000_060);
+        v2.record_modification(&modified);
+
+        assert_eq!(v2.created_slot, 300);
+        assert_eq!(v2.last_modified_slot, 450);
+        assert_eq!(v2.last_modified_unix_timestamp, 1_700_000_060);
+    }
+
+    #[test]
+    fn test_counter_v2_cooldown_elapses_after_both_limits() {
+        let v1 = CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: [4; 32],
+            bump: 253,
+            count: 7,
+        };
+        let modified = test_clock(300, 1_700_000_000);
+        let mut v2 = CounterV2::from_v1(&v1, &modified);
+        assert!(v2.cooldown_elapsed(&modified));
+
+        v2.cooldown_slots = 10;
+        v2.cooldown_seconds = 5;
+        assert!(v2.has_cooldown());
+
+        assert!(!v2.cooldown_elapsed(&modified));
+        assert!(!v2.cooldown_elapsed(&test_clock(310, 1_700_000_004)));
+        assert!(!v2.cooldown_elapsed(&test_clock(309, 1_700_000_005)));
+        assert!(v2.cooldown_elapsed(&test_clock(310, 1_700_000_005)));
+
+        v2.cooldown_seconds = 0;
+        assert!(v2.cooldown_elapsed(&test_clock(310, 1_700_000_000)));
+
+        // The end of the cooldown saturates rather than wrapping around to an elapsed slot
+        v2.cooldown_slots = u64::MAX;
+        assert!(!v2.cooldown_elapsed(&test_clock(u64::MAX - 1, 1_700_000_000)));
+        assert!(v2.cooldown_elapsed(&test_clock(u64::MAX, 1_700_000_000)));
+    }
+
+    #[test]
+    fn test_counter_deserializes_both_layouts() -> wincode::Result<()> {
+        let v1 = CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: [5; 32],
+            bump: 252,
+            count: 9,
+        };
+        let v2 = CounterV2::from_v1(&v1, &test_clock(0, 0));
+
+        let counter = Counter::deserialize(&v1.serialize()?)?;
+        assert!(matches!(counter, Counter::V1(_)));
+        assert_eq!(counter.count(), 9);
+        assert_eq!(counter.bump(), 252);
+        assert_eq!(counter.size(), CounterV1::size());
+
+        let counter = Counter::deserialize(&v2.serialize()?)?;
+        assert!(matches!(counter, Counter::V2(_)));
+        assert_eq!(counter.count(), 9);
+        assert_eq!(counter.bump(), 252);
+        assert_eq!(counter.size(), CounterV2::size());
+
+        assert!(Counter::deserialize(&[255, 0, 0, 0]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deactivated_counter_v1_keeps_authority() -> wincode::Result<()> {
+        let v1 = CounterV1 {
+            discriminator: AccountDiscriminator::CounterV1Account,
+            owner: [6; 32],
+            bump: 251,
+            count: 3,
+        };
+        let mut v2 = CounterV2::from_v1(&v1, &test_clock(0, 0));
+        v2.authority = [7; 32];
+        v2.pending_authority = [8; 32];
+
+        let original = DeactivatedCounterV1::from_counter(&Counter::V2(v2));
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), DeactivatedCounterV1::size());
+
+        let deserialized = DeactivatedCounterV1::from_account_data(&serialized, &[0; 32], 0)?;
+        assert_eq!(
+            deserialized.discriminator,
+            AccountDiscriminator::DeactivatedAccount
+        );
+        assert_eq!(deserialized.owner, [6; 32]);
+        assert_eq!(deserialized.bump, 251);
+        assert_eq!(deserialized.authority, [7; 32]);
+        assert_eq!(deserialized.pending_authority(), Some(&[8; 32]));
+        assert!(!deserialized.is_creator_owned());
+
+        let creator_owned = DeactivatedCounterV1::from_counter(&Counter::V1(v1));
+        assert_eq!(creator_owned.authority, [6; 32]);
+        assert!(creator_owned.is_creator_owned());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deactivated_counter_v1_reads_discriminator_only_accounts() -> wincode::Result<()> {
+        let legacy = [u8::from(AccountDiscriminator::DeactivatedAccount)];
+
+        let deactivated = DeactivatedCounterV1::from_account_data(&legacy, &[9; 32], 250)?;
+        assert_eq!(deactivated.owner, [9; 32]);
+        assert_eq!(deactivated.bump, 250);
+        assert_eq!(deactivated.authority, [9; 32]);
+        assert!(deactivated.is_creator_owned());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_indexed_counter_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let original = IndexedCounterV1 {
+            discriminator: AccountDiscriminator::IndexedCounterV1Account,
+            owner: [10; 32],
+            index: 7,
+            bump: 250,
+            count: 12,
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), IndexedCounterV1::size());
+
+        let deserialized = IndexedCounterV1::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.owner, deserialized.owner);
+        assert_eq!(original.index, deserialized.index);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.count, deserialized.count);
+
+        // Indexed counters are not accepted by the non-indexed counter instructions.
+        assert!(Counter::deserialize(&serialized).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delegate_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let original = DelegateV1 {
+            discriminator: AccountDiscriminator::DelegateV1Account,
+            counter: [6; 32],
+            delegate: [7; 32],
+            authority: [8; 32],
+            bump: 251,
+            allowance: 10,
+            expiry_slot: 1_000,
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), DelegateV1::size());
+
+        let deserialized = DelegateV1::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.counter, deserialized.counter);
+        assert_eq!(original.delegate, deserialized.delegate);
+        assert_eq!(original.authority, deserialized.authority);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.allowance, deserialized.allowance);
+        assert_eq!(original.expiry_slot, deserialized.expiry_slot);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sharded_counter_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let original = ShardedCounterV1 {
+            discriminator: AccountDiscriminator::ShardedCounterV1Account,
+            owner: [11; 32],
+            bump: 249,
+            shard_count: 4,
+            count: 13,
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), ShardedCounterV1::size());
+
+        let deserialized = ShardedCounterV1::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.owner, deserialized.owner);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.shard_count, deserialized.shard_count);
+        assert_eq!(original.count, deserialized.count);
+
+        // Sharded counters are not accepted by the non-sharded counter instructions.
+        assert!(Counter::deserialize(&serialized).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_counter_shard_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let original = CounterShardV1 {
+            discriminator: AccountDiscriminator::CounterShardV1Account,
+            sharded_counter: [12; 32],
+            index: 3,
+            bump: 248,
+            count: 14,
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), CounterShardV1::size());
+
+        let deserialized = CounterShardV1::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.sharded_counter, deserialized.sharded_counter);
+        assert_eq!(original.index, deserialized.index);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.count, deserialized.count);
+
+        assert!(Counter::deserialize(&serialized).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let mut original = ConfigV1 {
+            discriminator: AccountDiscriminator::ConfigV1Account,
+            bump: 251,
+            admin: [15; 32],
+            paused: true,
+            pause_exemptions: [0; 32],
+        };
+        original.set_pause_exempt(9, true);
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), ConfigV1::size());
+        assert_eq!(serialized[0], 7);
+
+        let deserialized = ConfigV1::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.admin, deserialized.admin);
+        assert_eq!(original.paused, deserialized.paused);
+        assert_eq!(original.pause_exemptions, deserialized.pause_exemptions);
+
+        assert!(Counter::deserialize(&serialized).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_v1_pause_exemptions() {
+        let mut config = ConfigV1 {
+            discriminator: AccountDiscriminator::ConfigV1Account,
+            bump: 255,
+            admin: [1; 32],
+            paused: false,
+            pause_exemptions: [0; 32],
+        };
+
+        // Nothing is blocked while the program is running.
+        assert!(!config.blocks(0));
+        assert!(!config.blocks(255));
+
+        config.paused = true;
+        assert!(config.blocks(0));
+        assert!(config.blocks(255));
+
+        config.set_pause_exempt(0, true);
+        config.set_pause_exempt(255, true);
+        assert!(config.is_pause_exempt(0));
+        assert!(config.is_pause_exempt(255));
+        assert!(!config.is_pause_exempt(1));
+        assert!(!config.is_pause_exempt(254));
+        assert!(!config.blocks(0));
+        assert!(!config.blocks(255));
+        assert!(config.blocks(1));
+        assert_eq!(config.pause_exemptions[0], 0b0000_0001);
+        assert_eq!(config.pause_exemptions[31], 0b1000_0000);
+
+        config.set_pause_exempt(255, false);
+        assert!(!config.is_pause_exempt(255));
+        assert!(config.blocks(255));
+        // Clearing one exemption leaves the others in place.
+        assert!(config.is_pause_exempt(0));
+    }
+
+    #[test]
+    fn test_tombstone_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let original = TombstoneV1 {
+            discriminator: AccountDiscriminator::TombstoneV1Account,
+            bump: 250,
+            counter: [7; 32],
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), TombstoneV1::size());
+
+        let deserialized = TombstoneV1::deserialize(&serialized)?;
+        assert_eq!(original.discriminator, deserialized.discriminator);
+        assert_eq!(original.bump, deserialized.bump);
+        assert_eq!(original.counter, deserialized.counter);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multisig_v1_serialization_roundtrip() -> wincode::Result<()> {
+        let mut members = [[0; 32]; MAX_MULTISIG_MEMBERS as usize];
+        members[0] = [1; 32];
+        members[1] = [2; 32];
+        members[2] = [3; 32];
+        let original = MultisigV1 {
+            discriminator: AccountDiscriminator::MultisigV1Account,
+            counter: [9; 32],
+            bump: 254,
+            threshold: 2,
+            member_count: 3,
+            members,
+        };
+
+        let serialized = original.serialize()?;
+        assert_eq!(serialized.len(), MultisigV1::size());
+        assert_eq!(
+            MultisigV1::size(),
+            4 + 32 + 3 + 32 * MAX_MULTISIG_MEMBERS as usize
+        );
+
+        let deserialized = MultisigV1::deserialize(&serialized)?;
+        assert_eq!(
+            deserialized.discriminator,
+            AccountDiscriminator::MultisigV1Account
+        );
+        assert_eq!(deserialized.counter, [9; 32]);
+        assert_eq!(deserialized.bump, 254);
+        assert_eq!(deserialized.threshold, 2);
+        assert_eq!(deserialized.members(), &[[1; 32], [2; 32], [3; 32]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multisig_v1_membership_ignores_unused_entries() {
+        let mut members = [[0; 32]; MAX_MULTISIG_MEMBERS as usize];
+        members[0] = [1; 32];
+        let multisig = MultisigV1 {
+            discriminator: AccountDiscriminator::MultisigV1Account,
+            counter: [9; 32],
+            bump: 254,
+            threshold: 1,
+            member_count: 1,
+            members,
+        };
+
+        assert!(multisig.is_member(&[1; 32]));
+        assert!(!multisig.is_member(&[2; 32]));
+        // The zeroed tail of `members` is not a member.
+        assert!(!multisig.is_member(&[0; 32]));
+    }
 }
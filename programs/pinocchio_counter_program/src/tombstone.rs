@@ -0,0 +1,139 @@
+use {
+    crate::{
+        create_pda_account, try_find_tombstone_v1, AccountDiscriminator, AccountDiscriminatorError,
+        TombstoneV1, TOMBSTONE_V1_SEED,
+    },
+    pinocchio::{
+        account_info::AccountInfo, instruction::Signer, program_error::ProgramError,
+        pubkey::Pubkey, seeds,
+    },
+    wincode::{ReadError, WriteError},
+};
+
+/// Errors raised while reading or creating a counter's tombstone.
+///
+/// Each instruction that takes a tombstone maps these into its own error type so that every
+/// failure keeps a stable, instruction-specific error code.
+#[derive(Debug)]
+pub enum TombstoneError {
+    ProgramError(ProgramError),
+    TombstoneAddressMismatch { expected: Pubkey, observed: Pubkey },
+    AccountDiscriminatorError(AccountDiscriminatorError),
+    DeserializeError(ReadError),
+    TombstoneCounterMismatch { expected: Pubkey, observed: Pubkey },
+    SerializeError(WriteError),
+}
+
+/// Whether a counter has a tombstone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TombstoneLookup {
+    /// The counter has not been closed, and its tombstone can be created with `bump`.
+    Missing { bump: u8 },
+    /// The counter has been closed.
+    Found,
+}
+
+/// Looks up the tombstone of `counter` in `tombstone`.
+///
+/// Until the counter is closed, the tombstone address is not owned by the program, so the address
+/// is checked by deriving it. Once it exists, only the program can write to it and it only ever
+/// creates a tombstone at the address derived from the counter it stores, so the owner,
+/// discriminator and stored counter are enough to trust it without deriving the address again.
+///
+/// Validates:
+/// - A tombstone that does not exist yet lives at the address derived for `counter`
+/// - An existing tombstone has the `TombstoneV1Account` discriminator and size
+/// - An existing tombstone records `counter`
+///
+/// # Errors
+///
+/// Returns [`TombstoneError`] if any of the checks fail.
+pub(crate) fn find_tombstone(
+    program_id: &Pubkey,
+    tombstone: &AccountInfo,
+    counter: &Pubkey,
+) -> Result<TombstoneLookup, TombstoneError> {
+    if !tombstone.is_owned_by(program_id) {
+        let (expected_tombstone, bump) =
+            try_find_tombstone_v1(program_id, counter).ok_or(ProgramError::InvalidSeeds)?;
+        let observed_tombstone = tombstone.key();
+        if observed_tombstone != &expected_tombstone {
+            return Err(TombstoneError::TombstoneAddressMismatch {
+                expected: expected_tombstone,
+                observed: *observed_tombstone,
+            });
+        }
+
+        return Ok(TombstoneLookup::Missing { bump });
+    }
+
+    let tombstone_data = tombstone.try_borrow_data()?;
+    AccountDiscriminator::check(AccountDiscriminator::TombstoneV1Account, &tombstone_data)?;
+    let tombstone_state = TombstoneV1::deserialize(&tombstone_data)?;
+    if &tombstone_state.counter != counter {
+        return Err(TombstoneError::TombstoneCounterMismatch {
+            expected: *counter,
+            observed: tombstone_state.counter,
+        });
+    }
+
+    Ok(TombstoneLookup::Found)
+}
+
+/// Creates the tombstone of `counter`, unless [`find_tombstone`] found one already.
+///
+/// # Errors
+///
+/// Returns [`TombstoneError`] if creating or writing the tombstone fails.
+pub(crate) fn record_tombstone(
+    program_id: &Pubkey,
+    payer: &AccountInfo,
+    tombstone: &AccountInfo,
+    counter: &Pubkey,
+    lookup: TombstoneLookup,
+) -> Result<(), TombstoneError> {
+    let TombstoneLookup::Missing { bump } = lookup else {
+        return Ok(());
+    };
+
+    let counter_ref = counter.as_ref();
+    let bump_ref = &[bump];
+    let seeds = seeds!(TOMBSTONE_V1_SEED, counter_ref, bump_ref);
+    let signer = Signer::from(&seeds);
+
+    create_pda_account(payer, tombstone, TombstoneV1::size(), program_id, &[signer])?;
+
+    let tombstone_state = TombstoneV1 {
+        discriminator: AccountDiscriminator::TombstoneV1Account,
+        bump,
+        counter: *counter,
+    };
+
+    let mut tombstone_data = tombstone.try_borrow_mut_data()?;
+    tombstone_state.serialize_into(&mut tombstone_data)?;
+
+    Ok(())
+}
+impl From<AccountDiscriminatorError> for TombstoneError {
+    fn from(err: AccountDiscriminatorError) -> Self {
+        Self::AccountDiscriminatorError(err)
+    }
+}
+
+impl From<ProgramError> for TombstoneError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
+    }
+}
+
+impl From<ReadError> for TombstoneError {
+    fn from(err: ReadError) -> Self {
+        Self::DeserializeError(err)
+    }
+}
+
+impl From<WriteError> for TombstoneError {
+    fn from(err: WriteError) -> Self {
+        Self::SerializeError(err)
+    }
+}
@@ -1,6 +1,8 @@
 # Project paths
 export PROJECT_ROOT="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
 export CARGO_INSTALL_ROOT="$PROJECT_ROOT/.tools"
+# Separate target directory so the lazy entrypoint build does not overwrite the default one
+export LAZY_ENTRYPOINT_TARGET_DIR="$PROJECT_ROOT/target/lazy-entrypoint"
 
 # Tools versions for this project
 export EXPECTED_RUST="1.92.0" # specified in rust-toolchain.toml
@@ -26,6 +26,9 @@ pub fn demand_tx_failure(tx_result: &TransactionResult) {
 
 /// Asserts that transaction logs contain the expected string.
 ///
+/// Match on lines written by the runtime, such as `failed: custom program error: 0x305`, so
+/// the assertion holds whether or not the program was built with the `logging` feature.
+///
 /// # Panics
 ///
 /// Panics if the expected string is not found in the transaction logs, printing
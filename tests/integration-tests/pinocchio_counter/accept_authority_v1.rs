@@ -0,0 +1,583 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousAcceptAuthorityV1Ix, MaliciousAcceptAuthorityV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        instructions::{
+            ApproveDelegateV1Ix, DeactivateCounterV1Ix, IncrementCountV1Ix, SetCountV1Ix,
+        },
+        transactions::{
+            AcceptAuthorityV1SimpleTx, ApproveDelegateV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, ProposeAuthorityV1SimpleTx, ReactivateCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{CounterV2, DeactivatedCounterV1},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_pubkey::Pubkey,
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn propose_authority(
+    ctx: &mut TestContext,
+    creator: Pubkey,
+    authority_kp: &Keypair,
+    new_authority: Pubkey,
+) -> TestResult {
+    let propose_tx = ProposeAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        creator,
+        authority_kp.insecure_clone(),
+        new_authority,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(propose_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Initializes a counter and transfers its authority to `new_authority_kp`.
+fn setup_rotated_authority(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    new_authority_kp: &Keypair,
+) -> TestResult {
+    initialize_counter(ctx, owner_kp)?;
+    propose_authority(ctx, owner_kp.pubkey(), owner_kp, new_authority_kp.pubkey())?;
+
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        new_authority_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Sends `instruction` signed and paid for by `signer_kp`.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_counter(
+    ctx: &TestContext,
+    creator: &Pubkey,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), creator);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Accept Authority Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    setup_rotated_authority(&mut ctx, &owner_kp, &new_authority_kp)?;
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.authority, new_authority_kp.pubkey().to_bytes());
+    assert_eq!(counter.pending_authority(), None);
+
+    Ok(())
+}
+
+#[test]
+fn new_authority_can_update_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let new_authority_pk = new_authority_kp.pubkey();
+
+    setup_rotated_authority(&mut ctx, &owner_kp, &new_authority_kp)?;
+
+    let instruction = IncrementCountV1Ix::new(ctx.program_id(), owner_pk)
+        .with_authority(new_authority_pk)
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &new_authority_kp)?;
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let instruction = SetCountV1Ix::new(ctx.program_id(), owner_pk, 42)
+        .with_authority(new_authority_pk)
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &new_authority_kp)?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_pk)?.count, 42);
+
+    Ok(())
+}
+
+#[test]
+fn previous_authority_cannot_update_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    setup_rotated_authority(&mut ctx, &owner_kp, &new_authority_kp)?;
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x315", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn delegate_approved_by_previous_authority_is_rejected() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        delegate_kp.pubkey(),
+        10,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_kp.pubkey())?;
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    // The delegation still has allowance, but the authority that approved it is gone
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x314", &tx_result);
+    assert_eq!(read_counter(&ctx, &owner_pk)?.count, 0);
+
+    // Approving again under the new authority restores the delegation
+    let instruction = ApproveDelegateV1Ix::new(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.pubkey(),
+        10,
+        u64::MAX,
+    )
+    .with_authority(new_authority_kp.pubkey())
+    .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &new_authority_kp)?;
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(read_counter(&ctx, &owner_pk)?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn new_authority_can_rotate_again() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let third_authority_kp = ctx.create_funded_keypair();
+
+    setup_rotated_authority(&mut ctx, &owner_kp, &new_authority_kp)?;
+    propose_authority(
+        &mut ctx,
+        owner_pk,
+        &new_authority_kp,
+        third_authority_kp.pubkey(),
+    )?;
+
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        third_authority_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(counter.authority, third_authority_kp.pubkey().to_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn new_authority_receives_deactivation_refund() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let new_authority_pk = new_authority_kp.pubkey();
+
+    setup_rotated_authority(&mut ctx, &owner_kp, &new_authority_kp)?;
+
+    let authority_lamports_before = ctx
+        .get_account(new_authority_pk)
+        .ok_or("Authority should exist")?
+        .lamports;
+
+    let instruction = DeactivateCounterV1Ix::new(ctx.program_id(), owner_pk)
+        .with_authority(new_authority_pk)
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &new_authority_kp)?;
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), DeactivatedCounterV1::size());
+
+    let authority_lamports_after = ctx
+        .get_account(new_authority_pk)
+        .ok_or("Authority should exist")?
+        .lamports;
+    // The authority also paid the transaction fee
+    assert!(authority_lamports_after > authority_lamports_before);
+
+    Ok(())
+}
+
+#[test]
+fn creator_cannot_reactivate_rotated_counter_alone() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    setup_rotated_authority(&mut ctx, &owner_kp, &new_authority_kp)?;
+
+    let instruction = DeactivateCounterV1Ix::new(ctx.program_id(), owner_pk)
+        .with_authority(new_authority_kp.pubkey())
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &new_authority_kp)?;
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // The deactivated record still names the rotated authority
+    let reactivate_tx = ReactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_failure(&tx_result);
+    // ReactivateCounterV1Error::AuthorityMustBeSigner
+    demand_logs_contain("failed: custom program error: 0x60d", &tx_result);
+
+    let reactivate_tx = ReactivateCounterV1SimpleTx::try_new_with_authority(
+        ctx.program_id(),
+        owner_kp,
+        new_authority_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.authority, new_authority_kp.pubkey().to_bytes());
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let new_authority_pk = new_authority_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_pk)?;
+
+    let instruction =
+        MaliciousAcceptAuthorityV1Ix::from_valid(ctx.program_id(), owner_pk, new_authority_pk)
+            .build_with_accounts(vec![
+                AccountMeta {
+                    pubkey: new_authority_pk,
+                    is_signer: true,
+                    is_writable: false,
+                },
+                // Missing counter - only 1 account instead of 2
+            ]);
+
+    let malicious_tx = MaliciousAcceptAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_new_authority_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_kp.pubkey())?;
+
+    let malicious_tx = MaliciousAcceptAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousAcceptAuthorityV1Ix::with_new_authority_not_signer)
+    .with_different_signer(owner_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_kp.pubkey())?;
+
+    let malicious_tx = MaliciousAcceptAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousAcceptAuthorityV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd03", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_kp.pubkey())?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousAcceptAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousAcceptAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd08", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_no_authority_proposed() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd09", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_proposal_cancelled() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_kp.pubkey())?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, Pubkey::default())?;
+
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd09", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_pending_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let other_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    propose_authority(&mut ctx, owner_pk, &owner_kp, new_authority_kp.pubkey())?;
+
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        other_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xd0a", &tx_result);
+
+    Ok(())
+}
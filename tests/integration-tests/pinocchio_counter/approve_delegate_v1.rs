@@ -0,0 +1,675 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousApproveDelegateV1Ix, MaliciousApproveDelegateV1Tx,
+                MaliciousIncrementCountV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address, find_delegate_v1_address,
+        instructions::IncrementCountV1Ix,
+        transactions::{
+            ApproveDelegateV1SimpleTx, DeactivateCounterV1SimpleTx, DecrementByV1SimpleTx,
+            DecrementCountV1SimpleTx, IncrementByV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccountDiscriminator, CounterV1, DelegateV1},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+/// Initializes a counter and approves `delegate_pk` on it.
+fn setup_delegate(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    delegate_pk: Pubkey,
+    allowance: u64,
+    expiry_slot: u64,
+) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        delegate_pk,
+        allowance,
+        expiry_slot,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_delegate(
+    ctx: &TestContext,
+    owner_kp: &Keypair,
+) -> Result<DelegateV1, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let delegate_pk = find_delegate_v1_address(&ctx.program_id(), &counter_pk);
+    let delegate_account = ctx
+        .get_account(delegate_pk)
+        .ok_or("Delegate record should exist")?;
+    Ok(DelegateV1::deserialize(&delegate_account.data)?)
+}
+
+fn read_count(ctx: &TestContext, owner_kp: &Keypair) -> Result<u64, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV1::deserialize(&counter_account.data)?.count)
+}
+
+// ============================================================================
+// Approve Delegate Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let delegate_pk = Pubkey::new_unique();
+
+    setup_delegate(&mut ctx, &owner_kp, delegate_pk, 5, 1_000)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let delegate_record_pk = find_delegate_v1_address(&ctx.program_id(), &counter_pk);
+    let delegate_account = ctx
+        .get_account(delegate_record_pk)
+        .ok_or("Delegate record should exist")?;
+    assert_eq!(delegate_account.owner, ctx.program_id());
+    assert_eq!(delegate_account.data.len(), DelegateV1::size());
+
+    let delegate = DelegateV1::deserialize(&delegate_account.data)?;
+    assert_eq!(
+        delegate.discriminator,
+        AccountDiscriminator::DelegateV1Account
+    );
+    assert_eq!(delegate.counter, counter_pk.to_bytes());
+    assert_eq!(delegate.delegate, delegate_pk.to_bytes());
+    assert_eq!(delegate.authority, owner_kp.pubkey().to_bytes());
+    assert_eq!(delegate.allowance, 5);
+    assert_eq!(delegate.expiry_slot, 1_000);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_delegate_record_is_pre_funded() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let delegate_record_pk = find_delegate_v1_address(&ctx.program_id(), &counter_pk);
+    ctx.airdrop_lamports(delegate_record_pk, 1)
+        .map_err(|e| format!("Airdrop failed: {e:?}"))?;
+
+    let delegate_pk = Pubkey::new_unique();
+    setup_delegate(&mut ctx, &owner_kp, delegate_pk, 5, 1_000)?;
+
+    let delegate_account = ctx
+        .get_account(delegate_record_pk)
+        .ok_or("Delegate record should exist")?;
+    assert_eq!(delegate_account.owner, ctx.program_id());
+
+    let delegate = read_delegate(&ctx, &owner_kp)?;
+    assert_eq!(delegate.delegate, delegate_pk.to_bytes());
+    assert_eq!(delegate.allowance, 5);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_replacing_existing_delegate() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique(), 5, 1_000)?;
+
+    let new_delegate_pk = Pubkey::new_unique();
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        new_delegate_pk,
+        2,
+        50,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_success(&tx_result);
+
+    let delegate = read_delegate(&ctx, &owner_kp)?;
+    assert_eq!(delegate.delegate, new_delegate_pk.to_bytes());
+    assert_eq!(delegate.allowance, 2);
+    assert_eq!(delegate.expiry_slot, 50);
+
+    Ok(())
+}
+
+// ============================================================================
+// Delegated Operation Tests
+// ============================================================================
+
+#[test]
+fn delegate_operations_consume_allowance() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, delegate_kp.pubkey(), 4, u64::MAX)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(increment_tx));
+
+    let increment_by_tx = IncrementByV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        10,
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(increment_by_tx));
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(decrement_tx));
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 10);
+    assert_eq!(read_delegate(&ctx, &owner_kp)?.allowance, 1);
+
+    let decrement_by_tx = DecrementByV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        3,
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(decrement_by_tx));
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 7);
+    assert_eq!(read_delegate(&ctx, &owner_kp)?.allowance, 0);
+
+    Ok(())
+}
+
+#[test]
+fn delegate_fails_when_allowance_exhausted() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, delegate_kp.pubkey(), 1, u64::MAX)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(increment_tx));
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x313", &tx_result);
+
+    let increment_by_tx = IncrementByV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_by_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x710", &tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 1);
+
+    // The owner is not limited by the delegate's allowance
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(increment_tx));
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 2);
+
+    Ok(())
+}
+
+#[test]
+fn delegate_fails_when_expired() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    // setup_delegate leaves the clock at slot 2, the last slot the delegation is valid for
+    setup_delegate(&mut ctx, &owner_kp, delegate_kp.pubkey(), 10, 2)?;
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(decrement_tx));
+
+    ctx.advance_slot(1)?;
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x412", &tx_result);
+
+    let decrement_by_tx = DecrementByV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        1,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_by_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x80f", &tx_result);
+
+    assert_eq!(read_delegate(&ctx, &owner_kp)?.allowance, 9);
+
+    Ok(())
+}
+
+#[test]
+fn delegate_fails_when_signer_is_not_delegate() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let intruder_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique(), 10, u64::MAX)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        intruder_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x311", &tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn delegate_fails_when_delegate_record_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, delegate_kp.pubkey(), 10, u64::MAX)?;
+
+    let instruction =
+        IncrementCountV1Ix::new_delegated(ctx.program_id(), owner_pk, delegate_kp.pubkey())
+            .with_delegate(Some(AccountMeta {
+                pubkey: Pubkey::new_unique(),
+                is_signer: false,
+                is_writable: true,
+            }))
+            .to_instruction(false)?;
+
+    let malicious_tx =
+        MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .with_different_signer(delegate_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x30f", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn delegate_fails_when_delegate_record_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, delegate_kp.pubkey(), 10, u64::MAX)?;
+
+    let mut delegated_ix =
+        IncrementCountV1Ix::new_delegated(ctx.program_id(), owner_pk, delegate_kp.pubkey());
+    if let Some(delegate) = delegated_ix.delegate.as_mut() {
+        delegate.is_writable = false;
+    }
+    let instruction = delegated_ix.to_instruction(false)?;
+
+    let malicious_tx =
+        MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .with_different_signer(delegate_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x30e", &tx_result);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_pk = Pubkey::new_unique();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_ix =
+        MaliciousApproveDelegateV1Ix::from_valid(ctx.program_id(), owner_pk, delegate_pk);
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: counter_pk,
+            is_signer: false,
+            is_writable: false,
+        },
+        // Missing delegate record and system program - only 2 accounts instead of 4
+    ]);
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        delegate_pk,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousApproveDelegateV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_delegate_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousApproveDelegateV1Ix::with_delegate_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_delegate_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousApproveDelegateV1Ix::with_random_delegate_address)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousApproveDelegateV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa07", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousApproveDelegateV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousApproveDelegateV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa08", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        1,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa0b", &tx_result);
+
+    Ok(())
+}
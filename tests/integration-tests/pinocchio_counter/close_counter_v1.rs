@@ -0,0 +1,679 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousCloseCounterV1Ix, MaliciousCloseCounterV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_counter_v1_address, find_tombstone_v1_address,
+        transactions::{
+            CloseCounterV1SimpleTx, DeactivateCounterV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, InitializeSponsoredCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccountDiscriminator, EventDiscriminator, TombstoneV1},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+};
+
+/// Initializes the owner's counter and advances to the next slot.
+fn setup_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Closes the owner's counter, sending its lamports back to the owner.
+fn close_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let destination_pk = Pubkey::new_unique();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_lamports_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?
+        .lamports;
+
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        destination_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_success(&tx_result);
+
+    // Nothing is left at the counter address
+    let counter_account = ctx.get_account(counter_pk);
+    assert!(counter_account.is_none_or(|account| account.lamports == 0));
+
+    // The destination did not sign, so it receives every lamport the counter held
+    let destination_account = ctx
+        .get_account(destination_pk)
+        .ok_or("Destination account should exist")?;
+    assert_eq!(destination_account.lamports, counter_lamports_before);
+
+    // The counter now has a tombstone recording it
+    let tombstone_pk = find_tombstone_v1_address(&ctx.program_id(), &counter_pk);
+    let tombstone_account = ctx
+        .get_account(tombstone_pk)
+        .ok_or("Tombstone account should exist")?;
+    assert_eq!(tombstone_account.owner, ctx.program_id());
+    assert_eq!(tombstone_account.data.len(), TombstoneV1::size());
+    assert_eq!(
+        tombstone_account.lamports,
+        Rent::default().minimum_balance(TombstoneV1::size())
+    );
+
+    let tombstone = TombstoneV1::deserialize(&tombstone_account.data)?;
+    assert_eq!(
+        tombstone.discriminator,
+        AccountDiscriminator::TombstoneV1Account
+    );
+    assert_eq!(tombstone.counter, counter_pk.to_bytes());
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_with_separate_tombstone_per_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let first_owner_kp = ctx.create_funded_keypair();
+    let second_owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &first_owner_kp)?;
+    setup_counter(&mut ctx, &second_owner_kp)?;
+
+    close_counter(&mut ctx, &first_owner_kp)?;
+    close_counter(&mut ctx, &second_owner_kp)?;
+
+    // Every close creates a tombstone of its own, so no account grows with the number of closes
+    for owner_kp in [&first_owner_kp, &second_owner_kp] {
+        let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+        let tombstone_pk = find_tombstone_v1_address(&ctx.program_id(), &counter_pk);
+        let tombstone_account = ctx
+            .get_account(tombstone_pk)
+            .ok_or("Tombstone account should exist")?;
+        assert_eq!(tombstone_account.data.len(), TombstoneV1::size());
+
+        let tombstone = TombstoneV1::deserialize(&tombstone_account.data)?;
+        assert_eq!(tombstone.counter, counter_pk.to_bytes());
+    }
+
+    Ok(())
+}
+
+/// Deactivates the owner's counter and advances to the next slot.
+fn deactivate_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let destination_pk = Pubkey::new_unique();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+    deactivate_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_lamports_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Deactivated counter account should exist")?
+        .lamports;
+
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        destination_pk,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_success(&tx_result);
+
+    // The deactivated record is drained like an active counter
+    let counter_account = ctx.get_account(counter_pk);
+    assert!(counter_account.is_none_or(|account| account.lamports == 0));
+
+    let destination_account = ctx
+        .get_account(destination_pk)
+        .ok_or("Destination account should exist")?;
+    assert_eq!(destination_account.lamports, counter_lamports_before);
+
+    let tombstone_pk = find_tombstone_v1_address(&ctx.program_id(), &counter_pk);
+    let tombstone_account = ctx
+        .get_account(tombstone_pk)
+        .ok_or("Tombstone account should exist")?;
+    let tombstone = TombstoneV1::deserialize(&tombstone_account.data)?;
+    assert_eq!(tombstone.counter, counter_pk.to_bytes());
+
+    ctx.advance_slot(1)?;
+
+    // The tombstone stops the counter from being created again
+    let reinit_counter_tx =
+        InitializeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(reinit_counter_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x110", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority_of_deactivated_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+    deactivate_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let tombstone_pk = find_tombstone_v1_address(&ctx.program_id(), &counter_pk);
+    let malicious_tx = MaliciousCloseCounterV1Tx::from_valid(
+        ctx.program_id(),
+        other_owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        ix.with_counter_address(counter_pk)
+            .with_tombstone(tombstone_pk)
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    // CloseCounterV1Error::AuthorityMismatch
+    demand_logs_contain("failed: custom program error: 0x2208", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_refunding_rent_payer_of_sponsored_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_lamports_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?
+        .lamports;
+    let payer_lamports_before = ctx
+        .get_account(payer_pk)
+        .ok_or("Payer account should exist")?
+        .lamports;
+
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        payer_pk,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_success(&tx_result);
+
+    let payer_lamports_after = ctx
+        .get_account(payer_pk)
+        .ok_or("Payer account should exist")?
+        .lamports;
+    assert_eq!(
+        payer_lamports_after - payer_lamports_before,
+        counter_lamports_before
+    );
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_closed_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        owner_pk,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterClosedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 1);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn initialize_fails_after_close() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+    close_counter(&mut ctx, &owner_kp)?;
+
+    let reinit_counter_tx =
+        InitializeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(reinit_counter_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x110", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn initialize_sponsored_fails_after_close() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+    close_counter(&mut ctx, &owner_kp)?;
+
+    let reinit_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(reinit_counter_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1410", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn initialize_succeeds_for_other_counter_after_close() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let first_owner_kp = ctx.create_funded_keypair();
+    let second_owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &first_owner_kp)?;
+    close_counter(&mut ctx, &first_owner_kp)?;
+
+    // Only the closed counter has a tombstone
+    setup_counter(&mut ctx, &second_owner_kp)?;
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_rent_payer_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // The owner cannot redirect the sponsor's lamports to itself
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x220b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let other_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousCloseCounterV1Ix::with_owner_not_signer)
+            .with_different_signer(other_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2202", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousCloseCounterV1Ix::with_counter_not_writable)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2204", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let tombstone_pk = find_tombstone_v1_address(&ctx.program_id(), &counter_pk);
+    let malicious_tx = MaliciousCloseCounterV1Tx::from_valid(
+        ctx.program_id(),
+        other_owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        ix.with_counter_address(counter_pk)
+            .with_tombstone(tombstone_pk)
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2208", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_destination_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(|ix| {
+                ix.with_destination(Pubkey::new_unique())
+                    .with_destination_not_writable()
+            })
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2209", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_destination_is_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(|ix| ix.with_destination(counter_pk))
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x220a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_tombstone_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousCloseCounterV1Ix::with_tombstone_not_writable)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x220c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_tombstone_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousCloseCounterV1Ix::with_random_tombstone)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x220d", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_tombstone_is_other_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let first_owner_kp = ctx.create_funded_keypair();
+    let second_owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &first_owner_kp)?;
+    setup_counter(&mut ctx, &second_owner_kp)?;
+    close_counter(&mut ctx, &first_owner_kp)?;
+
+    // An existing tombstone is trusted by the counter it stores rather than its address
+    let first_counter_pk = find_counter_v1_address(&ctx.program_id(), &first_owner_kp.pubkey());
+    let first_tombstone_pk = find_tombstone_v1_address(&ctx.program_id(), &first_counter_pk);
+    let malicious_tx = MaliciousCloseCounterV1Tx::from_valid(
+        ctx.program_id(),
+        second_owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_tombstone(first_tombstone_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x220e", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousCloseCounterV1Ix::with_random_system_program)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x220f", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_already_closed() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+    close_counter(&mut ctx, &owner_kp)?;
+
+    let close_tx = CloseCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_failure(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = MaliciousCloseCounterV1Ix::from_valid(ctx.program_id(), owner_pk)
+        .build_with_accounts(vec![
+            AccountMeta {
+                pubkey: owner_pk,
+                is_signer: true,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: counter_pk,
+                is_signer: false,
+                is_writable: true,
+            },
+            // Missing destination, tombstone and system_program - only 2 accounts instead of 5
+        ]);
+
+    let malicious_tx =
+        MaliciousCloseCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2201", &tx_result);
+
+    Ok(())
+}
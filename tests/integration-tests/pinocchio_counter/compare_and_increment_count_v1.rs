@@ -0,0 +1,318 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousCompareAndIncrementCountV1Ix, MaliciousCompareAndIncrementCountV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        transactions::{
+            CompareAndIncrementCountV1SimpleTx, DeactivateCounterV1SimpleTx,
+            InitializeCounterV1SimpleTx, SetCountPolicyV1SimpleTx, SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{Counter, EventDiscriminator, OverflowPolicy},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn stored_count(ctx: &TestContext, owner_pk: &Pubkey) -> Result<u64, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    Ok(Counter::deserialize(&counter_account.data)?.count())
+}
+
+// ============================================================================
+// Compare And Increment Count Tests
+// ============================================================================
+
+#[test]
+fn succeeds_as_sequence() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    for expected_count in 0..3 {
+        let cai_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            expected_count,
+            ctx.latest_blockhash(),
+        )?;
+        let tx_result = ctx.send_transaction(cai_tx);
+        demand_tx_success(&tx_result);
+
+        assert_eq!(ctx.count_return_data(&tx_result)?.count, expected_count + 1);
+
+        let events = ctx.counter_events(&tx_result)?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].discriminator,
+            EventDiscriminator::CountIncrementedV1
+        );
+        assert_eq!(events[0].event.old_count, expected_count);
+        assert_eq!(events[0].event.new_count, expected_count + 1);
+
+        ctx.advance_slot(1)?;
+    }
+
+    assert_eq!(stored_count(&ctx, &owner_pk)?, 3);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_nonce_already_consumed() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // Two clients read the same nonce and both try to consume it.
+    let observed_count = stored_count(&ctx, &owner_pk)?;
+
+    let first_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        observed_count,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(first_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let second_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        observed_count,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(second_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x180c", &tx_result);
+
+    assert_eq!(stored_count(&ctx, &owner_pk)?, observed_count + 1);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_expected_count_is_ahead() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let cai_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        5,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cai_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x180c", &tx_result);
+
+    assert_eq!(stored_count(&ctx, &owner_pk)?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_at_maximum_with_error_policy() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let set_policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        OverflowPolicy::Error,
+        None,
+        Some(10),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_policy_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        10,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let cai_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cai_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1809", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousCompareAndIncrementCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousCompareAndIncrementCountV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1802", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousCompareAndIncrementCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousCompareAndIncrementCountV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1803", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_owner_kp = ctx.create_funded_keypair();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousCompareAndIncrementCountV1Tx::from_valid(
+        ctx.program_id(),
+        other_owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+        ix.with_counter_address(counter_pk)
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x180b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_ix =
+        MaliciousCompareAndIncrementCountV1Ix::from_valid(ctx.program_id(), owner_pk, 0);
+    let instruction = malicious_ix.build_with_accounts(vec![AccountMeta {
+        pubkey: owner_pk,
+        is_signer: true,
+        is_writable: true,
+    }]);
+
+    let malicious_tx = MaliciousCompareAndIncrementCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1801", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let cai_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cai_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1808", &tx_result);
+
+    Ok(())
+}
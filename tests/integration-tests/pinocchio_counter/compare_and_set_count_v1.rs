@@ -0,0 +1,341 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousCompareAndSetCountV1Ix, MaliciousCompareAndSetCountV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        transactions::{
+            CompareAndSetCountV1SimpleTx, DeactivateCounterV1SimpleTx, InitializeCounterV1SimpleTx,
+            SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{CounterV1, EventDiscriminator},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+/// Initializes the owner's counter and sets it to `count`.
+fn setup_counter(ctx: &mut TestContext, owner_kp: &Keypair, count: u64) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        count,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn stored_count(ctx: &TestContext, owner_pk: &Pubkey) -> Result<u64, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    Ok(CounterV1::deserialize(&counter_account.data)?.count)
+}
+
+// ============================================================================
+// Compare And Set Count Tests
+// ============================================================================
+
+#[test]
+fn succeeds_when_count_matches_expected() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    setup_counter(&mut ctx, &owner_kp, 10)?;
+
+    let cas_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        42,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cas_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(stored_count(&ctx, &owner_pk)?, 42);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 42);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].discriminator, EventDiscriminator::CountSetV1);
+    assert_eq!(events[0].event.old_count, 10);
+    assert_eq!(events[0].event.new_count, 42);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_count_was_changed_after_read() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    setup_counter(&mut ctx, &owner_kp, 10)?;
+
+    // Two clients read the same count and each try to write their own update.
+    let observed_count = stored_count(&ctx, &owner_pk)?;
+
+    let first_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        observed_count,
+        observed_count + 1,
+        ctx.latest_blockhash(),
+    )?;
+    let second_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        observed_count,
+        observed_count + 5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(first_tx);
+    demand_tx_success(&tx_result);
+
+    // The second write was based on a stale read and must not clobber the first.
+    let tx_result = ctx.send_transaction(second_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x170c", &tx_result);
+
+    assert_eq!(stored_count(&ctx, &owner_pk)?, 11);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_retried_with_refreshed_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    setup_counter(&mut ctx, &owner_kp, 10)?;
+
+    // Another writer changes the count after this client read 10.
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        20,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let stale_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        10,
+        11,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(stale_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x170c", &tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // Re-read and retry the update on top of the current value.
+    let observed_count = stored_count(&ctx, &owner_pk)?;
+    let retry_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        observed_count,
+        observed_count + 1,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(retry_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(stored_count(&ctx, &owner_pk)?, 21);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+    setup_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousCompareAndSetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousCompareAndSetCountV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1702", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    setup_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousCompareAndSetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousCompareAndSetCountV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1703", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    setup_counter(&mut ctx, &owner_kp, 0)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousCompareAndSetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1704", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_owner_kp = ctx.create_funded_keypair();
+    setup_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousCompareAndSetCountV1Tx::from_valid(
+        ctx.program_id(),
+        other_owner_kp,
+        0,
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+        ix.with_counter_address(counter_pk)
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x170b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    setup_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_ix =
+        MaliciousCompareAndSetCountV1Ix::from_valid(ctx.program_id(), owner_pk, 0, 1);
+    let instruction = malicious_ix.build_with_accounts(vec![AccountMeta {
+        pubkey: owner_pk,
+        is_signer: true,
+        is_writable: true,
+    }]);
+
+    let malicious_tx = MaliciousCompareAndSetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1701", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    setup_counter(&mut ctx, &owner_kp, 0)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let cas_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        1,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cas_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1708", &tx_result);
+
+    Ok(())
+}
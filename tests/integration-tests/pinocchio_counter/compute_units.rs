@@ -0,0 +1,819 @@
+use {
+    crate::{
+        litesvm_utils::demand_tx_success,
+        pinocchio_counter::{TestContext, TestResult},
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1, find_indexed_counter_v1,
+        transactions::{
+            AcceptAuthorityV1SimpleTx, ApproveDelegateV1SimpleTx,
+            CompareAndIncrementCountV1SimpleTx, CompareAndSetCountV1SimpleTx,
+            DeactivateCounterV1SimpleTx, DeactivateIndexedCounterV1SimpleTx, DecrementByV1SimpleTx,
+            DecrementCountV1SimpleTx, DecrementIndexedCounterV1SimpleTx, ExecuteBatchV1SimpleTx,
+            FreezeCounterV1SimpleTx, GetCountV1SimpleTx, IncrementByV1SimpleTx,
+            IncrementCountV1SimpleTx, IncrementCounterShardV1SimpleTx,
+            IncrementIndexedCounterV1SimpleTx, InitializeCounterV1SimpleTx,
+            InitializeIndexedCounterV1SimpleTx, InitializeMultisigV1SimpleTx,
+            InitializeShardedCounterV1SimpleTx, InitializeSponsoredCounterV1SimpleTx,
+            ProposeAuthorityV1SimpleTx, ReactivateCounterV1SimpleTx,
+            ReactivateIndexedCounterV1SimpleTx, ReactivateSponsoredCounterV1SimpleTx,
+            ReconcileShardsV1SimpleTx, RevokeDelegateV1SimpleTx, SetAccessModeV1SimpleTx,
+            SetCooldownV1SimpleTx, SetCountPolicyV1SimpleTx, SetCountV1SimpleTx,
+            SetIndexedCountV1SimpleTx, ThawCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{
+        AccessMode, BatchOperationV1, OverflowPolicy, MAX_BATCH_OPERATIONS,
+    },
+    solana_keypair::{Keypair, Signer},
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+/// Upper bound on the compute units of a single count update, including the counter address
+/// derivation, the event and the return data. Counts are updated in place, without deserializing
+/// the counter into an owned value and serializing it back into a new buffer.
+const COUNT_UPDATE_COMPUTE_UNIT_LIMIT: u64 = 10_000;
+
+/// Allowed difference in compute units between updating a `CounterV1` and a `CounterV2`. Both
+/// layouts are read through the same view, so the only extra work is reading the count policy and
+/// writing the modification time.
+const COUNTER_V2_COMPUTE_UNIT_MARGIN: u64 = 500;
+
+/// Allowed difference in compute units between validating counters with different bump seeds.
+/// Searching for the bump seed costs a full address derivation for every rejected bump, while
+/// re-creating the address from the stored bump costs the same for every counter.
+const BUMP_COMPUTE_UNIT_MARGIN: u64 = 100;
+
+/// Compute units the runtime charges for each address derivation, including every bump seed tried
+/// by `find_program_address`.
+const ADDRESS_DERIVATION_COMPUTE_UNITS: u64 = 1_500;
+
+/// Upper bound on the compute units of an `ExecuteBatchV1` instruction, as a fraction of the
+/// compute units of the same operations sent as separate instructions. The accounts are validated
+/// and the result is written once per batch, so each extra operation only costs its arithmetic.
+const BATCH_COMPUTE_UNIT_PERCENT: u64 = 50;
+
+/// Canonical bump seed of the counter addresses that need the longest bump search in
+/// `test_counter_validation_cost_does_not_depend_on_bump`.
+const LOW_BUMP: u8 = 252;
+
+/// Index of the indexed counters measured against the owner's counter.
+const INDEX: u32 = 0;
+
+/// Creates a funded owner whose counter address and indexed counter address at [`INDEX`] both have
+/// a canonical bump seed matching `predicate`.
+fn create_owner_with_bump(ctx: &mut TestContext, predicate: impl Fn(u8) -> bool) -> Keypair {
+    loop {
+        let owner_kp = ctx.create_funded_keypair();
+        let (_counter_pk, bump) = find_counter_v1(&ctx.program_id(), &owner_kp.pubkey());
+        let (_indexed_counter_pk, indexed_bump) =
+            find_indexed_counter_v1(&ctx.program_id(), &owner_kp.pubkey(), INDEX);
+        if predicate(bump) && predicate(indexed_bump) {
+            return owner_kp;
+        }
+    }
+}
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn initialize_indexed_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_indexed_counter_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        INDEX,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_indexed_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Upgrades the counter to `CounterV2` with the default count policy.
+fn upgrade_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        OverflowPolicy::Saturate,
+        None,
+        None,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Returns the compute units consumed by a successful transaction and advances the slot.
+fn measure(
+    ctx: &mut TestContext,
+    label: &str,
+    tx_result: &TransactionResult,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    demand_tx_success(tx_result);
+    let compute_units = ctx.compute_units_consumed(tx_result)?;
+    println!("{label}: {compute_units} CU");
+
+    ctx.advance_slot(1)?;
+
+    Ok(compute_units)
+}
+
+/// Measures the compute units of every count update against the owner's counter, in order.
+fn measure_count_updates(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    layout: &str,
+) -> Result<Vec<u64>, Box<dyn std::error::Error>> {
+    let mut measurements = Vec::new();
+
+    let tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(
+        ctx,
+        &format!("{layout} IncrementCountV1"),
+        &tx_result,
+    )?);
+
+    let tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(
+        ctx,
+        &format!("{layout} DecrementCountV1"),
+        &tx_result,
+    )?);
+
+    let tx = IncrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        10,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(
+        ctx,
+        &format!("{layout} IncrementByV1"),
+        &tx_result,
+    )?);
+
+    let tx = DecrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(
+        ctx,
+        &format!("{layout} DecrementByV1"),
+        &tx_result,
+    )?);
+
+    let tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        100,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(ctx, &format!("{layout} SetCountV1"), &tx_result)?);
+
+    let tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        100,
+        200,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(
+        ctx,
+        &format!("{layout} CompareAndSetCountV1"),
+        &tx_result,
+    )?);
+
+    let tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        200,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(
+        ctx,
+        &format!("{layout} CompareAndIncrementCountV1"),
+        &tx_result,
+    )?);
+
+    let tx = GetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(tx);
+    measurements.push(measure(ctx, &format!("{layout} GetCountV1"), &tx_result)?);
+
+    Ok(measurements)
+}
+
+#[test]
+fn test_counter_v1_updates_stay_within_compute_unit_limit() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    for compute_units in measure_count_updates(&mut ctx, &owner_kp, "CounterV1")? {
+        assert!(
+            compute_units <= COUNT_UPDATE_COMPUTE_UNIT_LIMIT,
+            "{compute_units} CU exceeds the limit of {COUNT_UPDATE_COMPUTE_UNIT_LIMIT} CU"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_counter_v2_updates_stay_within_compute_unit_limit() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    upgrade_counter(&mut ctx, &owner_kp)?;
+
+    for compute_units in measure_count_updates(&mut ctx, &owner_kp, "CounterV2")? {
+        assert!(
+            compute_units <= COUNT_UPDATE_COMPUTE_UNIT_LIMIT,
+            "{compute_units} CU exceeds the limit of {COUNT_UPDATE_COMPUTE_UNIT_LIMIT} CU"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_counter_v2_updates_cost_about_the_same_as_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    let v1_measurements = measure_count_updates(&mut ctx, &owner_kp, "CounterV1")?;
+
+    // The same counter keeps its address and bump, so only the layout differs
+    upgrade_counter(&mut ctx, &owner_kp)?;
+    let v2_measurements = measure_count_updates(&mut ctx, &owner_kp, "CounterV2")?;
+
+    for (v1_compute_units, v2_compute_units) in v1_measurements.into_iter().zip(v2_measurements) {
+        assert!(
+            v2_compute_units <= v1_compute_units + COUNTER_V2_COMPUTE_UNIT_MARGIN,
+            "CounterV2 update used {v2_compute_units} CU, CounterV1 update used \
+             {v1_compute_units} CU"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_count_updates_cost_less_than_full_deserialization() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    // With bump 255 both addresses take a single derivation, so only the update itself differs
+    let owner_kp = create_owner_with_bump(&mut ctx, |bump| bump == u8::MAX);
+    initialize_counter(&mut ctx, &owner_kp)?;
+    initialize_indexed_counter(&mut ctx, &owner_kp)?;
+
+    // Indexed counters are still deserialized into an owned value and serialized back
+    let tx = IncrementIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        INDEX,
+        ctx.latest_blockhash(),
+    )?;
+    let full_deserialization_compute_units = send_and_measure(
+        &mut ctx,
+        "Full deserialization IncrementIndexedCounterV1",
+        tx,
+    )?;
+
+    // The counter is updated in place, and also applies its count policy and records the time
+    let tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let zero_copy_compute_units = send_and_measure(&mut ctx, "Zero-copy IncrementCountV1", tx)?;
+
+    assert!(
+        zero_copy_compute_units < full_deserialization_compute_units,
+        "Zero-copy update used {zero_copy_compute_units} CU, full deserialization used \
+         {full_deserialization_compute_units} CU"
+    );
+    println!(
+        "Zero-copy savings: {} CU",
+        full_deserialization_compute_units - zero_copy_compute_units
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_counter_validation_cost_does_not_depend_on_bump() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    // Searching for the bump seed of the low bump counter would reject at least three bumps
+    let high_bump_owner_kp = create_owner_with_bump(&mut ctx, |bump| bump == u8::MAX);
+    let low_bump_owner_kp = create_owner_with_bump(&mut ctx, |bump| bump <= LOW_BUMP);
+
+    let mut measurements = Vec::new();
+    for (owner_kp, label) in [
+        (&high_bump_owner_kp, "Canonical bump 255"),
+        (&low_bump_owner_kp, "Canonical bump <= 252"),
+    ] {
+        initialize_counter(&mut ctx, owner_kp)?;
+        initialize_indexed_counter(&mut ctx, owner_kp)?;
+
+        // Indexed counters are validated by searching for the bump seed
+        let tx = IncrementIndexedCounterV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            INDEX,
+            ctx.latest_blockhash(),
+        )?;
+        let tx_result = ctx.send_transaction(tx);
+        let indexed_increment_compute_units = measure(
+            &mut ctx,
+            &format!("{label} IncrementIndexedCounterV1"),
+            &tx_result,
+        )?;
+
+        let tx = IncrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?;
+        let tx_result = ctx.send_transaction(tx);
+        let increment_compute_units =
+            measure(&mut ctx, &format!("{label} IncrementCountV1"), &tx_result)?;
+
+        let tx = DeactivateCounterV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?;
+        let tx_result = ctx.send_transaction(tx);
+        let deactivate_compute_units = measure(
+            &mut ctx,
+            &format!("{label} DeactivateCounterV1"),
+            &tx_result,
+        )?;
+
+        measurements.push((
+            indexed_increment_compute_units,
+            increment_compute_units,
+            deactivate_compute_units,
+        ));
+    }
+
+    let (high_bump_indexed_increment, high_bump_increment, high_bump_deactivate) = measurements[0];
+    let (low_bump_indexed_increment, low_bump_increment, low_bump_deactivate) = measurements[1];
+
+    // The bump search is what the stored bump saves, so it must show up in the indexed counters
+    let bump_search_compute_units =
+        u64::from(u8::MAX - LOW_BUMP) * ADDRESS_DERIVATION_COMPUTE_UNITS;
+    assert!(
+        low_bump_indexed_increment >= high_bump_indexed_increment + bump_search_compute_units,
+        "IncrementIndexedCounterV1 used {high_bump_indexed_increment} CU for bump 255 and \
+         {low_bump_indexed_increment} CU for a low bump, expected a bump search of at least \
+         {bump_search_compute_units} CU"
+    );
+    assert!(
+        high_bump_increment.abs_diff(low_bump_increment) <= BUMP_COMPUTE_UNIT_MARGIN,
+        "IncrementCountV1 used {high_bump_increment} CU for bump 255 and {low_bump_increment} CU \
+         for a low bump"
+    );
+    assert!(
+        high_bump_deactivate.abs_diff(low_bump_deactivate) <= BUMP_COMPUTE_UNIT_MARGIN,
+        "DeactivateCounterV1 used {high_bump_deactivate} CU for bump 255 and \
+         {low_bump_deactivate} CU for a low bump"
+    );
+
+    Ok(())
+}
+
+/// Operations of the batch compared against the same operations sent as separate instructions.
+fn batch_operations() -> Vec<BatchOperationV1> {
+    vec![
+        BatchOperationV1::set(10),
+        BatchOperationV1::increment(),
+        BatchOperationV1::increment(),
+        BatchOperationV1::increment(),
+        BatchOperationV1::decrement(),
+    ]
+}
+
+/// Measures the compute units of `batch_operations` sent as one instruction each.
+fn measure_separate_instructions(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    layout: &str,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        10,
+        ctx.latest_blockhash(),
+    )?;
+    let mut compute_units = send_and_measure(ctx, &format!("{layout} SetCountV1"), tx)?;
+
+    for _ in 0..3 {
+        let tx = IncrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?;
+        compute_units += send_and_measure(ctx, &format!("{layout} IncrementCountV1"), tx)?;
+    }
+
+    let tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    compute_units += send_and_measure(ctx, &format!("{layout} DecrementCountV1"), tx)?;
+
+    Ok(compute_units)
+}
+
+/// Measures the compute units of `batch_operations` sent as a single `ExecuteBatchV1`.
+fn measure_batch(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    layout: &str,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let tx = ExecuteBatchV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        batch_operations(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(ctx, &format!("{layout} ExecuteBatchV1"), tx)
+}
+
+#[test]
+fn test_batch_costs_less_than_separate_instructions() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    let v1_measurements = (
+        measure_separate_instructions(&mut ctx, &owner_kp, "CounterV1")?,
+        measure_batch(&mut ctx, &owner_kp, "CounterV1")?,
+    );
+
+    upgrade_counter(&mut ctx, &owner_kp)?;
+    let v2_measurements = (
+        measure_separate_instructions(&mut ctx, &owner_kp, "CounterV2")?,
+        measure_batch(&mut ctx, &owner_kp, "CounterV2")?,
+    );
+
+    for (layout, (separate_compute_units, batch_compute_units)) in [
+        ("CounterV1", v1_measurements),
+        ("CounterV2", v2_measurements),
+    ] {
+        assert!(
+            batch_compute_units * 100 <= separate_compute_units * BATCH_COMPUTE_UNIT_PERCENT,
+            "{layout} ExecuteBatchV1 used {batch_compute_units} CU, the separate instructions \
+             used {separate_compute_units} CU"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_batch_stays_within_compute_unit_limit() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    upgrade_counter(&mut ctx, &owner_kp)?;
+
+    let tx = ExecuteBatchV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        vec![BatchOperationV1::increment(); usize::from(MAX_BATCH_OPERATIONS)],
+        ctx.latest_blockhash(),
+    )?;
+    let compute_units = send_and_measure(&mut ctx, "CounterV2 ExecuteBatchV1 (maximum)", tx)?;
+    assert!(
+        compute_units <= COUNT_UPDATE_COMPUTE_UNIT_LIMIT,
+        "{compute_units} CU exceeds the limit of {COUNT_UPDATE_COMPUTE_UNIT_LIMIT} CU"
+    );
+
+    Ok(())
+}
+
+/// Sends a transaction expected to succeed and returns the compute units it consumed.
+fn send_and_measure(
+    ctx: &mut TestContext,
+    label: &str,
+    tx: impl Into<VersionedTransaction>,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let tx_result = ctx.send_transaction(tx);
+    measure(ctx, label, &tx_result)
+}
+
+/// Runs every instruction once and prints the compute units it consumed, one `<instruction>: <n>
+/// CU` line each. `scripts/test-pinocchio-counter` records these lines for the default and the
+/// `lazy-entrypoint` builds.
+#[test]
+fn test_record_compute_units_per_instruction() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let sponsor_kp = ctx.create_funded_keypair();
+    let sponsored_owner_kp = ctx.create_funded_keypair();
+    let program_id = ctx.program_id();
+
+    // Counter lifecycle and count updates
+    let tx = InitializeCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "InitializeCounterV1", tx)?;
+
+    measure_count_updates(&mut ctx, &owner_kp, "CounterV1")?;
+
+    // Delegation
+    let tx = ApproveDelegateV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        delegate_kp.pubkey(),
+        10,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ApproveDelegateV1", tx)?;
+
+    let tx = IncrementCountV1SimpleTx::try_new_delegated(
+        program_id,
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "IncrementCountV1 (delegated)", tx)?;
+
+    let tx = RevokeDelegateV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "RevokeDelegateV1", tx)?;
+
+    let tx = DeactivateCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "DeactivateCounterV1", tx)?;
+
+    let tx = ReactivateCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ReactivateCounterV1", tx)?;
+
+    // Count policy and authority transfer, which upgrade the counter to `CounterV2`
+    let tx = SetCountPolicyV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        OverflowPolicy::Saturate,
+        None,
+        None,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "SetCountPolicyV1", tx)?;
+
+    let tx = SetAccessModeV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "SetAccessModeV1", tx)?;
+
+    let tx = SetCooldownV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "SetCooldownV1", tx)?;
+
+    let tx = FreezeCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "FreezeCounterV1", tx)?;
+
+    let tx = ThawCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ThawCounterV1", tx)?;
+
+    let tx = ExecuteBatchV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        batch_operations(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ExecuteBatchV1", tx)?;
+
+    let tx = ProposeAuthorityV1SimpleTx::try_new(
+        program_id,
+        owner_pk,
+        owner_kp.insecure_clone(),
+        new_authority_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ProposeAuthorityV1", tx)?;
+
+    let tx = AcceptAuthorityV1SimpleTx::try_new(
+        program_id,
+        owner_pk,
+        new_authority_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "AcceptAuthorityV1", tx)?;
+
+    // Indexed counters
+    let tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "InitializeIndexedCounterV1", tx)?;
+
+    let tx = IncrementIndexedCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "IncrementIndexedCounterV1", tx)?;
+
+    let tx = DecrementIndexedCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "DecrementIndexedCounterV1", tx)?;
+
+    let tx = SetIndexedCountV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        10,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "SetIndexedCountV1", tx)?;
+
+    let tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "DeactivateIndexedCounterV1", tx)?;
+
+    let tx = ReactivateIndexedCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ReactivateIndexedCounterV1", tx)?;
+
+    // Sponsored counters
+    let tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        program_id,
+        sponsor_kp.insecure_clone(),
+        sponsored_owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "InitializeSponsoredCounterV1", tx)?;
+
+    let tx = DeactivateCounterV1SimpleTx::try_new_with_rent_payer(
+        program_id,
+        sponsored_owner_kp.insecure_clone(),
+        sponsor_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "DeactivateCounterV1 (sponsored)", tx)?;
+
+    let tx = ReactivateSponsoredCounterV1SimpleTx::try_new(
+        program_id,
+        sponsor_kp.insecure_clone(),
+        sponsored_owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ReactivateSponsoredCounterV1", tx)?;
+
+    // Sharded counters
+    let tx = InitializeShardedCounterV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        4,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "InitializeShardedCounterV1", tx)?;
+
+    let tx = IncrementCounterShardV1SimpleTx::try_new(
+        program_id,
+        owner_pk,
+        delegate_kp.insecure_clone(),
+        4,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "IncrementCounterShardV1", tx)?;
+
+    let tx = ReconcileShardsV1SimpleTx::try_new(
+        program_id,
+        owner_kp.insecure_clone(),
+        4,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "ReconcileShardsV1", tx)?;
+
+    // Multisig counters
+    let member_kps = [
+        ctx.create_funded_keypair(),
+        ctx.create_funded_keypair(),
+        ctx.create_funded_keypair(),
+    ];
+    let member_pks = member_kps.each_ref().map(Keypair::pubkey);
+
+    let tx = InitializeMultisigV1SimpleTx::try_new(
+        program_id,
+        sponsored_owner_kp.pubkey(),
+        sponsored_owner_kp.insecure_clone(),
+        &member_pks,
+        2,
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "InitializeMultisigV1", tx)?;
+
+    let tx = IncrementCountV1SimpleTx::try_new_multisig(
+        program_id,
+        sponsored_owner_kp.pubkey(),
+        &[
+            member_kps[0].insecure_clone(),
+            member_kps[1].insecure_clone(),
+        ],
+        ctx.latest_blockhash(),
+    )?;
+    send_and_measure(&mut ctx, "IncrementCountV1 (multisig)", tx)?;
+
+    Ok(())
+}
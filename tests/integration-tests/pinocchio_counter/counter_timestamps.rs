@@ -0,0 +1,302 @@
+//! Checks that `CounterV2` records when the counter was created and last modified.
+//!
+//! Time is driven with `TestContext::advance_slot`, and the expected clock is computed with
+//! `clock_utils::advance_clock`, so the recorded Unix timestamps are checked as well as the slots.
+
+use {
+    crate::{
+        clock_utils::advance_clock,
+        litesvm_utils::demand_tx_success,
+        pinocchio_counter::{TestContext, TestResult},
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        transactions::{
+            AcceptAuthorityV1SimpleTx, CompareAndSetCountV1SimpleTx, DecrementCountV1SimpleTx,
+            FreezeCounterV1SimpleTx, IncrementByV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, InitializeSponsoredCounterV1SimpleTx,
+            MigrateCounterV1ToV2SimpleTx, ProposeAuthorityV1SimpleTx, SetAccessModeV1SimpleTx,
+            SetCountV1SimpleTx, ThawCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccessMode, CounterV1, CounterV2},
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+/// Number of slots to advance between mutations, long enough for the Unix timestamp to move.
+const SLOTS_BETWEEN_MUTATIONS: u64 = 25;
+
+fn read_counter_v2(
+    ctx: &TestContext,
+    owner: &Pubkey,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), owner);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+fn initialize_sponsored_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let payer_kp = ctx.create_funded_keypair();
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+/// Advances the clock, sends the transaction built by `build_tx` and checks that the counter
+/// recorded the new clock as its last modification, while keeping its creation slot.
+fn demand_mutation_recorded<T>(
+    ctx: &mut TestContext,
+    owner: &Pubkey,
+    build_tx: impl FnOnce(&TestContext) -> Result<T, Box<dyn std::error::Error>>,
+) -> TestResult
+where
+    T: Into<VersionedTransaction>,
+{
+    let before = read_counter_v2(ctx, owner)?;
+
+    let expected_clock = advance_clock(&ctx.current_clock(), SLOTS_BETWEEN_MUTATIONS)?;
+    ctx.advance_slot(SLOTS_BETWEEN_MUTATIONS)?;
+    assert_eq!(ctx.current_clock().slot, expected_clock.slot);
+    assert!(expected_clock.unix_timestamp > before.last_modified_unix_timestamp);
+
+    let tx = build_tx(ctx)?;
+    let tx_result = ctx.send_transaction(tx);
+    demand_tx_success(&tx_result);
+
+    let after = read_counter_v2(ctx, owner)?;
+    assert_eq!(after.created_slot, before.created_slot);
+    assert_eq!(after.last_modified_slot, expected_clock.slot);
+    assert_eq!(
+        after.last_modified_unix_timestamp,
+        expected_clock.unix_timestamp
+    );
+
+    Ok(())
+}
+
+#[test]
+fn sponsored_counter_records_creation_time() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    ctx.advance_slot(SLOTS_BETWEEN_MUTATIONS)?;
+    let clock = ctx.current_clock();
+
+    initialize_sponsored_counter(&mut ctx, &owner_kp)?;
+
+    let counter = read_counter_v2(&ctx, &owner_kp.pubkey())?;
+    assert_eq!(counter.created_slot, clock.slot);
+    assert_eq!(counter.last_modified_slot, clock.slot);
+    assert_eq!(counter.last_modified_unix_timestamp, clock.unix_timestamp);
+
+    Ok(())
+}
+
+#[test]
+fn count_updates_record_modification_time() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_sponsored_counter(&mut ctx, &owner_kp)?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(IncrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(IncrementByV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            5,
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(DecrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(SetCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            10,
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(CompareAndSetCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            10,
+            20,
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    assert_eq!(read_counter_v2(&ctx, &owner_pk)?.count, 20);
+
+    Ok(())
+}
+
+#[test]
+fn configuration_updates_record_modification_time() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+    let new_authority_pk = new_authority_kp.pubkey();
+
+    initialize_sponsored_counter(&mut ctx, &owner_kp)?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(SetAccessModeV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            AccessMode::Public,
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(FreezeCounterV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(ThawCounterV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(ProposeAuthorityV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_pk,
+            owner_kp.insecure_clone(),
+            new_authority_pk,
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(AcceptAuthorityV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_pk,
+            new_authority_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    assert_eq!(
+        read_counter_v2(&ctx, &owner_pk)?.authority,
+        new_authority_pk.to_bytes()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn migration_records_upgrade_as_creation_time() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let expected_clock = advance_clock(&ctx.current_clock(), SLOTS_BETWEEN_MUTATIONS)?;
+    ctx.advance_slot(SLOTS_BETWEEN_MUTATIONS)?;
+
+    let migrate_tx = MigrateCounterV1ToV2SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(migrate_tx);
+    demand_tx_success(&tx_result);
+
+    // CounterV1 does not record its creation, so the migration slot takes its place
+    let counter = read_counter_v2(&ctx, &owner_pk)?;
+    assert_eq!(counter.created_slot, expected_clock.slot);
+    assert_eq!(counter.last_modified_slot, expected_clock.slot);
+    assert_eq!(
+        counter.last_modified_unix_timestamp,
+        expected_clock.unix_timestamp
+    );
+
+    demand_mutation_recorded(&mut ctx, &owner_pk, |ctx| {
+        Ok(IncrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?)
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn counter_v1_updates_keep_counter_v1_layout() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(SLOTS_BETWEEN_MUTATIONS)?;
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    // Recording modifications needs the CounterV2 layout, which a count update never allocates
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+    assert_eq!(counter_account.data.len(), CounterV1::size());
+    assert_eq!(CounterV1::deserialize(&counter_account.data)?.count, 1);
+
+    Ok(())
+}
@@ -7,12 +7,19 @@ use {
         },
     },
     pinocchio_counter_client::{
+        events::CounterEvent,
         find_counter_v1_address,
-        transactions::{DeactivateCounterV1SimpleTx, InitializeCounterV1SimpleTx},
+        instructions::DeactivateCounterV1Ix,
+        transactions::{
+            DeactivateCounterV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            InitializeSponsoredCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterV1, DeactivatedCounterV1, EventDiscriminator,
     },
-    pinocchio_counter_program::{AccountDiscriminator, CounterV1, DEACTIVATED_ACCOUNT_SIZE},
     solana_instruction::AccountMeta,
-    solana_keypair::Signer,
+    solana_keypair::{Keypair, Signer},
     solana_rent::Rent,
 };
 
@@ -53,16 +60,16 @@ fn succeeds() -> TestResult {
     let tx_result = ctx.send_transaction(deactivate_tx);
     demand_tx_success(&tx_result);
 
-    // Verify counter account is marked as deactivated (1 byte with DeactivatedAccount discriminator)
+    // Verify counter account is marked as deactivated (deactivated record keeping the authority)
     let counter_account_after = ctx
         .get_account(counter_pk)
         .ok_or("Counter account should still exist with DeactivatedAccount discriminator")?;
 
     assert_eq!(
         counter_account_after.data.len(),
-        DEACTIVATED_ACCOUNT_SIZE,
+        DeactivatedCounterV1::size(),
         "Counter account should be resized to {expected:?} bytes, observed {observed:?}",
-        expected = DEACTIVATED_ACCOUNT_SIZE,
+        expected = DeactivatedCounterV1::size(),
         observed = counter_account_after.data.len()
     );
 
@@ -74,9 +81,9 @@ fn succeeds() -> TestResult {
         observed = counter_account_after.data[0]
     );
 
-    // Calculate rent-exempt minimum for 1-byte account
+    // Calculate rent-exempt minimum for the deactivated record
     let rent = Rent::default();
-    let rent_exempt_deactivated_account = rent.minimum_balance(DEACTIVATED_ACCOUNT_SIZE);
+    let rent_exempt_deactivated_account = rent.minimum_balance(DeactivatedCounterV1::size());
 
     // Verify lamports were transferred to owner (all except rent-exempt minimum for deactivated account)
     // Note: Owner also pays transaction fees, so we check that they received at least the expected amount
@@ -93,7 +100,7 @@ fn succeeds() -> TestResult {
         "Owner should have received counter's lamports minus rent-exempt minimum. Received: {lamports_received}, Expected: ~{expected_lamports_received}"
     );
 
-    // Verify counter account has rent-exempt minimum for 1-byte account
+    // Verify counter account has rent-exempt minimum for the deactivated record
     assert_eq!(
         counter_account_after.lamports,
         rent_exempt_deactivated_account,
@@ -105,6 +112,232 @@ fn succeeds() -> TestResult {
     Ok(())
 }
 
+/// Initializes the owner's counter with its rent paid by `payer_kp`.
+fn setup_sponsored_counter(
+    ctx: &mut TestContext,
+    payer_kp: &Keypair,
+    owner_kp: &Keypair,
+) -> TestResult {
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp.insecure_clone(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_refunding_rent_payer_of_sponsored_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_sponsored_counter(&mut ctx, &payer_kp, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_lamports_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?
+        .lamports;
+    let payer_lamports_before = ctx
+        .get_account(payer_pk)
+        .ok_or("Payer account should exist")?
+        .lamports;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new_with_rent_payer(
+        ctx.program_id(),
+        owner_kp,
+        payer_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+    assert_eq!(
+        counter_account_after.data[0],
+        u8::from(AccountDiscriminator::DeactivatedAccount)
+    );
+
+    // The payer did not sign, so it pays no fee and receives the exact refund
+    let rent_exempt_deactivated_account =
+        Rent::default().minimum_balance(DeactivatedCounterV1::size());
+    let payer_lamports_after = ctx
+        .get_account(payer_pk)
+        .ok_or("Payer account should exist")?
+        .lamports;
+    assert_eq!(
+        payer_lamports_after - payer_lamports_before,
+        counter_lamports_before - rent_exempt_deactivated_account
+    );
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_owner_passed_as_rent_payer_of_unsponsored_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new_with_rent_payer(
+        ctx.program_id(),
+        owner_kp,
+        owner_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_deactivated_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx =
+        DeactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterDeactivatedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 1);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_rent_payer_of_sponsored_counter_missing() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_sponsored_counter(&mut ctx, &payer_kp, &owner_kp)?;
+
+    let deactivate_tx =
+        DeactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x201", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_rent_payer_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_sponsored_counter(&mut ctx, &payer_kp, &owner_kp)?;
+
+    // The owner cannot redirect the sponsor's refund to itself or anyone else
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new_with_rent_payer(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x20e", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_rent_payer_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_sponsored_counter(&mut ctx, &payer_kp, &owner_kp)?;
+
+    let mut instruction = DeactivateCounterV1Ix::new(ctx.program_id(), owner_pk)
+        .with_rent_payer(payer_pk)
+        .to_instruction(false)?;
+    instruction.accounts[2].is_writable = false;
+
+    let malicious_tx = MaliciousDeactivateCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x20d", &tx_result);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_owner_not_signer() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -185,12 +418,16 @@ fn fails_when_counter_address_mismatch() -> TestResult {
 
     ctx.advance_slot(1)?;
 
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
     let malicious_tx = MaliciousDeactivateCounterV1Tx::from_valid(
         ctx.program_id(),
         owner_kp,
         ctx.latest_blockhash(),
     )
-    .with_malicious_instruction(MaliciousDeactivateCounterV1Ix::with_random_counter_address)
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
     .build();
 
     let tx_result = ctx.send_transaction(malicious_tx);
@@ -201,7 +438,7 @@ fn fails_when_counter_address_mismatch() -> TestResult {
 }
 
 #[test]
-fn fails_when_owner_mismatch_address_validation() -> TestResult {
+fn fails_when_signer_is_not_authority() -> TestResult {
     let mut ctx = TestContext::try_new()?;
     let owner_kp = ctx.create_funded_keypair();
     let owner_pk = owner_kp.pubkey();
@@ -218,19 +455,14 @@ fn fails_when_owner_mismatch_address_validation() -> TestResult {
 
     ctx.advance_slot(1)?;
 
-    // Try to deactivate with different owner
-    // This will fail at address validation (0x205 = CounterAddressMismatch) because the counter address
-    // is derived from the owner, so using a different owner means the address won't match.
-    // This is actually a good security property - address validation prevents
-    // owner mismatch attacks. The stored owner check would only be reached
-    // if address validation passed, which requires using the correct owner.
+    // The counter address is derived from the stored creator, so a different signer
+    // passes address validation and is rejected by the authority check (0x20c).
     let malicious_tx = MaliciousDeactivateCounterV1Tx::from_valid(
         ctx.program_id(),
         other_owner_kp,
         ctx.latest_blockhash(),
     )
     .with_malicious_instruction(|ix| {
-        // Use correct counter address but wrong owner signer
         let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
         ix.with_counter_address(counter_pk)
     })
@@ -238,8 +470,7 @@ fn fails_when_owner_mismatch_address_validation() -> TestResult {
 
     let tx_result = ctx.send_transaction(malicious_tx);
     demand_tx_failure(&tx_result);
-    // Address validation fails first, which is the expected security behavior
-    demand_logs_contain("failed: custom program error: 0x205", &tx_result);
+    demand_logs_contain("failed: custom program error: 0x20c", &tx_result);
 
     Ok(())
 }
@@ -0,0 +1,315 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousDeactivateIndexedCounterV1Ix, MaliciousDeactivateIndexedCounterV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_indexed_counter_v1_address,
+        transactions::{
+            DeactivateIndexedCounterV1SimpleTx, IncrementIndexedCounterV1SimpleTx,
+            InitializeIndexedCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{
+        AccountDiscriminator, EventDiscriminator, DEACTIVATED_ACCOUNT_SIZE,
+    },
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+};
+
+fn initialize_indexed_counter(ctx: &mut TestContext, owner_kp: &Keypair, index: u32) -> TestResult {
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        index,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Deactivate Indexed Counter Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 4)?;
+
+    let owner_lamports_before = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner should exist")?
+        .lamports;
+
+    let deactivate_tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        4,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 4);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), DEACTIVATED_ACCOUNT_SIZE);
+    assert_eq!(
+        counter_account.data[0],
+        u8::from(AccountDiscriminator::DeactivatedAccount)
+    );
+
+    let owner_lamports_after = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner should exist")?
+        .lamports;
+    // The owner also paid the transaction fee
+    assert!(owner_lamports_after > owner_lamports_before);
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_deactivated_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 4)?;
+
+    let deactivate_tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        4,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 4);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterDeactivatedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn other_indices_remain_active() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+    initialize_indexed_counter(&mut ctx, &owner_kp, 1)?;
+
+    let deactivate_tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        1,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    let increment_tx = IncrementIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    // AccountDiscriminatorError: the counter at index 0 is deactivated
+    demand_logs_contain("failed: custom program error: 0xf08", &tx_result);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let instruction =
+        MaliciousDeactivateIndexedCounterV1Ix::from_valid(ctx.program_id(), owner_pk, 0)
+            .build_with_accounts(vec![
+                AccountMeta {
+                    pubkey: owner_pk,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                // Missing counter - only 1 account instead of 2
+            ]);
+
+    let malicious_tx = MaliciousDeactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1201", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousDeactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousDeactivateIndexedCounterV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1202", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousDeactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousDeactivateIndexedCounterV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1204", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_is_for_another_index() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+    initialize_indexed_counter(&mut ctx, &owner_kp, 1)?;
+
+    let other_index_counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 1);
+    let malicious_tx = MaliciousDeactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(other_index_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1205", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_already_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let deactivate_tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1207", &tx_result);
+
+    Ok(())
+}
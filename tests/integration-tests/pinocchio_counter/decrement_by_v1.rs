@@ -0,0 +1,466 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousDecrementByV1Ix, MaliciousDecrementByV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_counter_v1_address,
+        transactions::{
+            DeactivateCounterV1SimpleTx, DecrementByV1SimpleTx, InitializeCounterV1SimpleTx,
+            SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{CounterV1, EventDiscriminator},
+    solana_instruction::AccountMeta,
+    solana_keypair::Signer,
+};
+
+// ============================================================================
+// Decrement By Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+
+    let counter_before = CounterV1::deserialize(&counter_account_before.data)?;
+    assert_eq!(counter_before.count, 0);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        1_000,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // A single instruction moves the count by the full amount
+    let decrement_tx =
+        DecrementByV1SimpleTx::try_new(ctx.program_id(), owner_kp, 400, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+
+    let counter_after = CounterV1::deserialize(&counter_account_after.data)?;
+    assert_eq!(counter_after.count, 600);
+    assert_eq!(counter_after.owner, counter_before.owner);
+    assert_eq!(counter_after.bump, counter_before.bump);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_multiple_times() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        2_000,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let mut expected_count = 2_000u64;
+    for amount in [1, 10, 0, 1_000, 42] {
+        let decrement_tx = DecrementByV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            amount,
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(decrement_tx);
+        demand_tx_success(&tx_result);
+
+        ctx.advance_slot(1)?;
+
+        expected_count -= amount;
+
+        let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+        let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+        let counter = CounterV1::deserialize(&counter_account.data)?;
+        assert_eq!(counter.count, expected_count);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn emits_count_decremented_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        1_000,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let decrement_tx =
+        DecrementByV1SimpleTx::try_new(ctx.program_id(), owner_kp, 300, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CountDecrementedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 1_000);
+    assert_eq!(event.new_count, 700);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn saturates_at_zero() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        10,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // Decrementing past 0 should saturate rather than underflow
+    let decrement_tx =
+        DecrementByV1SimpleTx::try_new(ctx.program_id(), owner_kp, 500, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    let counter = CounterV1::deserialize(&counter_account.data)?;
+    assert_eq!(counter.count, 0, "Decrementing past 0 should saturate at 0");
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx =
+        MaliciousDecrementByV1Tx::from_valid(ctx.program_id(), owner_kp, 5, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousDecrementByV1Ix::with_owner_not_signer)
+            .with_different_signer(fee_payer_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x802", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx =
+        MaliciousDecrementByV1Tx::from_valid(ctx.program_id(), owner_kp, 5, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousDecrementByV1Ix::with_counter_not_writable)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x803", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx =
+        MaliciousDecrementByV1Tx::from_valid(ctx.program_id(), owner_kp, 5, ctx.latest_blockhash())
+            .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x804", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // The counter address is derived from the stored creator, so a different signer
+    // passes address validation and is rejected by the authority check (0x812).
+    let malicious_tx = MaliciousDecrementByV1Tx::from_valid(
+        ctx.program_id(),
+        other_owner_kp,
+        5,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+        ix.with_counter_address(counter_pk)
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x812", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_ix = MaliciousDecrementByV1Ix::from_valid(ctx.program_id(), owner_pk, 5);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        // Missing counter - only 1 account instead of 2
+    ]);
+
+    let malicious_tx =
+        MaliciousDecrementByV1Tx::from_valid(ctx.program_id(), owner_kp, 5, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x801", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx =
+        MaliciousDecrementByV1Tx::from_valid(ctx.program_id(), owner_kp, 5, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousDecrementByV1Ix::with_truncated_args)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x805", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let decrement_tx =
+        DecrementByV1SimpleTx::try_new(ctx.program_id(), owner_kp, 5, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x808", &tx_result);
+
+    Ok(())
+}
@@ -7,13 +7,14 @@ use {
         },
     },
     pinocchio_counter_client::{
+        events::CounterEvent,
         find_counter_v1_address,
         transactions::{
             DeactivateCounterV1SimpleTx, DecrementCountV1SimpleTx, IncrementCountV1SimpleTx,
-            InitializeCounterV1SimpleTx,
+            InitializeCounterV1SimpleTx, SetCountV1SimpleTx,
         },
     },
-    pinocchio_counter_program::{AccountDiscriminator, CounterV1},
+    pinocchio_counter_program::{AccountDiscriminator, CounterV1, EventDiscriminator},
     solana_instruction::AccountMeta,
     solana_keypair::Signer,
 };
@@ -125,6 +126,95 @@ fn succeeds_multiple_times() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn emits_count_decremented_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let decrement_tx =
+        DecrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CountDecrementedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 1);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn returns_new_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        43,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let decrement_tx =
+        DecrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 42);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_owner_not_signer() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -202,9 +292,13 @@ fn fails_when_counter_address_mismatch() -> TestResult {
 
     ctx.advance_slot(1)?;
 
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
     let malicious_tx =
         MaliciousDecrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
-            .with_malicious_instruction(super::malicious_builders::decrement_count_v1::MaliciousDecrementCountV1Ix::with_random_counter_address)
+            .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
             .build();
 
     let tx_result = ctx.send_transaction(malicious_tx);
@@ -215,7 +309,7 @@ fn fails_when_counter_address_mismatch() -> TestResult {
 }
 
 #[test]
-fn fails_when_owner_mismatch_address_validation() -> TestResult {
+fn fails_when_signer_is_not_authority() -> TestResult {
     let mut ctx = TestContext::try_new()?;
     let owner_kp = ctx.create_funded_keypair();
     let owner_pk = owner_kp.pubkey();
@@ -233,16 +327,14 @@ fn fails_when_owner_mismatch_address_validation() -> TestResult {
 
     ctx.advance_slot(1)?;
 
-    // Try to decrement with different owner
-    // This will fail at address validation (0x405 = CounterAddressMismatch) because the counter address
-    // is derived from the owner, so using a different owner means the address won't match.
+    // The counter address is derived from the stored creator, so a different signer
+    // passes address validation and is rejected by the authority check (0x415).
     let malicious_tx = MaliciousDecrementCountV1Tx::from_valid(
         ctx.program_id(),
         other_owner_kp,
         ctx.latest_blockhash(),
     )
     .with_malicious_instruction(|ix| {
-        // Use correct counter address but wrong owner signer
         let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
         ix.with_counter_address(counter_pk)
     })
@@ -250,7 +342,7 @@ fn fails_when_owner_mismatch_address_validation() -> TestResult {
 
     let tx_result = ctx.send_transaction(malicious_tx);
     demand_tx_failure(&tx_result);
-    demand_logs_contain("failed: custom program error: 0x405", &tx_result);
+    demand_logs_contain("failed: custom program error: 0x415", &tx_result);
 
     Ok(())
 }
@@ -0,0 +1,664 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousExecuteBatchV1Ix, MaliciousExecuteBatchV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_counter_v1_address,
+        instructions::ExecuteBatchV1Ix,
+        transactions::{
+            DeactivateCounterV1SimpleTx, ExecuteBatchV1SimpleTx, FreezeCounterV1SimpleTx,
+            InitializeCounterV1SimpleTx, SetCooldownV1SimpleTx, SetCountPolicyV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{
+        BatchOperationV1, CounterV1, CounterV2, EventDiscriminator, OverflowPolicy,
+        MAX_BATCH_OPERATIONS,
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Bounds the counter to `0..=max_count`, rejecting updates that would leave the bounds.
+fn set_max_count(ctx: &mut TestContext, owner_kp: &Keypair, max_count: u64) -> TestResult {
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        OverflowPolicy::Error,
+        None,
+        Some(max_count),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn execute_batch(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    operations: Vec<BatchOperationV1>,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let batch_tx = ExecuteBatchV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        operations,
+        ctx.latest_blockhash(),
+    )?;
+    Ok(ctx.send_transaction(batch_tx))
+}
+
+/// Sends `instruction` in a transaction paid for and signed by `signer_kp` alone.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_count(ctx: &TestContext, owner_kp: &Keypair) -> Result<u64, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    if counter_account.data.len() == CounterV2::size() {
+        Ok(CounterV2::deserialize(&counter_account.data)?.count)
+    } else {
+        Ok(CounterV1::deserialize(&counter_account.data)?.count)
+    }
+}
+
+// ============================================================================
+// Execute Batch Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let instruction = ExecuteBatchV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .set(10)
+        .increment()
+        .increment()
+        .increment()
+        .decrement()
+        .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &owner_kp)?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 12);
+
+    Ok(())
+}
+
+#[test]
+fn applies_every_operation_kind_in_order() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![
+            BatchOperationV1::increment_by(100),
+            BatchOperationV1::decrement_by(40),
+            BatchOperationV1::set(7),
+            BatchOperationV1::increment_by(3),
+            BatchOperationV1::decrement(),
+        ],
+    )?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 9);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_with_maximum_operations() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![BatchOperationV1::increment(); usize::from(MAX_BATCH_OPERATIONS)],
+    )?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(
+        read_count(&ctx, &owner_kp)?,
+        u64::from(MAX_BATCH_OPERATIONS)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn emits_single_count_set_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![
+            BatchOperationV1::set(10),
+            BatchOperationV1::increment(),
+            BatchOperationV1::increment(),
+        ],
+    )?;
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CountSetV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 12);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn returns_final_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![BatchOperationV1::set(5), BatchOperationV1::decrement_by(2)],
+    )?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 3);
+
+    Ok(())
+}
+
+#[test]
+fn fails_atomically_when_operation_exceeds_maximum() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_max_count(&mut ctx, &owner_kp, 10)?;
+
+    // The first two operations are valid on their own, but the third leaves the bounds
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![
+            BatchOperationV1::set(8),
+            BatchOperationV1::increment(),
+            BatchOperationV1::increment_by(5),
+            BatchOperationV1::set(0),
+        ],
+    )?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2608", &tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_atomically_when_operation_goes_below_minimum() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_max_count(&mut ctx, &owner_kp, 10)?;
+
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![
+            BatchOperationV1::set(2),
+            BatchOperationV1::decrement(),
+            BatchOperationV1::decrement(),
+            BatchOperationV1::decrement(),
+        ],
+    )?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2609", &tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn intermediate_counts_may_pass_through_saturation() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // CounterV1 saturates, so each operation is applied to the saturated count
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![
+            BatchOperationV1::decrement_by(5),
+            BatchOperationV1::increment(),
+            BatchOperationV1::set(u64::MAX),
+            BatchOperationV1::increment(),
+            BatchOperationV1::decrement(),
+        ],
+    )?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, u64::MAX - 1);
+
+    Ok(())
+}
+
+#[test]
+fn fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let tx_result = execute_batch(&mut ctx, &owner_kp, vec![BatchOperationV1::set(3)])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_rejects_every_count_change() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let cooldown_tx = SetCooldownV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cooldown_tx);
+    demand_tx_success(&tx_result);
+
+    // Every operation is rate limited, as with its standalone instruction
+    for operation in [
+        BatchOperationV1::increment(),
+        BatchOperationV1::decrement(),
+        BatchOperationV1::increment_by(1),
+        BatchOperationV1::decrement_by(1),
+        BatchOperationV1::set(5),
+    ] {
+        let tx_result = execute_batch(&mut ctx, &owner_kp, vec![operation])?;
+        demand_tx_failure(&tx_result);
+        demand_logs_contain("failed: custom program error: 0x260c", &tx_result);
+        assert_eq!(read_count(&ctx, &owner_kp)?, 0);
+    }
+
+    // Once the cooldown passes the batch runs, and restarts the cooldown
+    ctx.advance_slot(10)?;
+    let tx_result = execute_batch(
+        &mut ctx,
+        &owner_kp,
+        vec![BatchOperationV1::set(5), BatchOperationV1::increment_by(1)],
+    )?;
+    demand_tx_success(&tx_result);
+    assert_eq!(read_count(&ctx, &owner_kp)?, 6);
+
+    let tx_result = execute_batch(&mut ctx, &owner_kp, vec![BatchOperationV1::increment()])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousExecuteBatchV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2602", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousExecuteBatchV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2603", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2604", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        other_kp,
+        vec![BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+        ix.with_counter_address(counter_pk)
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let operations = vec![BatchOperationV1::increment()];
+    let malicious_ix =
+        MaliciousExecuteBatchV1Ix::from_valid(ctx.program_id(), owner_pk, operations.clone());
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        // Missing counter - only 1 account instead of 2
+    ]);
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        operations,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2601", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_batch_is_empty() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![],
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260d", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_too_many_operations() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![BatchOperationV1::increment(); usize::from(MAX_BATCH_OPERATIONS) + 1],
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260e", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_operations_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![BatchOperationV1::set(4), BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousExecuteBatchV1Ix::with_truncated_operations)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260f", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_operation_count_does_not_match_operations() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        vec![BatchOperationV1::set(4), BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_operation_count(3))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x260f", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_atomically_when_operation_kind_is_invalid() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousExecuteBatchV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        vec![BatchOperationV1::set(4), BatchOperationV1::increment()],
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_operation_kind(1, 5))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2605", &tx_result);
+
+    assert_eq!(read_count(&ctx, &owner_kp)?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let tx_result = execute_batch(&mut ctx, &owner_kp, vec![BatchOperationV1::increment()])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2607", &tx_result);
+
+    Ok(())
+}
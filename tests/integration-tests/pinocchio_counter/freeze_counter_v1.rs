@@ -0,0 +1,563 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousFreezeCounterV1Ix, MaliciousFreezeCounterV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        instructions::FreezeCounterV1Ix,
+        transactions::{
+            CompareAndIncrementCountV1SimpleTx, CompareAndSetCountV1SimpleTx,
+            DeactivateCounterV1SimpleTx, DecrementByV1SimpleTx, DecrementCountV1SimpleTx,
+            FreezeCounterV1SimpleTx, IncrementByV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, SetAccessModeV1SimpleTx, SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccessMode, AccountDiscriminator, CounterV1, CounterV2},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn set_count(ctx: &mut TestContext, owner_kp: &Keypair, count: u64) -> TestResult {
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        count,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn freeze_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Sends `instruction` in a transaction paid for and signed by `signer_kp` alone.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_counter_v2(
+    ctx: &TestContext,
+    owner_kp: &Keypair,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+/// Creates a counter holding a count of 5 and freezes it.
+fn setup_frozen_counter(ctx: &mut TestContext) -> Result<Keypair, Box<dyn std::error::Error>> {
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(ctx, &owner_kp)?;
+    set_count(ctx, &owner_kp, 5)?;
+    freeze_counter(ctx, &owner_kp)?;
+
+    Ok(owner_kp)
+}
+
+// ============================================================================
+// Freeze Counter Tests
+// ============================================================================
+
+#[test]
+fn succeeds_upgrading_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_count(&mut ctx, &owner_kp, 5)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    assert_eq!(counter_account_before.data.len(), CounterV1::size());
+    let counter_before = CounterV1::deserialize(&counter_account_before.data)?;
+
+    let freeze_tx =
+        FreezeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 5);
+
+    let counter_account_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+    assert_eq!(counter_account_after.data.len(), CounterV2::size());
+    assert!(
+        counter_account_after.lamports > counter_account_before.lamports,
+        "Owner should have topped up rent for the larger layout"
+    );
+
+    let counter_after = CounterV2::deserialize(&counter_account_after.data)?;
+    assert_eq!(
+        counter_after.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter_after.owner, counter_before.owner);
+    assert_eq!(counter_after.bump, counter_before.bump);
+    assert_eq!(counter_after.count, counter_before.count);
+    assert!(counter_after.frozen);
+
+    Ok(())
+}
+
+#[test]
+fn increment_count_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x316", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn decrement_count_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x416", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn set_count_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        42,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x50f", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn increment_by_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let increment_tx = IncrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        3,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x713", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn decrement_by_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let decrement_tx = DecrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        3,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x813", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn compare_and_set_count_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let compare_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        42,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(compare_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x170d", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn compare_and_increment_count_fails_while_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let compare_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(compare_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x180d", &tx_result);
+
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_increment_fails_on_frozen_public_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let access_tx = SetAccessModeV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_public(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        third_party_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x316", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.access_mode, AccessMode::Public);
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn deactivating_a_frozen_counter_succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let deactivate_tx =
+        DeactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_already_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = setup_frozen_counter(&mut ctx)?;
+
+    let freeze_tx =
+        FreezeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d0c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_freeze_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let instruction = FreezeCounterV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_authority(third_party_kp.pubkey())
+        .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &third_party_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d0b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_ix = MaliciousFreezeCounterV1Ix::from_valid(ctx.program_id(), owner_pk);
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: counter_pk,
+            is_signer: false,
+            is_writable: true,
+        },
+        // Missing system program - only 2 accounts instead of 3
+    ]);
+
+    let malicious_tx =
+        MaliciousFreezeCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousFreezeCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousFreezeCounterV1Ix::with_owner_not_signer)
+            .with_different_signer(fee_payer_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // The owner still signs, but a separate fee payer keeps it read-only
+    let instruction = MaliciousFreezeCounterV1Ix::from_valid(ctx.program_id(), owner_kp.pubkey())
+        .with_owner_not_writable()
+        .build();
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &fee_payer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[&fee_payer_kp, &owner_kp])?;
+
+    let tx_result = ctx.send_transaction(tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d03", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousFreezeCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousFreezeCounterV1Ix::with_counter_not_writable)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx =
+        MaliciousFreezeCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousFreezeCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousFreezeCounterV1Ix::with_random_system_program)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let freeze_tx =
+        FreezeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d0a", &tx_result);
+
+    Ok(())
+}
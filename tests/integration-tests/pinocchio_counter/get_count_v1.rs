@@ -0,0 +1,262 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousGetCountV1Ix, MaliciousGetCountV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        transactions::{
+            DeactivateCounterV1SimpleTx, GetCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            SetCountV1SimpleTx,
+        },
+    },
+    solana_keypair::Signer,
+};
+
+// ============================================================================
+// Get Count Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        42,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_data_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?
+        .data;
+
+    let get_count_tx =
+        GetCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, owner_pk, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(get_count_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 42);
+
+    // The counter is only read
+    let counter_data_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?
+        .data;
+    assert_eq!(counter_data_after, counter_data_before);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_payer_is_not_owner() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let reader_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx =
+        SetCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, 7, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let get_count_tx = GetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        reader_kp,
+        owner_pk,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(get_count_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 7);
+
+    Ok(())
+}
+
+#[test]
+fn returns_zero_for_new_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let get_count_tx =
+        GetCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, owner_pk, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(get_count_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let instruction =
+        MaliciousGetCountV1Ix::from_valid(ctx.program_id(), owner_pk).build_with_accounts(vec![]);
+
+    let malicious_tx = MaliciousGetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        owner_pk,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1601", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // A copy of the counter at an address that is not the owner's PDA
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let copied_counter_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousGetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        owner_pk,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(copied_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1602", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let malicious_tx = MaliciousGetCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        owner_pk,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousGetCountV1Ix::with_random_counter_address)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let get_count_tx =
+        GetCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, owner_pk, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(get_count_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1604", &tx_result);
+
+    Ok(())
+}
@@ -7,15 +7,19 @@ use {
         },
     },
     pinocchio_counter_client::{
-        find_counter_v1_address,
+        events::CounterEvent,
+        find_counter_v1, find_counter_v1_address,
         transactions::{
             DeactivateCounterV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
             SetCountV1SimpleTx,
         },
     },
-    pinocchio_counter_program::{AccountDiscriminator, CounterV1},
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterV1, EventDiscriminator, COUNTER_V1_SEED,
+    },
     solana_instruction::AccountMeta,
     solana_keypair::Signer,
+    solana_pubkey::Pubkey,
 };
 
 // ============================================================================
@@ -108,6 +112,85 @@ fn succeeds_multiple_times() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn emits_count_incremented_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CountIncrementedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 1);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn returns_new_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        41,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 42);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_owner_not_signer() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -185,9 +268,13 @@ fn fails_when_counter_address_mismatch() -> TestResult {
 
     ctx.advance_slot(1)?;
 
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
     let malicious_tx =
         MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
-            .with_malicious_instruction(MaliciousIncrementCountV1Ix::with_random_counter_address)
+            .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
             .build();
 
     let tx_result = ctx.send_transaction(malicious_tx);
@@ -198,7 +285,7 @@ fn fails_when_counter_address_mismatch() -> TestResult {
 }
 
 #[test]
-fn fails_when_owner_mismatch_address_validation() -> TestResult {
+fn fails_when_signer_is_not_authority() -> TestResult {
     let mut ctx = TestContext::try_new()?;
     let owner_kp = ctx.create_funded_keypair();
     let owner_pk = owner_kp.pubkey();
@@ -216,16 +303,14 @@ fn fails_when_owner_mismatch_address_validation() -> TestResult {
 
     ctx.advance_slot(1)?;
 
-    // Try to increment with different owner
-    // This will fail at address validation (0x305 = CounterAddressMismatch) because the counter address
-    // is derived from the owner, so using a different owner means the address won't match.
+    // The counter address is derived from the stored creator, so a different signer
+    // passes address validation and is rejected by the authority check (0x315).
     let malicious_tx = MaliciousIncrementCountV1Tx::from_valid(
         ctx.program_id(),
         other_owner_kp,
         ctx.latest_blockhash(),
     )
     .with_malicious_instruction(|ix| {
-        // Use correct counter address but wrong owner signer
         let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
         ix.with_counter_address(counter_pk)
     })
@@ -233,7 +318,7 @@ fn fails_when_owner_mismatch_address_validation() -> TestResult {
 
     let tx_result = ctx.send_transaction(malicious_tx);
     demand_tx_failure(&tx_result);
-    demand_logs_contain("failed: custom program error: 0x305", &tx_result);
+    demand_logs_contain("failed: custom program error: 0x315", &tx_result);
 
     Ok(())
 }
@@ -278,6 +363,47 @@ fn fails_when_not_enough_accounts() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn fails_when_too_many_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // More accounts than any instruction takes, including a duplicate of the owner
+    let (counter_pk, _bump) = find_counter_v1(&ctx.program_id(), &owner_pk);
+    let mut accounts = vec![
+        AccountMeta::new(owner_pk, true),
+        AccountMeta::new(counter_pk, false),
+        AccountMeta::new(owner_pk, true),
+    ];
+    accounts.extend((0..6).map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false)));
+
+    let malicious_ix = MaliciousIncrementCountV1Ix::from_valid(ctx.program_id(), owner_pk);
+    let instruction = malicious_ix.build_with_accounts(accounts);
+
+    let malicious_tx =
+        MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x301", &tx_result);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_counter_not_initialized() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -344,6 +470,60 @@ fn fails_when_counter_has_invalid_discriminator() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn fails_when_counter_has_tampered_bump() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let (counter_pk, bump) = find_counter_v1(&ctx.program_id(), &owner_pk);
+
+    // A non-canonical bump that derives a valid program address other than the counter's
+    let tampered_bump = (0..bump)
+        .rev()
+        .find(|candidate| {
+            Pubkey::create_program_address(
+                &[COUNTER_V1_SEED, owner_pk.as_ref(), &[*candidate]],
+                &ctx.program_id(),
+            )
+            .is_ok()
+        })
+        .ok_or("A non-canonical bump should exist")?;
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+
+    let mut counter = CounterV1::deserialize(&counter_account.data)?;
+    counter.bump = tampered_bump;
+
+    let mut corrupted_account = counter_account;
+    corrupted_account.data = counter.serialize()?;
+
+    ctx.set_account(counter_pk, corrupted_account)?;
+    ctx.advance_slot(1)?;
+
+    // The address is re-created from the stored bump, which no longer derives the counter address
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x305", &tx_result);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_counter_is_deactivated() -> TestResult {
     let mut ctx = TestContext::try_new()?;
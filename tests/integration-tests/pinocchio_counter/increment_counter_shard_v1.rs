@@ -0,0 +1,333 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousIncrementCounterShardV1Ix, MaliciousIncrementCounterShardV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_shard_v1_address, find_sharded_counter_v1_address,
+        sharded_counter::shard_index_for_contributor,
+        transactions::{IncrementCounterShardV1SimpleTx, InitializeShardedCounterV1SimpleTx},
+    },
+    pinocchio_counter_program::{CounterShardV1, ShardedCounterV1},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_sharded_counter(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    shard_count: u8,
+) -> TestResult {
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        shard_count,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_shard(
+    ctx: &TestContext,
+    owner: &Pubkey,
+    index: u8,
+) -> Result<CounterShardV1, Box<dyn std::error::Error>> {
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), owner);
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, index);
+    let shard_account = ctx.get_account(shard_pk).ok_or("Shard should exist")?;
+    Ok(CounterShardV1::deserialize(&shard_account.data)?)
+}
+
+// ============================================================================
+// Increment Counter Shard Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 4)?;
+
+    // An unmodified builder increments the chosen shard like the simple transaction does
+    let increment_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 1);
+
+    for index in 0..4 {
+        let expected_count = u64::from(index == 2);
+        assert_eq!(read_shard(&ctx, &owner_pk, index)?.count, expected_count);
+    }
+
+    // Increments are not written to the sharded counter until the shards are reconciled
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let sharded_counter_account = ctx
+        .get_account(sharded_counter_pk)
+        .ok_or("Sharded counter should exist")?;
+    assert_eq!(
+        ShardedCounterV1::deserialize(&sharded_counter_account.data)?.count,
+        0
+    );
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_on_contributor_shard() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+    let index = shard_index_for_contributor(&contributor_kp.pubkey(), 4);
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 4)?;
+
+    for expected_count in 1..=3 {
+        let increment_tx = IncrementCounterShardV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_pk,
+            contributor_kp.insecure_clone(),
+            4,
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(increment_tx);
+        demand_tx_success(&tx_result);
+        assert_eq!(ctx.count_return_data(&tx_result)?.count, expected_count);
+
+        ctx.advance_slot(1)?;
+    }
+
+    assert_eq!(read_shard(&ctx, &owner_pk, index)?.count, 3);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_owner_contributes() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 1)?;
+
+    let increment_tx = IncrementCounterShardV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        owner_kp,
+        1,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_shard(&ctx, &owner_pk, 0)?.count, 1);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+    let contributor_pk = contributor_kp.pubkey();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let instruction = MaliciousIncrementCounterShardV1Ix::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_pk,
+        0,
+    )
+    .build_with_accounts(vec![AccountMeta {
+        pubkey: contributor_pk,
+        is_signer: true,
+        is_writable: false,
+    }]);
+
+    let malicious_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1a01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_contributor_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let malicious_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousIncrementCounterShardV1Ix::with_contributor_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1a02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let malicious_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousIncrementCounterShardV1Ix::with_shard_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1a03", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    // A genuine shard stored at an address that is not derived from its seeds
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 0);
+    let shard_copy_pk = ctx.copy_account_to_unique_address(shard_pk)?;
+
+    let malicious_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_address(shard_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1a04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_is_sharded_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+
+    let malicious_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_address(sharded_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1a08", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let contributor_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    // Index 2 is past the last shard of a counter with two shards
+    let malicious_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner_pk,
+        contributor_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1a08", &tx_result);
+
+    Ok(())
+}
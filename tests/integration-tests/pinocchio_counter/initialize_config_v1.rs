@@ -0,0 +1,307 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousInitializeConfigV1Ix, MaliciousInitializeConfigV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_config_v1_address, find_program_data_address,
+        transactions::{
+            IncrementCountV1SimpleTx, InitializeConfigV1SimpleTx, InitializeCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{ConfigV1, InstructionDiscriminator},
+    solana_keypair::Signer,
+    solana_pubkey::Pubkey,
+};
+
+fn read_config(ctx: &TestContext) -> Result<ConfigV1, Box<dyn std::error::Error>> {
+    let config_pk = find_config_v1_address(&ctx.program_id());
+    let config_account = ctx.get_account(config_pk).ok_or("Config should exist")?;
+    Ok(ConfigV1::deserialize(&config_account.data)?)
+}
+
+// ============================================================================
+// Initialize Config Tests
+// ============================================================================
+
+#[test]
+fn succeeds_initializing_config() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+    let admin_pk = Pubkey::new_unique();
+
+    let init_config_tx = InitializeConfigV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        admin_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_config_tx);
+    demand_tx_success(&tx_result);
+
+    let config_pk = find_config_v1_address(&ctx.program_id());
+    let config_account = ctx.get_account(config_pk).ok_or("Config should exist")?;
+    assert_eq!(config_account.owner, ctx.program_id());
+    assert_eq!(config_account.data.len(), ConfigV1::size());
+
+    let config = read_config(&ctx)?;
+    assert_eq!(config.admin, admin_pk.to_bytes());
+    assert!(!config.paused);
+    assert!(!config.is_pause_exempt(InstructionDiscriminator::IncrementCountV1.into()));
+
+    Ok(())
+}
+
+#[test]
+fn counter_instructions_run_before_config_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_already_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let new_admin_pk = Pubkey::new_unique();
+
+    ctx.advance_slot(1)?;
+
+    let init_config_tx = InitializeConfigV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        new_admin_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_config_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f06", &tx_result);
+
+    let config = read_config(&ctx)?;
+    assert_eq!(config.admin, ctx.admin_kp().pubkey().to_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_not_upgrade_authority() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+    let impostor_kp = ctx.create_funded_keypair();
+    let impostor_pk = impostor_kp.pubkey();
+
+    let init_config_tx = InitializeConfigV1SimpleTx::try_new(
+        ctx.program_id(),
+        impostor_kp,
+        impostor_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_config_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f0b", &tx_result);
+
+    let config_pk = find_config_v1_address(&ctx.program_id());
+    assert!(ctx.get_account(config_pk).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_program_is_immutable() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    // Dropping the upgrade authority, as `solana program set-upgrade-authority --final` does
+    let program_data_pk = find_program_data_address(&ctx.program_id());
+    let mut program_data = ctx
+        .get_account(program_data_pk)
+        .ok_or("Program data should exist")?;
+    program_data.data[12..45].fill(0);
+    ctx.set_account(program_data_pk, program_data)?;
+
+    let init_config_tx = InitializeConfigV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_config_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f0b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_program_data_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    // A copy of the real program data, with the right upgrade authority, at another address
+    let program_data_pk = find_program_data_address(&ctx.program_id());
+    let fake_program_data_pk = ctx.copy_account_to_unique_address(program_data_pk)?;
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_program_data_address(fake_program_data_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f08", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+    let authority_kp = ctx.upgrade_authority_kp();
+
+    let instruction = MaliciousInitializeConfigV1Ix::from_valid(
+        ctx.program_id(),
+        authority_kp.pubkey(),
+        Pubkey::new_unique(),
+    )
+    .build_with_accounts(vec![]);
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        authority_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_authority_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeConfigV1Ix::with_authority_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeConfigV1Ix::with_config_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeConfigV1Ix::with_random_config_address)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeConfigV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f0c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_truncated_args() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    let malicious_tx = MaliciousInitializeConfigV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeConfigV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1f0d", &tx_result);
+
+    Ok(())
+}
@@ -7,12 +7,16 @@ use {
         },
     },
     pinocchio_counter_client::{
+        events::CounterEvent,
         find_counter_v1, find_counter_v1_address,
         transactions::{DeactivateCounterV1SimpleTx, InitializeCounterV1SimpleTx},
     },
-    pinocchio_counter_program::{AccountDiscriminator, CounterV1},
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterV1, DeactivatedCounterV1, EventDiscriminator,
+    },
     solana_instruction::AccountMeta,
     solana_keypair::Signer,
+    solana_rent::Rent,
 };
 
 #[test]
@@ -81,10 +85,121 @@ fn succeeds() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn succeeds_when_counter_is_pre_funded() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    // Anyone can send lamports to the predictable counter address before it is initialized
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    ctx.airdrop_lamports(counter_pk, 1)
+        .map_err(|e| format!("Airdrop failed: {e:?}"))?;
+
+    let init_counter_tx =
+        InitializeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(counter_account.data.len(), CounterV1::size());
+    assert_eq!(
+        counter_account.lamports,
+        Rent::default().minimum_balance(CounterV1::size())
+    );
+
+    let counter = CounterV1::deserialize(&counter_account.data)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_counter_is_pre_funded_above_rent_exemption() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let pre_funded_lamports = Rent::default().minimum_balance(CounterV1::size()) * 2;
+    ctx.airdrop_lamports(counter_pk, pre_funded_lamports)
+        .map_err(|e| format!("Airdrop failed: {e:?}"))?;
+
+    let init_counter_tx =
+        InitializeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+
+    // No top-up is needed, and the existing lamports are left in place
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(counter_account.lamports, pre_funded_lamports);
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_initialized_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx =
+        InitializeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterInitializedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
 // ============================================================================
 // Malicious Transaction Tests - Account Validation Failures
 // ============================================================================
 
+#[test]
+fn returns_zero_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx =
+        InitializeCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 0);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_payer_not_signer() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -168,6 +283,26 @@ fn fails_when_system_program_address_mismatch() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn fails_when_tombstone_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeCounterV1Ix::with_random_tombstone)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x10d", &tx_result);
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_counter_has_pre_existing_data() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -229,11 +364,11 @@ fn fails_when_reinitializing_deactivated_counter() -> TestResult {
 
     let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
     let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
-    // Deactivated counter should have 1 byte (discriminator)
+    // Deactivated counter keeps only its owner, bump and authority
     assert_eq!(
         counter_account.data.len(),
-        1,
-        "Deactivated counter should be 1 byte"
+        DeactivatedCounterV1::size(),
+        "Deactivated counter should hold the deactivated record"
     );
 
     ctx.advance_slot(1)?;
@@ -309,7 +444,7 @@ fn fails_when_not_enough_accounts() -> TestResult {
             is_signer: false,
             is_writable: true,
         },
-        // Missing system_program - only 2 accounts instead of 3
+        // Missing system_program and tombstone - only 2 accounts instead of 4
     ]);
 
     let malicious_tx = MaliciousInitializeCounterV1Tx::from_valid(
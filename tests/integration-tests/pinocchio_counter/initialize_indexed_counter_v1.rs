@@ -0,0 +1,382 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousInitializeIndexedCounterV1Ix, MaliciousInitializeIndexedCounterV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_counter_v1_address, find_indexed_counter_v1_address,
+        transactions::{InitializeCounterV1SimpleTx, InitializeIndexedCounterV1SimpleTx},
+    },
+    pinocchio_counter_program::{AccountDiscriminator, EventDiscriminator, IndexedCounterV1},
+    solana_instruction::AccountMeta,
+    solana_keypair::Signer,
+    solana_pubkey::Pubkey,
+};
+
+fn read_indexed_counter(
+    ctx: &TestContext,
+    owner: &Pubkey,
+    index: u32,
+) -> Result<IndexedCounterV1, Box<dyn std::error::Error>> {
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), owner, index);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(IndexedCounterV1::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Initialize Indexed Counter Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        7,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 7);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(counter_account.data.len(), IndexedCounterV1::size());
+
+    let counter = IndexedCounterV1::deserialize(&counter_account.data)?;
+    assert_eq!(
+        counter.discriminator,
+        AccountDiscriminator::IndexedCounterV1Account
+    );
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.index, 7);
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_initialized_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        7,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 7);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterInitializedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_for_multiple_indices_of_one_owner() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    for index in [0, 1, u32::MAX] {
+        let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            index,
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(init_tx);
+        demand_tx_success(&tx_result);
+
+        ctx.advance_slot(1)?;
+    }
+
+    for index in [0, 1, u32::MAX] {
+        let counter = read_indexed_counter(&ctx, &owner_pk, index)?;
+        assert_eq!(counter.index, index);
+        assert_eq!(counter.owner, owner_pk.to_bytes());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_alongside_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    assert_ne!(
+        find_counter_v1_address(&ctx.program_id(), &owner_pk),
+        find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 0)
+    );
+    assert_eq!(read_indexed_counter(&ctx, &owner_pk, 0)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_counter_is_pre_funded() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 3);
+    ctx.airdrop_lamports(counter_pk, 1)
+        .map_err(|e| format!("Airdrop failed: {e:?}"))?;
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        3,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(counter_account.data.len(), IndexedCounterV1::size());
+
+    let counter = read_indexed_counter(&ctx, &owner_pk, 3)?;
+    assert_eq!(counter.index, 3);
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+
+    let instruction =
+        MaliciousInitializeIndexedCounterV1Ix::from_valid(ctx.program_id(), payer_pk, 0)
+            .build_with_accounts(vec![
+                AccountMeta {
+                    pubkey: payer_pk,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: find_indexed_counter_v1_address(&ctx.program_id(), &payer_pk, 0),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                // Missing system program - only 2 accounts instead of 3
+            ]);
+
+    let malicious_tx = MaliciousInitializeIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_payer_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeIndexedCounterV1Ix::with_payer_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeIndexedCounterV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_is_for_another_index() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let other_index_counter_pk =
+        find_indexed_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey(), 1);
+
+    let malicious_tx = MaliciousInitializeIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(other_index_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_already_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        3,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        3,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeIndexedCounterV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe09", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeIndexedCounterV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xe0a", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,408 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousInitializeMultisigV1Ix, MaliciousInitializeMultisigV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address, find_multisig_v1_address,
+        instructions::InitializeMultisigV1Ix,
+        transactions::{InitializeCounterV1SimpleTx, InitializeMultisigV1SimpleTx},
+    },
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterV2, MultisigV1, MAX_MULTISIG_MEMBERS, NO_PENDING_AUTHORITY,
+    },
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn unique_members(count: usize) -> Vec<Pubkey> {
+    (0..count).map(|_| Pubkey::new_unique()).collect()
+}
+
+fn read_multisig(
+    ctx: &TestContext,
+    creator: &Pubkey,
+) -> Result<MultisigV1, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), creator);
+    let multisig_pk = find_multisig_v1_address(&ctx.program_id(), &counter_pk);
+    let multisig_account = ctx
+        .get_account(multisig_pk)
+        .ok_or("Multisig should exist")?;
+    Ok(MultisigV1::deserialize(&multisig_account.data)?)
+}
+
+// ============================================================================
+// Initialize Multisig Tests
+// ============================================================================
+
+#[test]
+fn succeeds_and_upgrades_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let members = unique_members(3);
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let init_multisig_tx = InitializeMultisigV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        owner_kp,
+        &members,
+        2,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_multisig_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let multisig_pk = find_multisig_v1_address(&ctx.program_id(), &counter_pk);
+
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+    let counter = CounterV2::deserialize(&counter_account.data)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.authority, multisig_pk.to_bytes());
+    assert_eq!(counter.pending_authority, NO_PENDING_AUTHORITY);
+
+    let multisig = read_multisig(&ctx, &owner_pk)?;
+    assert_eq!(
+        multisig.discriminator,
+        AccountDiscriminator::MultisigV1Account
+    );
+    assert_eq!(multisig.counter, counter_pk.to_bytes());
+    assert_eq!(multisig.threshold, 2);
+    assert_eq!(multisig.member_count, 3);
+    for (stored, member) in multisig.members().iter().zip(&members) {
+        assert_eq!(stored, &member.to_bytes());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_with_maximum_members_and_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let members = unique_members(usize::from(MAX_MULTISIG_MEMBERS));
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let init_multisig_tx = InitializeMultisigV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        owner_kp,
+        &members,
+        MAX_MULTISIG_MEMBERS,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_multisig_tx);
+    demand_tx_success(&tx_result);
+
+    let multisig = read_multisig(&ctx, &owner_pk)?;
+    assert_eq!(multisig.threshold, MAX_MULTISIG_MEMBERS);
+    assert_eq!(multisig.member_count, MAX_MULTISIG_MEMBERS);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_without_members() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_members(&[]))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2501", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_authority_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeMultisigV1Ix::with_authority_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2502", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_multisig_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeMultisigV1Ix::with_multisig_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2506", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_multisig_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_multisig_address(Pubkey::new_unique()))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2507", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeMultisigV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2508", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeMultisigV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2509", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let attacker_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let instruction =
+        InitializeMultisigV1Ix::new(ctx.program_id(), owner_kp.pubkey(), &unique_members(2), 1)
+            .with_authority(attacker_kp.pubkey())
+            .to_instruction(true)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        attacker_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x250e", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_too_many_members() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let members = unique_members(usize::from(MAX_MULTISIG_MEMBERS) + 1);
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_members(&members))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x250f", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_duplicate_member() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let member = Pubkey::new_unique();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_members(&[member, Pubkey::new_unique(), member]))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2510", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_zero_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_threshold(0))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2511", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_threshold_above_member_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousInitializeMultisigV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        unique_members(2),
+        1,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_threshold(3))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2511", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,448 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousInitializeShardedCounterV1Ix, MaliciousInitializeShardedCounterV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_shard_v1_address, find_counter_v1_address, find_sharded_counter_v1_address,
+        sharded_counter::find_counter_shard_v1_addresses,
+        transactions::InitializeShardedCounterV1SimpleTx,
+    },
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterShardV1, ShardedCounterV1, MAX_COUNTER_SHARDS,
+    },
+    solana_account::Account,
+    solana_instruction::AccountMeta,
+    solana_keypair::Signer,
+    solana_pubkey::Pubkey,
+};
+
+fn system_account_with_data(data: Vec<u8>) -> Account {
+    Account {
+        lamports: 1_000_000_000,
+        data,
+        owner: solana_system_program::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+// ============================================================================
+// Initialize Sharded Counter Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        4,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 0);
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let sharded_counter_account = ctx
+        .get_account(sharded_counter_pk)
+        .ok_or("Sharded counter should exist")?;
+    assert_eq!(sharded_counter_account.owner, ctx.program_id());
+    assert_eq!(sharded_counter_account.data.len(), ShardedCounterV1::size());
+
+    let sharded_counter = ShardedCounterV1::deserialize(&sharded_counter_account.data)?;
+    assert_eq!(
+        sharded_counter.discriminator,
+        AccountDiscriminator::ShardedCounterV1Account
+    );
+    assert_eq!(sharded_counter.owner, owner_pk.to_bytes());
+    assert_eq!(sharded_counter.shard_count, 4);
+    assert_eq!(sharded_counter.count, 0);
+
+    for (index, shard_pk) in (0u8..).zip(find_counter_shard_v1_addresses(
+        &ctx.program_id(),
+        &sharded_counter_pk,
+        4,
+    )) {
+        let shard_account = ctx.get_account(shard_pk).ok_or("Shard should exist")?;
+        assert_eq!(shard_account.owner, ctx.program_id());
+        assert_eq!(shard_account.data.len(), CounterShardV1::size());
+
+        let shard = CounterShardV1::deserialize(&shard_account.data)?;
+        assert_eq!(
+            shard.discriminator,
+            AccountDiscriminator::CounterShardV1Account
+        );
+        assert_eq!(shard.sharded_counter, sharded_counter_pk.to_bytes());
+        assert_eq!(shard.index, index);
+        assert_eq!(shard.count, 0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_with_max_shards() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        MAX_COUNTER_SHARDS,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let last_shard_pk = find_counter_shard_v1_address(
+        &ctx.program_id(),
+        &sharded_counter_pk,
+        MAX_COUNTER_SHARDS - 1,
+    );
+    let last_shard_account = ctx.get_account(last_shard_pk).ok_or("Shard should exist")?;
+    let last_shard = CounterShardV1::deserialize(&last_shard_account.data)?;
+    assert_eq!(last_shard.index, MAX_COUNTER_SHARDS - 1);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_shard_is_pre_funded() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 1);
+    ctx.airdrop_lamports(shard_pk, 1)
+        .map_err(|e| format!("Airdrop failed: {e:?}"))?;
+
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    let shard_account = ctx.get_account(shard_pk).ok_or("Shard should exist")?;
+    assert_eq!(shard_account.owner, ctx.program_id());
+    assert_eq!(CounterShardV1::deserialize(&shard_account.data)?.index, 1);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    // No shards - only the payer, the sharded counter and the system program
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards(vec![]))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1901", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_payer_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeShardedCounterV1Ix::with_payer_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1902", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_sharded_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(
+        MaliciousInitializeShardedCounterV1Ix::with_sharded_counter_not_writable,
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1904", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_sharded_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let counter_v1_pk = find_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey());
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_sharded_counter_address(counter_v1_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1905", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_already_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        2,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // Adding shards later is not supported, so a second initialize fails on the sharded counter
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        4,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1906", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_sharded_counter_not_owned_by_system_program() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey());
+
+    let mut account = system_account_with_data(vec![]);
+    account.owner = Pubkey::new_unique();
+    ctx.set_account(sharded_counter_pk, account)?;
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1907", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeShardedCounterV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1908", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_too_many_shards() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey());
+
+    let shards = find_counter_shard_v1_addresses(
+        &ctx.program_id(),
+        &sharded_counter_pk,
+        MAX_COUNTER_SHARDS + 1,
+    )
+    .into_iter()
+    .map(|pubkey| AccountMeta {
+        pubkey,
+        is_signer: false,
+        is_writable: true,
+    })
+    .collect();
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards(shards))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1909", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        3,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_not_writable(2))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x190a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shards_out_of_order() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        3,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards_swapped(0, 1))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x190b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_not_empty() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey());
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 1);
+
+    ctx.set_account(shard_pk, system_account_with_data(vec![0; 8]))?;
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x190c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_not_owned_by_system_program() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey());
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 0);
+
+    let mut account = system_account_with_data(vec![]);
+    account.owner = Pubkey::new_unique();
+    ctx.set_account(shard_pk, account)?;
+
+    let malicious_tx = MaliciousInitializeShardedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x190d", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,397 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousInitializeSponsoredCounterV1Ix, MaliciousInitializeSponsoredCounterV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_counter_v1, find_counter_v1_address,
+        transactions::{InitializeCounterV1SimpleTx, InitializeSponsoredCounterV1SimpleTx},
+    },
+    pinocchio_counter_program::{AccountDiscriminator, CountPolicy, CounterV2, EventDiscriminator},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_rent::Rent,
+};
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let owner_lamports_before = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+    let payer_lamports_before = ctx
+        .get_account(payer_pk)
+        .ok_or("Payer account should exist")?
+        .lamports;
+
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let (counter_pk, bump) = find_counter_v1(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+    let rent_exempt_minimum_counter = Rent::default().minimum_balance(CounterV2::size());
+    assert_eq!(counter_account.lamports, rent_exempt_minimum_counter);
+
+    let counter = CounterV2::deserialize(&counter_account.data)?;
+    let policy = CountPolicy::default();
+    assert_eq!(
+        counter.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.bump, bump);
+    assert_eq!(counter.count, 0);
+    assert_eq!(counter.overflow_policy, policy.overflow_policy);
+    assert_eq!(counter.min_count, policy.min_count);
+    assert_eq!(counter.max_count, policy.max_count);
+    assert_eq!(counter.authority, owner_pk.to_bytes());
+    assert_eq!(counter.rent_payer(), Some(&payer_pk.to_bytes()));
+
+    // The payer covers the rent and the fee, while the owner only signs
+    let owner_lamports_after = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+    let payer_lamports_after = ctx
+        .get_account(payer_pk)
+        .ok_or("Payer account should exist")?
+        .lamports;
+
+    assert_eq!(owner_lamports_after, owner_lamports_before);
+    assert!(
+        payer_lamports_before - payer_lamports_after >= rent_exempt_minimum_counter,
+        "Payer should have paid at least {rent_exempt_minimum_counter} lamports of rent"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_initialized_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterInitializedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_owner_has_no_lamports() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = Keypair::new();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+    let counter = CounterV2::deserialize(&counter_account.data)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_counter_is_pre_funded() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    ctx.airdrop_lamports(counter_pk, 1)
+        .map_err(|e| format!("Airdrop failed: {e:?}"))?;
+
+    let init_counter_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(
+        counter_account.lamports,
+        Rent::default().minimum_balance(CounterV2::size())
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Malicious Transaction Tests - Account Validation Failures
+// ============================================================================
+
+#[test]
+fn fails_when_payer_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeSponsoredCounterV1Ix::with_payer_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1402", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeSponsoredCounterV1Ix::with_owner_not_signer)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1404", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeSponsoredCounterV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1405", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_derived_from_payer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    // The counter must be derived from the owner, not from whoever pays for it
+    let payer_counter_pk = find_counter_v1_address(&ctx.program_id(), &payer_kp.pubkey());
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(payer_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1406", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_already_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let init_sponsored_tx = InitializeSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_sponsored_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1407", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeSponsoredCounterV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1409", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_tombstone_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousInitializeSponsoredCounterV1Ix::with_random_tombstone)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x140d", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_ix = MaliciousInitializeSponsoredCounterV1Ix::from_valid(
+        ctx.program_id(),
+        payer_pk,
+        owner_kp.pubkey(),
+    );
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: payer_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        // Missing owner, counter, system_program and tombstone - only 1 account instead of 5
+    ]);
+
+    let malicious_tx = MaliciousInitializeSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1401", &tx_result);
+
+    Ok(())
+}
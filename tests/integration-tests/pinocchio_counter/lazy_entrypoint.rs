@@ -0,0 +1,106 @@
+//! Checks that the `lazy-entrypoint` build fails instructions given more accounts than any
+//! instruction takes with the same error as the default build.
+//!
+//! Both builds are loaded side by side, so these tests need the output of
+//! `scripts/build-pinocchio-counter`.
+
+use {
+    crate::{
+        litesvm_utils::demand_tx_success,
+        pinocchio_counter::{
+            TestContext, TestResult, DEFAULT_PROGRAM_PATH, LAZY_ENTRYPOINT_PROGRAM_PATH,
+        },
+    },
+    pinocchio_counter_client::{
+        instructions::{CloseCounterV1Ix, IncrementCountV1Ix},
+        transactions::InitializeCounterV1SimpleTx,
+    },
+    solana_instruction::Instruction,
+    solana_keypair::Signer,
+    solana_message::{v0, VersionedMessage},
+    solana_pubkey::Pubkey,
+    solana_transaction::versioned::VersionedTransaction,
+    std::path::PathBuf,
+};
+
+/// Accounts passed on top of an instruction's own, more than any instruction takes.
+const EXCESS_ACCOUNTS: usize = 40;
+
+type BuildInstruction = fn(Pubkey, Pubkey) -> Result<Instruction, Box<dyn std::error::Error>>;
+
+/// Sends the instruction built for a new counter to the build at `program_path`, with
+/// [`EXCESS_ACCOUNTS`] repeats of the counter before the config account, and returns the
+/// transaction error.
+fn oversized_instruction_error(
+    program_path: &str,
+    build_instruction: BuildInstruction,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut ctx = TestContext::try_new_with_program(
+        &PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(program_path),
+    )?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let mut instruction = build_instruction(ctx.program_id(), owner_kp.pubkey())?;
+    let counter = instruction.accounts[1].clone();
+    let config = instruction
+        .accounts
+        .pop()
+        .ok_or("Instruction should end with the config account")?;
+    instruction
+        .accounts
+        .extend(std::iter::repeat_n(counter, EXCESS_ACCOUNTS));
+    instruction.accounts.push(config);
+
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &owner_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[owner_kp])?;
+    let tx_result = ctx.send_transaction(tx);
+
+    let err = tx_result
+        .err()
+        .ok_or("Oversized instruction should fail")?
+        .err;
+    Ok(format!("{err:?}"))
+}
+
+/// Checks that both builds fail the oversized instruction with the instruction's own error.
+fn demand_same_error(build_instruction: BuildInstruction) -> TestResult {
+    let eager_err = oversized_instruction_error(DEFAULT_PROGRAM_PATH, build_instruction)?;
+    let lazy_err = oversized_instruction_error(LAZY_ENTRYPOINT_PROGRAM_PATH, build_instruction)?;
+
+    assert!(
+        eager_err.contains("Custom"),
+        "Expected a custom program error, observed {eager_err}"
+    );
+    assert_eq!(lazy_err, eager_err);
+
+    Ok(())
+}
+
+#[test]
+fn oversized_increment_fails_with_same_error() -> TestResult {
+    demand_same_error(|program_id, owner| {
+        Ok(IncrementCountV1Ix::new(program_id, owner).to_instruction(true)?)
+    })
+}
+
+#[test]
+fn oversized_close_fails_with_same_error() -> TestResult {
+    demand_same_error(|program_id, owner| {
+        Ok(CloseCounterV1Ix::new(program_id, owner, Pubkey::new_unique()).to_instruction(true)?)
+    })
+}
@@ -0,0 +1,175 @@
+use {
+    pinocchio_counter_client::{config_v1_account_meta, instructions::AcceptAuthorityV1Ix},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `AcceptAuthorityV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousAcceptAuthorityV1Ix {
+    program_id: Pubkey,
+    new_authority: AccountMeta,
+    counter: AccountMeta,
+}
+
+impl MaliciousAcceptAuthorityV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, creator: Pubkey, new_authority: Pubkey) -> Self {
+        let valid = AcceptAuthorityV1Ix::new(program_id, creator, new_authority);
+        Self {
+            program_id,
+            new_authority: valid.new_authority,
+            counter: valid.counter,
+        }
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Makes the new authority not a signer.
+    #[must_use]
+    pub fn with_new_authority_not_signer(mut self) -> Self {
+        self.new_authority.is_signer = false;
+        self
+    }
+
+    /// Makes the counter not writable.
+    #[must_use]
+    pub fn with_counter_not_writable(mut self) -> Self {
+        self.counter.is_writable = false;
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: vec![InstructionDiscriminator::AcceptAuthorityV1.into()],
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.new_authority,
+                self.counter,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: vec![InstructionDiscriminator::AcceptAuthorityV1.into()],
+        }
+    }
+}
+
+/// Builder for creating malicious `AcceptAuthorityV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousAcceptAuthorityV1Tx {
+    program_id: Pubkey,
+    creator: Pubkey,
+    new_authority_kp: Keypair,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of new authority
+}
+
+impl MaliciousAcceptAuthorityV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        creator: Pubkey,
+        new_authority_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix = AcceptAuthorityV1Ix::new(program_id, creator, new_authority_kp.pubkey());
+        Self {
+            program_id,
+            creator,
+            new_authority_kp,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with new authority
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousAcceptAuthorityV1Ix) -> MaliciousAcceptAuthorityV1Ix,
+    {
+        let malicious_ix = MaliciousAcceptAuthorityV1Ix::from_valid(
+            self.program_id,
+            self.creator,
+            self.new_authority_kp.pubkey(),
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so new authority is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use new_authority_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.new_authority_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
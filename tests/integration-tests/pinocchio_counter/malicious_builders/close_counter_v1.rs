@@ -0,0 +1,234 @@
+use {
+    pinocchio_counter_client::{config_v1_account_meta, instructions::CloseCounterV1Ix},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `CloseCounterV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousCloseCounterV1Ix {
+    program_id: Pubkey,
+    owner: AccountMeta,
+    counter: AccountMeta,
+    destination: AccountMeta,
+    tombstone: AccountMeta,
+    system_program: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousCloseCounterV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction that sends
+    /// the counter's lamports back to the owner.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, owner: Pubkey) -> Self {
+        let valid = CloseCounterV1Ix::new(program_id, owner, owner);
+        Self {
+            program_id,
+            owner: valid.owner,
+            counter: valid.counter,
+            destination: valid.destination,
+            tombstone: valid.tombstone,
+            system_program: valid.system_program,
+            instruction_data: vec![InstructionDiscriminator::CloseCounterV1.into()],
+        }
+    }
+
+    /// Sets the instruction discriminator to an invalid value.
+    #[must_use]
+    pub fn with_invalid_discriminator(mut self, discriminator: u8) -> Self {
+        self.instruction_data = vec![discriminator];
+        self
+    }
+
+    /// Sets empty instruction data.
+    #[must_use]
+    pub fn with_empty_data(mut self) -> Self {
+        self.instruction_data = vec![];
+        self
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Makes the owner not a signer.
+    #[must_use]
+    pub fn with_owner_not_signer(mut self) -> Self {
+        self.owner.is_signer = false;
+        self
+    }
+
+    /// Makes the counter not writable.
+    #[must_use]
+    pub fn with_counter_not_writable(mut self) -> Self {
+        self.counter.is_writable = false;
+        self
+    }
+
+    /// Sets the destination to a specific address.
+    #[must_use]
+    pub fn with_destination(mut self, address: Pubkey) -> Self {
+        self.destination.pubkey = address;
+        self
+    }
+
+    /// Makes the destination not writable.
+    #[must_use]
+    pub fn with_destination_not_writable(mut self) -> Self {
+        self.destination.is_writable = false;
+        self
+    }
+
+    /// Sets the tombstone to a specific address.
+    #[must_use]
+    pub fn with_tombstone(mut self, address: Pubkey) -> Self {
+        self.tombstone.pubkey = address;
+        self
+    }
+
+    /// Sets the tombstone to a random address.
+    #[must_use]
+    pub fn with_random_tombstone(mut self) -> Self {
+        self.tombstone.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Makes the tombstone not writable.
+    #[must_use]
+    pub fn with_tombstone_not_writable(mut self) -> Self {
+        self.tombstone.is_writable = false;
+        self
+    }
+
+    /// Sets the system program to a random address.
+    #[must_use]
+    pub fn with_random_system_program(mut self) -> Self {
+        self.system_program.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.owner,
+                self.counter,
+                self.destination,
+                self.tombstone,
+                self.system_program,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `CloseCounterV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousCloseCounterV1Tx {
+    program_id: Pubkey,
+    owner_kp: Keypair,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of owner
+}
+
+impl MaliciousCloseCounterV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction that sends
+    /// the counter's lamports back to the owner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, owner_kp: Keypair, recent_blockhash: Hash) -> Self {
+        let valid_ix = CloseCounterV1Ix::new(program_id, owner_kp.pubkey(), owner_kp.pubkey());
+        Self {
+            program_id,
+            owner_kp,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with owner
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousCloseCounterV1Ix) -> MaliciousCloseCounterV1Ix,
+    {
+        let malicious_ix =
+            MaliciousCloseCounterV1Ix::from_valid(self.program_id, self.owner_kp.pubkey());
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so owner is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use owner_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.owner_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
@@ -0,0 +1,221 @@
+use {
+    pinocchio_counter_client::{config_v1_account_meta, instructions::ExecuteBatchV1Ix},
+    pinocchio_counter_program::BatchOperationV1,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Offset of the operations' length prefix in the instruction data, after the discriminator.
+const OPERATION_COUNT_OFFSET: usize = 1;
+
+/// Offset of the first operation in the instruction data, after the `u64` length prefix.
+const FIRST_OPERATION_OFFSET: usize = OPERATION_COUNT_OFFSET + 8;
+
+/// Builder for creating malicious `ExecuteBatchV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousExecuteBatchV1Ix {
+    program_id: Pubkey,
+    owner: AccountMeta,
+    counter: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousExecuteBatchV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        owner: Pubkey,
+        operations: Vec<BatchOperationV1>,
+    ) -> Self {
+        let valid = ExecuteBatchV1Ix::new(program_id, owner).with_operations(operations);
+        // Build a valid instruction to get the serialized args, then extract the data
+        let instruction_data = valid
+            .clone()
+            .to_instruction(false)
+            .expect("Failed to build valid instruction")
+            .data;
+
+        Self {
+            program_id,
+            owner: valid.owner,
+            counter: valid.counter,
+            instruction_data,
+        }
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Makes the owner not a signer.
+    #[must_use]
+    pub fn with_owner_not_signer(mut self) -> Self {
+        self.owner.is_signer = false;
+        self
+    }
+
+    /// Makes the counter not writable.
+    #[must_use]
+    pub fn with_counter_not_writable(mut self) -> Self {
+        self.counter.is_writable = false;
+        self
+    }
+
+    /// Overwrites the length prefix of the encoded operations without changing the operations.
+    #[must_use]
+    pub fn with_operation_count(mut self, operation_count: u64) -> Self {
+        self.instruction_data[OPERATION_COUNT_OFFSET..FIRST_OPERATION_OFFSET]
+            .copy_from_slice(&operation_count.to_le_bytes());
+        self
+    }
+
+    /// Drops the last byte of the instruction data, truncating the last operation.
+    #[must_use]
+    pub fn with_truncated_operations(mut self) -> Self {
+        self.instruction_data.pop();
+        self
+    }
+
+    /// Overwrites the kind byte of the operation at `index`.
+    #[must_use]
+    pub fn with_operation_kind(mut self, index: usize, kind: u8) -> Self {
+        self.instruction_data[FIRST_OPERATION_OFFSET + index * BatchOperationV1::size()] = kind;
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.owner,
+                self.counter,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `ExecuteBatchV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousExecuteBatchV1Tx {
+    program_id: Pubkey,
+    owner_kp: Keypair,
+    operations: Vec<BatchOperationV1>,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of owner
+}
+
+impl MaliciousExecuteBatchV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        owner_kp: Keypair,
+        operations: Vec<BatchOperationV1>,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix = ExecuteBatchV1Ix::new(program_id, owner_kp.pubkey())
+            .with_operations(operations.clone());
+        Self {
+            program_id,
+            owner_kp,
+            operations,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with owner
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousExecuteBatchV1Ix) -> MaliciousExecuteBatchV1Ix,
+    {
+        let malicious_ix = MaliciousExecuteBatchV1Ix::from_valid(
+            self.program_id,
+            self.owner_kp.pubkey(),
+            self.operations.clone(),
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so owner is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use owner_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.owner_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
@@ -0,0 +1,147 @@
+use {
+    pinocchio_counter_client::{config_v1_account_meta, instructions::GetCountV1Ix},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `GetCountV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousGetCountV1Ix {
+    program_id: Pubkey,
+    counter: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousGetCountV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, owner: Pubkey) -> Self {
+        let valid = GetCountV1Ix::new(program_id, owner);
+        Self {
+            program_id,
+            counter: valid.counter,
+            instruction_data: vec![InstructionDiscriminator::GetCountV1.into()],
+        }
+    }
+
+    /// Sets the counter address to a random address.
+    #[must_use]
+    pub fn with_random_counter_address(mut self) -> Self {
+        self.counter.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![self.counter, config_v1_account_meta(&self.program_id)],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `GetCountV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousGetCountV1Tx {
+    program_id: Pubkey,
+    payer_kp: Keypair,
+    owner: Pubkey,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+}
+
+impl MaliciousGetCountV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix = GetCountV1Ix::new(program_id, owner);
+        Self {
+            program_id,
+            payer_kp,
+            owner,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousGetCountV1Ix) -> MaliciousGetCountV1Ix,
+    {
+        let malicious_ix = MaliciousGetCountV1Ix::from_valid(self.program_id, self.owner);
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &self.payer_kp.pubkey(),
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[&self.payer_kp])
+            .expect("Failed to create transaction")
+    }
+}
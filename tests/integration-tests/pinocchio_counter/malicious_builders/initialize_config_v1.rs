@@ -0,0 +1,209 @@
+use {
+    pinocchio_counter_client::instructions::InitializeConfigV1Ix,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `InitializeConfigV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousInitializeConfigV1Ix {
+    program_id: Pubkey,
+    authority: AccountMeta,
+    config: AccountMeta,
+    program_data: AccountMeta,
+    system_program: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousInitializeConfigV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, authority: Pubkey, admin: Pubkey) -> Self {
+        let valid = InitializeConfigV1Ix::new(program_id, authority, admin);
+        let instruction_data = valid
+            .clone()
+            .to_instruction(false)
+            .expect("Failed to build valid instruction")
+            .data;
+
+        Self {
+            program_id,
+            authority: valid.authority,
+            config: valid.config,
+            program_data: valid.program_data,
+            system_program: valid.system_program,
+            instruction_data,
+        }
+    }
+
+    /// Removes the last byte of the serialized args.
+    #[must_use]
+    pub fn with_truncated_args(mut self) -> Self {
+        self.instruction_data.pop();
+        self
+    }
+
+    /// Sets the config address to a random address.
+    #[must_use]
+    pub fn with_random_config_address(mut self) -> Self {
+        self.config.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Sets the program data address to a specific address.
+    #[must_use]
+    pub fn with_program_data_address(mut self, address: Pubkey) -> Self {
+        self.program_data.pubkey = address;
+        self
+    }
+
+    /// Makes the authority not a signer.
+    #[must_use]
+    pub fn with_authority_not_signer(mut self) -> Self {
+        self.authority.is_signer = false;
+        self
+    }
+
+    /// Makes the config not writable.
+    #[must_use]
+    pub fn with_config_not_writable(mut self) -> Self {
+        self.config.is_writable = false;
+        self
+    }
+
+    /// Sets the system program to a random address.
+    #[must_use]
+    pub fn with_random_system_program(mut self) -> Self {
+        self.system_program.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    #[must_use]
+    pub fn build_with_accounts(self, accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.authority,
+                self.config,
+                self.program_data,
+                self.system_program,
+            ],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `InitializeConfigV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousInitializeConfigV1Tx {
+    program_id: Pubkey,
+    authority_kp: Keypair,
+    admin: Pubkey,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of authority
+}
+
+impl MaliciousInitializeConfigV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        authority_kp: Keypair,
+        admin: Pubkey,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix = InitializeConfigV1Ix::new(program_id, authority_kp.pubkey(), admin);
+        Self {
+            program_id,
+            authority_kp,
+            admin,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with authority
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousInitializeConfigV1Ix) -> MaliciousInitializeConfigV1Ix,
+    {
+        let malicious_ix = MaliciousInitializeConfigV1Ix::from_valid(
+            self.program_id,
+            self.authority_kp.pubkey(),
+            self.admin,
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so authority is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use authority_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.authority_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    pinocchio_counter_client::instructions::InitializeCounterV1Ix,
+    pinocchio_counter_client::{config_v1_account_meta, instructions::InitializeCounterV1Ix},
     solana_hash::Hash,
     solana_instruction::{AccountMeta, Instruction},
     solana_keypair::{Keypair, Signer},
@@ -18,6 +18,7 @@ pub struct MaliciousInitializeCounterV1Ix {
     payer: AccountMeta,
     counter: AccountMeta,
     system_program: AccountMeta,
+    tombstone: AccountMeta,
     instruction_data: Vec<u8>,
 }
 
@@ -31,6 +32,7 @@ impl MaliciousInitializeCounterV1Ix {
             payer: valid.payer,
             counter: valid.counter,
             system_program: valid.system_program,
+            tombstone: valid.tombstone,
             instruction_data: vec![1u8], // InitializeCounterV1 discriminator
         }
     }
@@ -77,9 +79,19 @@ impl MaliciousInitializeCounterV1Ix {
         self
     }
 
+    /// Sets the tombstone to a random address.
+    #[must_use]
+    pub fn with_random_tombstone(mut self) -> Self {
+        self.tombstone.pubkey = Pubkey::new_unique();
+        self
+    }
+
     /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
     #[must_use]
-    pub fn build_with_accounts(self, accounts: Vec<AccountMeta>) -> Instruction {
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
         Instruction {
             program_id: self.program_id,
             accounts,
@@ -92,7 +104,13 @@ impl MaliciousInitializeCounterV1Ix {
     pub fn build(self) -> Instruction {
         Instruction {
             program_id: self.program_id,
-            accounts: vec![self.payer, self.counter, self.system_program],
+            accounts: vec![
+                self.payer,
+                self.counter,
+                self.system_program,
+                self.tombstone,
+                config_v1_account_meta(&self.program_id),
+            ],
             data: self.instruction_data,
         }
     }
@@ -194,7 +212,7 @@ mod tests {
             .with_payer_not_signer()
             .build();
 
-        assert!(!malicious.accounts[0].is_signer);
+        assert!(!malicious.accounts[1].is_signer);
     }
 
     #[test]
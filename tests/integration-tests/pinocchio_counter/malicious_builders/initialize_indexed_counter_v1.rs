@@ -0,0 +1,213 @@
+use {
+    pinocchio_counter_client::{
+        config_v1_account_meta, instructions::InitializeIndexedCounterV1Ix,
+    },
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `InitializeIndexedCounterV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousInitializeIndexedCounterV1Ix {
+    program_id: Pubkey,
+    payer: AccountMeta,
+    counter: AccountMeta,
+    system_program: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousInitializeIndexedCounterV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, payer: Pubkey, index: u32) -> Self {
+        let valid = InitializeIndexedCounterV1Ix::new(program_id, payer, index);
+        // Build a valid instruction to get the serialized args, then extract the data
+        let instruction_data = valid
+            .clone()
+            .to_instruction(false)
+            .expect("Failed to build valid instruction")
+            .data;
+
+        Self {
+            program_id,
+            payer: valid.payer,
+            counter: valid.counter,
+            system_program: valid.system_program,
+            instruction_data,
+        }
+    }
+
+    /// Truncates the instruction arguments so they can no longer be deserialized.
+    #[must_use]
+    pub fn with_truncated_args(mut self) -> Self {
+        self.instruction_data.truncate(2);
+        self
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Makes the payer not a signer.
+    #[must_use]
+    pub fn with_payer_not_signer(mut self) -> Self {
+        self.payer.is_signer = false;
+        self
+    }
+
+    /// Makes the payer not writable.
+    #[must_use]
+    pub fn with_payer_not_writable(mut self) -> Self {
+        self.payer.is_writable = false;
+        self
+    }
+
+    /// Makes the counter not writable.
+    #[must_use]
+    pub fn with_counter_not_writable(mut self) -> Self {
+        self.counter.is_writable = false;
+        self
+    }
+
+    /// Sets the system program to a random address.
+    #[must_use]
+    pub fn with_random_system_program(mut self) -> Self {
+        self.system_program.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.payer,
+                self.counter,
+                self.system_program,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `InitializeIndexedCounterV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousInitializeIndexedCounterV1Tx {
+    program_id: Pubkey,
+    payer_kp: Keypair,
+    index: u32,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of payer
+}
+
+impl MaliciousInitializeIndexedCounterV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        index: u32,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix = InitializeIndexedCounterV1Ix::new(program_id, payer_kp.pubkey(), index);
+        Self {
+            program_id,
+            payer_kp,
+            index,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with payer
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousInitializeIndexedCounterV1Ix) -> MaliciousInitializeIndexedCounterV1Ix,
+    {
+        let malicious_ix = MaliciousInitializeIndexedCounterV1Ix::from_valid(
+            self.program_id,
+            self.payer_kp.pubkey(),
+            self.index,
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so payer is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use payer_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.payer_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
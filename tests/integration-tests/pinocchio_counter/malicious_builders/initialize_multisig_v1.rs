@@ -0,0 +1,261 @@
+use {
+    pinocchio_counter_client::{config_v1_account_meta, instructions::InitializeMultisigV1Ix},
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `InitializeMultisigV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousInitializeMultisigV1Ix {
+    program_id: Pubkey,
+    authority: AccountMeta,
+    counter: AccountMeta,
+    multisig: AccountMeta,
+    system_program: AccountMeta,
+    members: Vec<AccountMeta>,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousInitializeMultisigV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        authority: Pubkey,
+        members: &[Pubkey],
+        threshold: u8,
+    ) -> Self {
+        let valid = InitializeMultisigV1Ix::new(program_id, authority, members, threshold);
+        // Build a valid instruction to get the serialized args, then extract the data
+        let instruction_data = valid
+            .clone()
+            .to_instruction(false)
+            .expect("Failed to build valid instruction")
+            .data;
+
+        Self {
+            program_id,
+            authority: valid.authority,
+            counter: valid.counter,
+            multisig: valid.multisig,
+            system_program: valid.system_program,
+            members: valid.members,
+            instruction_data,
+        }
+    }
+
+    /// Removes the last byte of the serialized args.
+    #[must_use]
+    pub fn with_truncated_args(mut self) -> Self {
+        self.instruction_data.pop();
+        self
+    }
+
+    /// Sets the threshold without checking it against the members.
+    #[must_use]
+    pub fn with_threshold(mut self, threshold: u8) -> Self {
+        self.instruction_data.truncate(1);
+        self.instruction_data.push(threshold);
+        self
+    }
+
+    /// Sets the members without checking for duplicates or the member limit.
+    #[must_use]
+    pub fn with_members(mut self, members: &[Pubkey]) -> Self {
+        self.members = members
+            .iter()
+            .map(|member| AccountMeta::new_readonly(*member, false))
+            .collect();
+        self
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Sets the multisig address to a specific address.
+    #[must_use]
+    pub fn with_multisig_address(mut self, address: Pubkey) -> Self {
+        self.multisig.pubkey = address;
+        self
+    }
+
+    /// Makes the authority not a signer.
+    #[must_use]
+    pub fn with_authority_not_signer(mut self) -> Self {
+        self.authority.is_signer = false;
+        self
+    }
+
+    /// Makes the authority not writable.
+    #[must_use]
+    pub fn with_authority_not_writable(mut self) -> Self {
+        self.authority.is_writable = false;
+        self
+    }
+
+    /// Makes the counter not writable.
+    #[must_use]
+    pub fn with_counter_not_writable(mut self) -> Self {
+        self.counter.is_writable = false;
+        self
+    }
+
+    /// Makes the multisig not writable.
+    #[must_use]
+    pub fn with_multisig_not_writable(mut self) -> Self {
+        self.multisig.is_writable = false;
+        self
+    }
+
+    /// Sets the system program to a random address.
+    #[must_use]
+    pub fn with_random_system_program(mut self) -> Self {
+        self.system_program.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        let mut accounts = vec![
+            self.authority,
+            self.counter,
+            self.multisig,
+            self.system_program,
+        ];
+        accounts.extend(self.members);
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `InitializeMultisigV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousInitializeMultisigV1Tx {
+    program_id: Pubkey,
+    authority_kp: Keypair,
+    members: Vec<Pubkey>,
+    threshold: u8,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of authority
+}
+
+impl MaliciousInitializeMultisigV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        authority_kp: Keypair,
+        members: Vec<Pubkey>,
+        threshold: u8,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix =
+            InitializeMultisigV1Ix::new(program_id, authority_kp.pubkey(), &members, threshold);
+        Self {
+            program_id,
+            authority_kp,
+            members,
+            threshold,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with authority
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousInitializeMultisigV1Ix) -> MaliciousInitializeMultisigV1Ix,
+    {
+        let malicious_ix = MaliciousInitializeMultisigV1Ix::from_valid(
+            self.program_id,
+            self.authority_kp.pubkey(),
+            &self.members,
+            self.threshold,
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so authority is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use authority_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.authority_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
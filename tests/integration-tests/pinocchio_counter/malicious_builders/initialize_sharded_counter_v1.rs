@@ -0,0 +1,228 @@
+use {
+    pinocchio_counter_client::{
+        config_v1_account_meta, instructions::InitializeShardedCounterV1Ix,
+    },
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `InitializeShardedCounterV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousInitializeShardedCounterV1Ix {
+    program_id: Pubkey,
+    payer: AccountMeta,
+    sharded_counter: AccountMeta,
+    system_program: AccountMeta,
+    shards: Vec<AccountMeta>,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousInitializeShardedCounterV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, payer: Pubkey, shard_count: u8) -> Self {
+        let valid = InitializeShardedCounterV1Ix::new(program_id, payer, shard_count);
+        let instruction_data = valid
+            .clone()
+            .to_instruction(false)
+            .expect("Failed to build valid instruction")
+            .data;
+
+        Self {
+            program_id,
+            payer: valid.payer,
+            sharded_counter: valid.sharded_counter,
+            system_program: valid.system_program,
+            shards: valid.shards,
+            instruction_data,
+        }
+    }
+
+    /// Sets the sharded counter address to a specific address.
+    #[must_use]
+    pub fn with_sharded_counter_address(mut self, address: Pubkey) -> Self {
+        self.sharded_counter.pubkey = address;
+        self
+    }
+
+    /// Makes the payer not a signer.
+    #[must_use]
+    pub fn with_payer_not_signer(mut self) -> Self {
+        self.payer.is_signer = false;
+        self
+    }
+
+    /// Makes the payer not writable.
+    #[must_use]
+    pub fn with_payer_not_writable(mut self) -> Self {
+        self.payer.is_writable = false;
+        self
+    }
+
+    /// Makes the sharded counter not writable.
+    #[must_use]
+    pub fn with_sharded_counter_not_writable(mut self) -> Self {
+        self.sharded_counter.is_writable = false;
+        self
+    }
+
+    /// Sets the system program to a random address.
+    #[must_use]
+    pub fn with_random_system_program(mut self) -> Self {
+        self.system_program.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Makes the shard at `position` in the account list not writable.
+    #[must_use]
+    pub fn with_shard_not_writable(mut self, position: usize) -> Self {
+        self.shards[position].is_writable = false;
+        self
+    }
+
+    /// Swaps the shards at two positions in the account list.
+    #[must_use]
+    pub fn with_shards_swapped(mut self, a: usize, b: usize) -> Self {
+        self.shards.swap(a, b);
+        self
+    }
+
+    /// Replaces the shard account list.
+    #[must_use]
+    pub fn with_shards(mut self, shards: Vec<AccountMeta>) -> Self {
+        self.shards = shards;
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        let mut accounts = vec![self.payer, self.sharded_counter, self.system_program];
+        accounts.extend(self.shards);
+        accounts.push(config_v1_account_meta(&self.program_id));
+
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `InitializeShardedCounterV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousInitializeShardedCounterV1Tx {
+    program_id: Pubkey,
+    payer_kp: Keypair,
+    shard_count: u8,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of payer
+}
+
+impl MaliciousInitializeShardedCounterV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        shard_count: u8,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix =
+            InitializeShardedCounterV1Ix::new(program_id, payer_kp.pubkey(), shard_count);
+        Self {
+            program_id,
+            payer_kp,
+            shard_count,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with payer
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousInitializeShardedCounterV1Ix) -> MaliciousInitializeShardedCounterV1Ix,
+    {
+        let malicious_ix = MaliciousInitializeShardedCounterV1Ix::from_valid(
+            self.program_id,
+            self.payer_kp.pubkey(),
+            self.shard_count,
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so payer is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use payer_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.payer_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
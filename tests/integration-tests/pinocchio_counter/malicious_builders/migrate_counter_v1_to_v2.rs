@@ -0,0 +1,225 @@
+use {
+    pinocchio_counter_client::{config_v1_account_meta, instructions::MigrateCounterV1ToV2Ix},
+    pinocchio_counter_program::InstructionDiscriminator,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `MigrateCounterV1ToV2` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousMigrateCounterV1ToV2Ix {
+    program_id: Pubkey,
+    payer: AccountMeta,
+    owner: AccountMeta,
+    counter: AccountMeta,
+    system_program: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousMigrateCounterV1ToV2Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, payer: Pubkey, owner: Pubkey) -> Self {
+        let valid = MigrateCounterV1ToV2Ix::new(program_id, payer, owner);
+        Self {
+            program_id,
+            payer: valid.payer,
+            owner: valid.owner,
+            counter: valid.counter,
+            system_program: valid.system_program,
+            instruction_data: vec![InstructionDiscriminator::MigrateCounterV1ToV2.into()],
+        }
+    }
+
+    /// Sets the counter address to a random address.
+    #[must_use]
+    pub fn with_random_counter_address(mut self) -> Self {
+        self.counter.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Sets the counter address to a specific address.
+    #[must_use]
+    pub fn with_counter_address(mut self, address: Pubkey) -> Self {
+        self.counter.pubkey = address;
+        self
+    }
+
+    /// Makes the payer not a signer.
+    #[must_use]
+    pub fn with_payer_not_signer(mut self) -> Self {
+        self.payer.is_signer = false;
+        self
+    }
+
+    /// Makes the payer not writable.
+    #[must_use]
+    pub fn with_payer_not_writable(mut self) -> Self {
+        self.payer.is_writable = false;
+        self
+    }
+
+    /// Makes the owner not a signer.
+    #[must_use]
+    pub fn with_owner_not_signer(mut self) -> Self {
+        self.owner.is_signer = false;
+        self
+    }
+
+    /// Makes the counter not writable.
+    #[must_use]
+    pub fn with_counter_not_writable(mut self) -> Self {
+        self.counter.is_writable = false;
+        self
+    }
+
+    /// Sets the system program to a random address.
+    #[must_use]
+    pub fn with_random_system_program(mut self) -> Self {
+        self.system_program.pubkey = Pubkey::new_unique();
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
+    #[must_use]
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                self.payer,
+                self.owner,
+                self.counter,
+                self.system_program,
+                config_v1_account_meta(&self.program_id),
+            ],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `MigrateCounterV1ToV2` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousMigrateCounterV1ToV2Tx {
+    program_id: Pubkey,
+    payer_kp: Keypair,
+    owner_kp: Keypair,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair as fee payer instead of payer
+}
+
+impl MaliciousMigrateCounterV1ToV2Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        payer_kp: Keypair,
+        owner_kp: Keypair,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix =
+            MigrateCounterV1ToV2Ix::new(program_id, payer_kp.pubkey(), owner_kp.pubkey());
+        Self {
+            program_id,
+            payer_kp,
+            owner_kp,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: payer pays the fee
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousMigrateCounterV1ToV2Ix) -> MaliciousMigrateCounterV1ToV2Ix,
+    {
+        let malicious_ix = MaliciousMigrateCounterV1ToV2Ix::from_valid(
+            self.program_id,
+            self.payer_kp.pubkey(),
+            self.owner_kp.pubkey(),
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair as the fee payer.
+    /// The `signer_kp` must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// The payer and owner only sign when the instruction requires their signature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        let fee_payer = self.signer_kp.as_ref().unwrap_or(&self.payer_kp);
+        let fee_payer_pk = fee_payer.pubkey();
+
+        let mut signers = vec![fee_payer];
+        for kp in [&self.payer_kp, &self.owner_kp] {
+            let must_sign = self
+                .instruction
+                .accounts
+                .iter()
+                .any(|account| account.pubkey == kp.pubkey() && account.is_signer);
+            if must_sign && kp.pubkey() != fee_payer_pk {
+                signers.push(kp);
+            }
+        }
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &signers).expect("Failed to create transaction")
+    }
+}
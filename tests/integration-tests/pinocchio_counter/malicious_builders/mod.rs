@@ -1,15 +1,99 @@
+pub mod accept_authority_v1;
+pub mod approve_delegate_v1;
+pub mod close_counter_v1;
+pub mod compare_and_increment_count_v1;
+pub mod compare_and_set_count_v1;
 pub mod deactivate_counter_v1;
+pub mod deactivate_indexed_counter_v1;
+pub mod decrement_by_v1;
 pub mod decrement_count_v1;
+pub mod decrement_indexed_counter_v1;
+pub mod execute_batch_v1;
+pub mod freeze_counter_v1;
+pub mod get_count_v1;
+pub mod increment_by_v1;
 pub mod increment_count_v1;
+pub mod increment_counter_shard_v1;
+pub mod increment_indexed_counter_v1;
+pub mod initialize_config_v1;
 pub mod initialize_counter_v1;
+pub mod initialize_indexed_counter_v1;
+pub mod initialize_multisig_v1;
+pub mod initialize_sharded_counter_v1;
+pub mod initialize_sponsored_counter_v1;
+pub mod migrate_counter_v1_to_v2;
+pub mod propose_authority_v1;
 pub mod reactivate_counter_v1;
+pub mod reactivate_indexed_counter_v1;
+pub mod reactivate_sponsored_counter_v1;
+pub mod reconcile_shards_v1;
+pub mod revoke_delegate_v1;
+pub mod set_access_mode_v1;
+pub mod set_cooldown_v1;
+pub mod set_count_policy_v1;
 pub mod set_count_v1;
+pub mod set_indexed_count_v1;
+pub mod set_pause_exemption_v1;
+pub mod set_paused_v1;
+pub mod thaw_counter_v1;
 
 pub use {
+    accept_authority_v1::{MaliciousAcceptAuthorityV1Ix, MaliciousAcceptAuthorityV1Tx},
+    approve_delegate_v1::{MaliciousApproveDelegateV1Ix, MaliciousApproveDelegateV1Tx},
+    close_counter_v1::{MaliciousCloseCounterV1Ix, MaliciousCloseCounterV1Tx},
+    compare_and_increment_count_v1::{
+        MaliciousCompareAndIncrementCountV1Ix, MaliciousCompareAndIncrementCountV1Tx,
+    },
+    compare_and_set_count_v1::{MaliciousCompareAndSetCountV1Ix, MaliciousCompareAndSetCountV1Tx},
     deactivate_counter_v1::{MaliciousDeactivateCounterV1Ix, MaliciousDeactivateCounterV1Tx},
+    deactivate_indexed_counter_v1::{
+        MaliciousDeactivateIndexedCounterV1Ix, MaliciousDeactivateIndexedCounterV1Tx,
+    },
+    decrement_by_v1::{MaliciousDecrementByV1Ix, MaliciousDecrementByV1Tx},
     decrement_count_v1::{MaliciousDecrementCountV1Ix, MaliciousDecrementCountV1Tx},
+    decrement_indexed_counter_v1::{
+        MaliciousDecrementIndexedCounterV1Ix, MaliciousDecrementIndexedCounterV1Tx,
+    },
+    execute_batch_v1::{MaliciousExecuteBatchV1Ix, MaliciousExecuteBatchV1Tx},
+    freeze_counter_v1::{MaliciousFreezeCounterV1Ix, MaliciousFreezeCounterV1Tx},
+    get_count_v1::{MaliciousGetCountV1Ix, MaliciousGetCountV1Tx},
+    increment_by_v1::{MaliciousIncrementByV1Ix, MaliciousIncrementByV1Tx},
     increment_count_v1::{MaliciousIncrementCountV1Ix, MaliciousIncrementCountV1Tx},
+    increment_counter_shard_v1::{
+        MaliciousIncrementCounterShardV1Ix, MaliciousIncrementCounterShardV1Tx,
+    },
+    increment_indexed_counter_v1::{
+        MaliciousIncrementIndexedCounterV1Ix, MaliciousIncrementIndexedCounterV1Tx,
+    },
+    initialize_config_v1::{MaliciousInitializeConfigV1Ix, MaliciousInitializeConfigV1Tx},
     initialize_counter_v1::{MaliciousInitializeCounterV1Ix, MaliciousInitializeCounterV1Tx},
+    initialize_indexed_counter_v1::{
+        MaliciousInitializeIndexedCounterV1Ix, MaliciousInitializeIndexedCounterV1Tx,
+    },
+    initialize_multisig_v1::{MaliciousInitializeMultisigV1Ix, MaliciousInitializeMultisigV1Tx},
+    initialize_sharded_counter_v1::{
+        MaliciousInitializeShardedCounterV1Ix, MaliciousInitializeShardedCounterV1Tx,
+    },
+    initialize_sponsored_counter_v1::{
+        MaliciousInitializeSponsoredCounterV1Ix, MaliciousInitializeSponsoredCounterV1Tx,
+    },
+    migrate_counter_v1_to_v2::{MaliciousMigrateCounterV1ToV2Ix, MaliciousMigrateCounterV1ToV2Tx},
+    propose_authority_v1::{MaliciousProposeAuthorityV1Ix, MaliciousProposeAuthorityV1Tx},
     reactivate_counter_v1::{MaliciousReactivateCounterV1Ix, MaliciousReactivateCounterV1Tx},
+    reactivate_indexed_counter_v1::{
+        MaliciousReactivateIndexedCounterV1Ix, MaliciousReactivateIndexedCounterV1Tx,
+    },
+    reactivate_sponsored_counter_v1::{
+        MaliciousReactivateSponsoredCounterV1Ix, MaliciousReactivateSponsoredCounterV1Tx,
+    },
+    reconcile_shards_v1::{MaliciousReconcileShardsV1Ix, MaliciousReconcileShardsV1Tx},
+    revoke_delegate_v1::{MaliciousRevokeDelegateV1Ix, MaliciousRevokeDelegateV1Tx},
+    set_access_mode_v1::{MaliciousSetAccessModeV1Ix, MaliciousSetAccessModeV1Tx},
+    set_cooldown_v1::{MaliciousSetCooldownV1Ix, MaliciousSetCooldownV1Tx},
+    set_count_policy_v1::{MaliciousSetCountPolicyV1Ix, MaliciousSetCountPolicyV1Tx},
     set_count_v1::{MaliciousSetCountV1Ix, MaliciousSetCountV1Tx},
+    set_indexed_count_v1::{MaliciousSetIndexedCountV1Ix, MaliciousSetIndexedCountV1Tx},
+    set_pause_exemption_v1::{MaliciousSetPauseExemptionV1Ix, MaliciousSetPauseExemptionV1Tx},
+    set_paused_v1::{MaliciousSetPausedV1Ix, MaliciousSetPausedV1Tx},
+    thaw_counter_v1::{MaliciousThawCounterV1Ix, MaliciousThawCounterV1Tx},
 };
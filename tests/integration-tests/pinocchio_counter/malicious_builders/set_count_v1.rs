@@ -1,5 +1,5 @@
 use {
-    pinocchio_counter_client::instructions::SetCountV1Ix,
+    pinocchio_counter_client::{config_v1_account_meta, instructions::SetCountV1Ix},
     solana_hash::Hash,
     solana_instruction::{AccountMeta, Instruction},
     solana_keypair::{Keypair, Signer},
@@ -94,8 +94,11 @@ impl MaliciousSetCountV1Ix {
     }
 
     /// Builds the malicious instruction with a custom account list.
+    ///
+    /// The config account is appended to `accounts`, since the program always expects it last.
     #[must_use]
-    pub fn build_with_accounts(self, accounts: Vec<AccountMeta>) -> Instruction {
+    pub fn build_with_accounts(self, mut accounts: Vec<AccountMeta>) -> Instruction {
+        accounts.push(config_v1_account_meta(&self.program_id));
         Instruction {
             program_id: self.program_id,
             accounts,
@@ -108,7 +111,11 @@ impl MaliciousSetCountV1Ix {
     pub fn build(self) -> Instruction {
         Instruction {
             program_id: self.program_id,
-            accounts: vec![self.owner, self.counter],
+            accounts: vec![
+                self.owner,
+                self.counter,
+                config_v1_account_meta(&self.program_id),
+            ],
             data: self.instruction_data,
         }
     }
@@ -0,0 +1,186 @@
+use {
+    pinocchio_counter_client::instructions::SetPausedV1Ix,
+    solana_hash::Hash,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::v0,
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, VersionedMessage},
+};
+
+/// Builder for creating malicious `SetPausedV1` instructions.
+///
+/// This builder allows you to start from a valid instruction and mutate
+/// specific properties to test security boundaries.
+#[derive(Debug, Clone)]
+pub struct MaliciousSetPausedV1Ix {
+    program_id: Pubkey,
+    admin: AccountMeta,
+    config: AccountMeta,
+    instruction_data: Vec<u8>,
+}
+
+impl MaliciousSetPausedV1Ix {
+    /// Creates a new malicious instruction builder starting from a valid instruction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(program_id: Pubkey, admin: Pubkey, paused: bool) -> Self {
+        let valid = SetPausedV1Ix::new(program_id, admin, paused);
+        let instruction_data = valid
+            .clone()
+            .to_instruction(false)
+            .expect("Failed to build valid instruction")
+            .data;
+
+        Self {
+            program_id,
+            admin: valid.admin,
+            config: valid.config,
+            instruction_data,
+        }
+    }
+
+    /// Removes the last byte of the serialized args.
+    #[must_use]
+    pub fn with_truncated_args(mut self) -> Self {
+        self.instruction_data.pop();
+        self
+    }
+
+    /// Sets the config address to a specific address.
+    #[must_use]
+    pub fn with_config_address(mut self, address: Pubkey) -> Self {
+        self.config.pubkey = address;
+        self
+    }
+
+    /// Makes the admin not a signer.
+    #[must_use]
+    pub fn with_admin_not_signer(mut self) -> Self {
+        self.admin.is_signer = false;
+        self
+    }
+
+    /// Makes the config not writable.
+    #[must_use]
+    pub fn with_config_not_writable(mut self) -> Self {
+        self.config.is_writable = false;
+        self
+    }
+
+    /// Builds the malicious instruction with a custom account list.
+    #[must_use]
+    pub fn build_with_accounts(self, accounts: Vec<AccountMeta>) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data: self.instruction_data,
+        }
+    }
+
+    /// Builds the malicious instruction.
+    #[must_use]
+    pub fn build(self) -> Instruction {
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![self.admin, self.config],
+            data: self.instruction_data,
+        }
+    }
+}
+
+/// Builder for creating malicious `SetPausedV1` transactions.
+///
+/// This builder allows you to create transactions with malicious instructions
+/// or transaction-level attacks.
+#[derive(Debug)]
+pub struct MaliciousSetPausedV1Tx {
+    program_id: Pubkey,
+    admin_kp: Keypair,
+    paused: bool,
+    recent_blockhash: Hash,
+    instruction: Instruction,
+    signer_kp: Option<Keypair>, // If Some, use this keypair to sign instead of admin
+}
+
+impl MaliciousSetPausedV1Tx {
+    /// Creates a new malicious transaction builder starting from a valid transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if building the instruction fails.
+    #[must_use]
+    pub fn from_valid(
+        program_id: Pubkey,
+        admin_kp: Keypair,
+        paused: bool,
+        recent_blockhash: Hash,
+    ) -> Self {
+        let valid_ix = SetPausedV1Ix::new(program_id, admin_kp.pubkey(), paused);
+        Self {
+            program_id,
+            admin_kp,
+            paused,
+            recent_blockhash,
+            instruction: valid_ix.to_instruction(false).unwrap(),
+            signer_kp: None, // Default: sign with admin
+        }
+    }
+
+    /// Uses a malicious instruction builder to create the instruction.
+    #[must_use]
+    pub fn with_malicious_instruction<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(MaliciousSetPausedV1Ix) -> MaliciousSetPausedV1Ix,
+    {
+        let malicious_ix = MaliciousSetPausedV1Ix::from_valid(
+            self.program_id,
+            self.admin_kp.pubkey(),
+            self.paused,
+        );
+        self.instruction = f(malicious_ix).build();
+        self
+    }
+
+    /// Uses a custom instruction.
+    #[must_use]
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.instruction = instruction;
+        self
+    }
+
+    /// Uses a different keypair to sign the transaction (so admin is not a signer).
+    /// The `signer_kp` will be the fee payer and must have funds.
+    #[must_use]
+    pub fn with_different_signer(mut self, signer_kp: Keypair) -> Self {
+        self.signer_kp = Some(signer_kp);
+        self
+    }
+
+    /// Builds the malicious transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if message compilation or transaction creation fails.
+    #[must_use]
+    pub fn build(self) -> VersionedTransaction {
+        // Use signer_kp if provided, otherwise use admin_kp
+        let signer = self.signer_kp.as_ref().unwrap_or(&self.admin_kp);
+        let fee_payer_pk = signer.pubkey();
+
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &fee_payer_pk,
+                &[self.instruction],
+                &[],
+                self.recent_blockhash,
+            )
+            .expect("Failed to compile message"),
+        );
+
+        VersionedTransaction::try_new(message, &[signer]).expect("Failed to create transaction")
+    }
+}
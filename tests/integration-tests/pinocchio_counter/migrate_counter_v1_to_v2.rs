@@ -0,0 +1,408 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousMigrateCounterV1ToV2Ix, MaliciousMigrateCounterV1ToV2Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1, find_counter_v1_address,
+        transactions::{
+            DeactivateCounterV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            MigrateCounterV1ToV2SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccessMode, AccountDiscriminator, CountPolicy, CounterV2},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_rent::Rent,
+};
+
+/// Initializes a `CounterV1` for the owner and increments it `increments` times.
+fn setup_counter_v1(ctx: &mut TestContext, owner_kp: &Keypair, increments: u64) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    for _ in 0..increments {
+        ctx.advance_slot(1)?;
+        let increment_tx = IncrementCountV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?;
+        let tx_result = ctx.send_transaction(increment_tx);
+        demand_tx_success(&tx_result);
+    }
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 3)?;
+
+    let owner_lamports_before = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+
+    let migrate_tx = MigrateCounterV1ToV2SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(migrate_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 3);
+
+    let (counter_pk, bump) = find_counter_v1(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+
+    assert_eq!(counter_account.owner, ctx.program_id());
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+    assert_eq!(
+        counter_account.lamports,
+        Rent::default().minimum_balance(CounterV2::size())
+    );
+
+    let counter = CounterV2::deserialize(&counter_account.data)?;
+    let policy = CountPolicy::default();
+    assert_eq!(
+        counter.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.bump, bump);
+    assert_eq!(counter.count, 3);
+    assert_eq!(counter.overflow_policy, policy.overflow_policy);
+    assert_eq!(counter.min_count, policy.min_count);
+    assert_eq!(counter.max_count, policy.max_count);
+    assert_eq!(counter.authority, owner_pk.to_bytes());
+    assert_eq!(counter.access_mode, AccessMode::Private);
+    assert!(!counter.frozen);
+    assert_eq!(counter.rent_payer(), None);
+
+    // The payer covers the additional rent and the fee, while the owner only signs
+    let owner_lamports_after = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+    assert_eq!(owner_lamports_after, owner_lamports_before);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_owner_pays() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 1)?;
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account not found")?;
+    let counter = CounterV2::deserialize(&counter_account.data)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn migrated_counter_accepts_v1_instructions() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 2)?;
+
+    let migrate_tx = MigrateCounterV1ToV2SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(migrate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 3);
+
+    ctx.advance_slot(1)?;
+
+    // Deactivation refunds the owner, since the payer only topped up the rent
+    let owner_lamports_before = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+
+    let deactivate_tx =
+        DeactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let owner_lamports_after = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+    assert!(owner_lamports_after > owner_lamports_before);
+
+    Ok(())
+}
+
+// ============================================================================
+// Malicious Transaction Tests - Account Validation Failures
+// ============================================================================
+
+#[test]
+fn fails_when_payer_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousMigrateCounterV1ToV2Ix::with_payer_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2302", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousMigrateCounterV1ToV2Ix::with_owner_not_signer)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2304", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousMigrateCounterV1ToV2Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2305", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_owned_by_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let other_kp = ctx.create_funded_keypair();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 0)?;
+
+    // Another signer tries to migrate the owner's counter
+    let owner_counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        other_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(owner_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2309", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let migrate_tx = MigrateCounterV1ToV2SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(migrate_tx);
+    demand_tx_failure(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_already_migrated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 0)?;
+
+    let migrate_tx = MigrateCounterV1ToV2SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp.insecure_clone(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(migrate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let migrate_tx = MigrateCounterV1ToV2SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(migrate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x230a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter_v1(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousMigrateCounterV1ToV2Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x230b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_ix =
+        MaliciousMigrateCounterV1ToV2Ix::from_valid(ctx.program_id(), payer_pk, owner_kp.pubkey());
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: payer_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        // Missing owner, counter and system_program - only 1 account instead of 4
+    ]);
+
+    let malicious_tx = MaliciousMigrateCounterV1ToV2Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2301", &tx_result);
+
+    Ok(())
+}
@@ -1,52 +1,243 @@
 use {
     crate::clock_utils::{advance_clock, ClockAdvanceError},
     litesvm::{error::LiteSVMError, types::TransactionResult, LiteSVM},
+    pinocchio_counter_client::{
+        events::{parse_counter_events, CounterEvent, CounterEventDecodeError},
+        find_program_data_address,
+        return_data::decode_count_return_data,
+        transactions::InitializeConfigV1SimpleTx,
+    },
+    pinocchio_counter_program::{CountReturnDataV1, BPF_LOADER_UPGRADEABLE_ID},
     solana_account::Account,
     solana_clock::Clock,
     solana_hash::Hash,
     solana_keypair::{Keypair, Signer},
     solana_pubkey::Pubkey,
     solana_transaction::versioned::VersionedTransaction,
-    std::path::PathBuf,
+    std::path::{Path, PathBuf},
 };
 
+pub mod accept_authority_v1;
+pub mod approve_delegate_v1;
+pub mod close_counter_v1;
+pub mod compare_and_increment_count_v1;
+pub mod compare_and_set_count_v1;
+pub mod compute_units;
+pub mod counter_timestamps;
 pub mod deactivate_counter_v1;
+pub mod deactivate_indexed_counter_v1;
+pub mod decrement_by_v1;
 pub mod decrement_count_v1;
+pub mod decrement_indexed_counter_v1;
+pub mod execute_batch_v1;
+pub mod freeze_counter_v1;
+pub mod get_count_v1;
+pub mod increment_by_v1;
 pub mod increment_count_v1;
+pub mod increment_counter_shard_v1;
+pub mod increment_indexed_counter_v1;
+pub mod initialize_config_v1;
 pub mod initialize_counter_v1;
+pub mod initialize_indexed_counter_v1;
+pub mod initialize_multisig_v1;
+pub mod initialize_sharded_counter_v1;
+pub mod initialize_sponsored_counter_v1;
+pub mod lazy_entrypoint;
 pub mod malicious_builders;
+pub mod migrate_counter_v1_to_v2;
+pub mod multisig_counter;
+pub mod propose_authority_v1;
 pub mod reactivate_counter_v1;
+pub mod reactivate_indexed_counter_v1;
+pub mod reactivate_sponsored_counter_v1;
+pub mod reconcile_shards_v1;
+pub mod revoke_delegate_v1;
+pub mod set_access_mode_v1;
+pub mod set_cooldown_v1;
+pub mod set_count_policy_v1;
 pub mod set_count_v1;
+pub mod set_indexed_count_v1;
+pub mod set_pause_exemption_v1;
+pub mod set_paused_v1;
+pub mod sharded_counter_load;
+pub mod thaw_counter_v1;
+
+/// Environment variable overriding the path of the program build loaded by [`TestContext`].
+pub const PROGRAM_PATH_ENV_VAR: &str = "PINOCCHIO_COUNTER_PROGRAM_PATH";
+
+/// Path of the `cargo build-sbf` output, relative to this crate.
+pub const DEFAULT_PROGRAM_PATH: &str =
+    "../../target/sbpf-solana-solana/release/pinocchio_counter_program.so";
+
+/// Path of the `lazy-entrypoint` build written by `scripts/build-pinocchio-counter`, relative to
+/// this crate.
+pub const LAZY_ENTRYPOINT_PROGRAM_PATH: &str =
+    "../../target/lazy-entrypoint/sbpf-solana-solana/release/pinocchio_counter_program.so";
 
 pub type TestResult = Result<(), Box<dyn std::error::Error>>;
 
+/// Size of the `UpgradeableLoaderState::Program` account data.
+const PROGRAM_ACCOUNT_SIZE: usize = 36;
+
+/// Size of the `UpgradeableLoaderState::ProgramData` header that precedes the program's ELF.
+const PROGRAM_DATA_METADATA_SIZE: usize = 45;
+
+/// Deploys `program_bytes` at `program_id` through the BPF upgradeable loader.
+///
+/// LiteSVM's own helpers deploy through the non-upgradeable loader, which has no program data
+/// account, so the program and program data accounts are written by hand in the loader's
+/// `bincode` layout. The program data account must exist before the program account, since
+/// LiteSVM compiles the program when the executable account is stored.
+fn add_upgradeable_program(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    program_bytes: &[u8],
+    upgrade_authority: &Pubkey,
+) -> Result<(), LiteSVMError> {
+    let loader_id = Pubkey::new_from_array(BPF_LOADER_UPGRADEABLE_ID);
+    let program_data_pk = find_program_data_address(&program_id);
+
+    let mut program_data = Vec::with_capacity(PROGRAM_DATA_METADATA_SIZE + program_bytes.len());
+    program_data.extend_from_slice(&3u32.to_le_bytes());
+    program_data.extend_from_slice(&0u64.to_le_bytes());
+    program_data.push(1);
+    program_data.extend_from_slice(upgrade_authority.as_ref());
+    program_data.extend_from_slice(program_bytes);
+
+    svm.set_account(
+        program_data_pk,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(program_data.len()),
+            data: program_data,
+            owner: loader_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )?;
+
+    let mut program = Vec::with_capacity(PROGRAM_ACCOUNT_SIZE);
+    program.extend_from_slice(&2u32.to_le_bytes());
+    program.extend_from_slice(program_data_pk.as_ref());
+
+    svm.set_account(
+        program_id,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(program.len()),
+            data: program,
+            owner: loader_id,
+            executable: true,
+            rent_epoch: 0,
+        },
+    )
+}
+
+/// Returns the path of the program build loaded by [`TestContext::try_new`].
+fn program_path() -> PathBuf {
+    std::env::var_os(PROGRAM_PATH_ENV_VAR).map_or_else(
+        || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_PROGRAM_PATH),
+        PathBuf::from,
+    )
+}
+
 pub struct TestContext {
     svm: LiteSVM,
     program_id: Pubkey,
+    upgrade_authority_kp: Keypair,
+    admin_kp: Keypair,
 }
 
 impl TestContext {
-    /// Creates a new test context with a loaded program.
+    /// Creates a new test context with a loaded program and an unpaused config.
+    ///
+    /// The program is loaded from the `cargo build-sbf` output, unless
+    /// [`PROGRAM_PATH_ENV_VAR`] points to another build, such as the `lazy-entrypoint` one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the program file cannot be found or loaded, or if the config cannot be
+    /// initialized.
+    pub fn try_new() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::try_new_with_program(&program_path())
+    }
+
+    /// Creates a new test context with the program build at `program_path` and an unpaused
+    /// config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the program file cannot be found or loaded, or if the config cannot be
+    /// initialized.
+    pub fn try_new_with_program(program_path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut ctx = Self::try_new_without_config_with_program(program_path)?;
+
+        let init_config_tx = InitializeConfigV1SimpleTx::try_new(
+            ctx.program_id,
+            ctx.upgrade_authority_kp.insecure_clone(),
+            ctx.admin_kp.pubkey(),
+            ctx.latest_blockhash(),
+        )?;
+        ctx.send_transaction(init_config_tx)
+            .map_err(|err| format!("Failed to initialize config: {:?}", err.err))?;
+
+        Ok(ctx)
+    }
+
+    /// Creates a new test context with a loaded program whose config has not been initialized.
+    ///
+    /// The program is deployed through the BPF upgradeable loader, the way `solana program
+    /// deploy` does, so that `InitializeConfigV1` can check the upgrade authority against its
+    /// program data account.
     ///
     /// # Errors
     ///
     /// Returns an error if the program file cannot be found or loaded.
-    pub fn try_new() -> Result<Self, LiteSVMError> {
+    pub fn try_new_without_config() -> Result<Self, Box<dyn std::error::Error>> {
+        Self::try_new_without_config_with_program(&program_path())
+    }
+
+    fn try_new_without_config_with_program(
+        program_path: &Path,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let mut litesvm = LiteSVM::new();
         let program_id = Pubkey::new_unique();
 
-        let program_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("../../target/sbpf-solana-solana/release/pinocchio_counter_program.so")
-            .canonicalize()?;
+        let program_bytes = std::fs::read(program_path.canonicalize()?)?;
 
-        litesvm.add_program_from_file(program_id, program_path)?;
+        let upgrade_authority_kp = Keypair::new();
+        let admin_kp = Keypair::new();
+        for pubkey in [upgrade_authority_kp.pubkey(), admin_kp.pubkey()] {
+            litesvm
+                .airdrop(&pubkey, 1_000_000_000)
+                .map_err(|err| format!("Failed to airdrop lamports: {:?}", err.err))?;
+        }
+
+        add_upgradeable_program(
+            &mut litesvm,
+            program_id,
+            &program_bytes,
+            &upgrade_authority_kp.pubkey(),
+        )?;
 
         Ok(Self {
             svm: litesvm,
             program_id,
+            upgrade_authority_kp,
+            admin_kp,
         })
     }
 
+    /// Returns the keypair of the program's upgrade authority, which is funded.
+    #[must_use]
+    pub fn upgrade_authority_kp(&self) -> Keypair {
+        self.upgrade_authority_kp.insecure_clone()
+    }
+
+    /// Returns the keypair of the config admin, which is funded.
+    #[must_use]
+    pub fn admin_kp(&self) -> Keypair {
+        self.admin_kp.insecure_clone()
+    }
+
     pub fn advance_slot(&mut self, n_slots: u64) -> Result<(), ClockAdvanceError> {
         let current_clock = self.svm.get_sysvar::<Clock>();
         let new_clock = advance_clock(&current_clock, n_slots)?;
@@ -86,6 +277,32 @@ impl TestContext {
         self.svm.set_account(pubkey, data)
     }
 
+    /// Copies an existing account to a new unique address and returns that address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source account does not exist or the copy cannot be stored.
+    pub fn copy_account_to_unique_address(
+        &mut self,
+        pubkey: Pubkey,
+    ) -> Result<Pubkey, Box<dyn std::error::Error>> {
+        let account = self.get_account(pubkey).ok_or("Account should exist")?;
+        let copy_pk = Pubkey::new_unique();
+        self.set_account(copy_pk, account)?;
+        Ok(copy_pk)
+    }
+
+    #[must_use]
+    pub fn current_slot(&self) -> u64 {
+        self.svm.get_sysvar::<Clock>().slot
+    }
+
+    /// Returns the current `Clock` sysvar, as set by [`TestContext::advance_slot`].
+    #[must_use]
+    pub fn current_clock(&self) -> Clock {
+        self.svm.get_sysvar::<Clock>()
+    }
+
     #[must_use]
     pub fn latest_blockhash(&self) -> Hash {
         self.svm.latest_blockhash()
@@ -105,4 +322,53 @@ impl TestContext {
     pub fn send_transaction(&mut self, tx: impl Into<VersionedTransaction>) -> TransactionResult {
         self.svm.send_transaction(tx)
     }
+
+    /// Parses the counter events emitted by the program from a transaction's logs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the program logged data that is not a valid counter event.
+    pub fn counter_events(
+        &self,
+        tx_result: &TransactionResult,
+    ) -> Result<Vec<CounterEvent>, CounterEventDecodeError> {
+        let logs = match tx_result {
+            Ok(meta) => &meta.logs,
+            Err(meta) => &meta.meta.logs,
+        };
+        parse_counter_events(&self.program_id, logs)
+    }
+
+    /// Decodes the count returned by the program from a successful transaction's return data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction failed or its return data was not set by the program.
+    pub fn count_return_data(
+        &self,
+        tx_result: &TransactionResult,
+    ) -> Result<CountReturnDataV1, Box<dyn std::error::Error>> {
+        let meta = tx_result
+            .as_ref()
+            .map_err(|err| format!("Transaction failed: {:?}", err.err))?;
+        Ok(decode_count_return_data(
+            &self.program_id,
+            &meta.return_data,
+        )?)
+    }
+
+    /// Returns the compute units consumed by a successful transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction failed.
+    pub fn compute_units_consumed(
+        &self,
+        tx_result: &TransactionResult,
+    ) -> Result<u64, Box<dyn std::error::Error>> {
+        let meta = tx_result
+            .as_ref()
+            .map_err(|err| format!("Transaction failed: {:?}", err.err))?;
+        Ok(meta.compute_units_consumed)
+    }
 }
@@ -0,0 +1,968 @@
+//! Checks that a counter whose authority was handed to a multisig with `InitializeMultisigV1`
+//! only accepts updates approved by at least `threshold` distinct members.
+//!
+//! Each test creates a counter with a 2-of-3 multisig. The members sign as extra accounts after
+//! the instruction's own accounts, while the multisig record itself does not sign.
+
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{TestContext, TestResult},
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1_address, find_delegate_v1_address, find_multisig_v1_address,
+        find_tombstone_v1_address,
+        instructions::{
+            CloseCounterV1Ix, CompareAndSetCountV1Ix, DecrementByV1Ix, ExecuteBatchV1Ix,
+            IncrementCountV1Ix, SetCountV1Ix,
+        },
+        transactions::{
+            AcceptAuthorityV1SimpleTx, ApproveDelegateV1SimpleTx, CloseCounterV1SimpleTx,
+            DeactivateCounterV1SimpleTx, FreezeCounterV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, InitializeMultisigV1SimpleTx, ProposeAuthorityV1SimpleTx,
+            ReactivateCounterV1SimpleTx, RevokeDelegateV1SimpleTx, SetAccessModeV1SimpleTx,
+            SetCooldownV1SimpleTx, SetCountPolicyV1SimpleTx, ThawCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{
+        AccessMode, CounterV2, DeactivatedCounterV1, DelegateV1, OverflowPolicy, MAX_COUNTER_SHARDS,
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_pubkey::Pubkey,
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+/// Number of members that must sign, out of [`MEMBER_COUNT`].
+const THRESHOLD: u8 = 2;
+
+const MEMBER_COUNT: usize = 3;
+
+/// Creates a counter for a new owner and hands its authority to a multisig of new members.
+fn initialize_multisig_counter(
+    ctx: &mut TestContext,
+) -> Result<(Keypair, Vec<Keypair>), Box<dyn std::error::Error>> {
+    let owner_kp = ctx.create_funded_keypair();
+    let member_kps: Vec<Keypair> = (0..MEMBER_COUNT)
+        .map(|_| ctx.create_funded_keypair())
+        .collect();
+    let member_pks: Vec<Pubkey> = member_kps.iter().map(Keypair::pubkey).collect();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let init_multisig_tx = InitializeMultisigV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        owner_kp.insecure_clone(),
+        &member_pks,
+        THRESHOLD,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_multisig_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok((owner_kp, member_kps))
+}
+
+/// Sends `instruction` signed by `signer_kps`, the first of which pays for the transaction.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kps: &[&Keypair],
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kps[0].pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, signer_kps)?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_counter(
+    ctx: &TestContext,
+    owner: &Pubkey,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), owner);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Multisig Counter Tests
+// ============================================================================
+
+#[test]
+fn increment_succeeds_with_exactly_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[
+            member_kps[0].insecure_clone(),
+            member_kps[2].insecure_clone(),
+        ],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_kp.pubkey())?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn increment_succeeds_with_every_member_signing() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &member_kps,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_kp.pubkey())?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn other_count_updates_succeed_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+    let signer_pks = [member_kps[0].pubkey(), member_kps[1].pubkey()];
+    let signer_kps = [&member_kps[0], &member_kps[1]];
+
+    let instruction = SetCountV1Ix::new(ctx.program_id(), owner_pk, 10)
+        .with_multisig_signers(&signer_pks)
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &signer_kps)?;
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let instruction = DecrementByV1Ix::new(ctx.program_id(), owner_pk, 3)
+        .with_multisig_signers(&signer_pks)
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &signer_kps)?;
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let instruction = CompareAndSetCountV1Ix::new(ctx.program_id(), owner_pk, 7, 20)
+        .with_multisig_signers(&signer_pks)
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &signer_kps)?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_pk)?.count, 20);
+
+    Ok(())
+}
+
+#[test]
+fn batch_succeeds_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+
+    let instruction = ExecuteBatchV1Ix::new(ctx.program_id(), owner_pk)
+        .set(10)
+        .increment()
+        .decrement_by(4)
+        .with_multisig_signers(&[member_kps[1].pubkey(), member_kps[2].pubkey()])
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[1], &member_kps[2]])?;
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_pk)?.count, 7);
+
+    Ok(())
+}
+
+#[test]
+fn members_can_hand_authority_back_to_a_single_key() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_kp = ctx.create_funded_keypair();
+
+    let propose_tx = ProposeAuthorityV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &[
+            member_kps[1].insecure_clone(),
+            member_kps[2].insecure_clone(),
+        ],
+        new_authority_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(propose_tx);
+    demand_tx_success(&tx_result);
+
+    let accept_tx = AcceptAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(accept_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(
+        read_counter(&ctx, &owner_pk)?.authority,
+        new_authority_kp.pubkey().to_bytes()
+    );
+
+    // The new authority replaces the members, and the previous members lose their approval
+    let replacement_kps = [ctx.create_funded_keypair(), ctx.create_funded_keypair()];
+    let replacement_pks: Vec<Pubkey> = replacement_kps.iter().map(Keypair::pubkey).collect();
+    let init_multisig_tx = InitializeMultisigV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        new_authority_kp,
+        &replacement_pks,
+        1,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_multisig_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &[
+            member_kps[0].insecure_clone(),
+            member_kps[1].insecure_clone(),
+        ],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x31b", &tx_result);
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &[replacement_kps[1].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_pk)?.count, 1);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x31d", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_one_member_signs_twice() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let member_pk = member_kps[0].pubkey();
+
+    // The same member listed twice would meet the threshold if signers were only counted
+    let instruction = IncrementCountV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_multisig_signers(&[member_pk, member_pk])
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[0]])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x31c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_member() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let outsider_kp = ctx.create_funded_keypair();
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone(), outsider_kp],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x31b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_invalid_signers_follow_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    // The leading members meet the threshold, so the trailing accounts must be checked too rather
+    // than dropped, even when the input holds more accounts than the largest instruction takes
+    let mut instruction = IncrementCountV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_multisig_signers(&[member_kps[0].pubkey(), member_kps[1].pubkey()])
+        .to_instruction(true)?;
+    let config_index = instruction.accounts.len() - 1;
+    instruction.accounts.splice(
+        config_index..config_index,
+        (0..MAX_COUNTER_SHARDS).map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false)),
+    );
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[0], &member_kps[1]])?;
+    demand_tx_failure(&tx_result);
+
+    assert_eq!(read_counter(&ctx, &owner_kp.pubkey())?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_member_does_not_sign() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let mut increment_ix = IncrementCountV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_multisig_signers(&[member_kps[0].pubkey(), member_kps[1].pubkey()]);
+    increment_ix.multisig_signers[1].is_signer = false;
+    let instruction = increment_ix.to_instruction(false)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[0]])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x31a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_signs_alone() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, _member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x315", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_multisig_of_another_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, _member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let (other_owner_kp, other_member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    // Members of another counter's multisig approve, passing their own multisig record
+    let other_counter_pk = find_counter_v1_address(&ctx.program_id(), &other_owner_kp.pubkey());
+    let mut increment_ix = IncrementCountV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_multisig_signers(&[other_member_kps[0].pubkey(), other_member_kps[1].pubkey()]);
+    increment_ix.owner.pubkey = find_multisig_v1_address(&ctx.program_id(), &other_counter_pk);
+    let instruction = increment_ix.to_instruction(false)?;
+
+    let tx_result = send_signed_by(
+        &mut ctx,
+        instruction,
+        &[&other_member_kps[0], &other_member_kps[1]],
+    )?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x302", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn set_count_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let instruction = SetCountV1Ix::new(ctx.program_id(), owner_kp.pubkey(), 10)
+        .with_multisig_signers(&[member_kps[2].pubkey()])
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[2]])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x515", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn batch_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let instruction = ExecuteBatchV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .set(10)
+        .with_multisig_signers(&[member_kps[2].pubkey()])
+        .to_instruction(true)?;
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[2]])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2615", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn propose_authority_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let propose_tx = ProposeAuthorityV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        member_kps[0].pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(propose_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc11", &tx_result);
+
+    Ok(())
+}
+
+// ============================================================================
+// Owner-Gated Instruction Tests
+// ============================================================================
+
+/// Returns clones of the first [`THRESHOLD`] members, the first of which pays.
+fn threshold_signer_kps(member_kps: &[Keypair]) -> Vec<Keypair> {
+    member_kps[..usize::from(THRESHOLD)]
+        .iter()
+        .map(Keypair::insecure_clone)
+        .collect()
+}
+
+#[test]
+fn counter_settings_succeed_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        OverflowPolicy::Error,
+        None,
+        Some(100),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let access_tx = SetAccessModeV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let cooldown_tx = SetCooldownV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        5,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cooldown_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(counter.overflow_policy, OverflowPolicy::Error);
+    assert_eq!(counter.max_count, 100);
+    assert_eq!(counter.access_mode, AccessMode::Public);
+    assert_eq!(counter.cooldown_slots, 5);
+
+    Ok(())
+}
+
+#[test]
+fn freeze_and_thaw_succeed_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    assert!(read_counter(&ctx, &owner_pk)?.frozen);
+
+    let thaw_tx = ThawCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_success(&tx_result);
+
+    assert!(!read_counter(&ctx, &owner_pk)?.frozen);
+
+    Ok(())
+}
+
+#[test]
+fn approve_and_revoke_delegate_succeed_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+    let delegate_pk = Pubkey::new_unique();
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let delegate_record_pk = find_delegate_v1_address(&ctx.program_id(), &counter_pk);
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        delegate_pk,
+        10,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    // The multisig is recorded as the approving authority, so the approval ends with it
+    let delegate_account = ctx
+        .get_account(delegate_record_pk)
+        .ok_or("Delegate account should exist")?;
+    let delegate = DelegateV1::deserialize(&delegate_account.data)?;
+    assert_eq!(delegate.delegate, delegate_pk.to_bytes());
+    assert_eq!(
+        delegate.authority,
+        find_multisig_v1_address(&ctx.program_id(), &counter_pk).to_bytes()
+    );
+
+    let recipient_lamports_before = ctx
+        .get_account(member_kps[0].pubkey())
+        .ok_or("Recipient account should exist")?
+        .lamports;
+
+    let revoke_tx = RevokeDelegateV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(revoke_tx);
+    demand_tx_success(&tx_result);
+
+    assert!(ctx.get_account(delegate_record_pk).is_none());
+    let recipient_lamports_after = ctx
+        .get_account(member_kps[0].pubkey())
+        .ok_or("Recipient account should exist")?
+        .lamports;
+    assert!(recipient_lamports_after > recipient_lamports_before);
+
+    Ok(())
+}
+
+#[test]
+fn deactivate_succeeds_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+
+    let recipient_lamports_before = ctx
+        .get_account(member_kps[0].pubkey())
+        .ok_or("Recipient account should exist")?
+        .lamports;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+    assert_eq!(counter_account.data.len(), DeactivatedCounterV1::size());
+
+    // The first signer stands in for the multisig, which cannot use lamports
+    let recipient_lamports_after = ctx
+        .get_account(member_kps[0].pubkey())
+        .ok_or("Recipient account should exist")?
+        .lamports;
+    assert!(recipient_lamports_after > recipient_lamports_before);
+
+    Ok(())
+}
+
+#[test]
+fn reactivation_keeps_multisig_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let owner_pk = owner_kp.pubkey();
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_pk,
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // The creator no longer controls the counter on its own
+    let reactivate_tx = ReactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_failure(&tx_result);
+    // ReactivateCounterV1Error::AuthorityMustBeSigner
+    demand_logs_contain("failed: custom program error: 0x60d", &tx_result);
+
+    let reactivate_tx = ReactivateCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        &[member_kps[0].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_failure(&tx_result);
+    // ReactivateCounterV1Error::MultisigThresholdNotMet
+    demand_logs_contain("failed: custom program error: 0x614", &tx_result);
+
+    let reactivate_tx = ReactivateCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp,
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(
+        counter.authority,
+        find_multisig_v1_address(&ctx.program_id(), &counter_pk).to_bytes()
+    );
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn close_succeeds_with_threshold_signers() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let destination_pk = Pubkey::new_unique();
+
+    let close_tx = CloseCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &threshold_signer_kps(&member_kps),
+        destination_pk,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_success(&tx_result);
+
+    assert!(ctx.get_account(counter_pk).is_none());
+    assert!(ctx
+        .get_account(find_tombstone_v1_address(&ctx.program_id(), &counter_pk))
+        .is_some());
+    assert!(ctx.get_account(destination_pk).is_some());
+
+    Ok(())
+}
+
+#[test]
+fn set_count_policy_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        OverflowPolicy::Error,
+        None,
+        None,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x913", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn set_access_mode_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let access_tx = SetAccessModeV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c11", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn set_cooldown_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let cooldown_tx = SetCooldownV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        5,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(cooldown_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2411", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn freeze_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d12", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn thaw_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &threshold_signer_kps(&member_kps),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let thaw_tx = ThawCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[2].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e10", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn approve_delegate_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        member_kps[0].pubkey(),
+        10,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xa13", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn revoke_delegate_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &threshold_signer_kps(&member_kps),
+        Pubkey::new_unique(),
+        10,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+
+    let revoke_tx = RevokeDelegateV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[2].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(revoke_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb0f", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn deactivate_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x215", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn close_fails_below_threshold() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let close_tx = CloseCounterV1SimpleTx::try_new_multisig(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        &[member_kps[0].insecure_clone()],
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(close_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2216", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn close_fails_when_first_signer_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    // The multisig cannot pay for the tombstone, so the first signer has to
+    let mut close_ix =
+        CloseCounterV1Ix::new(ctx.program_id(), owner_kp.pubkey(), owner_kp.pubkey())
+            .with_multisig_signers(&[member_kps[0].pubkey(), member_kps[1].pubkey()]);
+    close_ix.multisig_signers[0].is_writable = false;
+    let instruction = close_ix.to_instruction(false)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &[&member_kps[1], &member_kps[0]])?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2217", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn owner_gated_instructions_fail_when_owner_signs_alone() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let (owner_kp, _member_kps) = initialize_multisig_counter(&mut ctx)?;
+
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1d0b", &tx_result);
+
+    let deactivate_tx =
+        DeactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x20c", &tx_result);
+
+    Ok(())
+}
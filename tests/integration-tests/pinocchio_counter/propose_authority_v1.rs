@@ -0,0 +1,372 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousProposeAuthorityV1Ix, MaliciousProposeAuthorityV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        transactions::{
+            IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx, ProposeAuthorityV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccountDiscriminator, CounterV2},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_counter(
+    ctx: &TestContext,
+    creator: &Pubkey,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), creator);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Propose Authority Tests
+// ============================================================================
+
+#[test]
+fn succeeds_and_upgrades_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let new_authority_pk = Pubkey::new_unique();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let propose_tx = ProposeAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        owner_kp,
+        new_authority_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(propose_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(
+        counter.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.count, 1);
+    assert_eq!(counter.authority, owner_pk.to_bytes());
+    assert_eq!(
+        counter.pending_authority(),
+        Some(&new_authority_pk.to_bytes())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_replacing_pending_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let replacement_pk = Pubkey::new_unique();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    for new_authority_pk in [Pubkey::new_unique(), replacement_pk] {
+        let propose_tx = ProposeAuthorityV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_pk,
+            owner_kp.insecure_clone(),
+            new_authority_pk,
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(propose_tx);
+        demand_tx_success(&tx_result);
+
+        ctx.advance_slot(1)?;
+    }
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(
+        counter.pending_authority(),
+        Some(&replacement_pk.to_bytes())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_cancelling_pending_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    for new_authority_pk in [Pubkey::new_unique(), Pubkey::default()] {
+        let propose_tx = ProposeAuthorityV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_pk,
+            owner_kp.insecure_clone(),
+            new_authority_pk,
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(propose_tx);
+        demand_tx_success(&tx_result);
+
+        ctx.advance_slot(1)?;
+    }
+
+    let counter = read_counter(&ctx, &owner_pk)?;
+    assert_eq!(counter.pending_authority(), None);
+    assert_eq!(counter.authority, owner_pk.to_bytes());
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction =
+        MaliciousProposeAuthorityV1Ix::from_valid(ctx.program_id(), owner_pk, Pubkey::new_unique())
+            .build_with_accounts(vec![
+                AccountMeta {
+                    pubkey: owner_pk,
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: counter_pk,
+                    is_signer: false,
+                    is_writable: true,
+                },
+                // Missing system program - only 2 accounts instead of 3
+            ]);
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_authority_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousProposeAuthorityV1Ix::with_authority_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousProposeAuthorityV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousProposeAuthorityV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousProposeAuthorityV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc07", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousProposeAuthorityV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        Pubkey::new_unique(),
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc0a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_is_not_authority() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // Another key cannot propose itself as the authority of this counter
+    let other_pk = other_kp.pubkey();
+    let propose_tx = ProposeAuthorityV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_pk,
+        other_kp,
+        other_pk,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(propose_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xc0b", &tx_result);
+
+    Ok(())
+}
@@ -7,13 +7,16 @@ use {
         },
     },
     pinocchio_counter_client::{
+        events::CounterEvent,
         find_counter_v1_address,
         transactions::{
             DeactivateCounterV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
             ReactivateCounterV1SimpleTx,
         },
     },
-    pinocchio_counter_program::{AccountDiscriminator, CounterV1, DEACTIVATED_ACCOUNT_SIZE},
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterV1, DeactivatedCounterV1, EventDiscriminator,
+    },
     solana_instruction::AccountMeta,
     solana_keypair::Signer,
     solana_rent::Rent,
@@ -64,8 +67,8 @@ fn succeeds_full_lifecycle() -> TestResult {
 
     assert_eq!(
         counter_account_deactivated.data.len(),
-        DEACTIVATED_ACCOUNT_SIZE,
-        "Counter account should be resized to 1 byte"
+        DeactivatedCounterV1::size(),
+        "Counter account should be resized to the deactivated record"
     );
 
     assert_eq!(
@@ -121,7 +124,7 @@ fn succeeds_full_lifecycle() -> TestResult {
     // Verify lamports were transferred from owner to counter for rent
     let rent = Rent::default();
     let rent_exempt_minimum_counter = rent.minimum_balance(CounterV1::size());
-    let rent_exempt_minimum_deactivated = rent.minimum_balance(DEACTIVATED_ACCOUNT_SIZE);
+    let rent_exempt_minimum_deactivated = rent.minimum_balance(DeactivatedCounterV1::size());
     let additional_lamports_needed = rent_exempt_minimum_counter - rent_exempt_minimum_deactivated;
 
     let owner_lamports_after_reactivate = ctx
@@ -211,6 +214,59 @@ fn succeeds_after_increment_and_deactivate() -> TestResult {
     Ok(())
 }
 
+#[test]
+fn emits_counter_reactivated_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let reactivate_tx =
+        ReactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterReactivatedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
 #[test]
 fn fails_when_payer_not_signer() -> TestResult {
     let mut ctx = TestContext::try_new()?;
@@ -0,0 +1,245 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousReactivateIndexedCounterV1Ix, MaliciousReactivateIndexedCounterV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_indexed_counter_v1_address,
+        transactions::{
+            DeactivateIndexedCounterV1SimpleTx, InitializeIndexedCounterV1SimpleTx,
+            ReactivateIndexedCounterV1SimpleTx, SetIndexedCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{EventDiscriminator, IndexedCounterV1},
+    solana_keypair::{Keypair, Signer},
+};
+
+/// Initializes an indexed counter, sets its count and deactivates it.
+fn setup_deactivated_counter(ctx: &mut TestContext, owner_kp: &Keypair, index: u32) -> TestResult {
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        index,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_tx = SetIndexedCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        index,
+        9,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        index,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Reactivate Indexed Counter Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp, 5)?;
+
+    let reactivate_tx = ReactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 5);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), IndexedCounterV1::size());
+
+    let counter = IndexedCounterV1::deserialize(&counter_account.data)?;
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.index, 5);
+    assert_eq!(counter.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_reactivated_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp, 5)?;
+
+    let reactivate_tx = ReactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        5,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 5);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterReactivatedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_payer_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousReactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousReactivateIndexedCounterV1Ix::with_payer_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1302", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_is_for_another_index() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp, 0)?;
+    setup_deactivated_counter(&mut ctx, &owner_kp, 1)?;
+
+    let other_index_counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 1);
+    let malicious_tx = MaliciousReactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(other_index_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1305", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousReactivateIndexedCounterV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousReactivateIndexedCounterV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1306", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_active() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let reactivate_tx = ReactivateIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x130a", &tx_result);
+
+    Ok(())
+}
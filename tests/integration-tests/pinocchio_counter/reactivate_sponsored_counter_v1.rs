@@ -0,0 +1,251 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousReactivateSponsoredCounterV1Ix, MaliciousReactivateSponsoredCounterV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_counter_v1_address,
+        transactions::{
+            DeactivateCounterV1SimpleTx, InitializeCounterV1SimpleTx,
+            ReactivateSponsoredCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccountDiscriminator, CounterV2, EventDiscriminator},
+    solana_keypair::{Keypair, Signer},
+    solana_rent::Rent,
+};
+
+/// Initializes the owner's counter and deactivates it, leaving it ready to be reactivated.
+fn setup_deactivated_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let payer_pk = payer_kp.pubkey();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp)?;
+
+    let owner_lamports_before = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+
+    let reactivate_tx = ReactivateSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+    assert_eq!(
+        counter_account.lamports,
+        Rent::default().minimum_balance(CounterV2::size())
+    );
+
+    let counter = CounterV2::deserialize(&counter_account.data)?;
+    assert_eq!(
+        counter.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter.owner, owner_pk.to_bytes());
+    assert_eq!(counter.count, 0);
+    assert_eq!(counter.authority, owner_pk.to_bytes());
+    assert_eq!(counter.rent_payer(), Some(&payer_pk.to_bytes()));
+
+    let owner_lamports_after = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner account should exist")?
+        .lamports;
+    assert_eq!(
+        owner_lamports_after, owner_lamports_before,
+        "Owner should not pay for a sponsored reactivation"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn emits_counter_reactivated_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp)?;
+
+    let reactivate_tx = ReactivateSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CounterReactivatedV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 0);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_payer_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousReactivateSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousReactivateSponsoredCounterV1Ix::with_payer_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1502", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousReactivateSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousReactivateSponsoredCounterV1Ix::with_owner_not_signer)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1504", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_deactivated_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousReactivateSponsoredCounterV1Tx::from_valid(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(
+        MaliciousReactivateSponsoredCounterV1Ix::with_random_counter_address,
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1506", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let payer_kp = ctx.create_funded_keypair();
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let reactivate_tx = ReactivateSponsoredCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        payer_kp,
+        owner_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(reactivate_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x150a", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,519 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousIncrementCounterShardV1Tx, MaliciousReconcileShardsV1Ix,
+                MaliciousReconcileShardsV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_shard_v1_address, find_sharded_counter_v1_address,
+        sharded_counter::find_counter_shard_v1_addresses,
+        transactions::{InitializeShardedCounterV1SimpleTx, ReconcileShardsV1SimpleTx},
+    },
+    pinocchio_counter_program::{CounterShardV1, ShardedCounterV1, MAX_COUNTER_SHARDS},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_sharded_counter(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    shard_count: u8,
+) -> TestResult {
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        shard_count,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Increments the shard at `index` once, signed by a new contributor.
+fn increment_shard(ctx: &mut TestContext, owner: Pubkey, index: u8) -> TestResult {
+    let contributor_kp = ctx.create_funded_keypair();
+
+    let increment_tx = MaliciousIncrementCounterShardV1Tx::from_valid(
+        ctx.program_id(),
+        owner,
+        contributor_kp,
+        index,
+        ctx.latest_blockhash(),
+    )
+    .build();
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+fn shard_metas(shards: &[Pubkey]) -> Vec<AccountMeta> {
+    shards
+        .iter()
+        .map(|pubkey| AccountMeta {
+            pubkey: *pubkey,
+            is_signer: false,
+            is_writable: true,
+        })
+        .collect()
+}
+
+fn read_sharded_counter(
+    ctx: &TestContext,
+    owner: &Pubkey,
+) -> Result<ShardedCounterV1, Box<dyn std::error::Error>> {
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), owner);
+    let sharded_counter_account = ctx
+        .get_account(sharded_counter_pk)
+        .ok_or("Sharded counter should exist")?;
+    Ok(ShardedCounterV1::deserialize(
+        &sharded_counter_account.data,
+    )?)
+}
+
+fn read_shard(
+    ctx: &TestContext,
+    owner: &Pubkey,
+    index: u8,
+) -> Result<CounterShardV1, Box<dyn std::error::Error>> {
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), owner);
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, index);
+    let shard_account = ctx.get_account(shard_pk).ok_or("Shard should exist")?;
+    Ok(CounterShardV1::deserialize(&shard_account.data)?)
+}
+
+// ============================================================================
+// Reconcile Shards Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 3)?;
+    increment_shard(&mut ctx, owner_pk, 0)?;
+    increment_shard(&mut ctx, owner_pk, 0)?;
+    increment_shard(&mut ctx, owner_pk, 2)?;
+
+    let reconcile_tx =
+        ReconcileShardsV1SimpleTx::try_new(ctx.program_id(), owner_kp, 3, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(reconcile_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 3);
+
+    assert_eq!(read_sharded_counter(&ctx, &owner_pk)?.count, 3);
+    for index in 0..3 {
+        assert_eq!(read_shard(&ctx, &owner_pk, index)?.count, 0);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_repeatedly() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    for expected_count in [1, 3] {
+        increment_shard(&mut ctx, owner_pk, 0)?;
+        if expected_count > 1 {
+            increment_shard(&mut ctx, owner_pk, 1)?;
+        }
+
+        let reconcile_tx = ReconcileShardsV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_kp.insecure_clone(),
+            2,
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(reconcile_tx);
+        demand_tx_success(&tx_result);
+        assert_eq!(ctx.count_return_data(&tx_result)?.count, expected_count);
+
+        ctx.advance_slot(1)?;
+    }
+
+    assert_eq!(read_sharded_counter(&ctx, &owner_pk)?.count, 3);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_with_subset_of_shards() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+    increment_shard(&mut ctx, owner_pk, 0)?;
+    increment_shard(&mut ctx, owner_pk, 1)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let shard_1_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 1);
+
+    let reconcile_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards(shard_metas(&[shard_1_pk])))
+    .build();
+
+    let tx_result = ctx.send_transaction(reconcile_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_sharded_counter(&ctx, &owner_pk)?.count, 1);
+    assert_eq!(read_shard(&ctx, &owner_pk, 0)?.count, 1);
+    assert_eq!(read_shard(&ctx, &owner_pk, 1)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_counting_duplicate_shard_once() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+    increment_shard(&mut ctx, owner_pk, 0)?;
+    increment_shard(&mut ctx, owner_pk, 0)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let shard_0_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 0);
+
+    let reconcile_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards(shard_metas(&[shard_0_pk, shard_0_pk])))
+    .build();
+
+    let tx_result = ctx.send_transaction(reconcile_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_sharded_counter(&ctx, &owner_pk)?.count, 2);
+    assert_eq!(read_shard(&ctx, &owner_pk, 0)?.count, 0);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    // No shards - only the owner and the sharded counter
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards(vec![]))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousReconcileShardsV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_sharded_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousReconcileShardsV1Ix::with_sharded_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b03", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_sharded_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    // A genuine sharded counter stored at an address that is not derived from its owner
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let sharded_counter_copy_pk = ctx.copy_account_to_unique_address(sharded_counter_pk)?;
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_sharded_counter_address(sharded_counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_does_not_own_sharded_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let attacker_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let shards = find_counter_shard_v1_addresses(&ctx.program_id(), &sharded_counter_pk, 2);
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        attacker_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        ix.with_sharded_counter_address(sharded_counter_pk)
+            .with_shards(shard_metas(&shards))
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_too_many_shards() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let shards = find_counter_shard_v1_addresses(
+        &ctx.program_id(),
+        &sharded_counter_pk,
+        MAX_COUNTER_SHARDS + 1,
+    );
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shards(shard_metas(&shards)))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_not_writable(1))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b07", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    // A genuine shard stored at an address that is not derived from its seeds
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 0);
+    let shard_copy_pk = ctx.copy_account_to_unique_address(shard_pk)?;
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_address(0, shard_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b08", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_belongs_to_another_sharded_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let other_owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+    initialize_sharded_counter(&mut ctx, &other_owner_kp, 2)?;
+    increment_shard(&mut ctx, other_owner_kp.pubkey(), 0)?;
+
+    let other_sharded_counter_pk =
+        find_sharded_counter_v1_address(&ctx.program_id(), &other_owner_kp.pubkey());
+    let other_shard_pk =
+        find_counter_shard_v1_address(&ctx.program_id(), &other_sharded_counter_pk, 0);
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_address(0, other_shard_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b09", &tx_result);
+
+    assert_eq!(read_shard(&ctx, &other_owner_kp.pubkey(), 0)?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_sharded_counter_is_shard() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let shard_pk = find_counter_shard_v1_address(&ctx.program_id(), &sharded_counter_pk, 0);
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_sharded_counter_address(shard_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b0d", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_shard_is_sharded_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_sharded_counter(&mut ctx, &owner_kp, 2)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+
+    let malicious_tx = MaliciousReconcileShardsV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_shard_address(1, sharded_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1b0e", &tx_result);
+
+    Ok(())
+}
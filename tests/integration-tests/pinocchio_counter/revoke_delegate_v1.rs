@@ -0,0 +1,298 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousRevokeDelegateV1Ix, MaliciousRevokeDelegateV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address, find_delegate_v1_address,
+        transactions::{
+            ApproveDelegateV1SimpleTx, DeactivateCounterV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, RevokeDelegateV1SimpleTx,
+        },
+    },
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+/// Initializes a counter and approves `delegate_pk` on it.
+fn setup_delegate(ctx: &mut TestContext, owner_kp: &Keypair, delegate_pk: Pubkey) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let approve_tx = ApproveDelegateV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        delegate_pk,
+        10,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(approve_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+// ============================================================================
+// Revoke Delegate Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let delegate_record_pk = find_delegate_v1_address(&ctx.program_id(), &counter_pk);
+    let delegate_lamports = ctx
+        .get_account(delegate_record_pk)
+        .ok_or("Delegate record should exist")?
+        .lamports;
+    let owner_lamports_before = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner should exist")?
+        .lamports;
+
+    let revoke_tx =
+        RevokeDelegateV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(revoke_tx);
+    demand_tx_success(&tx_result);
+
+    let delegate_account = ctx.get_account(delegate_record_pk);
+    assert!(delegate_account.is_none_or(|account| account.lamports == 0));
+
+    let owner_lamports_after = ctx
+        .get_account(owner_pk)
+        .ok_or("Owner should exist")?
+        .lamports;
+    // The owner also paid the transaction fee
+    assert!(owner_lamports_after > owner_lamports_before);
+    assert!(owner_lamports_after <= owner_lamports_before + delegate_lamports);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let revoke_tx =
+        RevokeDelegateV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(revoke_tx);
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn revoked_delegate_cannot_increment() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let delegate_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, delegate_kp.pubkey())?;
+
+    let revoke_tx =
+        RevokeDelegateV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(revoke_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_delegated(
+        ctx.program_id(),
+        owner_pk,
+        delegate_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x310", &tx_result);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    let malicious_ix = MaliciousRevokeDelegateV1Ix::from_valid(ctx.program_id(), owner_pk);
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: counter_pk,
+            is_signer: false,
+            is_writable: false,
+        },
+        // Missing delegate record - only 2 accounts instead of 3
+    ]);
+
+    let malicious_tx =
+        MaliciousRevokeDelegateV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    let malicious_tx =
+        MaliciousRevokeDelegateV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousRevokeDelegateV1Ix::with_owner_not_signer)
+            .with_different_signer(fee_payer_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx =
+        MaliciousRevokeDelegateV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_delegate_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    let malicious_tx =
+        MaliciousRevokeDelegateV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousRevokeDelegateV1Ix::with_delegate_not_writable)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_delegate_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_delegate(&mut ctx, &owner_kp, Pubkey::new_unique())?;
+
+    let malicious_tx =
+        MaliciousRevokeDelegateV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousRevokeDelegateV1Ix::with_random_delegate_address)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_no_delegate_approved() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let revoke_tx =
+        RevokeDelegateV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(revoke_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0xb07", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,521 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousSetAccessModeV1Ix, MaliciousSetAccessModeV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        instructions::{DeactivateCounterV1Ix, SetAccessModeV1Ix, SetCountV1Ix},
+        transactions::{
+            DeactivateCounterV1SimpleTx, DecrementCountV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, SetAccessModeV1SimpleTx, SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccessMode, AccountDiscriminator, CounterV1, CounterV2},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn set_access_mode(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    access_mode: AccessMode,
+) -> TestResult {
+    let access_tx = SetAccessModeV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        access_mode,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Sends `instruction` in a transaction paid for and signed by `signer_kp` alone.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_counter_v2(
+    ctx: &TestContext,
+    owner_kp: &Keypair,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Set Access Mode Tests
+// ============================================================================
+
+#[test]
+fn succeeds_upgrading_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    assert_eq!(counter_account_before.data.len(), CounterV1::size());
+    let counter_before = CounterV1::deserialize(&counter_account_before.data)?;
+
+    let access_tx = SetAccessModeV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+    assert_eq!(counter_account_after.data.len(), CounterV2::size());
+    assert!(
+        counter_account_after.lamports > counter_account_before.lamports,
+        "Owner should have topped up rent for the larger layout"
+    );
+
+    let counter_after = CounterV2::deserialize(&counter_account_after.data)?;
+    assert_eq!(
+        counter_after.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter_after.owner, counter_before.owner);
+    assert_eq!(counter_after.bump, counter_before.bump);
+    assert_eq!(counter_after.count, counter_before.count);
+    assert_eq!(counter_after.access_mode, AccessMode::Public);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_switching_counter_v2_back_to_private() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Public)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let lamports_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?
+        .lamports;
+
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Private)?;
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+    assert_eq!(counter_account.lamports, lamports_before);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.access_mode, AccessMode::Private);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_increment_fails_while_private() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_public(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        third_party_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x315", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_succeeds_when_public() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Public)?;
+
+    for _ in 0..3 {
+        let increment_tx = IncrementCountV1SimpleTx::try_new_public(
+            ctx.program_id(),
+            owner_kp.pubkey(),
+            third_party_kp.insecure_clone(),
+            ctx.latest_blockhash(),
+        )?;
+
+        let tx_result = ctx.send_transaction(increment_tx);
+        demand_tx_success(&tx_result);
+
+        ctx.advance_slot(1)?;
+    }
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new_public(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        third_party_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 2);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_fails_after_switching_back_to_private() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Public)?;
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Private)?;
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new_public(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        third_party_kp,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x415", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_set_count_on_public_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Public)?;
+
+    let instruction = SetCountV1Ix::new(ctx.program_id(), owner_kp.pubkey(), 42)
+        .with_authority(third_party_kp.pubkey())
+        .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &third_party_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x50e", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_deactivate_public_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Public)?;
+
+    let instruction = DeactivateCounterV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_authority(third_party_kp.pubkey())
+        .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &third_party_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x20c", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_change_access_mode() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_access_mode(&mut ctx, &owner_kp, AccessMode::Public)?;
+
+    let instruction =
+        SetAccessModeV1Ix::new(ctx.program_id(), owner_kp.pubkey(), AccessMode::Private)
+            .with_authority(third_party_kp.pubkey())
+            .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &third_party_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c0b", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.access_mode, AccessMode::Public);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_ix =
+        MaliciousSetAccessModeV1Ix::from_valid(ctx.program_id(), owner_pk, AccessMode::Public);
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: counter_pk,
+            is_signer: false,
+            is_writable: true,
+        },
+        // Missing system program - only 2 accounts instead of 3
+    ]);
+
+    let malicious_tx = MaliciousSetAccessModeV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetAccessModeV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetAccessModeV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetAccessModeV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetAccessModeV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c04", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousSetAccessModeV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c05", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetAccessModeV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetAccessModeV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c06", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetAccessModeV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetAccessModeV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c07", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let access_tx = SetAccessModeV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1c0a", &tx_result);
+
+    Ok(())
+}
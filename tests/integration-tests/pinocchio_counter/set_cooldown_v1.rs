@@ -0,0 +1,650 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousSetCooldownV1Ix, MaliciousSetCooldownV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        instructions::SetCooldownV1Ix,
+        transactions::{
+            CompareAndIncrementCountV1SimpleTx, CompareAndSetCountV1SimpleTx,
+            DeactivateCounterV1SimpleTx, DecrementByV1SimpleTx, DecrementCountV1SimpleTx,
+            IncrementByV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            SetAccessModeV1SimpleTx, SetCooldownV1SimpleTx, SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{AccessMode, AccountDiscriminator, CounterV1, CounterV2},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Sets the cooldown without advancing the slot, so it starts counting from the current slot.
+fn set_cooldown(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    cooldown_slots: u64,
+    cooldown_seconds: u64,
+) -> TestResult {
+    let cooldown_tx = SetCooldownV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        cooldown_slots,
+        cooldown_seconds,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(cooldown_tx);
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+fn increment(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    Ok(ctx.send_transaction(increment_tx))
+}
+
+fn decrement(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let decrement_tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    Ok(ctx.send_transaction(decrement_tx))
+}
+
+/// Sends `instruction` in a transaction paid for and signed by `signer_kp` alone.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_counter_v2(
+    ctx: &TestContext,
+    owner_kp: &Keypair,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Set Cooldown Tests
+// ============================================================================
+
+#[test]
+fn succeeds_upgrading_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    assert_eq!(counter_account_before.data.len(), CounterV1::size());
+    let counter_before = CounterV1::deserialize(&counter_account_before.data)?;
+
+    let cooldown_tx =
+        SetCooldownV1SimpleTx::try_new(ctx.program_id(), owner_kp, 10, 5, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(cooldown_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+    assert_eq!(counter_account_after.data.len(), CounterV2::size());
+    assert!(
+        counter_account_after.lamports > counter_account_before.lamports,
+        "Owner should have topped up rent for the larger layout"
+    );
+
+    let counter_after = CounterV2::deserialize(&counter_account_after.data)?;
+    assert_eq!(
+        counter_after.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter_after.owner, counter_before.owner);
+    assert_eq!(counter_after.count, counter_before.count);
+    assert_eq!(counter_after.cooldown_slots, 10);
+    assert_eq!(counter_after.cooldown_seconds, 5);
+    assert_eq!(counter_after.last_modified_slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn slot_cooldown_releases_after_enough_slots() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x317", &tx_result);
+
+    ctx.advance_slot(9)?;
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x317", &tx_result);
+
+    ctx.advance_slot(1)?;
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 1);
+
+    // The successful increment restarts the cooldown, for decrements as well
+    let tx_result = decrement(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x417", &tx_result);
+
+    ctx.advance_slot(10)?;
+    let tx_result = decrement(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn time_cooldown_releases_after_enough_seconds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 0, 10)?;
+    let set_at = ctx.current_clock().unix_timestamp;
+
+    // 24 slots of 400ms move the Unix timestamp forward by only 9 seconds
+    ctx.advance_slot(24)?;
+    assert_eq!(ctx.current_clock().unix_timestamp, set_at + 9);
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x317", &tx_result);
+
+    ctx.advance_slot(3)?;
+    assert_eq!(ctx.current_clock().unix_timestamp, set_at + 10);
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn both_cooldowns_must_elapse() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 5, 10)?;
+
+    // The slot cooldown has passed, but only 8 seconds have
+    ctx.advance_slot(20)?;
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x317", &tx_result);
+
+    ctx.advance_slot(5)?;
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_applies_to_any_signer_of_public_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let access_tx = SetAccessModeV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        AccessMode::Public,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(access_tx);
+    demand_tx_success(&tx_result);
+
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+    ctx.advance_slot(10)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new_public(
+        ctx.program_id(),
+        owner_kp.pubkey(),
+        third_party_kp,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    // The owner is held back by the third party's increment
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x317", &tx_result);
+
+    ctx.advance_slot(10)?;
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 2);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_rejects_increment_by() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+
+    // An amount of 1 is no way around the cooldown on `IncrementCountV1`
+    let increment_by_tx = IncrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        1,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_by_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x71a", &tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_rejects_decrement_by() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+    ctx.advance_slot(1)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+
+    let decrement_by_tx = DecrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        1,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(decrement_by_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x81a", &tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_rejects_compare_and_increment() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+
+    let compare_tx = CompareAndIncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(compare_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1814", &tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_rejects_compare_and_set() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+
+    let compare_tx = CompareAndSetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        0,
+        5,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(compare_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1714", &tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn cooldown_rejects_set_count() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x516", &tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 0);
+
+    // Once the cooldown passes the count can be set again
+    ctx.advance_slot(10)?;
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(read_counter_v2(&ctx, &owner_kp)?.count, 5);
+
+    Ok(())
+}
+
+#[test]
+fn zero_cooldown_removes_limit() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 10)?;
+
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x317", &tx_result);
+
+    ctx.advance_slot(1)?;
+    set_cooldown(&mut ctx, &owner_kp, 0, 0)?;
+
+    let tx_result = increment(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+    let tx_result = decrement(&mut ctx, &owner_kp)?;
+    demand_tx_success(&tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_set_cooldown() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_cooldown(&mut ctx, &owner_kp, 10, 0)?;
+    ctx.advance_slot(1)?;
+
+    let instruction = SetCooldownV1Ix::new(ctx.program_id(), owner_kp.pubkey(), 0, 0)
+        .with_authority(third_party_kp.pubkey())
+        .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &third_party_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x240b", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.cooldown_slots, 10);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_ix = MaliciousSetCooldownV1Ix::from_valid(ctx.program_id(), owner_pk, 10, 0);
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: counter_pk,
+            is_signer: false,
+            is_writable: true,
+        },
+        // Missing system program - only 2 accounts instead of 3
+    ]);
+
+    let malicious_tx = MaliciousSetCooldownV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2401", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetCooldownV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCooldownV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2402", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetCooldownV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCooldownV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2404", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousSetCooldownV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2405", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetCooldownV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCooldownV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2406", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx = MaliciousSetCooldownV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        10,
+        0,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCooldownV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2407", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let cooldown_tx =
+        SetCooldownV1SimpleTx::try_new(ctx.program_id(), owner_kp, 10, 0, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(cooldown_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x240a", &tx_result);
+
+    Ok(())
+}
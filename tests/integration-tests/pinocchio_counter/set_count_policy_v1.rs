@@ -0,0 +1,765 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousSetCountPolicyV1Ix, MaliciousSetCountPolicyV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        transactions::{
+            DeactivateCounterV1SimpleTx, DecrementByV1SimpleTx, DecrementCountV1SimpleTx,
+            IncrementByV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            SetCountPolicyV1SimpleTx, SetCountV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{
+        AccountDiscriminator, CounterV1, CounterV2, DeactivatedCounterV1, OverflowPolicy,
+    },
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+};
+
+/// Initializes a counter, sets its count and configures its policy.
+fn setup_counter(
+    ctx: &mut TestContext,
+    owner_kp: &Keypair,
+    count: u64,
+    overflow_policy: OverflowPolicy,
+    min_count: Option<u64>,
+    max_count: Option<u64>,
+) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        count,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        overflow_policy,
+        min_count,
+        max_count,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_counter_v2(
+    ctx: &TestContext,
+    owner_kp: &Keypair,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Set Count Policy Tests
+// ============================================================================
+
+#[test]
+fn succeeds_upgrading_counter_v1() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    assert_eq!(counter_account_before.data.len(), CounterV1::size());
+    let counter_before = CounterV1::deserialize(&counter_account_before.data)?;
+
+    ctx.advance_slot(1)?;
+
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        Some(0),
+        Some(10_000),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_account_after = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should still exist")?;
+    assert_eq!(counter_account_after.data.len(), CounterV2::size());
+    assert!(
+        counter_account_after.lamports > counter_account_before.lamports,
+        "Owner should have topped up rent for the larger layout"
+    );
+
+    let counter_after = CounterV2::deserialize(&counter_account_after.data)?;
+    assert_eq!(
+        counter_after.discriminator,
+        AccountDiscriminator::CounterV2Account
+    );
+    assert_eq!(counter_after.owner, counter_before.owner);
+    assert_eq!(counter_after.bump, counter_before.bump);
+    assert_eq!(counter_after.count, counter_before.count);
+    assert_eq!(counter_after.overflow_policy, OverflowPolicy::Error);
+    assert_eq!(counter_after.min_count, 0);
+    assert_eq!(counter_after.max_count, 10_000);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_reconfiguring_counter_v2() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        50,
+        OverflowPolicy::Error,
+        Some(10),
+        Some(100),
+    )?;
+
+    // Omitted bounds fall back to the full u64 range
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        OverflowPolicy::Wrap,
+        None,
+        None,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 50);
+    assert_eq!(counter.overflow_policy, OverflowPolicy::Wrap);
+    assert_eq!(counter.min_count, 0);
+    assert_eq!(counter.max_count, u64::MAX);
+
+    Ok(())
+}
+
+#[test]
+fn error_policy_rejects_increment_above_max() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        10_000,
+        OverflowPolicy::Error,
+        None,
+        Some(10_000),
+    )?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x30c", &tx_result);
+
+    let increment_by_tx = IncrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_by_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x709", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 10_000);
+
+    Ok(())
+}
+
+#[test]
+fn error_policy_rejects_decrement_below_min() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        10,
+        OverflowPolicy::Error,
+        Some(10),
+        None,
+    )?;
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x40d", &tx_result);
+
+    let decrement_by_tx = DecrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        5,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_by_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x80a", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 10);
+
+    Ok(())
+}
+
+#[test]
+fn error_policy_rejects_set_out_of_bounds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        50,
+        OverflowPolicy::Error,
+        Some(10),
+        Some(100),
+    )?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        101,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x50c", &tx_result);
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        9,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x50d", &tx_result);
+
+    // In-bounds values are still accepted
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        100,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 100);
+
+    Ok(())
+}
+
+#[test]
+fn saturate_policy_clamps_to_custom_bounds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        9_990,
+        OverflowPolicy::Saturate,
+        Some(10),
+        Some(10_000),
+    )?;
+
+    let increment_by_tx = IncrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        500,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_by_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 10_000);
+
+    ctx.advance_slot(1)?;
+
+    let set_count_tx = SetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        0,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_count_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 10);
+
+    Ok(())
+}
+
+#[test]
+fn wrap_policy_wraps_around_bounds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        9,
+        OverflowPolicy::Wrap,
+        Some(0),
+        Some(9),
+    )?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 0);
+
+    ctx.advance_slot(1)?;
+
+    let decrement_by_tx = DecrementByV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        3,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(decrement_by_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 7);
+
+    Ok(())
+}
+
+#[test]
+fn deactivate_succeeds_for_counter_v2() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    setup_counter(
+        &mut ctx,
+        &owner_kp,
+        5,
+        OverflowPolicy::Error,
+        None,
+        Some(10),
+    )?;
+
+    let deactivate_tx =
+        DeactivateCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), DeactivatedCounterV1::size());
+    assert_eq!(
+        counter_account.data[0],
+        u8::from(AccountDiscriminator::DeactivatedAccount)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_ix =
+        MaliciousSetCountPolicyV1Ix::from_valid(ctx.program_id(), owner_pk, OverflowPolicy::Error);
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: true,
+        },
+        AccountMeta {
+            pubkey: counter_pk,
+            is_signer: false,
+            is_writable: true,
+        },
+        // Missing system program - only 2 accounts instead of 3
+    ]);
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x901", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCountPolicyV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x902", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCountPolicyV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x904", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // A genuine counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x905", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_system_program_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCountPolicyV1Ix::with_random_system_program)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x906", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetCountPolicyV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x907", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_is_deactivated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let deactivate_tx = DeactivateCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(deactivate_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        None,
+        None,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x90a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_min_exceeds_max() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let malicious_tx = MaliciousSetCountPolicyV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_bounds(Some(11), Some(10)))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x90b", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_count_outside_new_bounds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    // The freshly initialized count of 0 is below the requested minimum
+    let policy_tx = SetCountPolicyV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        OverflowPolicy::Error,
+        Some(1),
+        None,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(policy_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x90c", &tx_result);
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    assert_eq!(counter_account.data.len(), CounterV1::size());
+
+    Ok(())
+}
@@ -0,0 +1,331 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousSetIndexedCountV1Ix, MaliciousSetIndexedCountV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        events::CounterEvent,
+        find_indexed_counter_v1_address,
+        transactions::{InitializeIndexedCounterV1SimpleTx, SetIndexedCountV1SimpleTx},
+    },
+    pinocchio_counter_program::{EventDiscriminator, IndexedCounterV1},
+    solana_instruction::AccountMeta,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_indexed_counter(ctx: &mut TestContext, owner_kp: &Keypair, index: u32) -> TestResult {
+    let init_tx = InitializeIndexedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        index,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_indexed_counter(
+    ctx: &TestContext,
+    owner: &Pubkey,
+    index: u32,
+) -> Result<IndexedCounterV1, Box<dyn std::error::Error>> {
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), owner, index);
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(IndexedCounterV1::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Set Indexed Count Tests
+// ============================================================================
+
+#[test]
+fn succeeds() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 2)?;
+
+    let set_tx = SetIndexedCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        u64::MAX,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_indexed_counter(&ctx, &owner_pk, 2)?.count, u64::MAX);
+
+    Ok(())
+}
+
+#[test]
+fn emits_count_set_event() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 2)?;
+
+    let set_tx = SetIndexedCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        2,
+        42,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(set_tx);
+    demand_tx_success(&tx_result);
+
+    let events = ctx.counter_events(&tx_result)?;
+    assert_eq!(
+        events.len(),
+        1,
+        "Expected exactly one event, observed {events:?}"
+    );
+
+    let counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 2);
+    let CounterEvent {
+        discriminator,
+        event,
+    } = &events[0];
+    assert_eq!(*discriminator, EventDiscriminator::CountSetV1);
+    assert_eq!(event.counter, counter_pk.to_bytes());
+    assert_eq!(event.owner, owner_pk.to_bytes());
+    assert_eq!(event.old_count, 0);
+    assert_eq!(event.new_count, 42);
+    assert_eq!(event.slot, ctx.current_slot());
+
+    Ok(())
+}
+
+#[test]
+fn does_not_affect_other_indices_or_owners() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let other_owner_kp = ctx.create_funded_keypair();
+    let other_owner_pk = other_owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+    initialize_indexed_counter(&mut ctx, &owner_kp, 1)?;
+    initialize_indexed_counter(&mut ctx, &other_owner_kp, 0)?;
+
+    let set_tx = SetIndexedCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        100,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_tx);
+    demand_tx_success(&tx_result);
+
+    assert_eq!(read_indexed_counter(&ctx, &owner_pk, 0)?.count, 100);
+    assert_eq!(read_indexed_counter(&ctx, &owner_pk, 1)?.count, 0);
+    assert_eq!(read_indexed_counter(&ctx, &other_owner_pk, 0)?.count, 0);
+
+    Ok(())
+}
+
+// ============================================================================
+// Failure Tests
+// ============================================================================
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let instruction = MaliciousSetIndexedCountV1Ix::from_valid(ctx.program_id(), owner_pk, 0, 42)
+        .build_with_accounts(vec![
+            AccountMeta {
+                pubkey: owner_pk,
+                is_signer: true,
+                is_writable: false,
+            },
+            // Missing counter - only 1 account instead of 2
+        ]);
+
+    let malicious_tx = MaliciousSetIndexedCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1101", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousSetIndexedCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetIndexedCountV1Ix::with_owner_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1102", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousSetIndexedCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetIndexedCountV1Ix::with_counter_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1103", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_is_for_another_index() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+    initialize_indexed_counter(&mut ctx, &owner_kp, 1)?;
+
+    let other_index_counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 1);
+    let malicious_tx = MaliciousSetIndexedCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(other_index_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1104", &tx_result);
+
+    assert_eq!(read_indexed_counter(&ctx, &owner_pk, 1)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_signer_does_not_own_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+    let attacker_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let victim_counter_pk = find_indexed_counter_v1_address(&ctx.program_id(), &owner_pk, 0);
+    let malicious_tx = MaliciousSetIndexedCountV1Tx::from_valid(
+        ctx.program_id(),
+        attacker_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_counter_address(victim_counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1104", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_args_are_truncated() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_indexed_counter(&mut ctx, &owner_kp, 0)?;
+
+    let malicious_tx = MaliciousSetIndexedCountV1Tx::from_valid(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetIndexedCountV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1105", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    let set_tx = SetIndexedCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        0,
+        42,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1108", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,306 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousSetPauseExemptionV1Ix, MaliciousSetPauseExemptionV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_config_v1_address,
+        transactions::{
+            DecrementCountV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            SetPauseExemptionV1SimpleTx, SetPausedV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{ConfigV1, InstructionDiscriminator},
+    solana_keypair::{Keypair, Signer},
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn set_paused(ctx: &mut TestContext, paused: bool) -> TestResult {
+    let set_paused_tx = SetPausedV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        paused,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_paused_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn set_pause_exemption(
+    ctx: &mut TestContext,
+    instruction: InstructionDiscriminator,
+    exempt: bool,
+) -> TestResult {
+    let exemption_tx = SetPauseExemptionV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        instruction,
+        exempt,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(exemption_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_config(ctx: &TestContext) -> Result<ConfigV1, Box<dyn std::error::Error>> {
+    let config_pk = find_config_v1_address(&ctx.program_id());
+    let config_account = ctx.get_account(config_pk).ok_or("Config should exist")?;
+    Ok(ConfigV1::deserialize(&config_account.data)?)
+}
+
+// ============================================================================
+// Set Pause Exemption Tests
+// ============================================================================
+
+#[test]
+fn succeeds_exempting_instruction() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    set_pause_exemption(&mut ctx, InstructionDiscriminator::DecrementCountV1, true)?;
+
+    let config = read_config(&ctx)?;
+    assert!(config.is_pause_exempt(InstructionDiscriminator::DecrementCountV1.into()));
+    assert!(!config.is_pause_exempt(InstructionDiscriminator::IncrementCountV1.into()));
+    assert!(!config.paused);
+
+    Ok(())
+}
+
+#[test]
+fn exempt_instruction_runs_while_paused() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_pause_exemption(&mut ctx, InstructionDiscriminator::IncrementCountV1, true)?;
+    set_paused(&mut ctx, true)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 1);
+
+    let decrement_tx =
+        DecrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x85", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn removing_exemption_pauses_instruction_again() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_pause_exemption(&mut ctx, InstructionDiscriminator::IncrementCountV1, true)?;
+    set_paused(&mut ctx, true)?;
+    set_pause_exemption(&mut ctx, InstructionDiscriminator::IncrementCountV1, false)?;
+
+    assert!(!read_config(&ctx)?.is_pause_exempt(InstructionDiscriminator::IncrementCountV1.into()));
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x85", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn exemption_has_no_effect_while_unpaused() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_pause_exemption(&mut ctx, InstructionDiscriminator::IncrementCountV1, true)?;
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 0);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_exempting_config_instruction() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    let malicious_tx = MaliciousSetPauseExemptionV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| {
+        ix.with_raw_instruction(InstructionDiscriminator::SetPausedV1.into())
+    })
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x210a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_invalid_instruction_discriminator() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    let malicious_tx = MaliciousSetPauseExemptionV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_raw_instruction(u8::MAX))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2106", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_set_exemption() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let third_party_kp = ctx.create_funded_keypair();
+
+    let exemption_tx = SetPauseExemptionV1SimpleTx::try_new(
+        ctx.program_id(),
+        third_party_kp,
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(exemption_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2107", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let admin_kp = ctx.admin_kp();
+
+    let instruction = MaliciousSetPauseExemptionV1Ix::from_valid(
+        ctx.program_id(),
+        admin_kp.pubkey(),
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+    )
+    .build_with_accounts(vec![]);
+
+    let malicious_tx = MaliciousSetPauseExemptionV1Tx::from_valid(
+        ctx.program_id(),
+        admin_kp,
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2101", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_admin_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousSetPauseExemptionV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetPauseExemptionV1Ix::with_admin_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2102", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    let malicious_tx = MaliciousSetPauseExemptionV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        InstructionDiscriminator::IncrementCountV1,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetPauseExemptionV1Ix::with_config_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2103", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,411 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{
+                MaliciousIncrementCountV1Ix, MaliciousIncrementCountV1Tx, MaliciousSetPausedV1Ix,
+                MaliciousSetPausedV1Tx,
+            },
+            TestContext, TestResult,
+        },
+    },
+    pinocchio_counter_client::{
+        find_config_v1_address, find_counter_v1_address,
+        transactions::{
+            DecrementCountV1SimpleTx, GetCountV1SimpleTx, IncrementCountV1SimpleTx,
+            InitializeCounterV1SimpleTx, SetPausedV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::ConfigV1,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn set_paused(ctx: &mut TestContext, paused: bool) -> TestResult {
+    let set_paused_tx = SetPausedV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        paused,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_paused_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn read_config(ctx: &TestContext) -> Result<ConfigV1, Box<dyn std::error::Error>> {
+    let config_pk = find_config_v1_address(&ctx.program_id());
+    let config_account = ctx.get_account(config_pk).ok_or("Config should exist")?;
+    Ok(ConfigV1::deserialize(&config_account.data)?)
+}
+
+// ============================================================================
+// Set Paused Tests
+// ============================================================================
+
+#[test]
+fn succeeds_pausing_and_unpausing() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    set_paused(&mut ctx, true)?;
+    assert!(read_config(&ctx)?.paused);
+
+    set_paused(&mut ctx, false)?;
+    assert!(!read_config(&ctx)?.paused);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_pausing_when_already_paused() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    set_paused(&mut ctx, true)?;
+    set_paused(&mut ctx, true)?;
+    assert!(read_config(&ctx)?.paused);
+
+    Ok(())
+}
+
+#[test]
+fn counter_instructions_fail_while_paused() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let new_owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_paused(&mut ctx, true)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x85", &tx_result);
+
+    let decrement_tx = DecrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(decrement_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x85", &tx_result);
+
+    let get_count_tx = GetCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        owner_kp.pubkey(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(get_count_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x85", &tx_result);
+
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        new_owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x85", &tx_result);
+
+    let new_counter_pk = find_counter_v1_address(&ctx.program_id(), &new_owner_kp.pubkey());
+    assert!(ctx.get_account(new_counter_pk).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn counter_instructions_succeed_after_unpause() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_paused(&mut ctx, true)?;
+    set_paused(&mut ctx, false)?;
+
+    let increment_tx =
+        IncrementCountV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_pause() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let third_party_kp = ctx.create_funded_keypair();
+
+    let set_paused_tx = SetPausedV1SimpleTx::try_new(
+        ctx.program_id(),
+        third_party_kp,
+        true,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_paused_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2007", &tx_result);
+    assert!(!read_config(&ctx)?.paused);
+
+    Ok(())
+}
+
+#[test]
+fn upgrade_authority_cannot_pause() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    let set_paused_tx = SetPausedV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.upgrade_authority_kp(),
+        true,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_paused_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2007", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_not_initialized() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+
+    let set_paused_tx = SetPausedV1SimpleTx::try_new(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        true,
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(set_paused_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2004", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let admin_kp = ctx.admin_kp();
+
+    let instruction = MaliciousSetPausedV1Ix::from_valid(ctx.program_id(), admin_kp.pubkey(), true)
+        .build_with_accounts(vec![]);
+
+    let malicious_tx = MaliciousSetPausedV1Tx::from_valid(
+        ctx.program_id(),
+        admin_kp,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_instruction(instruction)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2001", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_admin_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    let malicious_tx = MaliciousSetPausedV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetPausedV1Ix::with_admin_not_signer)
+    .with_different_signer(fee_payer_kp)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2002", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    let malicious_tx = MaliciousSetPausedV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetPausedV1Ix::with_config_not_writable)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2003", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_passed_as_config() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let admin_kp = ctx.admin_kp();
+
+    initialize_counter(&mut ctx, &admin_kp)?;
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &admin_kp.pubkey());
+
+    let malicious_tx = MaliciousSetPausedV1Tx::from_valid(
+        ctx.program_id(),
+        admin_kp,
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(|ix| ix.with_config_address(counter_pk))
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2005", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_with_truncated_args() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+
+    let malicious_tx = MaliciousSetPausedV1Tx::from_valid(
+        ctx.program_id(),
+        ctx.admin_kp(),
+        true,
+        ctx.latest_blockhash(),
+    )
+    .with_malicious_instruction(MaliciousSetPausedV1Ix::with_truncated_args)
+    .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x2006", &tx_result);
+
+    Ok(())
+}
+
+// ============================================================================
+// Pause Check Tests
+// ============================================================================
+
+#[test]
+fn fails_when_config_account_missing() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let mut instruction =
+        MaliciousIncrementCountV1Ix::from_valid(ctx.program_id(), owner_pk).build();
+    instruction.accounts.clear();
+
+    let malicious_tx =
+        MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x81", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_config_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new_without_config()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    // Before the config exists, any account not owned by the program could stand in for it
+    let mut instruction =
+        MaliciousIncrementCountV1Ix::from_valid(ctx.program_id(), owner_pk).build();
+    instruction
+        .accounts
+        .last_mut()
+        .ok_or("Instruction should take the config account")?
+        .pubkey = Pubkey::new_unique();
+
+    let malicious_tx =
+        MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x82", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_passed_as_config_while_paused() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    set_paused(&mut ctx, true)?;
+
+    // The counter is owned by the program, so it must not pass for an unpaused config
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let mut instruction =
+        MaliciousIncrementCountV1Ix::from_valid(ctx.program_id(), owner_pk).build();
+    instruction
+        .accounts
+        .last_mut()
+        .ok_or("Instruction should take the config account")?
+        .pubkey = counter_pk;
+
+    let malicious_tx =
+        MaliciousIncrementCountV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x83", &tx_result);
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use {
+    crate::{
+        litesvm_utils::demand_tx_success,
+        pinocchio_counter::{TestContext, TestResult},
+    },
+    pinocchio_counter_client::{
+        find_sharded_counter_v1_address,
+        instructions::IncrementCounterShardV1Ix,
+        sharded_counter::{
+            find_counter_shard_v1_addresses, read_sharded_count, shard_index_for_contributor,
+        },
+        transactions::{
+            IncrementCounterShardV1SimpleTx, InitializeShardedCounterV1SimpleTx,
+            ReconcileShardsV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::{CounterShardV1, ShardedCounterV1},
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+    std::collections::HashSet,
+};
+
+const SHARD_COUNT: u8 = 8;
+const CONTRIBUTORS: u64 = 64;
+
+/// Reads the total count of the owner's sharded counter through the client-side aggregate read.
+fn read_total(ctx: &TestContext, owner: &Pubkey) -> Result<u64, Box<dyn std::error::Error>> {
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), owner);
+    let sharded_counter_account = ctx
+        .get_account(sharded_counter_pk)
+        .ok_or("Sharded counter should exist")?;
+
+    let shard_accounts =
+        find_counter_shard_v1_addresses(&ctx.program_id(), &sharded_counter_pk, SHARD_COUNT)
+            .into_iter()
+            .map(|shard_pk| ctx.get_account(shard_pk).ok_or("Shard should exist"))
+            .collect::<Result<Vec<_>, _>>()?;
+    let shard_data = shard_accounts
+        .iter()
+        .map(|account| account.data.as_slice())
+        .collect::<Vec<_>>();
+
+    Ok(read_sharded_count(
+        &sharded_counter_pk,
+        &sharded_counter_account.data,
+        &shard_data,
+    )?)
+}
+
+/// Many contributors increment one sharded counter, each in its own transaction, without
+/// advancing the slot between them.
+///
+/// Each increment write-locks only the contributor's shard, never the sharded counter, so
+/// increments landing on different shards do not contend for a write lock and can be scheduled
+/// in parallel. The aggregate read sees every increment before and after reconciliation.
+#[test]
+fn test_contributors_write_independent_shards() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    let init_tx = InitializeShardedCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        SHARD_COUNT,
+        ctx.latest_blockhash(),
+    )?;
+    demand_tx_success(&ctx.send_transaction(init_tx));
+    ctx.advance_slot(1)?;
+
+    let sharded_counter_pk = find_sharded_counter_v1_address(&ctx.program_id(), &owner_pk);
+    let shards =
+        find_counter_shard_v1_addresses(&ctx.program_id(), &sharded_counter_pk, SHARD_COUNT);
+
+    let contributors: Vec<Keypair> = (0..CONTRIBUTORS)
+        .map(|_| ctx.create_funded_keypair())
+        .collect();
+
+    let mut expected_shard_counts = vec![0u64; usize::from(SHARD_COUNT)];
+    let mut locked_shards = HashSet::new();
+
+    for contributor_kp in &contributors {
+        let contributor_pk = contributor_kp.pubkey();
+        let index = shard_index_for_contributor(&contributor_pk, SHARD_COUNT);
+        expected_shard_counts[usize::from(index)] += 1;
+
+        // The only program account the increment writes is the contributor's shard
+        let instruction =
+            IncrementCounterShardV1Ix::new(ctx.program_id(), owner_pk, contributor_pk, SHARD_COUNT)
+                .to_instruction(true)?;
+        let writable: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .filter(|account| account.is_writable)
+            .map(|account| account.pubkey)
+            .collect();
+        assert_eq!(writable, vec![shards[usize::from(index)]]);
+        assert!(instruction
+            .accounts
+            .iter()
+            .all(|account| account.pubkey != sharded_counter_pk));
+        locked_shards.insert(index);
+
+        let increment_tx = IncrementCounterShardV1SimpleTx::try_new(
+            ctx.program_id(),
+            owner_pk,
+            contributor_kp.insecure_clone(),
+            SHARD_COUNT,
+            ctx.latest_blockhash(),
+        )?;
+        demand_tx_success(&ctx.send_transaction(increment_tx));
+    }
+
+    // Contributors spread over the shards instead of all locking one account
+    assert!(
+        locked_shards.len() > 1,
+        "All {CONTRIBUTORS} contributors were assigned the same shard"
+    );
+
+    for (shard_pk, expected_count) in shards.iter().zip(expected_shard_counts) {
+        let shard_account = ctx.get_account(*shard_pk).ok_or("Shard should exist")?;
+        assert_eq!(
+            CounterShardV1::deserialize(&shard_account.data)?.count,
+            expected_count
+        );
+    }
+
+    let sharded_counter_account = ctx
+        .get_account(sharded_counter_pk)
+        .ok_or("Sharded counter should exist")?;
+    assert_eq!(
+        ShardedCounterV1::deserialize(&sharded_counter_account.data)?.count,
+        0
+    );
+    assert_eq!(read_total(&ctx, &owner_pk)?, CONTRIBUTORS);
+
+    let reconcile_tx = ReconcileShardsV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp,
+        SHARD_COUNT,
+        ctx.latest_blockhash(),
+    )?;
+    let tx_result = ctx.send_transaction(reconcile_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, CONTRIBUTORS);
+
+    assert_eq!(read_total(&ctx, &owner_pk)?, CONTRIBUTORS);
+
+    Ok(())
+}
@@ -0,0 +1,345 @@
+use {
+    crate::{
+        litesvm_utils::{demand_logs_contain, demand_tx_failure, demand_tx_success},
+        pinocchio_counter::{
+            malicious_builders::{MaliciousThawCounterV1Ix, MaliciousThawCounterV1Tx},
+            TestContext, TestResult,
+        },
+    },
+    litesvm::types::TransactionResult,
+    pinocchio_counter_client::{
+        find_counter_v1_address,
+        instructions::ThawCounterV1Ix,
+        transactions::{
+            FreezeCounterV1SimpleTx, IncrementCountV1SimpleTx, InitializeCounterV1SimpleTx,
+            ThawCounterV1SimpleTx,
+        },
+    },
+    pinocchio_counter_program::CounterV2,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_keypair::{Keypair, Signer},
+    solana_message::{v0, VersionedMessage},
+    solana_transaction::versioned::VersionedTransaction,
+};
+
+fn initialize_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let init_counter_tx = InitializeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(init_counter_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+fn freeze_counter(ctx: &mut TestContext, owner_kp: &Keypair) -> TestResult {
+    let freeze_tx = FreezeCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(freeze_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    Ok(())
+}
+
+/// Sends `instruction` in a transaction paid for and signed by `signer_kp` alone.
+fn send_signed_by(
+    ctx: &mut TestContext,
+    instruction: Instruction,
+    signer_kp: &Keypair,
+) -> Result<TransactionResult, Box<dyn std::error::Error>> {
+    let message = VersionedMessage::V0(v0::Message::try_compile(
+        &signer_kp.pubkey(),
+        &[instruction],
+        &[],
+        ctx.latest_blockhash(),
+    )?);
+    let tx = VersionedTransaction::try_new(message, &[signer_kp])?;
+    Ok(ctx.send_transaction(tx))
+}
+
+fn read_counter_v2(
+    ctx: &TestContext,
+    owner_kp: &Keypair,
+) -> Result<CounterV2, Box<dyn std::error::Error>> {
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_account = ctx.get_account(counter_pk).ok_or("Counter should exist")?;
+    Ok(CounterV2::deserialize(&counter_account.data)?)
+}
+
+// ============================================================================
+// Thaw Counter Tests
+// ============================================================================
+
+#[test]
+fn succeeds_thawing_frozen_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let lamports_before = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?
+        .lamports;
+
+    let thaw_tx = ThawCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_success(&tx_result);
+    assert_eq!(ctx.count_return_data(&tx_result)?.count, 0);
+
+    let counter_account = ctx
+        .get_account(counter_pk)
+        .ok_or("Counter account should exist")?;
+    assert_eq!(counter_account.data.len(), CounterV2::size());
+    assert_eq!(counter_account.lamports, lamports_before);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert!(!counter.frozen);
+
+    Ok(())
+}
+
+#[test]
+fn increment_succeeds_after_thaw() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let thaw_tx = ThawCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let increment_tx = IncrementCountV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(increment_tx);
+    demand_tx_success(&tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert_eq!(counter.count, 1);
+
+    Ok(())
+}
+
+#[test]
+fn succeeds_freezing_again_after_thaw() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let thaw_tx = ThawCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert!(counter.frozen);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_v1_not_frozen() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+
+    let thaw_tx =
+        ThawCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e0a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_already_thawed() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let thaw_tx = ThawCounterV1SimpleTx::try_new(
+        ctx.program_id(),
+        owner_kp.insecure_clone(),
+        ctx.latest_blockhash(),
+    )?;
+
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_success(&tx_result);
+
+    ctx.advance_slot(1)?;
+
+    let thaw_tx =
+        ThawCounterV1SimpleTx::try_new(ctx.program_id(), owner_kp, ctx.latest_blockhash())?;
+
+    let tx_result = ctx.send_transaction(thaw_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e0a", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn third_party_cannot_thaw_counter() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let third_party_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let instruction = ThawCounterV1Ix::new(ctx.program_id(), owner_kp.pubkey())
+        .with_authority(third_party_kp.pubkey())
+        .to_instruction(true)?;
+
+    let tx_result = send_signed_by(&mut ctx, instruction, &third_party_kp)?;
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e09", &tx_result);
+
+    let counter = read_counter_v2(&ctx, &owner_kp)?;
+    assert!(counter.frozen);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_not_enough_accounts() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let owner_pk = owner_kp.pubkey();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_ix = MaliciousThawCounterV1Ix::from_valid(ctx.program_id(), owner_pk);
+    let instruction = malicious_ix.build_with_accounts(vec![
+        AccountMeta {
+            pubkey: owner_pk,
+            is_signer: true,
+            is_writable: false,
+        },
+        // Missing counter - only 1 account instead of 2
+    ]);
+
+    let malicious_tx =
+        MaliciousThawCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_instruction(instruction)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e01", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_owner_not_signer() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+    let fee_payer_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousThawCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousThawCounterV1Ix::with_owner_not_signer)
+            .with_different_signer(fee_payer_kp)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e02", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_not_writable() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    let malicious_tx =
+        MaliciousThawCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(MaliciousThawCounterV1Ix::with_counter_not_writable)
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e03", &tx_result);
+
+    Ok(())
+}
+
+#[test]
+fn fails_when_counter_address_mismatch() -> TestResult {
+    let mut ctx = TestContext::try_new()?;
+    let owner_kp = ctx.create_funded_keypair();
+
+    initialize_counter(&mut ctx, &owner_kp)?;
+    freeze_counter(&mut ctx, &owner_kp)?;
+
+    // A genuine frozen counter stored at an address that is not derived from its creator
+    let counter_pk = find_counter_v1_address(&ctx.program_id(), &owner_kp.pubkey());
+    let counter_copy_pk = ctx.copy_account_to_unique_address(counter_pk)?;
+
+    let malicious_tx =
+        MaliciousThawCounterV1Tx::from_valid(ctx.program_id(), owner_kp, ctx.latest_blockhash())
+            .with_malicious_instruction(|ix| ix.with_counter_address(counter_copy_pk))
+            .build();
+
+    let tx_result = ctx.send_transaction(malicious_tx);
+    demand_tx_failure(&tx_result);
+    demand_logs_contain("failed: custom program error: 0x1e04", &tx_result);
+
+    Ok(())
+}